@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Serde adapter for `#[field(response, serialize_as_string)]`.
+//!
+//! JavaScript numbers are doubles, so an `i64` id above 2^53 silently loses
+//! precision in a browser. A field marked `serialize_as_string` keeps its
+//! Rust type in the Response DTO but goes over the wire as a JSON string:
+//!
+//! ```rust,ignore
+//! #[id]
+//! #[field(response, serialize_as_string)]
+//! pub id: i64,
+//! // {"id": "9007199254740993"}
+//! ```
+//!
+//! Values are written with `Display` and read back with `FromStr`, so the
+//! adapter works for any type implementing both. [`option`] handles
+//! `Option<T>` fields.
+
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+/// Serialize a value as its `Display` string.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer
+{
+    serializer.collect_str(value)
+}
+
+/// Deserialize a value from a string with `FromStr`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>
+{
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(D::Error::custom)
+}
+
+/// The same adapter for `Option<T>`; `None` stays `null`.
+pub mod option {
+    use std::{fmt::Display, str::FromStr};
+
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    /// Serialize `Some` as its `Display` string and `None` as `null`.
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer
+    {
+        match value {
+            Some(value) => serializer.collect_str(value),
+            None => serializer.serialize_none()
+        }
+    }
+
+    /// Deserialize an optional string with `FromStr`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| value.parse().map_err(D::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Dto {
+        #[serde(with = "super")]
+        id:        i64,
+        #[serde(with = "super::option")]
+        parent_id: Option<i64>
+    }
+
+    #[test]
+    fn round_trips_through_strings() {
+        let dto = Dto {
+            id:        9_007_199_254_740_993,
+            parent_id: Some(7)
+        };
+        let json = serde_json::to_string(&dto).unwrap();
+        assert_eq!(json, r#"{"id":"9007199254740993","parent_id":"7"}"#);
+        assert_eq!(serde_json::from_str::<Dto>(&json).unwrap(), dto);
+    }
+
+    #[test]
+    fn none_stays_null() {
+        let dto = Dto {
+            id:        1,
+            parent_id: None
+        };
+        let json = serde_json::to_string(&dto).unwrap();
+        assert_eq!(json, r#"{"id":"1","parent_id":null}"#);
+        assert_eq!(serde_json::from_str::<Dto>(&json).unwrap(), dto);
+    }
+
+    #[test]
+    fn rejects_invalid_numbers() {
+        assert!(serde_json::from_str::<Dto>(r#"{"id":"x","parent_id":null}"#).is_err());
+    }
+}
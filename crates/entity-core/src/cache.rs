@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Cache-aside support for entity-derive.
+//!
+//! Entities with `#[entity(cache)]` get `find_by_id_cached`, `update_cached`
+//! and `delete_cached` repository methods that read through and invalidate
+//! any [`EntityCache`] implementation — Redis, moka, or a plain map.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! struct MokaCache(moka::future::Cache<String, User>);
+//!
+//! #[async_trait]
+//! impl EntityCache<User> for MokaCache {
+//!     async fn get(&self, key: &str) -> Option<User> {
+//!         self.0.get(key).await
+//!     }
+//!
+//!     async fn set(&self, key: &str, value: &User) {
+//!         self.0.insert(key.to_string(), value.clone()).await;
+//!     }
+//!
+//!     async fn del(&self, key: &str) {
+//!         self.0.invalidate(key).await;
+//!     }
+//! }
+//!
+//! let user = pool.find_by_id_cached(&cache, id).await?;
+//! ```
+
+use std::fmt::Display;
+
+use async_trait::async_trait;
+
+/// Key-value cache for entities of type `T`.
+///
+/// Caching is best-effort: implementations swallow their own failures, so a
+/// cache outage degrades to plain database reads instead of failing requests.
+#[async_trait]
+pub trait EntityCache<T: Send + Sync>: Send + Sync {
+    /// Get a cached entity, or `None` on a miss.
+    async fn get(&self, key: &str) -> Option<T>;
+
+    /// Store an entity under `key`.
+    async fn set(&self, key: &str, value: &T);
+
+    /// Remove the entity stored under `key`.
+    async fn del(&self, key: &str);
+}
+
+/// Build the cache key for an entity: `{table}:{id}`.
+pub fn cache_key(table: &str, id: &impl Display) -> String {
+    format!("{}:{}", table, id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_key_joins_table_and_id() {
+        assert_eq!(cache_key("public.users", &42), "public.users:42");
+    }
+
+    #[test]
+    fn cache_key_uses_display() {
+        assert_eq!(cache_key("users", &"abc"), "users:abc");
+    }
+}
@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Field-level encryption for entity-derive.
+//!
+//! Columns marked `#[column(encrypt)]` are stored as ciphertext. The
+//! generated `{Entity}EncryptedRepository` passes them through an
+//! [`Encryptor`] before every write and after every read, so the rest of the
+//! application only ever sees plaintext.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! struct Aes(aes_gcm::Aes256Gcm);
+//!
+//! impl Encryptor for Aes {
+//!     fn encrypt(&self, plaintext: &str) -> Result<String, CryptoError> {
+//!         // nonce + ciphertext, base64-encoded
+//!     }
+//!
+//!     fn decrypt(&self, ciphertext: &str) -> Result<String, CryptoError> {
+//!         // reverse of encrypt
+//!     }
+//! }
+//!
+//! let users = UserEncryptedRepository::new(pool, Aes(cipher));
+//! ```
+
+/// Error returned by an [`Encryptor`].
+pub type CryptoError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Encrypts and decrypts text column values.
+///
+/// Ciphertext is stored in the same text column as the plaintext would be,
+/// so implementations should return a text-safe encoding such as base64.
+pub trait Encryptor: Send + Sync {
+    /// Encrypt a plaintext value before it is written.
+    fn encrypt(&self, plaintext: &str) -> Result<String, CryptoError>;
+
+    /// Decrypt a stored value after it is read.
+    fn decrypt(&self, ciphertext: &str) -> Result<String, CryptoError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Reverse;
+
+    impl Encryptor for Reverse {
+        fn encrypt(&self, plaintext: &str) -> Result<String, CryptoError> {
+            Ok(plaintext.chars().rev().collect())
+        }
+
+        fn decrypt(&self, ciphertext: &str) -> Result<String, CryptoError> {
+            if ciphertext.is_empty() {
+                return Err("empty ciphertext".into());
+            }
+            Ok(ciphertext.chars().rev().collect())
+        }
+    }
+
+    #[test]
+    fn encryptor_round_trip() {
+        let enc: &dyn Encryptor = &Reverse;
+        let ciphertext = enc.encrypt("secret").unwrap();
+        assert_eq!(ciphertext, "terces");
+        assert_eq!(enc.decrypt(&ciphertext).unwrap(), "secret");
+    }
+
+    #[test]
+    fn encryptor_error_is_boxed() {
+        let err = Reverse.decrypt("").unwrap_err();
+        assert_eq!(err.to_string(), "empty ciphertext");
+    }
+}
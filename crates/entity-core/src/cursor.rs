@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Opaque cursor tokens for keyset pagination.
+//!
+//! With `#[entity(cursor = "created_at, id")]`, the generated
+//! `{Entity}Cursor` holds the ordering columns of the last row on a page.
+//! [`Cursor::encode`] turns it into an opaque, URL-safe token for clients,
+//! and [`Cursor::decode`] reads the token back on the next request:
+//!
+//! ```rust,ignore
+//! let page = repo.list_after(None, 20).await?;
+//! let token = page.next_cursor.map(|c| c.encode());
+//!
+//! // next request
+//! let cursor = token.as_deref().map(PostCursor::decode).transpose()?;
+//! let page = repo.list_after(cursor, 20).await?;
+//! ```
+//!
+//! Tokens are base64 (URL-safe, unpadded) of the cursor's JSON encoding.
+//! They are not signed: clients can forge a token, but it only moves the
+//! page boundary, never widens the query.
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Error returned by [`Cursor::decode`] for a malformed token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CursorError {
+    /// The token is not URL-safe base64.
+    Encoding,
+
+    /// The decoded token is not a cursor of the expected type.
+    Payload(String)
+}
+
+impl std::fmt::Display for CursorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Encoding => write!(f, "invalid cursor: not base64"),
+            Self::Payload(msg) => write!(f, "invalid cursor: {}", msg)
+        }
+    }
+}
+
+impl std::error::Error for CursorError {}
+
+/// Keyset pagination cursor with an opaque string form.
+///
+/// Implemented by generated `{Entity}Cursor` structs; any serde type can
+/// opt in with an empty impl.
+///
+/// # Example
+///
+/// ```rust
+/// use entity_core::cursor::Cursor;
+///
+/// #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+/// struct PostCursor {
+///     created_at: i64,
+///     id:         u32
+/// }
+///
+/// impl Cursor for PostCursor {}
+///
+/// let cursor = PostCursor {
+///     created_at: 1700000000,
+///     id:         7
+/// };
+/// let token = cursor.encode();
+/// assert_eq!(PostCursor::decode(&token).unwrap(), cursor);
+/// ```
+pub trait Cursor: Serialize + DeserializeOwned {
+    /// Encode the cursor as an opaque URL-safe token.
+    fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor serializes to JSON");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// Decode a token produced by [`encode`](Cursor::encode).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CursorError`] if the token is not base64 or does not hold
+    /// a cursor of this type.
+    fn decode(token: &str) -> Result<Self, CursorError> {
+        let json = URL_SAFE_NO_PAD
+            .decode(token)
+            .map_err(|_| CursorError::Encoding)?;
+        serde_json::from_slice(&json).map_err(|e| CursorError::Payload(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct TestCursor {
+        name: String,
+        id:   i64
+    }
+
+    impl Cursor for TestCursor {}
+
+    #[test]
+    fn round_trips() {
+        let cursor = TestCursor {
+            name: "a/b+c?".to_string(),
+            id:   42
+        };
+        let token = cursor.encode();
+        assert!(
+            token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+        assert_eq!(TestCursor::decode(&token).unwrap(), cursor);
+    }
+
+    #[test]
+    fn rejects_non_base64() {
+        assert_eq!(
+            TestCursor::decode("not base64!"),
+            Err(CursorError::Encoding)
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_payload() {
+        let token = URL_SAFE_NO_PAD.encode(b"[1,2]");
+        let err = TestCursor::decode(&token).unwrap_err();
+        assert!(matches!(err, CursorError::Payload(_)));
+        assert!(err.to_string().starts_with("invalid cursor: "));
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Serde adapter for `Option<Option<T>>` update fields.
+//!
+//! With `#[entity(double_option_updates)]`, a nullable field is
+//! `Option<Option<T>>` in the Update DTO:
+//!
+//! | JSON | Value | Update |
+//! |------|-------|--------|
+//! | key absent | `None` | Column unchanged |
+//! | `null` | `Some(None)` | Column set to `NULL` |
+//! | value | `Some(Some(v))` | Column set to `v` |
+//!
+//! Plain serde reads both an absent key and `null` as `None`. The generated
+//! field is marked `#[serde(default, skip_serializing_if =
+//! "Option::is_none", with = "entity_derive::double_option")]`, so `null`
+//! survives a round trip as `Some(None)`.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialize the inner option: `Some(None)` as `null`, `Some(Some(v))` as
+/// `v`.
+///
+/// `None` is expected to be skipped with `skip_serializing_if`; if it is
+/// not, it is written as `null` too.
+pub fn serialize<T, S>(value: &Option<Option<T>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer
+{
+    match value {
+        Some(Some(value)) => value.serialize(serializer),
+        _ => serializer.serialize_none()
+    }
+}
+
+/// Deserialize a present key: `null` as `Some(None)`, a value as
+/// `Some(Some(v))`.
+///
+/// An absent key never reaches this function; `#[serde(default)]` makes it
+/// `None`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Patch {
+        #[serde(default, skip_serializing_if = "Option::is_none", with = "super")]
+        bio: Option<Option<String>>
+    }
+
+    #[test]
+    fn absent_null_and_value_are_distinct() {
+        let absent: Patch = serde_json::from_str("{}").unwrap();
+        let null: Patch = serde_json::from_str(r#"{"bio":null}"#).unwrap();
+        let value: Patch = serde_json::from_str(r#"{"bio":"hi"}"#).unwrap();
+        assert_eq!(absent.bio, None);
+        assert_eq!(null.bio, Some(None));
+        assert_eq!(value.bio, Some(Some("hi".to_string())));
+    }
+
+    #[test]
+    fn round_trips() {
+        for patch in [
+            Patch {
+                bio: None
+            },
+            Patch {
+                bio: Some(None)
+            },
+            Patch {
+                bio: Some(Some("hi".to_string()))
+            }
+        ] {
+            let json = serde_json::to_string(&patch).unwrap();
+            assert_eq!(serde_json::from_str::<Patch>(&json).unwrap(), patch);
+        }
+        assert_eq!(
+            serde_json::to_string(&Patch {
+                bio: None
+            })
+            .unwrap(),
+            "{}"
+        );
+        assert_eq!(
+            serde_json::to_string(&Patch {
+                bio: Some(None)
+            })
+            .unwrap(),
+            r#"{"bio":null}"#
+        );
+    }
+}
@@ -0,0 +1,228 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Classification of database errors for custom error types.
+//!
+//! `#[entity(error = "AppError")]` requires `AppError: From<sqlx::Error>`.
+//! Most applications want that conversion to tell a missing row from a
+//! unique or foreign key violation, and every one of them ends up writing
+//! the same `match` over `sqlx::Error`. [`classify_sqlx_error`] does that
+//! once and returns an [`ErrorClass`] the `From` impl can delegate to.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use entity_core::error::{ErrorClass, FromSqlxError};
+//!
+//! impl FromSqlxError for AppError {
+//!     fn from_sqlx(class: ErrorClass, error: sqlx::Error) -> Self {
+//!         match class {
+//!             ErrorClass::NotFound => AppError::NotFound,
+//!             ErrorClass::Conflict => AppError::Conflict(error.to_string()),
+//!             ErrorClass::ForeignKey => AppError::BadReference,
+//!             ErrorClass::Other => AppError::Database(error)
+//!         }
+//!     }
+//! }
+//!
+//! // Generates `impl From<sqlx::Error> for AppError` and
+//! // `impl NotFoundError for AppError`.
+//! entity_core::impl_from_sqlx_error!(AppError);
+//! ```
+//!
+//! [`NotFoundError`] lets generated code build a "not found" error for any
+//! repository error type, e.g. in `find_by_id_or_error`.
+//! [`RetryableError`] tells `Transaction::run_retrying` which errors are
+//! worth another attempt.
+
+/// Coarse category of a database error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// The query expected a row and found none.
+    NotFound,
+
+    /// A unique constraint was violated.
+    Conflict,
+
+    /// A foreign key constraint was violated.
+    ForeignKey,
+
+    /// Any other error.
+    Other
+}
+
+/// Error types with a "not found" value.
+///
+/// Generated repositories use it for `find_by_id_or_error`. Implemented for
+/// `sqlx::Error` (as `RowNotFound`) and by
+/// [`impl_from_sqlx_error!`](crate::impl_from_sqlx_error); implement it by
+/// hand for other error types.
+pub trait NotFoundError {
+    /// Build the error reported when an entity does not exist.
+    fn not_found() -> Self;
+}
+
+#[cfg(feature = "postgres")]
+impl NotFoundError for sqlx::Error {
+    fn not_found() -> Self {
+        sqlx::Error::RowNotFound
+    }
+}
+
+/// Error types that can report a transient transaction conflict.
+///
+/// `Transaction::run_retrying` re-runs its closure when the error is
+/// retryable. Implemented for `sqlx::Error` through
+/// [`is_serialization_failure`]; implement it by hand for custom error
+/// types, usually by delegating to the wrapped `sqlx::Error`:
+///
+/// ```rust,ignore
+/// impl RetryableError for AppError {
+///     fn is_retryable(&self) -> bool {
+///         matches!(self, AppError::Database(error) if error.is_retryable())
+///     }
+/// }
+/// ```
+pub trait RetryableError {
+    /// Check if the failed transaction can be run again.
+    fn is_retryable(&self) -> bool;
+}
+
+#[cfg(feature = "postgres")]
+impl RetryableError for sqlx::Error {
+    fn is_retryable(&self) -> bool {
+        is_serialization_failure(self)
+    }
+}
+
+/// Check if a [`sqlx::Error`] is a PostgreSQL serialization failure
+/// (`40001`) or deadlock (`40P01`).
+///
+/// Both abort the transaction and are expected to succeed when the whole
+/// transaction is retried.
+#[cfg(feature = "postgres")]
+pub fn is_serialization_failure(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::Database(db) => matches!(db.code().as_deref(), Some("40001" | "40P01")),
+        _ => false
+    }
+}
+
+/// Classify a [`sqlx::Error`] into an [`ErrorClass`].
+///
+/// Constraint violations are detected through the driver's error kind, so
+/// the result is the same for every database sqlx supports.
+#[cfg(feature = "postgres")]
+pub fn classify_sqlx_error(error: &sqlx::Error) -> ErrorClass {
+    use sqlx::error::ErrorKind;
+
+    match error {
+        sqlx::Error::RowNotFound => ErrorClass::NotFound,
+        sqlx::Error::Database(db) => match db.kind() {
+            ErrorKind::UniqueViolation => ErrorClass::Conflict,
+            ErrorKind::ForeignKeyViolation => ErrorClass::ForeignKey,
+            _ => ErrorClass::Other
+        },
+        _ => ErrorClass::Other
+    }
+}
+
+/// Marker for error types whose `From<sqlx::Error>` is generated by
+/// [`impl_from_sqlx_error!`](crate::impl_from_sqlx_error).
+#[cfg(feature = "postgres")]
+pub trait FromSqlxError {
+    /// Build the error from its classification and the original error.
+    fn from_sqlx(class: ErrorClass, error: sqlx::Error) -> Self;
+}
+
+/// Generate `impl From<sqlx::Error>` for a type implementing
+/// [`FromSqlxError`](crate::error::FromSqlxError).
+///
+/// Also implements [`NotFoundError`](crate::error::NotFoundError) as the
+/// conversion of [`ErrorClass::NotFound`].
+///
+/// Invoke once per error type, not per entity: several entities sharing
+/// `error = "AppError"` all use the same impl.
+#[cfg(feature = "postgres")]
+#[macro_export]
+macro_rules! impl_from_sqlx_error {
+    ($ty:ty) => {
+        impl ::core::convert::From<sqlx::Error> for $ty {
+            fn from(error: sqlx::Error) -> Self {
+                let class = $crate::error::classify_sqlx_error(&error);
+                <$ty as $crate::error::FromSqlxError>::from_sqlx(class, error)
+            }
+        }
+
+        impl $crate::error::NotFoundError for $ty {
+            fn not_found() -> Self {
+                <$ty as $crate::error::FromSqlxError>::from_sqlx(
+                    $crate::error::ErrorClass::NotFound,
+                    sqlx::Error::RowNotFound
+                )
+            }
+        }
+    };
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum AppError {
+        NotFound,
+        Other(String)
+    }
+
+    impl FromSqlxError for AppError {
+        fn from_sqlx(class: ErrorClass, error: sqlx::Error) -> Self {
+            match class {
+                ErrorClass::NotFound => AppError::NotFound,
+                _ => AppError::Other(error.to_string())
+            }
+        }
+    }
+
+    crate::impl_from_sqlx_error!(AppError);
+
+    #[test]
+    fn row_not_found_is_not_found() {
+        assert_eq!(
+            classify_sqlx_error(&sqlx::Error::RowNotFound),
+            ErrorClass::NotFound
+        );
+    }
+
+    #[test]
+    fn non_database_errors_are_other() {
+        assert_eq!(
+            classify_sqlx_error(&sqlx::Error::PoolTimedOut),
+            ErrorClass::Other
+        );
+    }
+
+    #[test]
+    fn generated_from_delegates_to_classification() {
+        assert_eq!(AppError::from(sqlx::Error::RowNotFound), AppError::NotFound);
+        assert!(matches!(
+            AppError::from(sqlx::Error::PoolClosed),
+            AppError::Other(_)
+        ));
+    }
+
+    #[test]
+    fn non_database_errors_are_not_retryable() {
+        assert!(!sqlx::Error::RowNotFound.is_retryable());
+        assert!(!is_serialization_failure(&sqlx::Error::PoolTimedOut));
+    }
+
+    #[test]
+    fn not_found_errors() {
+        assert!(matches!(
+            <sqlx::Error as NotFoundError>::not_found(),
+            sqlx::Error::RowNotFound
+        ));
+        assert_eq!(AppError::not_found(), AppError::NotFound);
+    }
+}
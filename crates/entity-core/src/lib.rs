@@ -9,7 +9,17 @@
 //! # Overview
 //!
 //! - [`Repository`] — Base trait for all generated repository traits
+//! - [`Entity`] — Table metadata implemented by every entity
 //! - [`Pagination`] — Common pagination parameters
+//! - [`Page`] — Keyset page with `has_next` and next cursor
+//! - [`cursor::Cursor`] — Opaque base64 tokens for `#[entity(cursor)]`
+//! - [`cache::EntityCache`] — Pluggable cache for cache-aside reads
+//! - [`crypto::Encryptor`] — Field-level encryption for `#[column(encrypt)]`
+//! - [`error::ErrorClass`] — Database error classification for custom error
+//!   types
+//! - [`mask`] — Built-in masks for `#[field(response, mask = "...")]`
+//! - [`as_string`] — Serde adapter for `#[field(serialize_as_string)]`
+//! - [`double_option`] — Serde adapter for `Option<Option<T>>` update fields
 //! - [`prelude`] — Convenient re-exports
 //!
 //! # Usage
@@ -31,6 +41,16 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+#[cfg(feature = "serde")]
+pub mod as_string;
+pub mod cache;
+pub mod crypto;
+#[cfg(feature = "serde")]
+pub mod cursor;
+#[cfg(feature = "serde")]
+pub mod double_option;
+pub mod error;
+pub mod mask;
 pub mod policy;
 pub mod prelude;
 #[cfg(feature = "streams")]
@@ -40,11 +60,30 @@ pub mod transaction;
 /// Re-export async_trait for generated code.
 pub use async_trait::async_trait;
 
+/// Re-export futures for generated stream code.
+#[cfg(feature = "streams")]
+pub use futures;
+
 /// Base repository trait.
 ///
 /// All generated `{Entity}Repository` traits include these associated types
-/// and methods. This trait is not directly extended but serves as documentation
-/// for the common interface.
+/// and methods. This trait is not directly extended by them, since each
+/// entity may pick its own error type.
+///
+/// With the `postgres` feature, `sqlx::PgPool` — the type generated
+/// repositories are implemented on — implements this trait with
+/// `Error = sqlx::Error`, so it can be used as a bound in generic code:
+///
+/// ```rust,ignore
+/// async fn with_tx<R>(repo: &R) -> Result<(), sqlx::Error>
+/// where
+///     R: Repository<Pool = PgPool> + UserRepository,
+/// {
+///     let mut tx = Repository::pool(repo).begin().await?;
+///     // ...
+///     tx.commit().await
+/// }
+/// ```
 ///
 /// # Associated Types
 ///
@@ -92,6 +131,60 @@ pub trait Repository: Send + Sync {
     fn pool(&self) -> &Self::Pool;
 }
 
+#[cfg(feature = "postgres")]
+impl Repository for sqlx::PgPool {
+    type Error = sqlx::Error;
+    type Pool = sqlx::PgPool;
+
+    fn pool(&self) -> &Self::Pool {
+        self
+    }
+}
+
+/// Table metadata of an entity.
+///
+/// Every `#[derive(Entity)]` struct implements this trait, so generic code
+/// such as migration runners or introspection tools can work with any
+/// entity without knowing its generated types.
+///
+/// # Example
+///
+/// ```rust
+/// use entity_core::Entity;
+///
+/// struct User;
+///
+/// impl Entity for User {
+///     type Id = i64;
+///     const TABLE: &'static str = "users";
+///     const SCHEMA: &'static str = "public";
+///     const COLUMNS: &'static [&'static str] = &["id", "name"];
+/// }
+///
+/// fn describe<E: Entity>() -> String {
+///     format!("{}.{} ({})", E::SCHEMA, E::TABLE, E::COLUMNS.join(", "))
+/// }
+///
+/// assert_eq!(describe::<User>(), "public.users (id, name)");
+/// ```
+pub trait Entity {
+    /// Type of the `#[id]` field.
+    type Id;
+
+    /// Table name, without schema.
+    const TABLE: &'static str;
+
+    /// Schema the table lives in.
+    const SCHEMA: &'static str;
+
+    /// Stored column names in field order.
+    ///
+    /// Renamed fields are listed under their column name. Computed
+    /// `#[field(returning = "...")]` fields have no column and are not
+    /// listed.
+    const COLUMNS: &'static [&'static str];
+}
+
 /// Pagination parameters for list operations.
 ///
 /// Used by `list` and `query` methods to control result pagination.
@@ -148,8 +241,77 @@ impl Pagination {
             offset: page * per_page
         }
     }
+
+    /// Check that neither limit nor offset is negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use entity_core::{Pagination, PaginationError};
+    ///
+    /// assert!(Pagination::new(10, 0).validate().is_ok());
+    /// assert_eq!(
+    ///     Pagination::new(-1, 0).validate(),
+    ///     Err(PaginationError::NegativeLimit(-1))
+    /// );
+    /// ```
+    pub const fn validate(&self) -> Result<(), PaginationError> {
+        if self.limit < 0 {
+            return Err(PaginationError::NegativeLimit(self.limit));
+        }
+        if self.offset < 0 {
+            return Err(PaginationError::NegativeOffset(self.offset));
+        }
+        Ok(())
+    }
+
+    /// Bound the limit to `1..=max_limit` and the offset to `0..`.
+    ///
+    /// Matches the clamping done by generated list handlers, so values from
+    /// untrusted input can be passed straight to `list`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use entity_core::Pagination;
+    ///
+    /// let page = Pagination::new(5000, -3).clamp(100);
+    /// assert_eq!(page, Pagination::new(100, 0));
+    /// ```
+    pub const fn clamp(self, max_limit: i64) -> Self {
+        let limit = if self.limit > max_limit {
+            max_limit
+        } else {
+            self.limit
+        };
+        Self {
+            limit:  if limit < 1 { 1 } else { limit },
+            offset: if self.offset < 0 { 0 } else { self.offset }
+        }
+    }
 }
 
+/// Error returned by [`Pagination::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaginationError {
+    /// `limit` was below zero.
+    NegativeLimit(i64),
+
+    /// `offset` was below zero.
+    NegativeOffset(i64)
+}
+
+impl std::fmt::Display for PaginationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NegativeLimit(limit) => write!(f, "negative pagination limit: {}", limit),
+            Self::NegativeOffset(offset) => write!(f, "negative pagination offset: {}", offset)
+        }
+    }
+}
+
+impl std::error::Error for PaginationError {}
+
 impl Default for Pagination {
     fn default() -> Self {
         Self {
@@ -159,6 +321,63 @@ impl Default for Pagination {
     }
 }
 
+/// One page of keyset-paginated results.
+///
+/// Produced by generated `list_page` methods, which fetch `limit + 1` rows
+/// and trim the extra one to learn whether another page exists.
+///
+/// # Example
+///
+/// ```rust
+/// use entity_core::Page;
+///
+/// let page = Page::from_overfetch(vec![5, 4, 3], 2, |n| *n);
+/// assert_eq!(page.items, vec![5, 4]);
+/// assert!(page.has_next);
+/// assert_eq!(page.next_cursor, Some(4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T, C> {
+    /// Items on this page, at most `limit`.
+    pub items: Vec<T>,
+
+    /// Whether more items exist after this page.
+    pub has_next: bool,
+
+    /// Cursor to pass for the next page, `None` on the last page.
+    pub next_cursor: Option<C>
+}
+
+impl<T, C> Page<T, C> {
+    /// Build a page from `limit + 1` fetched rows.
+    ///
+    /// Keeps the first `limit` items. If an extra item was fetched,
+    /// `has_next` is `true` and `next_cursor` is taken from the last kept
+    /// item.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` — Rows fetched with `LIMIT limit + 1`
+    /// * `limit` — Requested page size
+    /// * `cursor` — Extracts the cursor value from an item
+    pub fn from_overfetch(mut items: Vec<T>, limit: i64, cursor: impl Fn(&T) -> C) -> Self {
+        let limit = usize::try_from(limit).unwrap_or(0);
+        let has_next = items.len() > limit;
+        items.truncate(limit);
+        let next_cursor = if has_next {
+            items.last().map(cursor)
+        } else {
+            None
+        };
+
+        Self {
+            items,
+            has_next,
+            next_cursor
+        }
+    }
+}
+
 /// Sort direction for ordered queries.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SortDirection {
@@ -287,10 +506,37 @@ pub trait EntityCommand: Send + Sync + std::fmt::Debug {
     fn name(&self) -> &'static str;
 }
 
+/// Error returned when parsing an unknown command name.
+///
+/// Generated `{Entity}CommandKind` enums use this as their `FromStr` error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCommand(pub String);
+
+impl std::fmt::Display for UnknownCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown command: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownCommand {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn pg_pool_implements_repository() {
+        fn assert_repository<R: Repository<Pool = sqlx::PgPool, Error = sqlx::Error>>() {}
+        assert_repository::<sqlx::PgPool>();
+    }
+
+    #[test]
+    fn unknown_command_display() {
+        let err = UnknownCommand("Explode".to_string());
+        assert_eq!(err.to_string(), "unknown command: Explode");
+    }
+
     #[test]
     fn pagination_new() {
         let p = Pagination::new(50, 100);
@@ -312,6 +558,51 @@ mod tests {
         assert_eq!(p.offset, 0);
     }
 
+    #[test]
+    fn pagination_validate() {
+        assert_eq!(Pagination::new(0, 0).validate(), Ok(()));
+        assert_eq!(
+            Pagination::new(-5, 0).validate(),
+            Err(PaginationError::NegativeLimit(-5))
+        );
+        assert_eq!(
+            Pagination::new(10, -1).validate(),
+            Err(PaginationError::NegativeOffset(-1))
+        );
+    }
+
+    #[test]
+    fn pagination_clamp() {
+        assert_eq!(
+            Pagination::new(500, 20).clamp(100),
+            Pagination::new(100, 20)
+        );
+        assert_eq!(Pagination::new(-5, -1).clamp(100), Pagination::new(1, 0));
+        assert_eq!(Pagination::new(50, 0).clamp(100), Pagination::new(50, 0));
+    }
+
+    #[test]
+    fn pagination_error_display() {
+        let err = PaginationError::NegativeOffset(-2);
+        assert_eq!(err.to_string(), "negative pagination offset: -2");
+    }
+
+    #[test]
+    fn page_from_overfetch_has_next() {
+        let page = Page::from_overfetch(vec![10, 9, 8], 2, |n| *n);
+        assert_eq!(page.items, vec![10, 9]);
+        assert!(page.has_next);
+        assert_eq!(page.next_cursor, Some(9));
+    }
+
+    #[test]
+    fn page_from_overfetch_last_page() {
+        let page = Page::from_overfetch(vec![10, 9], 2, |n| *n);
+        assert_eq!(page.items, vec![10, 9]);
+        assert!(!page.has_next);
+        assert_eq!(page.next_cursor, None);
+    }
+
     #[test]
     fn sort_direction_sql() {
         assert_eq!(SortDirection::Asc.as_sql(), "ASC");
@@ -0,0 +1,87 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Built-in masking strategies for `#[field(response, mask = "...")]`.
+//!
+//! A masked field stays in the Response DTO, but the entity-to-response
+//! mappers pass its value through a mask first.
+//!
+//! | Strategy | Input | Output |
+//! |----------|-------|--------|
+//! | `last4` | `4111111111111234` | `****1234` |
+//! | `email` | `john.doe@example.com` | `j***@example.com` |
+//! | `redact` | anything | `[REDACTED]` |
+//!
+//! Any other value of `mask` is taken as a path to a user function with the
+//! same signature as these, `fn(&str) -> String`.
+
+/// Replacement text used by [`redact`].
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Keep only the last four characters: `****1234`.
+///
+/// Values of four characters or fewer are fully masked. The prefix has a
+/// fixed width so the original length is not revealed.
+pub fn last4(value: &str) -> String {
+    let len = value.chars().count();
+    if len <= 4 {
+        return "****".to_string();
+    }
+    let tail: String = value.chars().skip(len - 4).collect();
+    format!("****{}", tail)
+}
+
+/// Keep the first character of the local part and the domain:
+/// `j***@example.com`.
+///
+/// Values without `@` are redacted.
+pub fn email(value: &str) -> String {
+    match value.split_once('@') {
+        Some((local, domain)) => {
+            let first: String = local.chars().take(1).collect();
+            format!("{}***@{}", first, domain)
+        }
+        None => redact(value)
+    }
+}
+
+/// Replace the whole value with [`REDACTED`].
+pub fn redact(_value: &str) -> String {
+    REDACTED.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last4_keeps_tail() {
+        assert_eq!(last4("4111111111111234"), "****1234");
+    }
+
+    #[test]
+    fn last4_short_value_fully_masked() {
+        assert_eq!(last4("123"), "****");
+        assert_eq!(last4("1234"), "****");
+    }
+
+    #[test]
+    fn last4_counts_chars_not_bytes() {
+        assert_eq!(last4("ключ-åäöü"), "****åäöü");
+    }
+
+    #[test]
+    fn email_keeps_first_char_and_domain() {
+        assert_eq!(email("john.doe@example.com"), "j***@example.com");
+    }
+
+    #[test]
+    fn email_without_at_is_redacted() {
+        assert_eq!(email("not-an-email"), REDACTED);
+    }
+
+    #[test]
+    fn redact_hides_everything() {
+        assert_eq!(redact("secret"), "[REDACTED]");
+    }
+}
@@ -9,13 +9,20 @@
 //! use entity_core::prelude::*;
 //! ```
 
+#[cfg(feature = "serde")]
+pub use crate::cursor::{Cursor, CursorError};
+#[cfg(feature = "postgres")]
+pub use crate::error::{FromSqlxError, classify_sqlx_error};
 #[cfg(feature = "streams")]
 pub use crate::stream::StreamError;
 #[cfg(feature = "postgres")]
 pub use crate::transaction::TransactionContext;
 pub use crate::{
-    CommandKind, EntityCommand, EntityEvent, EventKind, Pagination, Repository, SortDirection,
-    async_trait,
+    CommandKind, Entity, EntityCommand, EntityEvent, EventKind, Page, Pagination, PaginationError,
+    Repository, SortDirection, UnknownCommand, async_trait,
+    cache::EntityCache,
+    crypto::{CryptoError, Encryptor},
+    error::{ErrorClass, NotFoundError, RetryableError},
     policy::{PolicyError, PolicyOperation},
-    transaction::{Transaction, TransactionError}
+    transaction::{IsolationLevel, Transaction, TransactionError}
 };
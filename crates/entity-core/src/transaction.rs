@@ -12,6 +12,7 @@
 //! - [`Transaction`] — Entry point for creating transactions
 //! - [`TransactionContext`] — Holds active transaction, provides repo access
 //! - [`TransactionError`] — Error wrapper for transaction operations
+//! - [`IsolationLevel`] — SQL isolation level for a transaction
 //!
 //! # Example
 //!
@@ -36,10 +37,39 @@
 //!         .await
 //! }
 //! ```
+//!
+//! # Isolation Level
+//!
+//! Transactions start at the database default (`READ COMMITTED` on
+//! PostgreSQL). Set a level on the builder to emit
+//! `SET TRANSACTION ISOLATION LEVEL ...` before the closure runs:
+//!
+//! ```rust,ignore
+//! Transaction::new(pool)
+//!     .isolation(IsolationLevel::Serializable)
+//!     .with_accounts()
+//!     .run(|mut ctx| async move { /* ... */ })
+//!     .await
+//! ```
+//!
+//! Entities declared with `#[entity(transactions, isolation = "...")]`
+//! raise the level from their `with_*()` method; the strictest level
+//! requested wins.
+//!
+//! # Retrying
+//!
+//! At `SERIALIZABLE`, PostgreSQL aborts conflicting transactions with a
+//! serialization failure (`40001`). [`Transaction::run_retrying`] runs the
+//! closure in a fresh transaction again after a short backoff when it fails
+//! with a serialization failure or deadlock (`40P01`), up to
+//! `max_attempts` times in total. Any other error is returned at once.
 
 #[cfg(feature = "postgres")]
 use std::future::Future;
-use std::{error::Error as StdError, fmt};
+use std::{error::Error as StdError, fmt, time::Duration};
+
+#[cfg(feature = "postgres")]
+use crate::error::RetryableError;
 
 /// Transaction builder for composing multi-entity operations.
 ///
@@ -65,7 +95,8 @@ use std::{error::Error as StdError, fmt};
 ///     .await?;
 /// ```
 pub struct Transaction<'p, DB> {
-    pool: &'p DB
+    pool:      &'p DB,
+    isolation: Option<IsolationLevel>
 }
 
 impl<'p, DB> Transaction<'p, DB> {
@@ -82,7 +113,8 @@ impl<'p, DB> Transaction<'p, DB> {
     /// ```
     pub const fn new(pool: &'p DB) -> Self {
         Self {
-            pool
+            pool,
+            isolation: None
         }
     }
 
@@ -90,6 +122,73 @@ impl<'p, DB> Transaction<'p, DB> {
     pub const fn pool(&self) -> &'p DB {
         self.pool
     }
+
+    /// Set the isolation level, replacing any level set before.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Transaction::new(&pool)
+    ///     .isolation(IsolationLevel::Serializable)
+    ///     .run(|mut ctx| async move { /* ... */ })
+    ///     .await?;
+    /// ```
+    pub const fn isolation(mut self, level: IsolationLevel) -> Self {
+        self.isolation = Some(level);
+        self
+    }
+
+    /// Raise the isolation level to at least `level`.
+    ///
+    /// Keeps a stricter level that is already set. Generated `with_*()`
+    /// methods call this for entities with `isolation = "..."`.
+    pub fn require_isolation(mut self, level: IsolationLevel) -> Self {
+        self.isolation = Some(self.isolation.map_or(level, |current| current.max(level)));
+        self
+    }
+
+    /// Get the configured isolation level.
+    ///
+    /// `None` means the database default.
+    pub const fn isolation_level(&self) -> Option<IsolationLevel> {
+        self.isolation
+    }
+}
+
+/// SQL transaction isolation level.
+///
+/// Ordered from weakest to strictest, so `max` picks the stricter level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IsolationLevel {
+    /// `READ UNCOMMITTED` (behaves as `READ COMMITTED` on PostgreSQL).
+    ReadUncommitted,
+
+    /// `READ COMMITTED`, the PostgreSQL default.
+    ReadCommitted,
+
+    /// `REPEATABLE READ`.
+    RepeatableRead,
+
+    /// `SERIALIZABLE`.
+    Serializable
+}
+
+impl IsolationLevel {
+    /// SQL keywords for this level, e.g. `"REPEATABLE READ"`.
+    pub const fn as_sql(self) -> &'static str {
+        match self {
+            Self::ReadUncommitted => "READ UNCOMMITTED",
+            Self::ReadCommitted => "READ COMMITTED",
+            Self::RepeatableRead => "REPEATABLE READ",
+            Self::Serializable => "SERIALIZABLE"
+        }
+    }
+}
+
+impl fmt::Display for IsolationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_sql())
+    }
 }
 
 /// Active transaction context with repository access.
@@ -231,6 +330,20 @@ impl From<TransactionError<sqlx::Error>> for sqlx::Error {
 // PostgreSQL implementation
 #[cfg(feature = "postgres")]
 impl<'p> Transaction<'p, sqlx::PgPool> {
+    /// Begin the transaction and apply the isolation level, if any.
+    async fn begin(&self) -> Result<TransactionContext, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        if let Some(level) = self.isolation {
+            sqlx::query(&format!(
+                "SET TRANSACTION ISOLATION LEVEL {}",
+                level.as_sql()
+            ))
+            .execute(&mut *tx)
+            .await?;
+        }
+        Ok(TransactionContext::new(tx))
+    }
+
     /// Execute a closure within a PostgreSQL transaction.
     ///
     /// Automatically commits on `Ok`, rolls back on `Err` or drop.
@@ -259,8 +372,7 @@ impl<'p> Transaction<'p, sqlx::PgPool> {
         Fut: Future<Output = Result<T, E>> + Send,
         E: From<sqlx::Error>
     {
-        let tx = self.pool.begin().await.map_err(E::from)?;
-        let ctx = TransactionContext::new(tx);
+        let ctx = self.begin().await.map_err(E::from)?;
 
         match f(ctx).await {
             Ok(result) => Ok(result),
@@ -290,10 +402,68 @@ impl<'p> Transaction<'p, sqlx::PgPool> {
         Fut: Future<Output = Result<T, E>> + Send,
         E: From<sqlx::Error>
     {
-        let tx = self.pool.begin().await.map_err(E::from)?;
-        let ctx = TransactionContext::new(tx);
+        let ctx = self.begin().await.map_err(E::from)?;
         f(ctx).await
     }
+
+    /// Execute a closure within a transaction, retrying on serialization
+    /// failures.
+    ///
+    /// Runs `f` in a new transaction up to `max_attempts` times (at least
+    /// once). An attempt is retried only when its error is
+    /// [`retryable`](RetryableError::is_retryable), i.e. a PostgreSQL
+    /// serialization failure or deadlock; the wait before attempt `n + 1`
+    /// is [`retry_backoff(n)`](retry_backoff). The last error is returned
+    /// once the attempts are used up.
+    ///
+    /// The closure must commit itself, as with
+    /// [`run_with_commit`](Self::run_with_commit): serialization failures
+    /// are often only reported by `COMMIT`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Transaction::new(&pool)
+    ///     .isolation(IsolationLevel::Serializable)
+    ///     .with_accounts()
+    ///     .run_retrying(5, |mut ctx| async move {
+    ///         let account = ctx.accounts().find_by_id(id).await?.ok_or(AppError::NotFound)?;
+    ///         ctx.accounts().update(id, debit(&account, amount)).await?;
+    ///         ctx.commit().await?;
+    ///         Ok(())
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn run_retrying<F, Fut, T, E>(self, max_attempts: u32, mut f: F) -> Result<T, E>
+    where
+        F: FnMut(TransactionContext) -> Fut + Send,
+        Fut: Future<Output = Result<T, E>> + Send,
+        E: From<sqlx::Error> + RetryableError
+    {
+        let mut attempt = 1;
+        loop {
+            let ctx = self.begin().await.map_err(E::from)?;
+            match f(ctx).await {
+                Err(e) if attempt < max_attempts && e.is_retryable() => {
+                    tokio::time::sleep(retry_backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result
+            }
+        }
+    }
+}
+
+/// Wait before retrying a transaction after `attempt` failed attempts.
+///
+/// Doubles from 10 ms after the first attempt up to 640 ms.
+pub const fn retry_backoff(attempt: u32) -> Duration {
+    let exponent = if attempt > 7 {
+        6
+    } else {
+        attempt.saturating_sub(1)
+    };
+    Duration::from_millis(10 << exponent)
 }
 
 #[cfg(test)]
@@ -431,6 +601,49 @@ mod tests {
         assert_eq!(tx.pool().id, 42);
     }
 
+    #[test]
+    fn isolation_level_sql() {
+        assert_eq!(IsolationLevel::ReadUncommitted.as_sql(), "READ UNCOMMITTED");
+        assert_eq!(IsolationLevel::ReadCommitted.as_sql(), "READ COMMITTED");
+        assert_eq!(IsolationLevel::RepeatableRead.as_sql(), "REPEATABLE READ");
+        assert_eq!(IsolationLevel::Serializable.to_string(), "SERIALIZABLE");
+    }
+
+    #[test]
+    fn transaction_builder_isolation() {
+        struct MockPool;
+        let pool = MockPool;
+        assert_eq!(Transaction::new(&pool).isolation_level(), None);
+
+        let tx = Transaction::new(&pool).isolation(IsolationLevel::Serializable);
+        assert_eq!(tx.isolation_level(), Some(IsolationLevel::Serializable));
+
+        let tx = tx.isolation(IsolationLevel::ReadCommitted);
+        assert_eq!(tx.isolation_level(), Some(IsolationLevel::ReadCommitted));
+    }
+
+    #[test]
+    fn transaction_builder_require_isolation_keeps_strictest() {
+        struct MockPool;
+        let pool = MockPool;
+        let tx = Transaction::new(&pool)
+            .require_isolation(IsolationLevel::RepeatableRead)
+            .require_isolation(IsolationLevel::ReadCommitted);
+        assert_eq!(tx.isolation_level(), Some(IsolationLevel::RepeatableRead));
+
+        let tx = tx.require_isolation(IsolationLevel::Serializable);
+        assert_eq!(tx.isolation_level(), Some(IsolationLevel::Serializable));
+    }
+
+    #[test]
+    fn retry_backoff_doubles_up_to_cap() {
+        assert_eq!(retry_backoff(1), Duration::from_millis(10));
+        assert_eq!(retry_backoff(2), Duration::from_millis(20));
+        assert_eq!(retry_backoff(3), Duration::from_millis(40));
+        assert_eq!(retry_backoff(7), Duration::from_millis(640));
+        assert_eq!(retry_backoff(50), Duration::from_millis(640));
+    }
+
     #[test]
     fn transaction_error_debug() {
         let err: TransactionError<&str> = TransactionError::Begin("test");
@@ -22,10 +22,17 @@
 //! │   ├── enum_gen.rs    → Command enum
 //! │   ├── result_gen.rs  → Result enum
 //! │   └── handler_gen.rs → Handler trait
+//! ├── cursor.rs      → {Entity}Cursor for #[entity(cursor = "...")]
 //! ├── repository.rs  → Repository trait definition
+//! ├── repo_struct.rs → Pool-owning {Entity}Repo for #[entity(repo_struct)]
 //! ├── row.rs         → Database row struct (sqlx::FromRow)
 //! ├── insertable.rs  → Insertable struct for INSERT operations
 //! ├── mappers.rs     → From implementations between types
+//! ├── encryption.rs  → Encrypt/decrypt helpers for #[column(encrypt)]
+//! ├── ordering.rs    → Eq/Ord by id for #[entity(ord_by_id)]
+//! ├── diff.rs        → Field change lists for #[entity(diffable)]
+//! ├── metadata.rs    → entity_core::Entity impl (table, schema, columns)
+//! ├── schema_json.rs → {ENTITY}_SCHEMA_JSON description for tooling
 //! │
 //! └── sql/           → Database-specific implementations
 //!     ├── postgres.rs   → PostgreSQL (sqlx::PgPool)
@@ -60,21 +67,32 @@
 //! | `InsertableUser` | Struct for INSERT operations |
 //! | `impl From<...>` | Conversions between types |
 //! | `impl UserRepository for PgPool` | PostgreSQL implementation |
+//! | `impl Entity for User` | Table metadata for generic code |
+//! | `USER_SCHEMA_JSON` | JSON description of fields and relations |
 
 mod api;
+mod audit;
 mod commands;
+mod cursor;
+mod diff;
 mod dto;
+mod encryption;
 mod events;
 mod hooks;
 mod insertable;
 mod mappers;
+mod metadata;
 mod migrations;
+mod order_by;
+mod ordering;
 pub mod parse;
 mod policy;
 mod projection;
 mod query;
+mod repo_struct;
 mod repository;
 mod row;
+mod schema_json;
 mod sql;
 mod streams;
 mod transaction;
@@ -99,17 +117,26 @@ fn generate(entity: EntityDef) -> TokenStream {
     let dto = dto::generate(&entity);
     let projections = projection::generate(&entity);
     let query_struct = query::generate(&entity);
+    let order_by = order_by::generate(&entity);
+    let cursor = cursor::generate(&entity);
     let events = events::generate(&entity);
     let hooks = hooks::generate(&entity);
     let commands = commands::generate(&entity);
     let policy = policy::generate(&entity);
     let streams = streams::generate(&entity);
     let transaction = transaction::generate(&entity);
+    let audit = audit::generate(&entity);
     let api = api::generate(&entity);
     let repository = repository::generate(&entity);
+    let repo_struct = repo_struct::generate(&entity);
     let row = row::generate(&entity);
     let insertable = insertable::generate(&entity);
     let mappers = mappers::generate(&entity);
+    let encryption = encryption::generate(&entity);
+    let ordering = ordering::generate(&entity);
+    let diff = diff::generate(&entity);
+    let metadata = metadata::generate(&entity);
+    let schema_json = schema_json::generate(&entity);
     let sql = sql::generate(&entity);
     let migrations = migrations::generate(&entity);
 
@@ -117,17 +144,26 @@ fn generate(entity: EntityDef) -> TokenStream {
         #dto
         #projections
         #query_struct
+        #order_by
+        #cursor
         #events
         #hooks
         #commands
         #policy
         #streams
         #transaction
+        #audit
         #api
         #repository
+        #repo_struct
         #row
         #insertable
         #mappers
+        #encryption
+        #ordering
+        #diff
+        #metadata
+        #schema_json
         #sql
         #migrations
     };
@@ -31,6 +31,10 @@
 //! | `delete_user` | DELETE | `/users/{id}` | Delete entity |
 //! | `list_user` | GET | `/users` | List with pagination |
 //!
+//! `handlers(list_stream)` adds `stream_user`, a `GET /users/stream`
+//! Server-Sent Events endpoint. It is never part of the plain `handlers`
+//! flag.
+//!
 //! # Selective Handler Generation
 //!
 //! You can generate only specific handlers:
@@ -41,7 +45,8 @@
 //! pub struct User { ... }
 //! ```
 //!
-//! Available handler options: `create`, `get`, `update`, `delete`, `list`.
+//! Available handler options: `create`, `get`, `update`, `delete`, `list`,
+//! `list_stream`.
 //!
 //! # Security Integration
 //!
@@ -104,6 +109,8 @@
 //! ├── update.rs   — PATCH handler generation
 //! ├── delete.rs   — DELETE handler generation
 //! ├── list.rs     — GET collection handler generation
+//! ├── list_stream.rs — GET Server-Sent Events handler generation
+//! ├── envelope.rs — Response envelope structs
 //! └── tests.rs    — Unit tests
 //! ```
 //!
@@ -133,17 +140,22 @@
 
 mod create;
 mod delete;
+mod envelope;
 mod get;
 mod helpers;
 mod list;
+mod list_stream;
 mod update;
 
 use create::generate_create_handler;
 use delete::generate_delete_handler;
+pub use envelope::envelope_schema_types;
+use envelope::generate_envelopes;
 use get::generate_get_handler;
 #[cfg(test)]
 pub use helpers::{build_collection_path, build_item_path};
 use list::generate_list_handler;
+use list_stream::generate_list_stream_handler;
 use proc_macro2::TokenStream;
 use quote::quote;
 use update::generate_update_handler;
@@ -178,6 +190,7 @@ use crate::entity::parse::EntityDef;
 /// | `handlers` | All 5 handlers |
 /// | `handlers(create, get)` | Only create and get |
 /// | `handlers(list)` | Only list |
+/// | `handlers(list_stream)` | Only the SSE stream |
 /// | No `handlers` | Nothing (empty stream) |
 ///
 /// # Example Usage
@@ -235,13 +248,21 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
     } else {
         TokenStream::new()
     };
+    let list_stream = if handlers.list_stream {
+        generate_list_stream_handler(entity)
+    } else {
+        TokenStream::new()
+    };
+    let envelopes = generate_envelopes(entity);
 
     quote! {
+        #envelopes
         #create
         #get
         #update
         #delete
         #list
+        #list_stream
     }
 }
 
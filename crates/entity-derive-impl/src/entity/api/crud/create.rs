@@ -84,13 +84,46 @@
 //!
 //! The `UserResponse::from(entity)` conversion is automatically generated
 //! by the derive macro based on `#[field(response)]` attributes.
+//!
+//! # File Uploads
+//!
+//! With `api(multipart_field = "file")` the handler extracts
+//! `axum::extract::Multipart` (axum's `multipart` feature) and advertises
+//! `multipart/form-data`:
+//!
+//! ```text
+//! POST /assets
+//! Content-Type: multipart/form-data; boundary=X
+//!
+//! --X
+//! Content-Disposition: form-data; name="title"
+//!
+//! Invoice scan
+//! --X
+//! Content-Disposition: form-data; name="file"; filename="scan.pdf"
+//!
+//! %PDF-1.7 ...
+//! --X--
+//! ```
+//!
+//! The `file` part is read into the field's `Vec<u8>`. Every other part is
+//! a create field under its API name: `String` fields take the text as is,
+//! other fields parse it as JSON (`42`, `true`, `"2026-01-01T00:00:00Z"`).
+//! A missing file part or a field that fails to deserialize is a
+//! `400 Bad Request`.
 
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use super::helpers::{build_collection_path, build_deprecated_attr, build_security_attr};
-use crate::entity::parse::EntityDef;
+use super::{
+    envelope::{Body, wrap_body},
+    helpers::{
+        Access, build_body_extractor, build_collection_path, build_deprecated_attr,
+        build_security_attr, with_request_id, with_static_headers
+    }
+};
+use crate::entity::parse::{EntityDef, FieldDef};
 
 /// Generates the POST handler for creating new entities.
 ///
@@ -120,7 +153,7 @@ use crate::entity::parse::EntityDef;
 /// | Path | Collection path (e.g., `/users`) |
 /// | Method | POST |
 /// | Request body | `Create{Entity}Request` |
-/// | Response body | `{Entity}Response` |
+/// | Response body | `{Entity}Response`, or the id with `returning = "minimal"` |
 /// | Status code | 201 Created on success |
 ///
 /// # Security Handling
@@ -159,13 +192,38 @@ pub fn generate_create_handler(entity: &EntityDef) -> TokenStream {
     let create_dto = entity.ident_with("Create", "Request");
     let response_dto = entity.ident_with("", "Response");
 
+    // With `returning = "minimal"` the repository yields only the id.
+    let (response_body, response_value) = if entity.create_returns_id() {
+        let id_type = entity.id_field().ty();
+        wrap_body(entity, Body::Id, quote! { #id_type }, quote! { created })
+    } else {
+        wrap_body(
+            entity,
+            Body::Single,
+            quote! { #response_dto },
+            quote! { #response_dto::from(created) }
+        )
+    };
+
     let path = build_collection_path(entity);
-    let tag = api_config.tag_or_default(&entity_name_str);
+    let tag = api_config.write_tag_or_default(&entity_name_str);
 
     let security_attr = build_security_attr(entity);
     let deprecated_attr = build_deprecated_attr(entity);
 
     let request_body_desc = format!("Data for creating a new {}", entity_name);
+    let (content_type, body, build_dto) = match &api_config.multipart_field {
+        Some(file) => (
+            "multipart/form-data",
+            quote! { mut multipart: axum::extract::Multipart },
+            build_multipart_dto(entity, file, &create_dto)
+        ),
+        None => (
+            api_config.content_type.media_type(),
+            build_body_extractor(entity, &create_dto),
+            TokenStream::new()
+        )
+    };
     let success_desc = format!("{} created successfully", entity_name);
 
     let utoipa_attr = if has_security {
@@ -174,9 +232,9 @@ pub fn generate_create_handler(entity: &EntityDef) -> TokenStream {
                 post,
                 path = #path,
                 tag = #tag,
-                request_body(content = #create_dto, description = #request_body_desc),
+                request_body(content = #create_dto, content_type = #content_type, description = #request_body_desc),
                 responses(
-                    (status = 201, description = #success_desc, body = #response_dto),
+                    (status = 201, description = #success_desc, body = #response_body),
                     (status = 400, description = "Invalid request data"),
                     (status = 401, description = "Authentication required"),
                     (status = 500, description = "Internal server error")
@@ -191,9 +249,9 @@ pub fn generate_create_handler(entity: &EntityDef) -> TokenStream {
                 post,
                 path = #path,
                 tag = #tag,
-                request_body(content = #create_dto, description = #request_body_desc),
+                request_body(content = #create_dto, content_type = #content_type, description = #request_body_desc),
                 responses(
-                    (status = 201, description = #success_desc, body = #response_dto),
+                    (status = 201, description = #success_desc, body = #response_body),
                     (status = 400, description = "Invalid request data"),
                     (status = 500, description = "Internal server error")
                 )
@@ -218,21 +276,101 @@ pub fn generate_create_handler(entity: &EntityDef) -> TokenStream {
         }
     );
 
+    let (success_ty, handler_body) = with_static_headers(
+        entity,
+        Access::Write,
+        quote! { (axum::http::StatusCode, axum::response::Json<#response_body>) },
+        quote! {
+            #build_dto
+            let created = repo
+                .create(dto)
+                .await
+                .map_err(|e| masterror::AppError::internal(e.to_string()))?;
+            Ok((axum::http::StatusCode::CREATED, axum::response::Json(#response_value)))
+        }
+    );
+    let (return_ty, request_id_param, handler_body) =
+        with_request_id(entity, &handler_name, success_ty, handler_body);
+
     quote! {
         #[doc = #doc]
         #utoipa_attr
         #vis async fn #handler_name<R>(
             axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
-            axum::extract::Json(dto): axum::extract::Json<#create_dto>,
-        ) -> masterror::AppResult<(axum::http::StatusCode, axum::response::Json<#response_dto>)>
+            #request_id_param
+            #body,
+        ) -> #return_ty
         where
             R: #repo_trait + 'static,
         {
-            let entity = repo
-                .create(dto)
+            #handler_body
+        }
+    }
+}
+
+/// Builds `dto` from the parts of a `multipart/form-data` body.
+///
+/// Expects `multipart: axum::extract::Multipart` in scope. Text parts are
+/// gathered into a JSON object keyed by API name and deserialized into the
+/// Create DTO with an empty placeholder for the file field, which is then
+/// set to the bytes of the `file` part.
+fn build_multipart_dto(entity: &EntityDef, file: &str, create_dto: &syn::Ident) -> TokenStream {
+    let create_fields = entity.create_fields();
+    let Some(file_field) = create_fields.iter().find(|f| f.name_str() == file) else {
+        return TokenStream::new();
+    };
+    let file_ident = file_field.name();
+    let file_part = file_field.api_name();
+    let missing_msg = format!("missing multipart part '{}'", file_part);
+    let text_parts: Vec<String> = create_fields
+        .iter()
+        .filter(|f| is_text(f))
+        .map(|f| f.api_name())
+        .collect();
+
+    quote! {
+        let mut form = serde_json::Map::new();
+        let mut file: Option<Vec<u8>> = None;
+        while let Some(part) = multipart
+            .next_field()
+            .await
+            .map_err(|e| masterror::AppError::bad_request(e.to_string()))?
+        {
+            let Some(name) = part.name().map(str::to_owned) else {
+                continue;
+            };
+            if name == #file_part {
+                let bytes = part
+                    .bytes()
+                    .await
+                    .map_err(|e| masterror::AppError::bad_request(e.to_string()))?;
+                file = Some(bytes.to_vec());
+                continue;
+            }
+            let text = part
+                .text()
                 .await
-                .map_err(|e| masterror::AppError::internal(e.to_string()))?;
-            Ok((axum::http::StatusCode::CREATED, axum::response::Json(#response_dto::from(entity))))
+                .map_err(|e| masterror::AppError::bad_request(e.to_string()))?;
+            let value = if [#(#text_parts),*].contains(&name.as_str()) {
+                serde_json::Value::String(text)
+            } else {
+                serde_json::from_str(&text).unwrap_or(serde_json::Value::String(text))
+            };
+            form.insert(name, value);
         }
+        let file = file.ok_or_else(|| masterror::AppError::bad_request(#missing_msg))?;
+        form.insert(#file_part.to_owned(), serde_json::Value::Array(Vec::new()));
+        let mut dto: #create_dto = serde_json::from_value(serde_json::Value::Object(form))
+            .map_err(|e| masterror::AppError::bad_request(e.to_string()))?;
+        dto.#file_ident = file;
     }
 }
+
+/// Whether a multipart part for this field is taken as plain text.
+fn is_text(field: &FieldDef) -> bool {
+    let ty = field.create_ty();
+    matches!(
+        quote!(#ty).to_string().replace(' ', "").as_str(),
+        "String" | "Option<String>"
+    )
+}
@@ -105,7 +105,10 @@ use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use super::helpers::{build_deprecated_attr, build_item_path, build_security_attr};
+use super::helpers::{
+    Access, build_deprecated_attr, build_etag_value, build_if_match_check, build_item_path,
+    build_security_attr, with_request_id, with_static_headers
+};
 use crate::entity::parse::EntityDef;
 
 /// Generates the DELETE handler for removing entities.
@@ -138,6 +141,11 @@ use crate::entity::parse::EntityDef;
 /// | Response | `StatusCode::NO_CONTENT` (204) |
 /// | Status codes | 204, 401 (if auth), 404, 500 |
 ///
+/// # Conditional Requests
+///
+/// With `api(etag = "field")` the handler honors `If-Match`, returning
+/// `412 Precondition Failed` on mismatch.
+///
 /// # Return Type
 ///
 /// Unlike other handlers, DELETE returns only a status code:
@@ -168,7 +176,7 @@ pub fn generate_delete_handler(entity: &EntityDef) -> TokenStream {
     let handler_name = format_ident!("delete_{}", entity_name_str.to_case(Case::Snake));
 
     let path = build_item_path(entity);
-    let tag = api_config.tag_or_default(&entity_name_str);
+    let tag = api_config.write_tag_or_default(&entity_name_str);
 
     let security_attr = build_security_attr(entity);
     let deprecated_attr = build_deprecated_attr(entity);
@@ -177,17 +185,29 @@ pub fn generate_delete_handler(entity: &EntityDef) -> TokenStream {
     let success_desc = format!("{} deleted successfully", entity_name);
     let not_found_desc = format!("{} not found", entity_name);
 
+    let etag = build_etag_value(entity);
+    let if_match_check = build_if_match_check(entity);
+    let (if_match_param, precondition_response) = if etag.is_some() {
+        (
+            quote! { , ("If-Match" = Option<String>, Header, description = "Expected ETag") },
+            quote! { (status = 412, description = "ETag does not match If-Match"), }
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
     let utoipa_attr = if has_security {
         quote! {
             #[utoipa::path(
                 delete,
                 path = #path,
                 tag = #tag,
-                params(("id" = #id_type, Path, description = #id_desc)),
+                params(("id" = #id_type, Path, description = #id_desc) #if_match_param),
                 responses(
                     (status = 204, description = #success_desc),
                     (status = 401, description = "Authentication required"),
                     (status = 404, description = #not_found_desc),
+                    #precondition_response
                     (status = 500, description = "Internal server error")
                 ),
                 #security_attr
@@ -200,10 +220,11 @@ pub fn generate_delete_handler(entity: &EntityDef) -> TokenStream {
                 delete,
                 path = #path,
                 tag = #tag,
-                params(("id" = #id_type, Path, description = #id_desc)),
+                params(("id" = #id_type, Path, description = #id_desc) #if_match_param),
                 responses(
                     (status = 204, description = #success_desc),
                     (status = 404, description = #not_found_desc),
+                    #precondition_response
                     (status = 500, description = "Internal server error")
                 )
                 #deprecated_attr
@@ -230,16 +251,51 @@ pub fn generate_delete_handler(entity: &EntityDef) -> TokenStream {
 
     let not_found_msg = format!("{} not found", entity_name);
 
-    quote! {
-        #[doc = #doc]
-        #utoipa_attr
-        #vis async fn #handler_name<R>(
-            axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
-            axum::extract::Path(id): axum::extract::Path<#id_type>,
-        ) -> masterror::AppResult<axum::http::StatusCode>
-        where
-            R: #repo_trait + 'static,
-        {
+    if etag.is_some() {
+        let (success_ty, handler_body) = with_static_headers(
+            entity,
+            Access::Write,
+            quote! { axum::response::Response },
+            quote! {
+                #if_match_check
+                let deleted = repo
+                    .delete(id)
+                    .await
+                    .map_err(|e| masterror::AppError::internal(e.to_string()))?;
+                if deleted {
+                    Ok(axum::response::IntoResponse::into_response(
+                        axum::http::StatusCode::NO_CONTENT
+                    ))
+                } else {
+                    Err(masterror::AppError::not_found(#not_found_msg))
+                }
+            }
+        );
+        let (return_ty, request_id_param, handler_body) =
+            with_request_id(entity, &handler_name, success_ty, handler_body);
+
+        return quote! {
+            #[doc = #doc]
+            #utoipa_attr
+            #vis async fn #handler_name<R>(
+                axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
+                #request_id_param
+                axum::extract::Path(id): axum::extract::Path<#id_type>,
+                headers: axum::http::HeaderMap,
+            ) -> #return_ty
+            where
+                R: #repo_trait + 'static,
+            {
+                #handler_body
+            }
+        };
+    }
+
+    let (success_ty, handler_body) = with_static_headers(
+        entity,
+        Access::Write,
+        quote! { axum::http::StatusCode },
+        quote! {
             let deleted = repo
                 .delete(id)
                 .await
@@ -250,5 +306,22 @@ pub fn generate_delete_handler(entity: &EntityDef) -> TokenStream {
                 Err(masterror::AppError::not_found(#not_found_msg))
             }
         }
+    );
+    let (return_ty, request_id_param, handler_body) =
+        with_request_id(entity, &handler_name, success_ty, handler_body);
+
+    quote! {
+        #[doc = #doc]
+        #utoipa_attr
+        #vis async fn #handler_name<R>(
+            axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
+            #request_id_param
+            axum::extract::Path(id): axum::extract::Path<#id_type>,
+        ) -> #return_ty
+        where
+            R: #repo_trait + 'static,
+        {
+            #handler_body
+        }
     }
 }
@@ -0,0 +1,136 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Response envelopes for `api(response_envelope = "...")`.
+//!
+//! Some API conventions wrap every payload in an object, e.g.
+//! `{ "data": ... }`. With `response_envelope` set, the handlers return
+//! generated envelope structs instead of the bare DTO:
+//!
+//! | Struct | Body | Used by |
+//! |--------|------|---------|
+//! | `{Entity}Envelope` | `{Entity}Response` | create, get, update |
+//! | `{Entity}ListEnvelope` | `Vec<{Entity}Response>`, or `{Entity}Page` with cursor pagination | list |
+//! | `{Entity}IdEnvelope` | id type | create with `returning = "minimal"` |
+//!
+//! The field is renamed to the configured key, so the OpenAPI schemas
+//! derived from these structs describe the wrapper as sent.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    entity::parse::{EntityDef, PaginationStyle},
+    utils::marker
+};
+
+/// Kind of response body a handler returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Body {
+    /// One `{Entity}Response`.
+    Single,
+
+    /// A `Vec<{Entity}Response>`.
+    List,
+
+    /// The id returned by a minimal create.
+    Id
+}
+
+/// Name of the envelope struct for a body kind.
+pub fn envelope_ident(entity: &EntityDef, body: Body) -> syn::Ident {
+    match body {
+        Body::Single => entity.ident_with("", "Envelope"),
+        Body::List => entity.ident_with("", "ListEnvelope"),
+        Body::Id => entity.ident_with("", "IdEnvelope")
+    }
+}
+
+/// Wraps a response body in its envelope.
+///
+/// Takes the bare body type and value expression and returns them
+/// unchanged without `response_envelope`, otherwise the envelope type and
+/// its construction.
+pub fn wrap_body(
+    entity: &EntityDef,
+    body: Body,
+    ty: TokenStream,
+    value: TokenStream
+) -> (TokenStream, TokenStream) {
+    if entity.api_config().response_envelope.is_none() {
+        return (ty, value);
+    }
+    let envelope = envelope_ident(entity, body);
+    (quote! { #envelope }, quote! { #envelope { data: #value } })
+}
+
+/// Body kinds returned by the enabled handlers, with their bare types.
+fn enveloped_bodies(entity: &EntityDef) -> Vec<(Body, TokenStream, &'static str)> {
+    let handlers = entity.api_config().handlers();
+    let response_dto = entity.ident_with("", "Response");
+    let id_type = entity.id_field().ty();
+    let minimal_create = handlers.create && entity.create_returns_id();
+
+    let mut bodies = Vec::new();
+    if (handlers.create && !minimal_create) || handlers.get || handlers.update {
+        bodies.push((Body::Single, quote! { #response_dto }, "a single response"));
+    }
+    if handlers.list {
+        let list = if entity.api_config().pagination == PaginationStyle::Cursor {
+            let page = entity.ident_with("", "Page");
+            quote! { #page }
+        } else {
+            quote! { Vec<#response_dto> }
+        };
+        bodies.push((Body::List, list, "a list response"));
+    }
+    if minimal_create {
+        bodies.push((Body::Id, quote! { #id_type }, "the id of a created entity"));
+    }
+    bodies
+}
+
+/// Generates the envelope structs the enabled handlers return.
+///
+/// Returns empty `TokenStream` without `response_envelope`.
+pub fn generate_envelopes(entity: &EntityDef) -> TokenStream {
+    let Some(key) = entity.api_config().response_envelope.as_deref() else {
+        return TokenStream::new();
+    };
+
+    let vis = &entity.vis;
+    let marker = marker::generated();
+    let structs = enveloped_bodies(entity)
+        .into_iter()
+        .map(|(body, ty, what)| {
+            let name = envelope_ident(entity, body);
+            let doc = format!(" `{{ \"{}\": ... }}` wrapper of {}.", key, what);
+            quote! {
+                #marker
+                #[doc = #doc]
+                #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+                #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+                #vis struct #name {
+                    /// The wrapped response body.
+                    #[serde(rename = #key)]
+                    pub data: #ty,
+                }
+            }
+        });
+
+    quote! { #(#structs)* }
+}
+
+/// Names of the generated envelope structs, for OpenAPI registration.
+pub fn envelope_schema_types(entity: &EntityDef) -> Vec<TokenStream> {
+    if entity.api_config().response_envelope.is_none() {
+        return Vec::new();
+    }
+    enveloped_bodies(entity)
+        .into_iter()
+        .map(|(body, ..)| {
+            let name = envelope_ident(entity, body);
+            quote! { #name }
+        })
+        .collect()
+}
@@ -63,7 +63,7 @@
 //!   │                      │ repo.find_by_id(id)  │                   │
 //!   │                      │─────────────────────>│                   │
 //!   │                      │                      │                   │
-//!   │                      │                      │ SELECT * WHERE id │
+//!   │                      │                      │ SELECT … WHERE id │
 //!   │                      │                      │──────────────────>│
 //!   │                      │                      │                   │
 //!   │                      │                      │<──────────────────│
@@ -93,7 +93,13 @@ use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use super::helpers::{build_deprecated_attr, build_item_path, build_security_attr};
+use super::{
+    envelope::{Body, wrap_body},
+    helpers::{
+        Access, build_deprecated_attr, build_etag_value, build_item_path, build_security_attr,
+        with_request_id, with_static_headers
+    }
+};
 use crate::entity::parse::EntityDef;
 
 /// Generates the GET handler for retrieving a single entity by ID.
@@ -139,6 +145,11 @@ use crate::entity::parse::EntityDef;
 /// When security is configured:
 /// - Adds `401 Unauthorized` to response list
 /// - Includes security requirement in OpenAPI spec
+///
+/// # Conditional Requests
+///
+/// With `api(etag = "field")` the response carries an `ETag` header built
+/// from that field, for use in `If-Match` on update and delete.
 pub fn generate_get_handler(entity: &EntityDef) -> TokenStream {
     let vis = &entity.vis;
     let entity_name = entity.name();
@@ -151,9 +162,15 @@ pub fn generate_get_handler(entity: &EntityDef) -> TokenStream {
 
     let handler_name = format_ident!("get_{}", entity_name_str.to_case(Case::Snake));
     let response_dto = entity.ident_with("", "Response");
+    let (response_body, response_value) = wrap_body(
+        entity,
+        Body::Single,
+        quote! { #response_dto },
+        quote! { #response_dto::from(entity) }
+    );
 
     let path = build_item_path(entity);
-    let tag = api_config.tag_or_default(&entity_name_str);
+    let tag = api_config.read_tag_or_default(&entity_name_str);
 
     let security_attr = build_security_attr(entity);
     let deprecated_attr = build_deprecated_attr(entity);
@@ -162,6 +179,11 @@ pub fn generate_get_handler(entity: &EntityDef) -> TokenStream {
     let success_desc = format!("{} found", entity_name);
     let not_found_desc = format!("{} not found", entity_name);
 
+    let etag = build_etag_value(entity);
+    let etag_header = etag.as_ref().map(|_| {
+        quote! { , headers(("ETag" = String, description = "Current entity version for If-Match")) }
+    });
+
     let utoipa_attr = if has_security {
         quote! {
             #[utoipa::path(
@@ -170,7 +192,7 @@ pub fn generate_get_handler(entity: &EntityDef) -> TokenStream {
                 tag = #tag,
                 params(("id" = #id_type, Path, description = #id_desc)),
                 responses(
-                    (status = 200, description = #success_desc, body = #response_dto),
+                    (status = 200, description = #success_desc, body = #response_body #etag_header),
                     (status = 401, description = "Authentication required"),
                     (status = 404, description = #not_found_desc),
                     (status = 500, description = "Internal server error")
@@ -187,7 +209,7 @@ pub fn generate_get_handler(entity: &EntityDef) -> TokenStream {
                 tag = #tag,
                 params(("id" = #id_type, Path, description = #id_desc)),
                 responses(
-                    (status = 200, description = #success_desc, body = #response_dto),
+                    (status = 200, description = #success_desc, body = #response_body #etag_header),
                     (status = 404, description = #not_found_desc),
                     (status = 500, description = "Internal server error")
                 )
@@ -215,22 +237,48 @@ pub fn generate_get_handler(entity: &EntityDef) -> TokenStream {
 
     let not_found_msg = format!("{} not found", entity_name);
 
+    let (success_ty, respond) = match etag {
+        Some(etag) => (
+            quote! { ([(axum::http::HeaderName, String); 1], axum::response::Json<#response_body>) },
+            quote! {
+                let etag = #etag;
+                Ok(([(axum::http::header::ETAG, etag)], axum::response::Json(#response_value)))
+            }
+        ),
+        None => (
+            quote! { axum::response::Json<#response_body> },
+            quote! { Ok(axum::response::Json(#response_value)) }
+        )
+    };
+
+    let (success_ty, handler_body) = with_static_headers(
+        entity,
+        Access::Read,
+        success_ty,
+        quote! {
+            let entity = repo
+                .find_by_id(id)
+                .await
+                .map_err(|e| masterror::AppError::internal(e.to_string()))?
+                .ok_or_else(|| masterror::AppError::not_found(#not_found_msg))?;
+            #respond
+        }
+    );
+    let (return_ty, request_id_param, handler_body) =
+        with_request_id(entity, &handler_name, success_ty, handler_body);
+
     quote! {
         #[doc = #doc]
         #utoipa_attr
         #vis async fn #handler_name<R>(
             axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
+            #request_id_param
             axum::extract::Path(id): axum::extract::Path<#id_type>,
-        ) -> masterror::AppResult<axum::response::Json<#response_dto>>
+        ) -> #return_ty
         where
             R: #repo_trait + 'static,
         {
-            let entity = repo
-                .find_by_id(id)
-                .await
-                .map_err(|e| masterror::AppError::internal(e.to_string()))?
-                .ok_or_else(|| masterror::AppError::not_found(#not_found_msg))?;
-            Ok(axum::response::Json(#response_dto::from(entity)))
+            #handler_body
         }
     }
 }
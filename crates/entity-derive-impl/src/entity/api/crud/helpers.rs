@@ -13,7 +13,13 @@
 //!
 //! - **Path Building**: Constructing RESTful URL paths following conventions
 //! - **Security Attributes**: Generating utoipa security annotations
-//! - **Deprecation Handling**: Adding deprecated markers to OpenAPI spec
+//! - **Deprecation Handling**: Adding deprecated markers to OpenAPI spec and
+//!   `Deprecation`/`Sunset` headers to responses
+//! - **Static Headers**: `api(base_response_headers)` on reads and
+//!   `api(write_response_headers)` on writes
+//! - **Conditional Requests**: `ETag` values and `If-Match` checks
+//! - **Correlation IDs**: `X-Request-Id` handling with `api(request_id)`
+//! - **Request Bodies**: Extractor matching `api(content_type = "...")`
 //!
 //! # Path Conventions
 //!
@@ -24,7 +30,8 @@
 //! | Collection | `/{prefix}/{entity}s` | `/api/v1/users` |
 //! | Item | `/{prefix}/{entity}s/{id}` | `/api/v1/users/{id}` |
 //!
-//! Entity names are converted to kebab-case and pluralized.
+//! Entity names are converted to kebab-case and pluralized, unless
+//! `api(path_style = "singular")` is set, which drops the `s`.
 //!
 //! # Security Schemes
 //!
@@ -49,11 +56,10 @@
 //! // Result: "/api/v1/user-profiles/{id}"
 //! ```
 
-use convert_case::{Case, Casing};
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 
-use crate::entity::parse::EntityDef;
+use crate::entity::parse::{ContentType, EntityDef, UuidVersion};
 
 /// Builds the collection endpoint path for an entity.
 ///
@@ -66,7 +72,7 @@ use crate::entity::parse::EntityDef;
 ///
 /// 1. **Prefix**: From `api(path_prefix = "...")` attribute
 /// 2. **Entity name**: Converted to kebab-case
-/// 3. **Plural suffix**: Always adds "s" for collections
+/// 3. **Plural suffix**: Adds "s" unless `path_style = "singular"`
 ///
 /// # Arguments
 ///
@@ -94,12 +100,13 @@ use crate::entity::parse::EntityDef;
 /// - Double slashes (`//`) are automatically normalized to single slashes
 /// - Entity names are converted from PascalCase to kebab-case
 /// - The plural form is naive (just adds "s"), not grammatically correct
+/// - `api(path_style = "singular")` yields `/user`, `/user/{id}`
 pub fn build_collection_path(entity: &EntityDef) -> String {
     let api_config = entity.api_config();
     let prefix = api_config.full_path_prefix();
-    let entity_path = entity.name_str().to_case(Case::Kebab);
+    let resource = api_config.resource_segment(&entity.name_str());
 
-    let path = format!("{}/{}s", prefix, entity_path);
+    let path = format!("{}/{}", prefix, resource);
     path.replace("//", "/")
 }
 
@@ -279,6 +286,200 @@ pub fn build_deprecated_attr(entity: &EntityDef) -> TokenStream {
     }
 }
 
+/// Whether a handler only reads or changes state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    /// Safe methods: get and list.
+    Read,
+
+    /// Create, update and delete.
+    Write
+}
+
+/// Wraps a handler so its success response carries static headers.
+///
+/// Takes the handler's success type and body and returns them unchanged
+/// when there is nothing to add. Otherwise the body runs in an inner block
+/// and its success response is sent with:
+///
+/// - `api(base_response_headers)` for [`Access::Read`], or
+///   `api(write_response_headers)` for [`Access::Write`]
+/// - `Deprecation: true` and, with `api(deprecated_sunset = "...")`, the RFC
+///   8594 `Sunset` header when the API is deprecated
+///
+/// Error responses are left untouched.
+pub fn with_static_headers(
+    entity: &EntityDef,
+    access: Access,
+    success_ty: TokenStream,
+    body: TokenStream
+) -> (TokenStream, TokenStream) {
+    let api_config = entity.api_config();
+    let configured = match access {
+        Access::Read => &api_config.base_response_headers,
+        Access::Write => &api_config.write_response_headers
+    };
+
+    let mut headers: Vec<TokenStream> = configured
+        .iter()
+        .map(|(name, value)| {
+            quote! { (axum::http::HeaderName::from_static(#name), #value) }
+        })
+        .collect();
+    if api_config.is_deprecated() {
+        headers.push(quote! {
+            (axum::http::HeaderName::from_static("deprecation"), "true")
+        });
+        if let Some(sunset) = api_config.sunset_http_date() {
+            headers.push(quote! {
+                (axum::http::HeaderName::from_static("sunset"), #sunset)
+            });
+        }
+    }
+    if headers.is_empty() {
+        return (success_ty, body);
+    }
+    let count = headers.len();
+
+    (
+        quote! { ([(axum::http::HeaderName, &'static str); #count], #success_ty) },
+        quote! {
+            let response: masterror::AppResult<#success_ty> = async move { #body }.await;
+            response.map(|response| ([#(#headers),*], response))
+        }
+    )
+}
+
+/// Wraps a handler so it carries an `X-Request-Id` correlation ID.
+///
+/// Returns the handler's return type, an extra parameter and the body.
+/// Without `api(request_id)` these are `masterror::AppResult<T>`, nothing
+/// and the body unchanged. Otherwise the handler extracts the request
+/// headers, reuses a valid incoming `X-Request-Id` or generates a UUID,
+/// runs the body inside a `tracing` span named after the handler, and sets
+/// the ID on the response, so error responses carry it too.
+pub fn with_request_id(
+    entity: &EntityDef,
+    handler_name: &syn::Ident,
+    success_ty: TokenStream,
+    body: TokenStream
+) -> (TokenStream, TokenStream, TokenStream) {
+    if !entity.api_config().request_id {
+        return (
+            quote! { masterror::AppResult<#success_ty> },
+            TokenStream::new(),
+            body
+        );
+    }
+
+    let span_name = handler_name.to_string();
+    let generate = match entity.uuid {
+        UuidVersion::V7 => quote! { uuid::Uuid::now_v7() },
+        UuidVersion::V4 => quote! { uuid::Uuid::new_v4() }
+    };
+
+    (
+        quote! { axum::response::Response },
+        quote! { request_headers: axum::http::HeaderMap, },
+        quote! {
+            let request_id = request_headers
+                .get("x-request-id")
+                .and_then(|value| value.to_str().ok())
+                .filter(|value| !value.is_empty() && value.len() <= 128)
+                .map(str::to_owned)
+                .unwrap_or_else(|| #generate.to_string());
+            let span = tracing::info_span!(#span_name, request_id = %request_id);
+            let result: masterror::AppResult<#success_ty> =
+                tracing::Instrument::instrument(async move { #body }, span).await;
+            let mut response = axum::response::IntoResponse::into_response(result);
+            if let Ok(value) = axum::http::HeaderValue::from_str(&request_id) {
+                response
+                    .headers_mut()
+                    .insert(axum::http::HeaderName::from_static("x-request-id"), value);
+            }
+            response
+        }
+    )
+}
+
+/// Builds the request body extractor binding `dto` for create and update.
+///
+/// `axum::extract::Json` by default, `axum::extract::Form` with
+/// `api(content_type = "application/x-www-form-urlencoded")`.
+pub fn build_body_extractor(entity: &EntityDef, dto: &syn::Ident) -> TokenStream {
+    match entity.api_config().content_type {
+        ContentType::Json => {
+            quote! { axum::extract::Json(dto): axum::extract::Json<#dto> }
+        }
+        ContentType::Form => {
+            quote! { axum::extract::Form(dto): axum::extract::Form<#dto> }
+        }
+    }
+}
+
+/// Builds the `ETag` value for an entity bound to `entity`.
+///
+/// Returns `None` unless `api(etag = "field")` is set. The tag is the
+/// quoted `Display` form of a version counter, or the RFC 3339 form of a
+/// `chrono` timestamp: `"3"`, `"2026-01-01T00:00:00+00:00"`.
+pub fn build_etag_value(entity: &EntityDef) -> Option<TokenStream> {
+    let etag = entity.api_config().etag.as_deref()?;
+    let is_datetime = entity.all_fields().iter().any(|f| {
+        f.name_str() == etag
+            && matches!(
+                f.ty(),
+                syn::Type::Path(path)
+                    if path.path.segments.last().is_some_and(|s| s.ident == "DateTime")
+            )
+    });
+    let field = syn::Ident::new(etag, Span::call_site());
+    if is_datetime {
+        Some(quote! { format!("\"{}\"", entity.#field.to_rfc3339()) })
+    } else {
+        Some(quote! { format!("\"{}\"", entity.#field) })
+    }
+}
+
+/// Generates the `If-Match` precondition check for update and delete.
+///
+/// Expects `repo`, `id` and `headers: HeaderMap` in scope. When the request
+/// carries `If-Match`, the current entity is loaded and the handler returns
+/// `412 Precondition Failed` unless one of the listed tags (or `*`) matches
+/// its `ETag`. Without the header the request proceeds unconditionally.
+///
+/// The check and the write are separate statements, so two requests with
+/// the same tag can still both pass; pair this with a version check in the
+/// write itself where strict guarantees are needed.
+///
+/// Returns empty `TokenStream` if `etag` is not configured.
+pub fn build_if_match_check(entity: &EntityDef) -> TokenStream {
+    let Some(etag) = build_etag_value(entity) else {
+        return TokenStream::new();
+    };
+    let not_found_msg = format!("{} not found", entity.name());
+    let mismatch_msg = format!("{} has been modified", entity.name());
+
+    quote! {
+        if let Some(if_match) = headers.get(axum::http::header::IF_MATCH) {
+            let entity = repo
+                .find_by_id(id.clone())
+                .await
+                .map_err(|e| masterror::AppError::internal(e.to_string()))?
+                .ok_or_else(|| masterror::AppError::not_found(#not_found_msg))?;
+            let etag = #etag;
+            let matches = if_match.to_str().is_ok_and(|value| {
+                value.split(',').map(str::trim).any(|tag| tag == "*" || tag == etag)
+            });
+            if !matches {
+                return Ok(axum::response::IntoResponse::into_response((
+                    axum::http::StatusCode::PRECONDITION_FAILED,
+                    #mismatch_msg
+                )));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,6 +554,20 @@ mod tests {
         assert_eq!(path, "/api/v2/users/{id}");
     }
 
+    #[test]
+    fn paths_singular_style() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", api(tag = "Users", path_prefix = "/api", path_style = "singular", handlers))]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        assert_eq!(build_collection_path(&entity), "/api/user");
+        assert_eq!(build_item_path(&entity), "/api/user/{id}");
+    }
+
     #[test]
     fn security_attr_bearer() {
         let input: syn::DeriveInput = syn::parse_quote! {
@@ -78,6 +78,36 @@
 //! GET /users?limit=10&offset=20  # Users 21-30
 //! ```
 //!
+//! # Filtering
+//!
+//! With `api(list_query)`, the handler extracts `{Entity}Query` instead of
+//! `PaginationQuery` and calls `repo.query(...)`. Every `#[filter]` field is
+//! then a query parameter, documented through `IntoParams`:
+//!
+//! ```text
+//! GET /users?name=alice&created_at_from=2026-01-01T00:00:00Z&limit=10
+//! ```
+//!
+//! `limit` keeps the default of 100 and the `max_limit` clamp.
+//!
+//! # Cursor Pagination
+//!
+//! With `api(pagination = "cursor")`, the handler extracts
+//! `{Entity}CursorQuery { after, limit }`, calls `repo.list_page(...)` and
+//! responds with `{Entity}Page`:
+//!
+//! ```json
+//! { "items": [{ "id": "uuid-9", ... }], "next_cursor": "uuid-8" }
+//! ```
+//!
+//! `next_cursor` is `null` on the last page; otherwise it is passed back as
+//! `after`:
+//!
+//! ```text
+//! GET /users?limit=10
+//! GET /users?limit=10&after=uuid-8
+//! ```
+//!
 //! # Request Flow
 //!
 //! ```text
@@ -89,7 +119,7 @@
 //!   │                      │ repo.list(10, 0)     │                   │
 //!   │                      │─────────────────────>│                   │
 //!   │                      │                      │                   │
-//!   │                      │                      │ SELECT * LIMIT 10 │
+//!   │                      │                      │ SELECT … LIMIT 10 │
 //!   │                      │                      │──────────────────>│
 //!   │                      │                      │                   │
 //!   │                      │                      │<──────────────────│
@@ -123,8 +153,17 @@ use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use super::helpers::{build_collection_path, build_deprecated_attr, build_security_attr};
-use crate::entity::parse::EntityDef;
+use super::{
+    envelope::{Body, wrap_body},
+    helpers::{
+        Access, build_collection_path, build_deprecated_attr, build_security_attr,
+        with_request_id, with_static_headers
+    }
+};
+use crate::{
+    entity::parse::{EntityDef, PaginationStyle},
+    utils::marker
+};
 
 /// Generates the GET handler for listing entities with pagination.
 ///
@@ -177,7 +216,22 @@ use crate::entity::parse::EntityDef;
 /// The default limit of 100 items prevents accidental full-table scans.
 /// Clients can override this but should implement proper pagination
 /// for large datasets.
+///
+/// Requested limits are clamped to `1..=max_limit`, where `max_limit` comes
+/// from `api(max_limit = N)` and defaults to 1000. The same bound is
+/// advertised in the OpenAPI parameter schema.
+///
+/// With `api(list_query)`, the handler takes `{Entity}Query` and calls
+/// `query` instead; `PaginationQuery` is not generated. The same holds for
+/// `api(pagination = "cursor")`, which takes `{Entity}CursorQuery`.
 pub fn generate_list_handler(entity: &EntityDef) -> TokenStream {
+    if entity.api_config().list_query {
+        return generate_filtered_list_handler(entity);
+    }
+    if entity.api_config().pagination == PaginationStyle::Cursor {
+        return generate_cursor_list_handler(entity);
+    }
+
     let vis = &entity.vis;
     let entity_name = entity.name();
     let entity_name_str = entity.name_str();
@@ -187,14 +241,26 @@ pub fn generate_list_handler(entity: &EntityDef) -> TokenStream {
 
     let handler_name = format_ident!("list_{}", entity_name_str.to_case(Case::Snake));
     let response_dto = entity.ident_with("", "Response");
+    let (response_body, response_value) = wrap_body(
+        entity,
+        Body::List,
+        quote! { Vec<#response_dto> },
+        quote! { responses }
+    );
 
     let path = build_collection_path(entity);
-    let tag = api_config.tag_or_default(&entity_name_str);
+    let tag = api_config.read_tag_or_default(&entity_name_str);
 
     let security_attr = build_security_attr(entity);
     let deprecated_attr = build_deprecated_attr(entity);
 
     let success_desc = format!("List of {} entities", entity_name);
+    let max_limit = api_config.max_limit_or_default();
+    let limit_desc = format!(
+        "Maximum number of items to return (default: 100, max: {})",
+        max_limit
+    );
+    let max_limit_lit = proc_macro2::Literal::i64_unsuffixed(max_limit);
 
     let utoipa_attr = if has_security {
         quote! {
@@ -203,11 +269,11 @@ pub fn generate_list_handler(entity: &EntityDef) -> TokenStream {
                 path = #path,
                 tag = #tag,
                 params(
-                    ("limit" = Option<i64>, Query, description = "Maximum number of items to return (default: 100)"),
+                    ("limit" = Option<i64>, Query, description = #limit_desc, minimum = 1, maximum = #max_limit_lit),
                     ("offset" = Option<i64>, Query, description = "Number of items to skip for pagination")
                 ),
                 responses(
-                    (status = 200, description = #success_desc, body = Vec<#response_dto>),
+                    (status = 200, description = #success_desc, body = #response_body),
                     (status = 401, description = "Authentication required"),
                     (status = 500, description = "Internal server error")
                 ),
@@ -222,11 +288,11 @@ pub fn generate_list_handler(entity: &EntityDef) -> TokenStream {
                 path = #path,
                 tag = #tag,
                 params(
-                    ("limit" = Option<i64>, Query, description = "Maximum number of items to return (default: 100)"),
+                    ("limit" = Option<i64>, Query, description = #limit_desc, minimum = 1, maximum = #max_limit_lit),
                     ("offset" = Option<i64>, Query, description = "Number of items to skip for pagination")
                 ),
                 responses(
-                    (status = 200, description = #success_desc, body = Vec<#response_dto>),
+                    (status = 200, description = #success_desc, body = #response_body),
                     (status = 500, description = "Internal server error")
                 )
                 #deprecated_attr
@@ -237,13 +303,14 @@ pub fn generate_list_handler(entity: &EntityDef) -> TokenStream {
     let doc = format!(
         "List {} entities with pagination.\n\n\
          # Query Parameters\n\n\
-         - `limit` - Maximum number of items to return (default: 100)\n\
+         - `limit` - Maximum number of items to return (default: 100, max: {})\n\
          - `offset` - Number of items to skip for pagination\n\n\
          # Responses\n\n\
          - `200 OK` - List of {} entities\n\
          {}\
          - `500 Internal Server Error` - Database or server error",
         entity_name,
+        max_limit,
         entity_name,
         if has_security {
             "- `401 Unauthorized` - Authentication required\n"
@@ -252,6 +319,23 @@ pub fn generate_list_handler(entity: &EntityDef) -> TokenStream {
         }
     );
 
+    let (success_ty, handler_body) = with_static_headers(
+        entity,
+        Access::Read,
+        quote! { axum::response::Json<#response_body> },
+        quote! {
+            let limit = pagination.limit.clamp(1, #max_limit);
+            let entities = repo
+                .list(limit, pagination.offset)
+                .await
+                .map_err(|e| masterror::AppError::internal(e.to_string()))?;
+            let responses: Vec<#response_dto> = entities.into_iter().map(#response_dto::from).collect();
+            Ok(axum::response::Json(#response_value))
+        }
+    );
+    let (return_ty, request_id_param, handler_body) =
+        with_request_id(entity, &handler_name, success_ty, handler_body);
+
     quote! {
         /// Pagination query parameters for list endpoints.
         ///
@@ -271,8 +355,8 @@ pub fn generate_list_handler(entity: &EntityDef) -> TokenStream {
         #vis struct PaginationQuery {
             /// Maximum number of items to return.
             ///
-            /// Defaults to 100 if not specified. Use reasonable limits
-            /// to prevent performance issues with large datasets.
+            /// Defaults to 100 if not specified. The handler clamps it to
+            /// the entity's `max_limit`.
             #[serde(default = "default_limit")]
             pub limit: i64,
 
@@ -295,17 +379,276 @@ pub fn generate_list_handler(entity: &EntityDef) -> TokenStream {
         #utoipa_attr
         #vis async fn #handler_name<R>(
             axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
+            #request_id_param
             axum::extract::Query(pagination): axum::extract::Query<PaginationQuery>,
-        ) -> masterror::AppResult<axum::response::Json<Vec<#response_dto>>>
+        ) -> #return_ty
         where
             R: #repo_trait + 'static,
         {
+            #handler_body
+        }
+    }
+}
+
+/// Generates the GET list handler for `api(list_query)`.
+///
+/// Extracts `{Entity}Query` from the query string, clamps its `limit` like
+/// the paginated handler and runs `repository.query(...)`, so the
+/// `#[filter]` fields apply over HTTP.
+fn generate_filtered_list_handler(entity: &EntityDef) -> TokenStream {
+    let vis = &entity.vis;
+    let entity_name = entity.name();
+    let entity_name_str = entity.name_str();
+    let api_config = entity.api_config();
+    let repo_trait = entity.ident_with("", "Repository");
+    let query_type = entity.ident_with("", "Query");
+    let has_security = api_config.security.is_some();
+
+    let handler_name = format_ident!("list_{}", entity_name_str.to_case(Case::Snake));
+    let response_dto = entity.ident_with("", "Response");
+    let (response_body, response_value) = wrap_body(
+        entity,
+        Body::List,
+        quote! { Vec<#response_dto> },
+        quote! { responses }
+    );
+
+    let path = build_collection_path(entity);
+    let tag = api_config.read_tag_or_default(&entity_name_str);
+
+    let security_attr = build_security_attr(entity);
+    let deprecated_attr = build_deprecated_attr(entity);
+
+    let success_desc = format!("List of {} entities", entity_name);
+    let max_limit = api_config.max_limit_or_default();
+
+    let utoipa_attr = if has_security {
+        quote! {
+            #[utoipa::path(
+                get,
+                path = #path,
+                tag = #tag,
+                params(#query_type),
+                responses(
+                    (status = 200, description = #success_desc, body = #response_body),
+                    (status = 401, description = "Authentication required"),
+                    (status = 500, description = "Internal server error")
+                ),
+                #security_attr
+                #deprecated_attr
+            )]
+        }
+    } else {
+        quote! {
+            #[utoipa::path(
+                get,
+                path = #path,
+                tag = #tag,
+                params(#query_type),
+                responses(
+                    (status = 200, description = #success_desc, body = #response_body),
+                    (status = 500, description = "Internal server error")
+                )
+                #deprecated_attr
+            )]
+        }
+    };
+
+    let doc = format!(
+        "List {} entities matching the `{}` filters.\n\n\
+         # Query Parameters\n\n\
+         - One parameter per `#[filter]` field; unset filters match everything\n\
+         - `limit` - Maximum number of items to return (default: 100, max: {})\n\
+         - `offset` - Number of items to skip for pagination\n\n\
+         # Responses\n\n\
+         - `200 OK` - List of {} entities\n\
+         {}\
+         - `500 Internal Server Error` - Database or server error",
+        entity_name,
+        query_type,
+        max_limit,
+        entity_name,
+        if has_security {
+            "- `401 Unauthorized` - Authentication required\n"
+        } else {
+            ""
+        }
+    );
+
+    let (success_ty, handler_body) = with_static_headers(
+        entity,
+        Access::Read,
+        quote! { axum::response::Json<#response_body> },
+        quote! {
+            query.limit = Some(query.limit.unwrap_or(100).clamp(1, #max_limit));
             let entities = repo
-                .list(pagination.limit, pagination.offset)
+                .query(query)
                 .await
                 .map_err(|e| masterror::AppError::internal(e.to_string()))?;
             let responses: Vec<#response_dto> = entities.into_iter().map(#response_dto::from).collect();
-            Ok(axum::response::Json(responses))
+            Ok(axum::response::Json(#response_value))
+        }
+    );
+    let (return_ty, request_id_param, handler_body) =
+        with_request_id(entity, &handler_name, success_ty, handler_body);
+
+    quote! {
+        #[doc = #doc]
+        #utoipa_attr
+        #vis async fn #handler_name<R>(
+            axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
+            #request_id_param
+            axum::extract::Query(mut query): axum::extract::Query<#query_type>,
+        ) -> #return_ty
+        where
+            R: #repo_trait + 'static,
+        {
+            #handler_body
+        }
+    }
+}
+
+/// Generates the GET list handler for `api(pagination = "cursor")`.
+///
+/// Extracts `{Entity}CursorQuery`, clamps `limit` like the offset handler
+/// and runs `repository.list_page(...)`, returning `{Entity}Page`.
+fn generate_cursor_list_handler(entity: &EntityDef) -> TokenStream {
+    let vis = &entity.vis;
+    let entity_name = entity.name();
+    let entity_name_str = entity.name_str();
+    let api_config = entity.api_config();
+    let repo_trait = entity.ident_with("", "Repository");
+    let cursor_query = entity.ident_with("", "CursorQuery");
+    let page_type = entity.ident_with("", "Page");
+    let id_type = entity.id_field().ty();
+    let has_security = api_config.security.is_some();
+    let marker = marker::generated();
+
+    let handler_name = format_ident!("list_{}", entity_name_str.to_case(Case::Snake));
+    let response_dto = entity.ident_with("", "Response");
+    let (response_body, response_value) =
+        wrap_body(entity, Body::List, quote! { #page_type }, quote! { page });
+
+    let path = build_collection_path(entity);
+    let tag = api_config.read_tag_or_default(&entity_name_str);
+
+    let security_attr = build_security_attr(entity);
+    let deprecated_attr = build_deprecated_attr(entity);
+
+    let success_desc = format!("Page of {} entities", entity_name);
+    let max_limit = api_config.max_limit_or_default();
+    let max_limit_lit = proc_macro2::Literal::i64_unsuffixed(max_limit);
+    let query_doc = format!(" Cursor query parameters for `{}`.", handler_name);
+    let page_doc = format!(" One page of `{}` items, newest first.", response_dto);
+
+    let utoipa_attr = if has_security {
+        quote! {
+            #[utoipa::path(
+                get,
+                path = #path,
+                tag = #tag,
+                params(#cursor_query),
+                responses(
+                    (status = 200, description = #success_desc, body = #response_body),
+                    (status = 401, description = "Authentication required"),
+                    (status = 500, description = "Internal server error")
+                ),
+                #security_attr
+                #deprecated_attr
+            )]
+        }
+    } else {
+        quote! {
+            #[utoipa::path(
+                get,
+                path = #path,
+                tag = #tag,
+                params(#cursor_query),
+                responses(
+                    (status = 200, description = #success_desc, body = #response_body),
+                    (status = 500, description = "Internal server error")
+                )
+                #deprecated_attr
+            )]
+        }
+    };
+
+    let doc = format!(
+        "List {} entities with cursor pagination.\n\n\
+         # Query Parameters\n\n\
+         - `after` - `next_cursor` of the previous page; omit for the first page\n\
+         - `limit` - Maximum number of items to return (default: 100, max: {})\n\n\
+         # Responses\n\n\
+         - `200 OK` - Page of {} entities with `next_cursor`\n\
+         {}\
+         - `500 Internal Server Error` - Database or server error",
+        entity_name,
+        max_limit,
+        entity_name,
+        if has_security {
+            "- `401 Unauthorized` - Authentication required\n"
+        } else {
+            ""
+        }
+    );
+
+    let (success_ty, handler_body) = with_static_headers(
+        entity,
+        Access::Read,
+        quote! { axum::response::Json<#response_body> },
+        quote! {
+            let limit = query.limit.unwrap_or(100).clamp(1, #max_limit);
+            let page = repo
+                .list_page(query.after, limit)
+                .await
+                .map_err(|e| masterror::AppError::internal(e.to_string()))?;
+            let page = #page_type {
+                items: page.items.into_iter().map(#response_dto::from).collect(),
+                next_cursor: page.next_cursor,
+            };
+            Ok(axum::response::Json(#response_value))
+        }
+    );
+    let (return_ty, request_id_param, handler_body) =
+        with_request_id(entity, &handler_name, success_ty, handler_body);
+
+    quote! {
+        #marker
+        #[doc = #query_doc]
+        #[derive(Debug, Clone, Default, serde::Deserialize, utoipa::IntoParams)]
+        #[into_params(parameter_in = Query)]
+        #vis struct #cursor_query {
+            /// `next_cursor` of the previous page; omit for the first page.
+            pub after: Option<#id_type>,
+
+            /// Maximum number of items to return.
+            #[param(minimum = 1, maximum = #max_limit_lit)]
+            pub limit: Option<i64>,
+        }
+
+        #marker
+        #[doc = #page_doc]
+        #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+        #vis struct #page_type {
+            /// Items on this page, at most `limit`.
+            pub items: Vec<#response_dto>,
+
+            /// Cursor for the next page, `null` on the last page.
+            pub next_cursor: Option<#id_type>,
+        }
+
+        #[doc = #doc]
+        #utoipa_attr
+        #vis async fn #handler_name<R>(
+            axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
+            #request_id_param
+            axum::extract::Query(query): axum::extract::Query<#cursor_query>,
+        ) -> #return_ty
+        where
+            R: #repo_trait + 'static,
+        {
+            #handler_body
         }
     }
 }
@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! GET handler generation for streaming entities as Server-Sent Events.
+//!
+//! This module generates the `stream_{entity}` HTTP handler function,
+//! enabled with `api(handlers(list_stream))`. The calling crate needs
+//! `futures` next to `axum`.
+//!
+//! # Generated Handler
+//!
+//! For an entity `User`, generates:
+//!
+//! ```rust,ignore
+//! /// Stream every User as Server-Sent Events.
+//! #[utoipa::path(
+//!     get,
+//!     path = "/users/stream",
+//!     tag = "Users",
+//!     responses(
+//!         (status = 200, description = "...", content_type = "text/event-stream", body = UserResponse),
+//!         (status = 500, description = "Internal server error")
+//!     )
+//! )]
+//! pub async fn stream_user<R>(
+//!     State(repo): State<Arc<R>>,
+//! ) -> Sse<impl Stream<Item = Result<Event, axum::Error>>>
+//! where
+//!     R: UserRepository + 'static,
+//! { ... }
+//! ```
+//!
+//! # Event Format
+//!
+//! Each entity becomes one unnamed event whose data is the JSON-encoded
+//! `{Entity}Response`:
+//!
+//! ```text
+//! data: {"id":"uuid-1","name":"Alice"}
+//!
+//! data: {"id":"uuid-2","name":"Bob"}
+//! ```
+//!
+//! A repository error mid-stream is sent as a final `error` event carrying
+//! the message, after which the stream ends.
+//!
+//! # Fetching
+//!
+//! Rows are read through `repository.list_page(cursor, size)` in pages of
+//! `api(max_limit)` (default 1000), newest first. Each page continues from
+//! the last id of the previous one (keyset pagination), so every page is an
+//! indexed range scan, and rows inserted or deleted while the stream runs
+//! never shift it into repeating or skipping rows. The next page is fetched
+//! only once the client has consumed the previous one, so slow clients
+//! never make the server buffer the whole table.
+
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::helpers::{build_collection_path, build_deprecated_attr, build_security_attr};
+use crate::entity::parse::EntityDef;
+
+/// Build the SSE endpoint path (e.g., `/users/stream`).
+fn build_stream_path(entity: &EntityDef) -> String {
+    format!("{}/stream", build_collection_path(entity))
+}
+
+/// Generates the GET handler streaming all entities as Server-Sent Events.
+///
+/// # Generated Components
+///
+/// | Component | Description |
+/// |-----------|-------------|
+/// | Function name | `stream_{entity_snake}` (e.g., `stream_user`) |
+/// | Path | Collection path + `/stream` (e.g., `/users/stream`) |
+/// | Method | GET |
+/// | Response | `text/event-stream` of `{Entity}Response` JSON |
+/// | Status codes | 200, 401 (if auth), 500 |
+///
+/// Response envelopes, static headers and request IDs do not apply: each
+/// event carries a bare response DTO and the SSE response sets its own
+/// headers.
+pub fn generate_list_stream_handler(entity: &EntityDef) -> TokenStream {
+    let vis = &entity.vis;
+    let entity_name = entity.name();
+    let entity_name_str = entity.name_str();
+    let api_config = entity.api_config();
+    let repo_trait = entity.ident_with("", "Repository");
+    let id_type = entity.id_field().ty();
+    let has_security = api_config.security.is_some();
+
+    let handler_name = format_ident!("stream_{}", entity_name_str.to_case(Case::Snake));
+    let response_dto = entity.ident_with("", "Response");
+
+    let path = build_stream_path(entity);
+    let tag = api_config.read_tag_or_default(&entity_name_str);
+    let chunk_size = api_config.max_limit_or_default();
+
+    let security_attr = build_security_attr(entity);
+    let deprecated_attr = build_deprecated_attr(entity);
+
+    let success_desc = format!("Stream of {} entities as Server-Sent Events", entity_name);
+
+    let utoipa_attr = if has_security {
+        quote! {
+            #[utoipa::path(
+                get,
+                path = #path,
+                tag = #tag,
+                responses(
+                    (status = 200, description = #success_desc, content_type = "text/event-stream", body = #response_dto),
+                    (status = 401, description = "Authentication required"),
+                    (status = 500, description = "Internal server error")
+                ),
+                #security_attr
+                #deprecated_attr
+            )]
+        }
+    } else {
+        quote! {
+            #[utoipa::path(
+                get,
+                path = #path,
+                tag = #tag,
+                responses(
+                    (status = 200, description = #success_desc, content_type = "text/event-stream", body = #response_dto),
+                    (status = 500, description = "Internal server error")
+                )
+                #deprecated_attr
+            )]
+        }
+    };
+
+    let doc = format!(
+        "Stream every {} as Server-Sent Events.\n\n\
+         Each event carries one JSON-encoded `{}`. Rows are fetched newest \
+         first in keyset pages of {} as the client consumes them. A repository error is sent as a \
+         final `error` event.\n\n\
+         # Responses\n\n\
+         - `200 OK` - `text/event-stream` of {} entities\n\
+         {}\
+         - `500 Internal Server Error` - Database or server error",
+        entity_name,
+        response_dto,
+        chunk_size,
+        entity_name,
+        if has_security {
+            "- `401 Unauthorized` - Authentication required\n"
+        } else {
+            ""
+        }
+    );
+
+    quote! {
+        #[doc = #doc]
+        #utoipa_attr
+        #vis async fn #handler_name<R>(
+            axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
+        ) -> axum::response::sse::Sse<
+            impl futures::Stream<Item = Result<axum::response::sse::Event, axum::Error>>
+        >
+        where
+            R: #repo_trait + 'static,
+        {
+            use futures::StreamExt;
+
+            // State is the cursor of the next page, `None` once exhausted.
+            let start: Option<Option<#id_type>> = Some(None);
+            let pages = futures::stream::unfold(start, move |next| {
+                let repo = repo.clone();
+                async move {
+                    let cursor = next?;
+                    match repo.list_page(cursor, #chunk_size).await {
+                        Ok(page) if page.items.is_empty() => None,
+                        Ok(page) => {
+                            let next = page.next_cursor.map(Some);
+                            let events: Vec<Result<axum::response::sse::Event, axum::Error>> = page
+                                .items
+                                .into_iter()
+                                .map(|entity| {
+                                    axum::response::sse::Event::default()
+                                        .json_data(#response_dto::from(entity))
+                                })
+                                .collect();
+                            Some((futures::stream::iter(events), next))
+                        }
+                        Err(e) => {
+                            let event = axum::response::sse::Event::default()
+                                .event("error")
+                                .data(e.to_string());
+                            Some((futures::stream::iter(vec![Ok(event)]), None))
+                        }
+                    }
+                }
+            });
+
+            axum::response::sse::Sse::new(pages.flatten())
+                .keep_alive(axum::response::sse::KeepAlive::default())
+        }
+    }
+}
@@ -57,3 +57,400 @@ fn no_handlers_when_disabled() {
     let tokens = generate(&entity);
     assert!(tokens.is_empty());
 }
+
+#[test]
+fn etag_handlers_use_conditional_headers() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", etag = "version", handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+            #[field(response)]
+            pub version: i64,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("header :: ETAG"));
+    assert!(output.contains("header :: IF_MATCH"));
+    assert!(output.contains("PRECONDITION_FAILED"));
+    assert!(output.contains("status = 412"));
+    assert!(output.contains("format ! (\"\\\"{}\\\"\" , entity . version)"));
+}
+
+#[test]
+fn etag_formats_timestamps_as_rfc3339() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", etag = "updated_at", handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            pub updated_at: chrono::DateTime<chrono::Utc>,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("entity . updated_at . to_rfc3339 ()"));
+}
+
+#[test]
+fn no_etag_without_option() {
+    let entity = create_test_entity();
+    let output = generate(&entity).to_string();
+    assert!(!output.contains("ETAG"));
+    assert!(!output.contains("IF_MATCH"));
+}
+
+#[test]
+fn list_handler_clamps_to_max_limit() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", max_limit = 250, handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("clamp (1 , 250i64)"));
+    assert!(output.contains("maximum = 250"));
+}
+
+#[test]
+fn list_stream_handler_sends_sse_events() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", max_limit = 250, handlers(list_stream)))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("async fn stream_user < R >"));
+    assert!(output.contains("path = \"/users/stream\""));
+    assert!(output.contains("content_type = \"text/event-stream\""));
+    assert!(output.contains("repo . list_page (cursor , 250i64)"));
+    assert!(output.contains("let start : Option < Option < uuid :: Uuid >> = Some (None)"));
+    assert!(!output.contains("fetch_chunk"));
+    assert!(output.contains(". json_data (UserResponse :: from (entity))"));
+    assert!(output.contains(". event (\"error\")"));
+    assert!(!output.contains("list_user"));
+}
+
+#[test]
+fn multipart_field_create_extracts_multipart() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "assets", api(tag = "Assets", multipart_field = "file", handlers(create, update)))]
+        pub struct Asset {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub title: String,
+            #[field(create, response)]
+            pub pages: i32,
+            #[field(create, rename_api = "upload")]
+            pub file: Vec<u8>,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("mut multipart : axum :: extract :: Multipart"));
+    assert!(output.contains("content_type = \"multipart/form-data\""));
+    assert!(output.contains("if name == \"upload\""));
+    assert!(output.contains("[\"title\"] . contains (& name . as_str ())"));
+    assert!(output.contains("bad_request (\"missing multipart part 'upload'\")"));
+    assert!(output.contains("dto . file = file ;"));
+    assert!(output.contains(
+        "axum :: extract :: Json (dto) : axum :: extract :: Json < UpdateAssetRequest >"
+    ));
+}
+
+#[test]
+fn handlers_flag_omits_list_stream() {
+    let output = generate(&create_test_entity()).to_string();
+    assert!(!output.contains("stream_user"));
+}
+
+#[test]
+fn list_query_handler_extracts_entity_query() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", max_limit = 250, list_query, handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            #[filter(like)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("axum :: extract :: Query < UserQuery >"));
+    assert!(output.contains("params (UserQuery)"));
+    assert!(
+        output.contains(
+            "query . limit = Some (query . limit . unwrap_or (100) . clamp (1 , 250i64))"
+        )
+    );
+    assert!(output.contains(". query (query)"));
+    assert!(!output.contains("PaginationQuery"));
+}
+
+#[test]
+fn cursor_list_handler_returns_page() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", max_limit = 250, pagination = "cursor", handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("pub struct UserCursorQuery { # [doc"));
+    assert!(output.contains("pub after : Option < uuid :: Uuid >"));
+    assert!(output.contains("pub next_cursor : Option < uuid :: Uuid >"));
+    assert!(output.contains("axum :: extract :: Query < UserCursorQuery >"));
+    assert!(output.contains("params (UserCursorQuery)"));
+    assert!(output.contains("body = UserPage"));
+    assert!(output.contains("query . limit . unwrap_or (100) . clamp (1 , 250i64)"));
+    assert!(output.contains(". list_page (query . after , limit)"));
+    assert!(!output.contains("PaginationQuery"));
+    assert!(!output.contains(". list (limit"));
+}
+
+#[test]
+fn cursor_list_envelope_wraps_page() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", pagination = "cursor", handlers(list), response_envelope = "data"))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("pub data : UserPage"));
+    assert!(output.contains("UserListEnvelope { data : page }"));
+}
+
+#[test]
+fn list_handler_default_max_limit() {
+    let entity = create_test_entity();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("clamp (1 , 1000i64)"));
+}
+
+#[test]
+fn form_content_type_uses_form_extractor() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(
+            table = "users",
+            api(tag = "Users", handlers, content_type = "application/x-www-form-urlencoded")
+        )]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("axum :: extract :: Form"));
+    assert!(output.contains("\"application/x-www-form-urlencoded\""));
+    assert!(!output.contains("axum :: extract :: Json (dto)"));
+}
+
+#[test]
+fn deprecated_sunset_handlers_send_lifecycle_headers() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", handlers, deprecated_sunset = "2026-12-31"))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("from_static (\"deprecation\")"));
+    assert!(output.contains("\"Thu, 31 Dec 2026 23:59:59 GMT\""));
+    assert!(output.contains("deprecated = true"));
+}
+
+#[test]
+fn static_headers_split_by_access() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(
+            table = "users",
+            api(
+                tag = "Users",
+                handlers,
+                base_response_headers = ["Cache-Control: max-age=60"],
+                write_response_headers = ["Cache-Control: no-store"]
+            )
+        )]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let cache = "(axum :: http :: HeaderName :: from_static (\"cache-control\") , \"max-age=60\")";
+    let no_store =
+        "(axum :: http :: HeaderName :: from_static (\"cache-control\") , \"no-store\")";
+
+    for read in [
+        generate_get_handler(&entity),
+        generate_list_handler(&entity)
+    ] {
+        let output = read.to_string();
+        assert!(output.contains(cache));
+        assert!(!output.contains(no_store));
+    }
+    for write in [
+        generate_create_handler(&entity),
+        generate_update_handler(&entity),
+        generate_delete_handler(&entity)
+    ] {
+        let output = write.to_string();
+        assert!(output.contains(no_store));
+        assert!(!output.contains(cache));
+    }
+}
+
+#[test]
+fn static_headers_combine_with_lifecycle_headers() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(
+            table = "users",
+            api(
+                tag = "Users",
+                handlers(get),
+                deprecated_in = "2.0",
+                base_response_headers = ["Cache-Control: max-age=60"]
+            )
+        )]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate_get_handler(&entity).to_string();
+    assert!(output.contains("[(axum :: http :: HeaderName , & 'static str) ; 2usize]"));
+    assert!(output.contains("from_static (\"cache-control\")"));
+    assert!(output.contains("from_static (\"deprecation\")"));
+}
+
+#[test]
+fn no_lifecycle_headers_without_deprecation() {
+    let entity = create_test_entity();
+    let output = generate(&entity).to_string();
+    assert!(!output.contains("\"deprecation\""));
+    assert!(!output.contains("\"sunset\""));
+}
+
+#[test]
+fn request_id_handlers_correlate_requests() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", handlers, request_id, etag = "version"))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+            #[field(response)]
+            pub version: i64,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert_eq!(
+        output
+            .matches("request_headers : axum :: http :: HeaderMap")
+            .count(),
+        5
+    );
+    assert!(
+        output.contains("tracing :: info_span ! (\"create_user\" , request_id = % request_id)")
+    );
+    assert!(output.contains("from_static (\"x-request-id\")"));
+    assert!(output.contains("uuid :: Uuid :: now_v7 ()"));
+    assert!(!output.contains("-> masterror :: AppResult"));
+}
+
+#[test]
+fn no_request_id_without_option() {
+    let entity = create_test_entity();
+    let output = generate(&entity).to_string();
+    assert!(!output.contains("x-request-id"));
+    assert!(!output.contains("request_headers"));
+}
+
+#[test]
+fn response_envelope_wraps_bodies() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", handlers, response_envelope = "data"))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("pub struct UserEnvelope"));
+    assert!(output.contains("pub struct UserListEnvelope"));
+    assert!(!output.contains("UserIdEnvelope"));
+    assert!(output.contains("# [serde (rename = \"data\")] pub data : Vec < UserResponse >"));
+    assert!(output.contains("body = UserEnvelope"));
+    assert!(output.contains("body = UserListEnvelope"));
+    assert!(output.contains("UserEnvelope { data : UserResponse :: from (created) }"));
+    assert!(output.contains("UserListEnvelope { data : responses }"));
+    assert!(!output.contains("body = UserResponse"));
+}
+
+#[test]
+fn response_envelope_wraps_minimal_create_id() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", returning = "minimal", api(tag = "Users", handlers(create), response_envelope = "result"))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("# [serde (rename = \"result\")] pub data : uuid :: Uuid"));
+    assert!(output.contains("UserIdEnvelope { data : created }"));
+    assert!(!output.contains("pub struct UserEnvelope"));
+}
+
+#[test]
+fn no_envelope_without_option() {
+    let output = generate(&create_test_entity()).to_string();
+    assert!(!output.contains("Envelope"));
+    assert!(output.contains("body = UserResponse"));
+}
@@ -107,7 +107,14 @@ use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use super::helpers::{build_deprecated_attr, build_item_path, build_security_attr};
+use super::{
+    envelope::{Body, wrap_body},
+    helpers::{
+        Access, build_body_extractor, build_deprecated_attr, build_etag_value,
+        build_if_match_check, build_item_path, build_security_attr, with_request_id,
+        with_static_headers
+    }
+};
 use crate::entity::parse::EntityDef;
 
 /// Generates the PATCH handler for updating existing entities.
@@ -142,6 +149,11 @@ use crate::entity::parse::EntityDef;
 /// | Response body | `{Entity}Response` |
 /// | Status codes | 200, 400, 401 (if auth), 500 |
 ///
+/// # Conditional Requests
+///
+/// With `api(etag = "field")` the handler honors `If-Match`, returning
+/// `412 Precondition Failed` on mismatch, and sends the new `ETag`.
+///
 /// # UpdateRequest Generation
 ///
 /// The `UpdateEntityRequest` is generated separately with all fields
@@ -167,31 +179,51 @@ pub fn generate_update_handler(entity: &EntityDef) -> TokenStream {
     let handler_name = format_ident!("update_{}", entity_name_str.to_case(Case::Snake));
     let update_dto = entity.ident_with("Update", "Request");
     let response_dto = entity.ident_with("", "Response");
+    let (response_body, response_value) = wrap_body(
+        entity,
+        Body::Single,
+        quote! { #response_dto },
+        quote! { #response_dto::from(entity) }
+    );
 
     let path = build_item_path(entity);
-    let tag = api_config.tag_or_default(&entity_name_str);
+    let tag = api_config.write_tag_or_default(&entity_name_str);
 
     let security_attr = build_security_attr(entity);
     let deprecated_attr = build_deprecated_attr(entity);
 
     let id_desc = format!("{} unique identifier", entity_name);
     let request_body_desc = format!("Fields to update for {}", entity_name);
+    let content_type = api_config.content_type.media_type();
+    let body = build_body_extractor(entity, &update_dto);
     let success_desc = format!("{} updated successfully", entity_name);
     let not_found_desc = format!("{} not found", entity_name);
 
+    let etag = build_etag_value(entity);
+    let if_match_check = build_if_match_check(entity);
+    let (if_match_param, precondition_response) = if etag.is_some() {
+        (
+            quote! { , ("If-Match" = Option<String>, Header, description = "Expected ETag") },
+            quote! { (status = 412, description = "ETag does not match If-Match"), }
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
     let utoipa_attr = if has_security {
         quote! {
             #[utoipa::path(
                 patch,
                 path = #path,
                 tag = #tag,
-                params(("id" = #id_type, Path, description = #id_desc)),
-                request_body(content = #update_dto, description = #request_body_desc),
+                params(("id" = #id_type, Path, description = #id_desc) #if_match_param),
+                request_body(content = #update_dto, content_type = #content_type, description = #request_body_desc),
                 responses(
-                    (status = 200, description = #success_desc, body = #response_dto),
+                    (status = 200, description = #success_desc, body = #response_body),
                     (status = 400, description = "Invalid request data"),
                     (status = 401, description = "Authentication required"),
                     (status = 404, description = #not_found_desc),
+                    #precondition_response
                     (status = 500, description = "Internal server error")
                 ),
                 #security_attr
@@ -204,12 +236,13 @@ pub fn generate_update_handler(entity: &EntityDef) -> TokenStream {
                 patch,
                 path = #path,
                 tag = #tag,
-                params(("id" = #id_type, Path, description = #id_desc)),
-                request_body(content = #update_dto, description = #request_body_desc),
+                params(("id" = #id_type, Path, description = #id_desc) #if_match_param),
+                request_body(content = #update_dto, content_type = #content_type, description = #request_body_desc),
                 responses(
-                    (status = 200, description = #success_desc, body = #response_dto),
+                    (status = 200, description = #success_desc, body = #response_body),
                     (status = 400, description = "Invalid request data"),
                     (status = 404, description = #not_found_desc),
+                    #precondition_response
                     (status = 500, description = "Internal server error")
                 )
                 #deprecated_attr
@@ -235,22 +268,73 @@ pub fn generate_update_handler(entity: &EntityDef) -> TokenStream {
         entity_name
     );
 
+    let Some(etag) = etag else {
+        let (success_ty, handler_body) = with_static_headers(
+            entity,
+            Access::Write,
+            quote! { axum::response::Json<#response_body> },
+            quote! {
+                let entity = repo
+                    .update(id, dto)
+                    .await
+                    .map_err(|e| masterror::AppError::internal(e.to_string()))?;
+                Ok(axum::response::Json(#response_value))
+            }
+        );
+        let (return_ty, request_id_param, handler_body) =
+            with_request_id(entity, &handler_name, success_ty, handler_body);
+
+        return quote! {
+            #[doc = #doc]
+            #utoipa_attr
+            #vis async fn #handler_name<R>(
+                axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
+                #request_id_param
+                axum::extract::Path(id): axum::extract::Path<#id_type>,
+                #body,
+            ) -> #return_ty
+            where
+                R: #repo_trait + 'static,
+            {
+                #handler_body
+            }
+        };
+    };
+
+    let (success_ty, handler_body) = with_static_headers(
+        entity,
+        Access::Write,
+        quote! { axum::response::Response },
+        quote! {
+            #if_match_check
+            let entity = repo
+                .update(id, dto)
+                .await
+                .map_err(|e| masterror::AppError::internal(e.to_string()))?;
+            let etag = #etag;
+            Ok(axum::response::IntoResponse::into_response((
+                [(axum::http::header::ETAG, etag)],
+                axum::response::Json(#response_value)
+            )))
+        }
+    );
+    let (return_ty, request_id_param, handler_body) =
+        with_request_id(entity, &handler_name, success_ty, handler_body);
+
     quote! {
         #[doc = #doc]
         #utoipa_attr
         #vis async fn #handler_name<R>(
             axum::extract::State(repo): axum::extract::State<std::sync::Arc<R>>,
+            #request_id_param
             axum::extract::Path(id): axum::extract::Path<#id_type>,
-            axum::extract::Json(dto): axum::extract::Json<#update_dto>,
-        ) -> masterror::AppResult<axum::response::Json<#response_dto>>
+            headers: axum::http::HeaderMap,
+            #body,
+        ) -> #return_ty
         where
             R: #repo_trait + 'static,
         {
-            let entity = repo
-                .update(id, dto)
-                .await
-                .map_err(|e| masterror::AppError::internal(e.to_string()))?;
-            Ok(axum::response::Json(#response_dto::from(entity)))
+            #handler_body
         }
     }
 }
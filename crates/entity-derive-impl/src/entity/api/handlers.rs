@@ -79,7 +79,7 @@ fn generate_handler(entity: &EntityDef, cmd: &CommandDef) -> TokenStream {
     let path = build_path(entity, cmd);
     let http_method = http_method_for_command(cmd);
     let http_method_ident = format_ident!("{}", http_method);
-    let tag = api_config.tag_or_default(&entity_name_str);
+    let tag = api_config.write_tag_or_default(&entity_name_str);
 
     let security_attr = if cmd.is_public() {
         quote! {}
@@ -136,7 +136,7 @@ use quote::{format_ident, quote};
 pub use self::paths::{build_collection_path, build_item_path};
 pub use self::{
     info::generate_info_code,
-    paths::generate_paths_code,
+    paths::{generate_extensions_code, generate_paths_code},
     schemas::{generate_all_schema_types, generate_common_schemas_code},
     security::generate_security_code
 };
@@ -225,6 +225,20 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
         .clone()
         .or_else(|| entity.doc().map(String::from))
         .unwrap_or_else(|| format!("{} management", entity_name));
+    let tag_external_docs = api_config.external_docs.as_deref().map(|url| {
+        let description = api_config
+            .external_docs_description
+            .as_deref()
+            .map(|d| quote! { , description = #d });
+        quote! { , external_docs(url = #url #description) }
+    });
+    let mut extra_tags: Vec<String> = [&api_config.read_tag, &api_config.write_tag]
+        .into_iter()
+        .flatten()
+        .filter(|t| **t != tag)
+        .cloned()
+        .collect();
+    extra_tags.dedup();
 
     let schema_types = generate_all_schema_types(entity);
     let modifier_impl = generate_modifier(entity, &modifier_struct);
@@ -247,7 +261,7 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
         #[openapi(
             components(schemas(#schema_types)),
             modifiers(&#modifier_struct),
-            tags((name = #tag, description = #tag_description))
+            tags((name = #tag, description = #tag_description #tag_external_docs) #(, (name = #extra_tags))*)
         )]
         #vis struct #api_struct;
     }
@@ -302,6 +316,9 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
 /// │     ├─► PATCH /entities/{id} (update)                      │
 /// │     └─► DELETE /entities/{id} (delete)                     │
 /// │                                                            │
+/// │  5. Operation Extensions                                   │
+/// │     └─► x-owner, ... from api(extensions(...))             │
+/// │                                                            │
 /// └────────────────────────────────────────────────────────────┘
 /// ```
 ///
@@ -329,7 +346,7 @@ fn generate_modifier(entity: &EntityDef, modifier_name: &syn::Ident) -> TokenStr
     let info_code = generate_info_code(entity);
     let security_code = generate_security_code(api_config.security.as_deref());
     let common_schemas_code = if api_config.has_handlers() {
-        generate_common_schemas_code()
+        generate_common_schemas_code(api_config.max_limit_or_default())
     } else {
         TokenStream::new()
     };
@@ -338,6 +355,7 @@ fn generate_modifier(entity: &EntityDef, modifier_name: &syn::Ident) -> TokenStr
     } else {
         TokenStream::new()
     };
+    let extensions_code = generate_extensions_code(entity);
 
     let doc = format!("OpenAPI modifier for {} entity.", entity_name);
 
@@ -353,6 +371,7 @@ fn generate_modifier(entity: &EntityDef, modifier_name: &syn::Ident) -> TokenStr
                 #security_code
                 #common_schemas_code
                 #paths_code
+                #extensions_code
             }
         }
     }
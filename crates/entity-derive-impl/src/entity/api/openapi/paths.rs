@@ -23,7 +23,8 @@
 //! │  │   └─► security: bearerAuth                                       │
 //! │  │                                                                  │
 //! │  └─► GET    list_user          # List entities with pagination      │
-//! │      ├─► parameters: limit, offset                                  │
+//! │      ├─► parameters: limit, offset (or {Entity}Query filters)       │
+//! │      │   (or after, limit with cursor pagination → {Entity}Page)    │
 //! │      ├─► responses: 200, 401, 500                                   │
 //! │      └─► security: bearerAuth                                       │
 //! │                                                                     │
@@ -85,12 +86,17 @@
 //! | `operationId` | Unique identifier | `create_user` |
 //! | `summary` | Short description | "Create a new User" |
 //! | `description` | Detailed description | "Creates a new User entity" |
-//! | `tag` | API grouping | "Users" |
+//! | `tag` | API grouping; `read_tag`/`write_tag` override per operation | "Users" |
 //! | `parameters` | Path/query params | `id: Uuid` |
 //! | `requestBody` | Request schema | `CreateUserRequest` |
 //! | `responses` | Response codes/bodies | 200, 404, 500 |
 //! | `security` | Auth requirements | `bearerAuth` |
 //!
+//! Reads (get, list, list_stream) use `read_tag` and writes (create, update,
+//! delete) use `write_tag`, both falling back to `tag`. Swagger UI lists
+//! operations per tag in path order, and within a path by HTTP method;
+//! collection paths are registered before item paths.
+//!
 //! # Response Codes
 //!
 //! Standard HTTP response codes per operation:
@@ -99,6 +105,7 @@
 //! |-----------|---------|--------------|--------------|
 //! | Create | 201 | 400, 401 | 500 |
 //! | List | 200 | 401 | 500 |
+//! | List stream | 200 (`text/event-stream`) | 401 | 500 |
 //! | Get | 200 | 401, 404 | 500 |
 //! | Update | 200 | 400, 401, 404 | 500 |
 //! | Delete | 204 | 401, 404 | 500 |
@@ -108,7 +115,7 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
 use super::security::security_scheme_name;
-use crate::entity::parse::{CommandDef, EntityDef};
+use crate::entity::parse::{CommandDef, EntityDef, PaginationStyle};
 
 /// Generates code to add CRUD path operations to the OpenAPI specification.
 ///
@@ -173,7 +180,14 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
     let id_field = entity.id_field();
     let id_type = &id_field.ty;
 
-    let tag = api_config.tag_or_default(&entity_name_str);
+    let read_tag = api_config.read_tag_or_default(&entity_name_str);
+    let write_tag = api_config.write_tag_or_default(&entity_name_str);
+    let body_content_type = api_config.content_type.media_type();
+    let create_content_type = if api_config.multipart_field.is_some() {
+        "multipart/form-data"
+    } else {
+        body_content_type
+    };
     let collection_path = build_collection_path(entity);
     let item_path = build_item_path(entity);
 
@@ -211,23 +225,29 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
         }
     };
 
-    let create_op_id = format!("create_{}", entity_name_str.to_case(Case::Snake));
-    let get_op_id = format!("get_{}", entity_name_str.to_case(Case::Snake));
-    let update_op_id = format!("update_{}", entity_name_str.to_case(Case::Snake));
-    let delete_op_id = format!("delete_{}", entity_name_str.to_case(Case::Snake));
-    let list_op_id = format!("list_{}", entity_name_str.to_case(Case::Snake));
+    let create_op_id = api_config.operation_id("create", &entity_name_str);
+    let get_op_id = api_config.operation_id("get", &entity_name_str);
+    let update_op_id = api_config.operation_id("update", &entity_name_str);
+    let delete_op_id = api_config.operation_id("delete", &entity_name_str);
+    let list_op_id = api_config.operation_id("list", &entity_name_str);
+    let stream_op_id = api_config.operation_id("stream", &entity_name_str);
 
     let create_summary = format!("Create a new {}", entity_name);
     let get_summary = format!("Get {} by ID", entity_name);
     let update_summary = format!("Update {} by ID", entity_name);
     let delete_summary = format!("Delete {} by ID", entity_name);
     let list_summary = format!("List all {}", entity_name);
+    let stream_summary = format!("Stream all {}", entity_name);
 
     let create_desc = format!("Creates a new {} entity", entity_name);
     let get_desc = format!("Retrieves a {} by its unique identifier", entity_name);
     let update_desc = format!("Updates an existing {} by ID", entity_name);
     let delete_desc = format!("Deletes a {} by ID", entity_name);
     let list_desc = format!("Returns a paginated list of {} entities", entity_name);
+    let stream_desc = format!(
+        "Streams every {} as Server-Sent Events, one JSON-encoded response per event",
+        entity_name
+    );
 
     let id_param_desc = format!("{} unique identifier", entity_name);
     let created_desc = format!("{} created successfully", entity_name);
@@ -235,6 +255,7 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
     let updated_desc = format!("{} updated successfully", entity_name);
     let deleted_desc = format!("{} deleted successfully", entity_name);
     let list_desc_resp = format!("List of {} entities", entity_name);
+    let stream_desc_resp = format!("Stream of {} entities", entity_name);
     let not_found_desc = format!("{} not found", entity_name);
 
     let common_code = quote! {
@@ -271,14 +292,14 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
             let create_op = {
                 let mut op = path::OperationBuilder::new()
                     .operation_id(Some(#create_op_id))
-                    .tag(#tag)
+                    .tag(#write_tag)
                     .summary(Some(#create_summary))
                     .description(Some(#create_desc))
                     .request_body(Some(
                         request_body::RequestBodyBuilder::new()
                             .description(Some("Request body"))
                             .required(Some(utoipa::openapi::Required::True))
-                            .content("application/json",
+                            .content(#create_content_type,
                                 content::ContentBuilder::new()
                                     .schema(Some(Ref::from_schema_name(#create_ref)))
                                     .build()
@@ -310,41 +331,74 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
     };
 
     let list_code = if handlers.list {
-        quote! {
-            let limit_param = path::ParameterBuilder::new()
-                .name("limit")
-                .parameter_in(path::ParameterIn::Query)
-                .required(utoipa::openapi::Required::False)
-                .description(Some("Maximum number of items to return (default: 100)"))
-                .schema(Some(ObjectBuilder::new().schema_type(schema::Type::Integer).build()))
-                .build();
+        let cursor = api_config.pagination == PaginationStyle::Cursor;
+        let list_params = if api_config.list_query {
+            let query_type = entity.ident_with("", "Query");
+            quote! {
+                <#query_type as utoipa::IntoParams>::into_params(|| Some(path::ParameterIn::Query))
+            }
+        } else if cursor {
+            let cursor_query = entity.ident_with("", "CursorQuery");
+            quote! {
+                <#cursor_query as utoipa::IntoParams>::into_params(|| Some(path::ParameterIn::Query))
+            }
+        } else {
+            let max_limit = api_config.max_limit_or_default();
+            let limit_desc = format!(
+                "Maximum number of items to return (default: 100, max: {})",
+                max_limit
+            );
+            let max_limit = max_limit as f64;
+            quote! {
+                {
+                    let limit_param = path::ParameterBuilder::new()
+                        .name("limit")
+                        .parameter_in(path::ParameterIn::Query)
+                        .required(utoipa::openapi::Required::False)
+                        .description(Some(#limit_desc))
+                        .schema(Some(ObjectBuilder::new()
+                            .schema_type(schema::Type::Integer)
+                            .minimum(Some(1.0))
+                            .maximum(Some(#max_limit))
+                            .build()))
+                        .build();
 
-            let offset_param = path::ParameterBuilder::new()
-                .name("offset")
-                .parameter_in(path::ParameterIn::Query)
-                .required(utoipa::openapi::Required::False)
-                .description(Some("Number of items to skip for pagination"))
-                .schema(Some(ObjectBuilder::new().schema_type(schema::Type::Integer).build()))
-                .build();
+                    let offset_param = path::ParameterBuilder::new()
+                        .name("offset")
+                        .parameter_in(path::ParameterIn::Query)
+                        .required(utoipa::openapi::Required::False)
+                        .description(Some("Number of items to skip for pagination"))
+                        .schema(Some(ObjectBuilder::new().schema_type(schema::Type::Integer).build()))
+                        .build();
 
+                    vec![limit_param, offset_param]
+                }
+            }
+        };
+        let list_schema = if cursor {
+            let page_ref = entity.ident_with("", "Page").to_string();
+            quote! { Ref::from_schema_name(#page_ref) }
+        } else {
+            quote! {
+                schema::ArrayBuilder::new()
+                    .items(Ref::from_schema_name(#response_ref))
+                    .build()
+            }
+        };
+        quote! {
             let list_op = {
                 let mut op = path::OperationBuilder::new()
                     .operation_id(Some(#list_op_id))
-                    .tag(#tag)
+                    .tag(#read_tag)
                     .summary(Some(#list_summary))
                     .description(Some(#list_desc))
-                    .parameter(limit_param)
-                    .parameter(offset_param)
+                    .parameters(Some(#list_params))
                     .response("200",
                         response::ResponseBuilder::new()
                             .description(#list_desc_resp)
                             .content("application/json",
                                 content::ContentBuilder::new()
-                                    .schema(Some(
-                                        schema::ArrayBuilder::new()
-                                            .items(Ref::from_schema_name(#response_ref))
-                                            .build()
-                                    ))
+                                    .schema(Some(#list_schema))
                                     .build()
                             )
                             .build()
@@ -362,12 +416,44 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
         TokenStream::new()
     };
 
+    let stream_code = if handlers.list_stream {
+        let stream_path = format!("{}/stream", collection_path);
+        quote! {
+            let stream_op = {
+                let mut op = path::OperationBuilder::new()
+                    .operation_id(Some(#stream_op_id))
+                    .tag(#read_tag)
+                    .summary(Some(#stream_summary))
+                    .description(Some(#stream_desc))
+                    .response("200",
+                        response::ResponseBuilder::new()
+                            .description(#stream_desc_resp)
+                            .content("text/event-stream",
+                                content::ContentBuilder::new()
+                                    .schema(Some(Ref::from_schema_name(#response_ref)))
+                                    .build()
+                            )
+                            .build()
+                    )
+                    .response("500", error_response("Internal server error"));
+                if let Some(ref sec) = security_req {
+                    op = op.securities(Some(sec.clone()))
+                        .response("401", error_response("Authentication required"));
+                }
+                op.build()
+            };
+            openapi.paths.add_path_operation(#stream_path, vec![path::HttpMethod::Get], stream_op);
+        }
+    } else {
+        TokenStream::new()
+    };
+
     let get_code = if handlers.get {
         quote! {
             let get_op = {
                 let mut op = path::OperationBuilder::new()
                     .operation_id(Some(#get_op_id))
-                    .tag(#tag)
+                    .tag(#read_tag)
                     .summary(Some(#get_summary))
                     .description(Some(#get_desc))
                     .parameter(id_param.clone())
@@ -400,7 +486,7 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
             let update_op = {
                 let mut op = path::OperationBuilder::new()
                     .operation_id(Some(#update_op_id))
-                    .tag(#tag)
+                    .tag(#write_tag)
                     .summary(Some(#update_summary))
                     .description(Some(#update_desc))
                     .parameter(id_param.clone())
@@ -408,7 +494,7 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
                         request_body::RequestBodyBuilder::new()
                             .description(Some("Fields to update"))
                             .required(Some(utoipa::openapi::Required::True))
-                            .content("application/json",
+                            .content(#body_content_type,
                                 content::ContentBuilder::new()
                                     .schema(Some(Ref::from_schema_name(#update_ref)))
                                     .build()
@@ -445,7 +531,7 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
             let delete_op = {
                 let mut op = path::OperationBuilder::new()
                     .operation_id(Some(#delete_op_id))
-                    .tag(#tag)
+                    .tag(#write_tag)
                     .summary(Some(#delete_summary))
                     .description(Some(#delete_desc))
                     .parameter(id_param.clone())
@@ -473,12 +559,65 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
         #id_param_code
         #create_code
         #list_code
+        #stream_code
         #get_code
         #update_code
         #delete_code
     }
 }
 
+/// Generates code adding `api(extensions(...))` to every operation.
+///
+/// Runs after the CRUD paths are added, so it covers them and any
+/// operation already in the entity's document. Existing keys are
+/// overwritten.
+///
+/// # Generated Code
+///
+/// ```rust,ignore
+/// let extensions: [(&str, &str); 1] = [("x-owner", "billing-team")];
+/// for item in openapi.paths.paths.values_mut() {
+///     for op in [&mut item.get, &mut item.post, ...].into_iter().flatten() {
+///         op.extensions
+///             .get_or_insert_with(Default::default)
+///             .merge(extensions::Extensions::from_iter(extensions));
+///     }
+/// }
+/// ```
+///
+/// Returns an empty `TokenStream` when no extensions are configured.
+pub fn generate_extensions_code(entity: &EntityDef) -> TokenStream {
+    let extensions = &entity.api_config().extensions;
+    if extensions.is_empty() {
+        return TokenStream::new();
+    }
+    let count = extensions.len();
+    let pairs = extensions
+        .iter()
+        .map(|(key, value)| quote! { (#key, #value) });
+
+    quote! {
+        let extensions: [(&str, &str); #count] = [#(#pairs),*];
+        for item in openapi.paths.paths.values_mut() {
+            let operations = [
+                &mut item.get,
+                &mut item.put,
+                &mut item.post,
+                &mut item.delete,
+                &mut item.options,
+                &mut item.head,
+                &mut item.patch,
+                &mut item.trace
+            ];
+            for op in operations.into_iter().flatten() {
+                op.extensions
+                    .get_or_insert_with(Default::default)
+                    .merge(extensions::Extensions::from_iter(extensions));
+            }
+        }
+    }
+}
+
 /// Builds the collection path for an entity (e.g., `/users`).
 ///
 /// Collection paths are used for operations that affect multiple entities
@@ -518,13 +657,13 @@ pub fn generate_paths_code(entity: &EntityDef) -> TokenStream {
 /// # Pluralization
 ///
 /// Simple `s` suffix is added. For irregular plurals, use `prefix` to
-/// customize the full path.
+/// customize the full path. `api(path_style = "singular")` skips the suffix.
 pub fn build_collection_path(entity: &EntityDef) -> String {
     let api_config = entity.api_config();
     let prefix = api_config.full_path_prefix();
-    let entity_path = entity.name_str().to_case(Case::Kebab);
+    let resource = api_config.resource_segment(&entity.name_str());
 
-    let path = format!("{}/{}s", prefix, entity_path);
+    let path = format!("{}/{}", prefix, resource);
     path.replace("//", "/")
 }
 
@@ -42,6 +42,8 @@
 //! | `{Entity}Response` | Entity struct | Always (if handlers) |
 //! | `Create{Entity}Request` | Create DTO | If `create` handler enabled |
 //! | `Update{Entity}Request` | Update DTO | If `update` handler enabled |
+//! | `{Entity}Page` | Cursor list response | With `api(pagination = "cursor")` |
+//! | `{Entity}Envelope`, `{Entity}ListEnvelope` | Response envelopes | With `api(response_envelope = "...")` |
 //! | `{Command}` | Command struct | If commands defined |
 //!
 //! ## Common Schemas (Runtime)
@@ -92,7 +94,10 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::entity::parse::EntityDef;
+use crate::entity::{
+    api::crud::envelope_schema_types,
+    parse::{EntityDef, PaginationStyle}
+};
 
 /// Generates the list of schema types to register with OpenAPI.
 ///
@@ -116,7 +121,9 @@ use crate::entity::parse::EntityDef;
 ///     │       │
 ///     │       ├─► create == true ────► Create{Entity}Request
 ///     │       │
-///     │       └─► update == true ────► Update{Entity}Request
+///     │       ├─► update == true ────► Update{Entity}Request
+///     │       │
+///     │       └─► list + cursor ─────► {Entity}Page
 ///     │
 ///     └─► CommandDefs ───────────────► {Command} for each command
 /// ```
@@ -146,6 +153,13 @@ pub fn generate_all_schema_types(entity: &EntityDef) -> TokenStream {
             let update = entity.ident_with("Update", "Request");
             types.push(quote! { #update });
         }
+
+        if handlers.list && entity.api_config().pagination == PaginationStyle::Cursor {
+            let page = entity.ident_with("", "Page");
+            types.push(quote! { #page });
+        }
+
+        types.extend(envelope_schema_types(entity));
     }
 
     for cmd in entity.command_defs() {
@@ -163,6 +177,10 @@ pub fn generate_all_schema_types(entity: &EntityDef) -> TokenStream {
 /// schemas are built at runtime using utoipa's builder API rather than
 /// being derived from structs.
 ///
+/// # Arguments
+///
+/// * `max_limit` - Upper bound advertised for `limit`, from `api(max_limit)`
+///
 /// # Returns
 ///
 /// A `TokenStream` containing code to insert schemas into `openapi.components`.
@@ -199,7 +217,7 @@ pub fn generate_all_schema_types(entity: &EntityDef) -> TokenStream {
 ///
 /// | Field | Type | Default | Min | Max | Description |
 /// |-------|------|---------|-----|-----|-------------|
-/// | `limit` | integer | 100 | 1 | 1000 | Items per page |
+/// | `limit` | integer | 100 | 1 | `max_limit` (1000) | Items per page |
 /// | `offset` | integer | 0 | 0 | - | Items to skip |
 ///
 /// # Implementation
@@ -227,7 +245,9 @@ pub fn generate_all_schema_types(entity: &EntityDef) -> TokenStream {
 ///     // Insert PaginationQuery schema
 /// }
 /// ```
-pub fn generate_common_schemas_code() -> TokenStream {
+pub fn generate_common_schemas_code(max_limit: i64) -> TokenStream {
+    let max_limit = max_limit as f64;
+
     quote! {
         if let Some(components) = openapi.components.as_mut() {
             let error_schema = schema::ObjectBuilder::new()
@@ -275,7 +295,7 @@ pub fn generate_common_schemas_code() -> TokenStream {
                     .description(Some("Maximum number of items to return"))
                     .default(Some(serde_json::json!(100)))
                     .minimum(Some(1.0))
-                    .maximum(Some(1000.0))
+                    .maximum(Some(#max_limit))
                     .build())
                 .property("offset", schema::ObjectBuilder::new()
                     .schema_type(schema::Type::Integer)
@@ -369,12 +389,19 @@ mod tests {
 
     #[test]
     fn common_schemas_code_generated() {
-        let code = generate_common_schemas_code();
+        let code = generate_common_schemas_code(1000);
         let code_str = code.to_string();
         assert!(code_str.contains("ErrorResponse"));
         assert!(code_str.contains("PaginationQuery"));
         assert!(code_str.contains("RFC 7807"));
         assert!(code_str.contains("limit"));
         assert!(code_str.contains("offset"));
+        assert!(code_str.contains("maximum (Some (1000f64))"));
+    }
+
+    #[test]
+    fn common_schemas_custom_max_limit() {
+        let code = generate_common_schemas_code(250);
+        assert!(code.to_string().contains("maximum (Some (250f64))"));
     }
 }
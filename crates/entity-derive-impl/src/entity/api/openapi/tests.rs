@@ -16,6 +16,11 @@
 //! | Disabled | `no_api_when_disabled` | No output when API disabled |
 //! | Paths | `collection_path_format`, `item_path_format` | URL patterns |
 //! | Handlers | `selective_handlers_*` | Conditional schema generation |
+//! | Tags | `read_write_tags_registered`, `tag_external_docs` | Tag metadata |
+//! | Params | `list_query_documents_filter_params`, `cursor_pagination_documents_page` | List query parameters |
+//! | Streaming | `list_stream_documents_event_stream` | SSE list operation |
+//! | Uploads | `multipart_field_documents_form_data_create` | Multipart create body |
+//! | Operation ids | `operation_id_prefix_applied` | Prefixed `operationId`s |
 //!
 //! # Test Methodology
 //!
@@ -147,6 +152,22 @@ fn selective_handlers_schemas_get_list_only() {
     assert!(!output.contains("UpdateUserRequest"));
 }
 
+#[test]
+fn response_envelope_schemas_registered() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", handlers(get, list), response_envelope = "data"))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("UserResponse , UserEnvelope , UserListEnvelope"));
+}
+
 #[test]
 fn selective_handlers_schemas_create_only() {
     let input: syn::DeriveInput = syn::parse_quote! {
@@ -184,3 +205,177 @@ fn selective_handlers_all_schemas() {
     assert!(output.contains("CreateUserRequest"));
     assert!(output.contains("UpdateUserRequest"));
 }
+
+#[test]
+fn read_write_tags_registered() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(
+            table = "users",
+            api(tag = "Users", read_tag = "Users: Read", write_tag = "Users: Write", handlers)
+        )]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("name = \"Users: Read\""));
+    assert!(output.contains("name = \"Users: Write\""));
+    assert!(output.contains(". tag (\"Users: Read\")"));
+    assert!(output.contains(". tag (\"Users: Write\")"));
+}
+
+#[test]
+fn tag_external_docs() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(
+            table = "users",
+            api(
+                tag = "Users",
+                external_docs = "https://wiki.example.com/users",
+                external_docs_description = "Users wiki",
+                handlers
+            )
+        )]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains(
+        "external_docs (url = \"https://wiki.example.com/users\" , description = \"Users wiki\")"
+    ));
+}
+
+#[test]
+fn list_query_documents_filter_params() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", list_query, handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            #[filter]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("< UserQuery as utoipa :: IntoParams > :: into_params"));
+    assert!(!output.contains("limit_param"));
+}
+
+#[test]
+fn cursor_pagination_documents_page() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", pagination = "cursor", handlers(get, list)))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("UserResponse , UserPage"));
+    assert!(output.contains("< UserCursorQuery as utoipa :: IntoParams > :: into_params"));
+    assert!(output.contains("Ref :: from_schema_name (\"UserPage\")"));
+    assert!(!output.contains("limit_param"));
+}
+
+#[test]
+fn multipart_field_documents_form_data_create() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "assets", api(tag = "Assets", multipart_field = "file", handlers(create, update)))]
+        pub struct Asset {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub title: String,
+            #[field(create)]
+            pub file: Vec<u8>,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert_eq!(
+        output.matches(". content (\"multipart/form-data\"").count(),
+        1
+    );
+    assert_eq!(output.matches(". content (\"application/json\" , content :: ContentBuilder :: new () . schema (Some (Ref :: from_schema_name (\"UpdateAssetRequest\")))").count(), 1);
+}
+
+#[test]
+fn list_stream_documents_event_stream() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", streams, api(tag = "Users", handlers(list_stream)))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains(". operation_id (Some (\"stream_user\"))"));
+    assert!(output.contains(". content (\"text/event-stream\""));
+    assert!(output.contains("add_path_operation (\"/users/stream\""));
+}
+
+#[test]
+fn operation_id_prefix_applied() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "invoices", api(tag = "Invoices", operation_id_prefix = "billing_", handlers))]
+        pub struct Invoice {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub total: i64,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    for op in ["create", "list", "get", "update", "delete"] {
+        let expected = format!(". operation_id (Some (\"billing_{op}_invoice\"))");
+        assert!(output.contains(&expected), "missing {expected}");
+    }
+}
+
+#[test]
+fn extensions_added_to_every_operation() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "invoices", api(tag = "Invoices", extensions(owner = "billing-team"), handlers))]
+        pub struct Invoice {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub total: i64,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let output = generate(&entity).to_string();
+    assert!(output.contains("[(\"x-owner\" , \"billing-team\")]"));
+    assert!(output.contains("extensions :: Extensions :: from_iter (extensions)"));
+}
+
+#[test]
+fn no_extensions_code_by_default() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[entity(table = "invoices", api(tag = "Invoices", handlers))]
+        pub struct Invoice {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(!generate(&entity).to_string().contains("Extensions"));
+}
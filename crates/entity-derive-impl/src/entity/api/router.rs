@@ -12,6 +12,33 @@
 //! | `handlers` | `{entity}_router<R>` | Repository trait |
 //! | `commands` | `{entity}_commands_router<H>` | CommandHandler trait |
 //!
+//! # Route Table
+//!
+//! Alongside the routers, `{Entity}::API_ROUTES` lists every generated
+//! `(method, path)` pair. Use it to configure CORS allowed methods or build
+//! routing tables without going through OpenAPI:
+//!
+//! ```rust,ignore
+//! for (method, path) in User::API_ROUTES {
+//!     println!("{method} {path}");
+//! }
+//! // POST /users, GET /users, GET /users/{id}, ...
+//! ```
+//!
+//! # Rate Limits
+//!
+//! `api(rate_limit(create = 10, list = 100), rate_limit_window = 60)`
+//! declares a request budget per operation. The macro does not enforce it;
+//! it exposes the policy for middleware:
+//!
+//! ```rust,ignore
+//! assert_eq!(User::API_RATE_LIMITS, &[("create", 10), ("list", 100)]);
+//! assert_eq!(User::API_RATE_LIMIT_WINDOW_SECS, 60);
+//! ```
+//!
+//! Operations are CRUD handler names and snake_case command names, in
+//! declaration order. Operations without an entry are unlimited.
+//!
 //! # Example
 //!
 //! For `User` entity with both handlers and commands:
@@ -48,13 +75,93 @@ use crate::entity::parse::{CommandDef, CommandKindHint, EntityDef};
 pub fn generate(entity: &EntityDef) -> TokenStream {
     let crud_router = generate_crud_router(entity);
     let commands_router = generate_commands_router(entity);
+    let routes_const = generate_routes_const(entity);
+    let rate_limits_const = generate_rate_limits_const(entity);
 
     quote! {
         #crud_router
         #commands_router
+        #routes_const
+        #rate_limits_const
     }
 }
 
+/// Generate the `API_RATE_LIMITS` and `API_RATE_LIMIT_WINDOW_SECS` constants.
+fn generate_rate_limits_const(entity: &EntityDef) -> TokenStream {
+    let entity_name = entity.name();
+    let api_config = entity.api_config();
+    let limits = api_config
+        .rate_limits
+        .iter()
+        .map(|(operation, requests)| quote! { (#operation, #requests) });
+    let window = api_config.rate_limit_window_or_default();
+
+    quote! {
+        impl #entity_name {
+            /// Declared request budget per operation as `(operation, requests)`.
+            ///
+            /// Applies per [`Self::API_RATE_LIMIT_WINDOW_SECS`]. Not enforced
+            /// by the generated handlers.
+            pub const API_RATE_LIMITS: &'static [(&'static str, u32)] = &[#(#limits),*];
+
+            /// Length of the rate limit window in seconds.
+            pub const API_RATE_LIMIT_WINDOW_SECS: u64 = #window;
+        }
+    }
+}
+
+/// Generate the `API_ROUTES` constant listing `(method, path)` pairs.
+fn generate_routes_const(entity: &EntityDef) -> TokenStream {
+    let entity_name = entity.name();
+    let routes = route_table(entity).into_iter().map(|(method, path)| {
+        quote! { (#method, #path) }
+    });
+
+    quote! {
+        impl #entity_name {
+            /// HTTP routes served by the generated routers as `(method, path)`.
+            pub const API_ROUTES: &'static [(&'static str, &'static str)] = &[#(#routes),*];
+        }
+    }
+}
+
+/// Collect `(method, path)` pairs for all generated routes.
+///
+/// Order matches route registration: CRUD collection, SSE stream, CRUD
+/// item, then commands in declaration order.
+fn route_table(entity: &EntityDef) -> Vec<(String, String)> {
+    let mut routes = Vec::new();
+
+    if entity.api_config().has_handlers() {
+        let handlers = entity.api_config().handlers();
+        let collection_path = build_crud_collection_path(entity);
+        let item_path = build_crud_item_path(entity);
+
+        let stream_path = format!("{}/stream", collection_path);
+
+        let crud = [
+            (handlers.create, "POST", &collection_path),
+            (handlers.list, "GET", &collection_path),
+            (handlers.list_stream, "GET", &stream_path),
+            (handlers.get, "GET", &item_path),
+            (handlers.update, "PATCH", &item_path),
+            (handlers.delete, "DELETE", &item_path)
+        ];
+        for (enabled, method, path) in crud {
+            if enabled {
+                routes.push((method.to_string(), path.clone()));
+            }
+        }
+    }
+
+    for cmd in entity.command_defs() {
+        let method = axum_method_for_command(cmd).to_string().to_uppercase();
+        routes.push((method, build_command_path(entity, cmd)));
+    }
+
+    routes
+}
+
 /// Generate CRUD router for repository-based handlers.
 fn generate_crud_router(entity: &EntityDef) -> TokenStream {
     if !entity.api_config().has_handlers() {
@@ -107,6 +214,7 @@ fn generate_crud_routes(entity: &EntityDef) -> TokenStream {
     let update_handler = format_ident!("update_{}", snake);
     let delete_handler = format_ident!("delete_{}", snake);
     let list_handler = format_ident!("list_{}", snake);
+    let stream_handler = format_ident!("stream_{}", snake);
 
     let mut collection_methods = Vec::new();
     if handlers.create {
@@ -137,6 +245,15 @@ fn generate_crud_routes(entity: &EntityDef) -> TokenStream {
         TokenStream::new()
     };
 
+    let stream_route = if handlers.list_stream {
+        let stream_path = format!("{}/stream", collection_path);
+        quote! {
+            .route(#stream_path, axum::routing::get(#stream_handler::<R>))
+        }
+    } else {
+        TokenStream::new()
+    };
+
     let item_route = if !item_methods.is_empty() {
         let first = &item_methods[0];
         let rest: Vec<_> = item_methods.iter().skip(1).collect();
@@ -149,6 +266,7 @@ fn generate_crud_routes(entity: &EntityDef) -> TokenStream {
 
     quote! {
         #collection_route
+        #stream_route
         #item_route
     }
 }
@@ -157,9 +275,9 @@ fn generate_crud_routes(entity: &EntityDef) -> TokenStream {
 fn build_crud_collection_path(entity: &EntityDef) -> String {
     let api_config = entity.api_config();
     let prefix = api_config.full_path_prefix();
-    let entity_path = entity.name_str().to_case(Case::Kebab);
+    let resource = api_config.resource_segment(&entity.name_str());
 
-    let path = format!("{}/{}s", prefix, entity_path);
+    let path = format!("{}/{}", prefix, resource);
     path.replace("//", "/")
 }
 
@@ -236,13 +354,13 @@ fn generate_command_route(entity: &EntityDef, cmd: &CommandDef) -> TokenStream {
 fn build_command_path(entity: &EntityDef, cmd: &CommandDef) -> String {
     let api_config = entity.api_config();
     let prefix = api_config.full_path_prefix();
-    let entity_path = entity.name_str().to_case(Case::Kebab);
+    let resource = api_config.resource_segment(&entity.name_str());
     let cmd_path = cmd.name.to_string().to_case(Case::Kebab);
 
     let path = if cmd.requires_id {
-        format!("{}/{}s/{{id}}/{}", prefix, entity_path, cmd_path)
+        format!("{}/{}/{{id}}/{}", prefix, resource, cmd_path)
     } else {
-        format!("{}/{}s/{}", prefix, entity_path, cmd_path)
+        format!("{}/{}/{}", prefix, resource, cmd_path)
     };
 
     path.replace("//", "/")
@@ -312,6 +430,20 @@ mod tests {
         assert_eq!(path, "/users/{id}");
     }
 
+    #[test]
+    fn crud_item_path_singular() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", api(tag = "Users", path_style = "singular", handlers))]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let path = build_crud_item_path(&entity);
+        assert_eq!(path, "/user/{id}");
+    }
+
     #[test]
     fn crud_path_with_prefix() {
         let input: syn::DeriveInput = syn::parse_quote! {
@@ -491,6 +623,89 @@ mod tests {
         assert!(output_str.contains("UserCommandHandler"));
     }
 
+    #[test]
+    fn route_table_crud_handlers() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", api(tag = "Users", handlers(create, get, delete)))]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let table = route_table(&entity);
+        assert_eq!(
+            table,
+            vec![
+                ("POST".to_string(), "/users".to_string()),
+                ("GET".to_string(), "/users/{id}".to_string()),
+                ("DELETE".to_string(), "/users/{id}".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn route_table_commands() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", commands, api(tag = "Users", path_prefix = "/api/v1"))]
+            #[command(Register)]
+            #[command(UpdateEmail: email)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub email: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let table = route_table(&entity);
+        assert_eq!(
+            table,
+            vec![
+                ("POST".to_string(), "/api/v1/users/register".to_string()),
+                (
+                    "PUT".to_string(),
+                    "/api/v1/users/{id}/update-email".to_string()
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn generate_emits_api_routes_const() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", api(tag = "Users", handlers))]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(output.contains("API_ROUTES"));
+        assert!(output.contains("\"PATCH\""));
+    }
+
+    #[test]
+    fn generate_emits_rate_limits_const() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(
+                table = "users",
+                api(tag = "Users", handlers, rate_limit(create = 10), rate_limit_window = 30)
+            )]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(output.contains(
+            "API_RATE_LIMITS : & 'static [(& 'static str , u32)] = & [(\"create\" , 10u32)]"
+        ));
+        assert!(output.contains("API_RATE_LIMIT_WINDOW_SECS : u64 = 30u64"));
+    }
+
     #[test]
     fn generate_crud_routes_with_specific_handlers() {
         let input: syn::DeriveInput = syn::parse_quote! {
@@ -507,4 +722,27 @@ mod tests {
         assert!(routes_str.contains("get_user"));
         assert!(!routes_str.contains("delete_user"));
     }
+
+    #[test]
+    fn list_stream_routed_before_item_path() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", streams, api(tag = "Users", handlers(get, list_stream)))]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let routes = generate_crud_routes(&entity).to_string();
+        assert!(routes.contains(
+            ". route (\"/users/stream\" , axum :: routing :: get (stream_user :: < R >))"
+        ));
+        assert_eq!(
+            route_table(&entity),
+            vec![
+                ("GET".to_string(), "/users/stream".to_string()),
+                ("GET".to_string(), "/users/{id}".to_string())
+            ]
+        );
+    }
 }
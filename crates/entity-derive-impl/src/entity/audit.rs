@@ -0,0 +1,357 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Audit log code generation.
+//!
+//! Generates `{Entity}AuditRepository` for entities with
+//! `#[entity(audit = "audit_logs")]`. Every mutation runs in a transaction
+//! together with an insert into the audit table, so the change and its audit
+//! record commit or roll back together.
+//!
+//! # Generated Methods
+//!
+//! | Method | Audit Action | Old Data | New Data |
+//! |--------|--------------|----------|----------|
+//! | `create` | `"create"` | `NULL` | created entity |
+//! | `update` | `"update"` | entity before | entity after |
+//! | `delete` | `"delete"` | entity before | `NULL` |
+//!
+//! Snapshots are the entity's `{Entity}Response` serialized to JSON, so
+//! fields hidden from responses never reach the audit table.
+//!
+//! # Audit Table
+//!
+//! ```sql
+//! CREATE TABLE audit_logs (
+//!     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+//!     entity_type TEXT NOT NULL,
+//!     entity_id UUID NOT NULL,
+//!     action TEXT NOT NULL,
+//!     old_data JSONB,
+//!     new_data JSONB,
+//!     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+//! );
+//! ```
+//!
+//! `entity_id` must match the entity's id type. The generated code uses
+//! `serde_json`, which must be a dependency of the calling crate.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let audited = UserAuditRepository::new(pool.clone());
+//! let user = audited.create(dto).await?;
+//! audited.delete(user.id).await?;
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{
+    parse::{EntityDef, SqlLevel},
+    sql::postgres::{
+        Context,
+        helpers::{insert_bindings, update_bindings, update_param_count, update_set_clause}
+    }
+};
+use crate::utils::marker;
+
+/// Generate the audited repository for an entity.
+///
+/// Returns empty `TokenStream` if `audit` is not set, SQL is not fully
+/// generated, or the entity has no Response DTO to snapshot.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    let Some(audit_table) = entity.audit.as_deref() else {
+        return TokenStream::new();
+    };
+    if entity.sql != SqlLevel::Full || entity.response_fields().is_empty() {
+        return TokenStream::new();
+    }
+
+    let ctx = Context::new(entity);
+    let vis = &entity.vis;
+    let entity_name = ctx.entity_name;
+    let entity_type = entity.name_str();
+    let response_name = entity.ident_with("", "Response");
+    let repo_name = entity.ident_with("", "AuditRepository");
+    let id_type = ctx.id_type;
+    let marker = marker::generated();
+
+    let audit_sql = format!(
+        "INSERT INTO {} (entity_type, entity_id, action, old_data, new_data) \
+         VALUES ($1, $2, $3, $4::jsonb, $5::jsonb)",
+        audit_table
+    );
+
+    let create_method = generate_create(&ctx);
+    let update_method = generate_update(&ctx);
+    let delete_method = generate_delete(&ctx);
+
+    let doc = format!(
+        "Repository for [`{entity_name}`] that writes an audit record to `{audit_table}` \
+         in the same transaction as every mutation."
+    );
+
+    quote! {
+        #marker
+        #[doc = #doc]
+        #[derive(Debug, Clone)]
+        #vis struct #repo_name {
+            pool: sqlx::PgPool,
+        }
+
+        impl #repo_name {
+            /// Create an audited repository over a connection pool.
+            pub fn new(pool: sqlx::PgPool) -> Self {
+                Self { pool }
+            }
+
+            /// Get reference to the underlying pool.
+            pub fn pool(&self) -> &sqlx::PgPool {
+                &self.pool
+            }
+
+            #create_method
+            #update_method
+            #delete_method
+
+            async fn write_audit(
+                conn: &mut sqlx::PgConnection,
+                id: &#id_type,
+                action: &str,
+                old: Option<&#entity_name>,
+                new: Option<&#entity_name>
+            ) -> Result<(), sqlx::Error> {
+                let snapshot = |e: &#entity_name| {
+                    serde_json::to_string(&#response_name::from(e))
+                        .map_err(|err| sqlx::Error::Encode(Box::new(err)))
+                };
+                let old_data = old.map(snapshot).transpose()?;
+                let new_data = new.map(snapshot).transpose()?;
+                sqlx::query(#audit_sql)
+                    .bind(#entity_type)
+                    .bind(id)
+                    .bind(action)
+                    .bind(old_data)
+                    .bind(new_data)
+                    .execute(conn)
+                    .await?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Generate audited `create`.
+fn generate_create(ctx: &Context<'_>) -> TokenStream {
+    let entity = ctx.entity;
+    if entity.create_fields().is_empty() {
+        return TokenStream::new();
+    }
+
+    let Context {
+        entity_name,
+        row_name,
+        insertable_name,
+        create_dto,
+        table,
+        columns_str,
+        placeholders_str,
+        returning_str,
+        id_name,
+        ..
+    } = ctx;
+    let bindings = insert_bindings(entity.all_fields());
+
+    quote! {
+        /// Create an entity and record a `"create"` audit entry.
+        pub async fn create(&self, dto: #create_dto) -> Result<#entity_name, sqlx::Error> {
+            let mut tx = self.pool.begin().await?;
+            let entity = #entity_name::from(dto);
+            let insertable = #insertable_name::from(&entity);
+            let row: #row_name = sqlx::query_as(
+                concat!("INSERT INTO ", #table, " (", #columns_str, ") VALUES (", #placeholders_str, ") RETURNING ", #returning_str)
+            )
+                #(#bindings)*
+                .fetch_one(&mut *tx).await?;
+            let created = #entity_name::from(row);
+            Self::write_audit(&mut tx, &created.#id_name, "create", None, Some(&created)).await?;
+            tx.commit().await?;
+            Ok(created)
+        }
+    }
+}
+
+/// Generate audited `update`.
+fn generate_update(ctx: &Context<'_>) -> TokenStream {
+    let entity = ctx.entity;
+    let update_fields = entity.update_fields();
+    if update_fields.is_empty() {
+        return TokenStream::new();
+    }
+
+    let Context {
+        entity_name,
+        row_name,
+        update_dto,
+        table,
+        select_str,
+        returning_str,
+        id_column,
+        id_type,
+        dialect,
+        ..
+    } = ctx;
+    let set_clause = update_set_clause(dialect, &update_fields);
+    let where_placeholder = dialect.placeholder(update_param_count(&update_fields) + 1);
+    let bindings = update_bindings(&update_fields);
+    let select_for_update = select_for_update(ctx);
+
+    quote! {
+        /// Update an entity and record an `"update"` audit entry.
+        ///
+        /// Returns `sqlx::Error::RowNotFound` if the entity does not exist.
+        pub async fn update(&self, id: #id_type, dto: #update_dto) -> Result<#entity_name, sqlx::Error> {
+            let mut tx = self.pool.begin().await?;
+            let old: Option<#row_name> = sqlx::query_as(
+                &format!(#select_for_update, #select_str, #table, #id_column)
+            ).bind(&id).fetch_optional(&mut *tx).await?;
+            let old = #entity_name::from(old.ok_or(sqlx::Error::RowNotFound)?);
+            let row: #row_name = sqlx::query_as(
+                &format!("UPDATE {} SET {} WHERE {} = {} RETURNING {}",
+                    #table, #set_clause, #id_column, #where_placeholder, #returning_str)
+            )
+                #(#bindings)*
+                .bind(&id)
+                .fetch_one(&mut *tx).await?;
+            let updated = #entity_name::from(row);
+            Self::write_audit(&mut tx, &id, "update", Some(&old), Some(&updated)).await?;
+            tx.commit().await?;
+            Ok(updated)
+        }
+    }
+}
+
+/// Generate audited `delete`.
+fn generate_delete(ctx: &Context<'_>) -> TokenStream {
+    let Context {
+        entity_name,
+        row_name,
+        table,
+        select_str,
+        id_column,
+        id_type,
+        soft_delete,
+        ..
+    } = ctx;
+    let select_for_update = select_for_update(ctx);
+    let delete_sql = if *soft_delete {
+        "UPDATE {} SET deleted_at = NOW() WHERE {} = $1 AND deleted_at IS NULL"
+    } else {
+        "DELETE FROM {} WHERE {} = $1"
+    };
+
+    quote! {
+        /// Delete an entity and record a `"delete"` audit entry.
+        ///
+        /// Returns `false` without writing an audit entry if nothing was
+        /// deleted.
+        pub async fn delete(&self, id: #id_type) -> Result<bool, sqlx::Error> {
+            let mut tx = self.pool.begin().await?;
+            let old: Option<#row_name> = sqlx::query_as(
+                &format!(#select_for_update, #select_str, #table, #id_column)
+            ).bind(&id).fetch_optional(&mut *tx).await?;
+            let Some(old) = old.map(#entity_name::from) else {
+                return Ok(false);
+            };
+            sqlx::query(&format!(#delete_sql, #table, #id_column))
+                .bind(&id)
+                .execute(&mut *tx).await?;
+            Self::write_audit(&mut tx, &id, "delete", Some(&old), None).await?;
+            tx.commit().await?;
+            Ok(true)
+        }
+    }
+}
+
+/// `SELECT ... FOR UPDATE` format string locking the current row.
+///
+/// Takes column list, table and id column as format arguments.
+fn select_for_update(ctx: &Context<'_>) -> String {
+    let deleted_filter = if ctx.soft_delete {
+        " AND deleted_at IS NULL"
+    } else {
+        ""
+    };
+    format!(
+        "SELECT {{}} FROM {{}} WHERE {{}} = $1{} FOR UPDATE",
+        deleted_filter
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: syn::DeriveInput) -> EntityDef {
+        EntityDef::from_derive_input(&input).unwrap()
+    }
+
+    #[test]
+    fn no_audit_returns_empty() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(generate(&entity).is_empty());
+    }
+
+    #[test]
+    fn audit_generates_repository() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "users", audit = "audit_logs")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub name: String,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("UserAuditRepository"));
+        assert!(output.contains("INSERT INTO audit_logs"));
+        assert!(output.contains("pub async fn create"));
+        assert!(output.contains("pub async fn update"));
+        assert!(output.contains("pub async fn delete"));
+    }
+
+    #[test]
+    fn audit_skipped_without_full_sql() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "users", audit = "audit_logs", sql = "trait")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(generate(&entity).is_empty());
+    }
+
+    #[test]
+    fn audit_soft_delete_updates_deleted_at() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "users", audit = "audit_logs", soft_delete)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("SET deleted_at = NOW()"));
+        assert!(!output.contains("pub async fn create"));
+    }
+}
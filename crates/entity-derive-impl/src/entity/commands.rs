@@ -45,6 +45,7 @@
 //! | `UpdateEmailUser` | Command payload for email update |
 //! | `DeactivateUser` | Command payload for deactivation |
 //! | `UserCommand` | Enum wrapping all commands |
+//! | `UserCommandKind` | Payload-free command discriminant with `FromStr` |
 //! | `UserCommandResult` | Enum for command results |
 //! | `UserCommandHandler` | Async trait for handling commands |
 //!
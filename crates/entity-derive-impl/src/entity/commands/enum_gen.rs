@@ -21,8 +21,52 @@
 //!     fn kind(&self) -> entity_core::CommandKind { ... }
 //!     fn name(&self) -> &'static str { ... }
 //! }
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+//! pub enum UserCommandKind {
+//!     Register,
+//!     UpdateEmail,
+//!     Deactivate,
+//! }
+//!
+//! impl UserCommand {
+//!     pub fn command_kind(&self) -> UserCommandKind { ... }
+//! }
+//!
+//! impl UserCommandKind {
+//!     pub const ALL: &'static [Self] = &[...];
+//!     pub const fn as_str(&self) -> &'static str { ... }
+//!     pub const fn path_segment(&self) -> &'static str { ... }
+//! }
+//!
+//! impl std::fmt::Display for UserCommandKind { ... }
+//! impl std::str::FromStr for UserCommandKind { ... }
+//! impl TryFrom<&str> for UserCommandKind { ... }
 //! ```
+//!
+//! `UserCommandKind` parses command names (as returned by `name()`) so a
+//! dispatcher can route incoming strings to handlers. `TryFrom<&str>` also
+//! accepts the kebab-case and snake_case forms, so a path segment such as
+//! `update-email` routes to `UpdateEmail`.
+//!
+//! # Deduplication
+//!
+//! With `#[entity(command_dedup)]`, commands are keyed by kind and target id:
+//!
+//! ```rust,ignore
+//! impl UserCommand {
+//!     pub fn dedup_key(&self) -> (UserCommandKind, Option<Uuid>) { ... }
+//! }
+//!
+//! impl std::hash::Hash for UserCommand { ... }
+//! ```
+//!
+//! Commands without an `id` (creates, custom payloads) key on the kind
+//! alone. The key is `Eq`, so a `HashSet` of keys detects duplicates.
+
+use std::collections::BTreeSet;
 
+use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
@@ -47,6 +91,8 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
     let variants = generate_variants(entity, commands);
     let kind_arms = generate_kind_arms(commands);
     let name_arms = generate_name_arms(commands);
+    let kind_enum = generate_kind_enum(entity, &enum_name);
+    let dedup = generate_dedup(entity, &enum_name);
 
     let doc = format!(
         "Command enum for [`{}`] entity.\n\n\
@@ -75,6 +121,149 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
                 }
             }
         }
+
+        #kind_enum
+
+        #dedup
+    }
+}
+
+/// Generate `dedup_key` and `Hash` for `#[entity(command_dedup)]`.
+///
+/// Returns empty `TokenStream` without the flag.
+fn generate_dedup(entity: &EntityDef, enum_name: &syn::Ident) -> TokenStream {
+    if !entity.has_command_dedup() {
+        return TokenStream::new();
+    }
+
+    let kind_name = format_ident!("{}Kind", enum_name);
+    let id_type = entity.id_field().ty();
+    let id_arms = entity.command_defs().iter().map(|cmd| {
+        let variant = &cmd.name;
+        if cmd.requires_id && !uses_custom_payload(cmd) {
+            quote! { Self::#variant(cmd) => Some(cmd.id.clone()), }
+        } else {
+            quote! { Self::#variant(_) => None, }
+        }
+    });
+
+    quote! {
+        impl #enum_name {
+            /// Deduplication key: command kind and target id.
+            ///
+            /// Payload fields are ignored, so two commands with the same
+            /// kind and id are duplicates. Commands without an id key on
+            /// the kind alone.
+            pub fn dedup_key(&self) -> (#kind_name, Option<#id_type>) {
+                let id = match self {
+                    #(#id_arms)*
+                };
+                (self.command_kind(), id)
+            }
+        }
+
+        impl ::std::hash::Hash for #enum_name {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                self.dedup_key().hash(state);
+            }
+        }
+    }
+}
+
+/// Generate the `{Entity}CommandKind` discriminant enum.
+///
+/// Mirrors the command enum without payloads and round-trips through
+/// `Display`/`FromStr` using the command names.
+fn generate_kind_enum(entity: &EntityDef, enum_name: &syn::Ident) -> TokenStream {
+    let commands = entity.command_defs();
+    let vis = &entity.vis;
+    let kind_name = format_ident!("{}Kind", enum_name);
+    let variants: Vec<&syn::Ident> = commands.iter().map(|cmd| &cmd.name).collect();
+    let names: Vec<String> = variants.iter().map(|v| v.to_string()).collect();
+    let segments: Vec<String> = names.iter().map(|n| n.to_case(Case::Kebab)).collect();
+    let aliases = names.iter().map(|name| {
+        let forms: BTreeSet<String> = [
+            name.clone(),
+            name.to_case(Case::Kebab),
+            name.to_case(Case::Snake)
+        ]
+        .into();
+        let forms = forms.into_iter();
+        quote! { #(#forms)|* }
+    });
+
+    let doc = format!(
+        "Payload-free discriminant of [`{}`].\n\n\
+         Parses from and displays as the command name.",
+        enum_name
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #kind_name {
+            #(
+                #[doc = concat!("`", #names, "` command.")]
+                #variants,
+            )*
+        }
+
+        impl #enum_name {
+            /// Get the payload-free kind of this command.
+            pub fn command_kind(&self) -> #kind_name {
+                match self {
+                    #(Self::#variants(_) => #kind_name::#variants,)*
+                }
+            }
+        }
+
+        impl #kind_name {
+            /// All command kinds, in declaration order.
+            pub const ALL: &'static [Self] = &[#(Self::#variants),*];
+
+            /// Get the command name.
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #names,)*
+                }
+            }
+
+            /// Get the kebab-case path segment used by command routes.
+            pub const fn path_segment(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #segments,)*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for #kind_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::std::str::FromStr for #kind_name {
+            type Err = entity_core::UnknownCommand;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#names => Ok(Self::#variants),)*
+                    _ => Err(entity_core::UnknownCommand(s.to_string()))
+                }
+            }
+        }
+
+        impl ::std::convert::TryFrom<&str> for #kind_name {
+            type Error = entity_core::UnknownCommand;
+
+            /// Parse a command name or its kebab-case/snake_case path segment.
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                match s {
+                    #(#aliases => Ok(Self::#variants),)*
+                    _ => Err(entity_core::UnknownCommand(s.to_string()))
+                }
+            }
+        }
     }
 }
 
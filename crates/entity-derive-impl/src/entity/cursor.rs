@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Keyset cursor generation.
+//!
+//! With `#[entity(cursor = "...")]`, the listed fields become an
+//! `{Entity}Cursor` struct. The repository's `list_after` orders by those
+//! columns and returns the cursor of the last row on each page.
+//!
+//! # Generated Code
+//!
+//! ```rust,ignore
+//! #[derive(Entity)]
+//! #[entity(table = "posts", cursor = "created_at, id")]
+//! pub struct Post {
+//!     #[id]
+//!     pub id: Uuid,
+//!
+//!     #[field(response)]
+//!     pub created_at: DateTime<Utc>,
+//! }
+//! ```
+//!
+//! Generates:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+//! pub struct PostCursor {
+//!     pub created_at: DateTime<Utc>,
+//!     pub id: Uuid,
+//! }
+//!
+//! impl entity_derive::cursor::Cursor for PostCursor {}
+//!
+//! impl From<&Post> for PostCursor { ... }
+//! ```
+//!
+//! `Cursor::encode`/`decode` turn the struct into an opaque base64 token
+//! for clients.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::parse::EntityDef;
+use crate::utils::marker;
+
+/// Generates the Cursor struct for the entity.
+///
+/// Returns an empty `TokenStream` without `#[entity(cursor = "...")]`.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    let fields = entity.cursor_fields();
+    if fields.is_empty() {
+        return TokenStream::new();
+    }
+
+    let vis = &entity.vis;
+    let entity_name = entity.name();
+    let cursor_name = entity.ident_with("", "Cursor");
+
+    let field_defs = fields.iter().map(|f| {
+        let name = f.name();
+        let ty = f.ty();
+        quote! { pub #name: #ty }
+    });
+    let field_copies = fields.iter().map(|f| {
+        let name = f.name();
+        quote! { #name: entity.#name.clone() }
+    });
+
+    let marker = marker::generated();
+    let columns: Vec<String> = fields.iter().map(|f| f.column_name()).collect();
+    let doc = format!(
+        "Keyset position in [`{}`] lists ordered by `{}`.\n\n\
+         Pass `next_cursor` of a `list_after` page to fetch the next one. \
+         `encode`/`decode` convert it to an opaque token.",
+        entity_name,
+        columns.join(", ")
+    );
+
+    quote! {
+        #marker
+        #[doc = #doc]
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+        #vis struct #cursor_name {
+            #(#field_defs),*
+        }
+
+        impl entity_derive::cursor::Cursor for #cursor_name {}
+
+        impl From<&#entity_name> for #cursor_name {
+            fn from(entity: &#entity_name) -> Self {
+                Self { #(#field_copies),* }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: syn::DeriveInput) -> EntityDef {
+        EntityDef::from_derive_input(&input).unwrap()
+    }
+
+    #[test]
+    fn no_cursor_returns_empty() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "posts")]
+            pub struct Post {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(generate(&entity).is_empty());
+    }
+
+    #[test]
+    fn cursor_fields_in_declared_order() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "posts", cursor = "created_at, id")]
+            pub struct Post {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(response)]
+                pub created_at: chrono::DateTime<chrono::Utc>,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("pub struct PostCursor"));
+        assert!(output.contains(
+            "pub created_at : chrono :: DateTime < chrono :: Utc > , pub id : uuid :: Uuid"
+        ));
+        assert!(output.contains("impl entity_derive :: cursor :: Cursor for PostCursor"));
+        assert!(output.contains("impl From < & Post > for PostCursor"));
+    }
+}
@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Field-level change lists for audit trails.
+//!
+//! For entities with `#[entity(diffable)]`, generates
+//! `diff(&self, other: &Self)` returning `(field, old, new)` for every field
+//! whose value differs. Values are compared and reported through their
+//! `Debug` representation, so field types need `Debug` but not `PartialEq`.
+//!
+//! Fields with `#[field(mask = "...")]` are compared on the raw value but
+//! reported masked, so change logs do not leak what responses hide.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Entity)]
+//! #[entity(table = "users", diffable)]
+//! pub struct User {
+//!     #[id]
+//!     pub id: Uuid,
+//!     #[field(create, update, response)]
+//!     pub name: String,
+//! }
+//!
+//! let changes = before.diff(&after);
+//! // [("name", "\"Alice\"", "\"Alicia\"")]
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::parse::{EntityDef, FieldDef};
+
+/// Generate the `diff` method.
+///
+/// Returns empty `TokenStream` unless `diffable` is set.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    if !entity.is_diffable() {
+        return TokenStream::new();
+    }
+
+    let entity_name = entity.name();
+    let checks = entity.all_fields().iter().map(field_check);
+
+    quote! {
+        impl #entity_name {
+            /// Fields that differ from `other` as `(field, old, new)`.
+            ///
+            /// Values are `Debug`-formatted, in declaration order.
+            pub fn diff(&self, other: &Self) -> Vec<(&'static str, String, String)> {
+                let mut changes = Vec::new();
+                #(#checks)*
+                changes
+            }
+        }
+    }
+}
+
+/// Compare one field and push a change entry when it differs.
+fn field_check(field: &FieldDef) -> TokenStream {
+    let name = field.name();
+    let name_str = field.name_str();
+    let report = |value: TokenStream| match field.expose.mask.as_ref() {
+        Some(mask) if field.is_option() => {
+            quote! { format!("{:?}", #value.as_deref().map(#mask)) }
+        }
+        Some(mask) => quote! { format!("{:?}", #mask(&#value)) },
+        None => quote! { format!("{:?}", #value) }
+    };
+    let old = report(quote! { self.#name });
+    let new = report(quote! { other.#name });
+
+    quote! {
+        if format!("{:?}", self.#name) != format!("{:?}", other.#name) {
+            changes.push((#name_str, #old, #new));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_entity(input: syn::DeriveInput) -> EntityDef {
+        EntityDef::from_derive_input(&input).unwrap()
+    }
+
+    #[test]
+    fn not_generated_without_flag() {
+        let entity = parse_entity(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(generate(&entity).is_empty());
+    }
+
+    #[test]
+    fn compares_every_field() {
+        let entity = parse_entity(syn::parse_quote! {
+            #[entity(table = "users", diffable)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub name: String,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("pub fn diff"));
+        assert!(output.contains("changes . push ((\"id\" ,"));
+        assert!(output.contains("changes . push ((\"name\" ,"));
+    }
+
+    #[test]
+    fn masked_fields_report_masked_values() {
+        let entity = parse_entity(syn::parse_quote! {
+            #[entity(table = "users", diffable)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(response, mask = "redact")]
+                pub email: String,
+                #[field(response, mask = "redact")]
+                pub phone: Option<String>,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("entity_derive :: mask :: redact (& self . email)"));
+        assert!(
+            output.contains("self . phone . as_deref () . map (entity_derive :: mask :: redact)")
+        );
+    }
+}
@@ -9,7 +9,7 @@
 //! |--------|---------|--------|
 //! | `Create{Name}Request` | Entity creation | `#[field(create)]` fields |
 //! | `Update{Name}Request` | Partial updates | `#[field(update)]` fields (wrapped in `Option`) |
-//! | `{Name}Response` | API responses | `#[field(response)]` + `#[id]` fields |
+//! | `{Name}Response` | API responses | `#[field(response)]` + `#[id]` fields, minus `skip_response` |
 //!
 //! # Derive Macros
 //!
@@ -17,30 +17,130 @@
 //! - `Debug`, `Clone` — standard traits
 //! - `serde::Serialize`, `serde::Deserialize` — JSON serialization
 //!
+//! Additional derives can be appended per DTO with
+//! `#[entity(derive(Response: PartialEq))]`.
+//!
+//! With `#[entity(strict_requests)]`, Create/Update DTOs also get
+//! `#[serde(deny_unknown_fields)]`.
+//!
+//! With `#[entity(arbitrary)]`, all DTOs also derive `arbitrary::Arbitrary`
+//! for fuzzing and property tests. Every field type needs an `Arbitrary`
+//! impl; enable the `arbitrary` feature of `uuid`, `chrono` and similar
+//! crates.
+//!
+//! With `#[entity(graphql)]`, Create/Update also derive
+//! `async_graphql::InputObject` and Response derives
+//! `async_graphql::SimpleObject`. This needs the `uuid` and `chrono`
+//! features of `async-graphql`. Skipped fields never reach a DTO, so they
+//! are absent from the GraphQL types too.
+//!
 //! # Feature Flags
 //!
 //! - `api` — adds `utoipa::ToSchema` for OpenAPI documentation
 //! - `validate` — adds `validator::Validate` for input validation
 //!
+//! # Axum Responses
+//!
+//! With `#[entity(api(...))]`, which already builds on axum, Response
+//! implements `axum::response::IntoResponse` (JSON, `200 OK`) and
+//! `{Name}Created(pub {Name}Response)` responds with `201 Created`.
+//!
 //! # Field Selection
 //!
 //! Fields are included based on attributes:
 //!
 //! ```rust,ignore
-//! #[field(create)]           // → CreateRequest only
-//! #[field(update)]           // → UpdateRequest only
-//! #[field(response)]         // → Response only
-//! #[field(create, response)] // → CreateRequest + Response
-//! #[field(skip)]             // → excluded from all DTOs
-//! #[id]                      // → always in Response
-//! #[auto]                    // → excluded from Create/Update
+//! #[field(create)]              // → CreateRequest only
+//! #[field(update)]              // → UpdateRequest only
+//! #[field(response)]            // → Response only
+//! #[field(create, response)]    // → CreateRequest + Response
+//! #[field(skip)]                // → excluded from all DTOs
+//! #[id]                         // → in Response
+//! #[id] #[field(skip_response)] // → internal key, never in Response
+//! #[auto]                       // → excluded from Create/Update
+//! ```
+//!
+//! # Constraints
+//!
+//! `min_len`/`max_len` and `min`/`max` on a field become both a
+//! `#[validate(...)]` rule and a `#[schema(...)]` constraint on its
+//! Create/Update field, each behind its own feature:
+//!
+//! ```rust,ignore
+//! #[field(create, update, max_len = 255)]
+//! pub name: String,
+//!
+//! // In CreateUserRequest and UpdateUserRequest:
+//! #[cfg_attr(feature = "validate", validate(length(max = 255)))]
+//! #[cfg_attr(feature = "api", schema(max_length = 255))]
+//! pub name: String,
+//! ```
+//!
+//! # Validation
+//!
+//! `#[validate(...)]` rules on an entity field are copied to its Create and
+//! Update DTO fields. `#[validate_create(...)]` and `#[validate_update(...)]`
+//! rules go to one DTO only, e.g. a password required to be long on create:
+//!
+//! ```rust,ignore
+//! #[field(create, update)]
+//! #[validate_create(length(min = 8))]
+//! pub password: String,
+//!
+//! // In CreateUserRequest only:
+//! #[cfg_attr(feature = "validate", validate(length(min = 8)))]
+//! pub password: String,
+//! ```
+//!
+//! # Renaming
+//!
+//! A `rename_api` field keeps its Rust name in every DTO but gets
+//! `#[serde(rename = "...")]` (and `#[graphql(name = "...")]` with
+//! `graphql`), so JSON, OpenAPI and GraphQL all use the API name:
+//!
+//! ```rust,ignore
+//! #[field(create, response, rename_api = "emailAddress")]
+//! pub email: String,
+//!
+//! // In CreateUserRequest and UserResponse:
+//! #[serde(rename = "emailAddress")]
+//! #[graphql(name = "emailAddress")]
+//! pub email: String,
+//! ```
+//!
+//! # File Uploads
+//!
+//! The `api(multipart_field)` field of the Create DTO is documented as a
+//! `binary` string, matching the file part of a `multipart/form-data`
+//! upload:
+//!
+//! ```rust,ignore
+//! #[cfg_attr(feature = "api", schema(value_type = String, format = Binary))]
+//! pub file: Vec<u8>,
+//! ```
+//!
+//! # String Serialization
+//!
+//! A `serialize_as_string` field keeps its type in the Response, but is
+//! written as a JSON string through `entity_derive::as_string` and
+//! documented as `String` in the OpenAPI schema.
+//!
+//! # Transparent Newtypes
+//!
+//! A `#[column(transparent = "T")]` field keeps its newtype in every DTO
+//! but is documented as `T`, so the newtype needs no `ToSchema` impl:
+//!
+//! ```rust,ignore
+//! #[cfg_attr(feature = "api", schema(value_type = Option<i64>))]
+//! pub balance: Option<Cents>,
 //! ```
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
+use syn::Type;
 
-use super::parse::EntityDef;
-use crate::utils::marker;
+use super::parse::{DeriveTarget, EntityDef, FieldDef};
+use crate::utils::{derives, marker};
 
 /// Generates all DTO structs for the entity.
 ///
@@ -64,17 +164,30 @@ fn generate_create_dto(entity: &EntityDef) -> TokenStream {
     let name = entity.ident_with("Create", "Request");
     let field_defs = fields.iter().map(|f| {
         let n = f.name();
-        let t = f.ty();
-        quote! { pub #n: #t }
+        let t = f.create_ty();
+        let constraints = constraint_attrs(f);
+        let validators = validator_attrs(&f.validation().create_validators());
+        let rename = f.api_rename_attrs(entity.has_graphql());
+        let binary = binary_schema_attr(entity, f);
+        let value_type = f.api_value_type_attr(t);
+        quote! { #rename #binary #value_type #constraints #validators pub #n: #t }
     });
 
     let marker = marker::generated();
+    let extra_derives = derives::extra(entity, DeriveTarget::CreateRequest);
+    let strict = strict_attr(entity);
+    let arbitrary = arbitrary_attr(entity);
+    let graphql = graphql_attr(entity, quote! { async_graphql::InputObject });
 
     quote! {
         #marker
         #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        #extra_derives
         #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
         #[cfg_attr(feature = "validate", derive(validator::Validate))]
+        #arbitrary
+        #graphql
+        #strict
         #vis struct #name { #(#field_defs),* }
     }
 }
@@ -89,25 +202,79 @@ fn generate_update_dto(entity: &EntityDef) -> TokenStream {
     let name = entity.ident_with("Update", "Request");
     let field_defs = fields.iter().map(|f| {
         let n = f.name();
-        let t = f.ty();
-        if f.is_option() {
-            quote! { pub #n: #t }
+        let t = f.update_ty();
+        let constraints = constraint_attrs(f);
+        let validators = validator_attrs(&f.validation().update_validators());
+        let rename = f.api_rename_attrs(entity.has_graphql());
+        let ty: Type = if f.update_ty_is_option() && !f.is_double_option() {
+            t.clone()
+        } else {
+            syn::parse_quote!(Option<#t>)
+        };
+        let value_type = f.api_value_type_attr(&ty);
+        let constraints = quote! { #rename #value_type #constraints #validators };
+        if f.is_double_option() {
+            quote! {
+                #[serde(
+                    default,
+                    skip_serializing_if = "Option::is_none",
+                    with = "entity_derive::double_option"
+                )]
+                #constraints pub #n: #ty
+            }
         } else {
-            quote! { pub #n: Option<#t> }
+            quote! { #constraints pub #n: #ty }
         }
     });
 
     let marker = marker::generated();
+    let extra_derives = derives::extra(entity, DeriveTarget::UpdateRequest);
+    let strict = strict_attr(entity);
+    let arbitrary = arbitrary_attr(entity);
+    let graphql = graphql_attr(entity, quote! { async_graphql::InputObject });
 
     quote! {
         #marker
         #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+        #extra_derives
         #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
         #[cfg_attr(feature = "validate", derive(validator::Validate))]
+        #arbitrary
+        #graphql
+        #strict
         #vis struct #name { #(#field_defs),* }
     }
 }
 
+/// `#[serde(deny_unknown_fields)]` for request DTOs of a
+/// `strict_requests` entity.
+fn strict_attr(entity: &EntityDef) -> TokenStream {
+    if entity.has_strict_requests() {
+        quote! { #[serde(deny_unknown_fields)] }
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// `#[derive(arbitrary::Arbitrary)]` for the DTOs of an `arbitrary` entity.
+fn arbitrary_attr(entity: &EntityDef) -> TokenStream {
+    if entity.has_arbitrary() {
+        quote! { #[derive(arbitrary::Arbitrary)] }
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// `#[derive(...)]` of the `async_graphql` object type `derive` for the
+/// DTOs of a `graphql` entity.
+fn graphql_attr(entity: &EntityDef, derive: TokenStream) -> TokenStream {
+    if entity.has_graphql() {
+        quote! { #[derive(#derive)] }
+    } else {
+        TokenStream::new()
+    }
+}
+
 fn generate_response_dto(entity: &EntityDef) -> TokenStream {
     let fields = entity.response_fields();
     if fields.is_empty() {
@@ -118,16 +285,422 @@ fn generate_response_dto(entity: &EntityDef) -> TokenStream {
     let name = entity.ident_with("", "Response");
     let field_defs = fields.iter().map(|f| {
         let n = f.name();
-        let t = f.ty();
-        quote! { pub #n: #t }
+        let t = f.response_ty();
+        let string_attrs = as_string_attrs(f);
+        let rename = f.api_rename_attrs(entity.has_graphql());
+        let value_type = f.api_value_type_attr(&t);
+        quote! { #rename #string_attrs #value_type pub #n: #t }
     });
 
     let marker = marker::generated();
+    let extra_derives = derives::extra(entity, DeriveTarget::Response);
+    let arbitrary = arbitrary_attr(entity);
+    let graphql = graphql_attr(entity, quote! { async_graphql::SimpleObject });
+    let into_response = generate_into_response(entity);
 
     quote! {
         #marker
         #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        #extra_derives
         #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+        #arbitrary
+        #graphql
         #vis struct #name { #(#field_defs),* }
+
+        #into_response
+    }
+}
+
+/// `#[serde(with = ...)]` and `#[schema(value_type = ...)]` for a
+/// `serialize_as_string` Response field.
+fn as_string_attrs(field: &FieldDef) -> TokenStream {
+    if !field.expose.serialize_as_string {
+        return TokenStream::new();
+    }
+    if field.is_option() {
+        quote! {
+            #[serde(with = "entity_derive::as_string::option")]
+            #[cfg_attr(feature = "api", schema(value_type = Option<String>))]
+        }
+    } else {
+        quote! {
+            #[serde(with = "entity_derive::as_string")]
+            #[cfg_attr(feature = "api", schema(value_type = String))]
+        }
+    }
+}
+
+/// `#[schema(...)]` documenting the `api(multipart_field)` as a binary
+/// file part instead of an array of integers.
+fn binary_schema_attr(entity: &EntityDef, field: &FieldDef) -> TokenStream {
+    if entity.api_config().multipart_field.as_deref() != Some(field.name_str().as_str()) {
+        return TokenStream::new();
+    }
+    quote! { #[cfg_attr(feature = "api", schema(value_type = String, format = Binary))] }
+}
+
+/// `#[validate(...)]` and `#[schema(...)]` attributes for a request field's
+/// length and range bounds.
+fn constraint_attrs(field: &FieldDef) -> TokenStream {
+    let mut validate = Vec::new();
+    let mut schema = Vec::new();
+
+    let (min_len, max_len) = field.length_bounds();
+    let min_len = min_len.map(Literal::usize_unsuffixed);
+    let max_len = max_len.map(Literal::usize_unsuffixed);
+    if min_len.is_some() || max_len.is_some() {
+        let min = min_len.as_ref().map(|n| quote! { min = #n });
+        let max = max_len.as_ref().map(|n| quote! { max = #n });
+        let bounds = min.iter().chain(max.iter());
+        validate.push(quote! { length(#(#bounds),*) });
+        if let Some(n) = &min_len {
+            schema.push(quote! { min_length = #n });
+        }
+        if let Some(n) = &max_len {
+            schema.push(quote! { max_length = #n });
+        }
+    }
+
+    let (min, max) = field.range_bounds();
+    let min = min.map(Literal::i64_unsuffixed);
+    let max = max.map(Literal::i64_unsuffixed);
+    if min.is_some() || max.is_some() {
+        let lower = min.as_ref().map(|n| quote! { min = #n });
+        let upper = max.as_ref().map(|n| quote! { max = #n });
+        let bounds = lower.iter().chain(upper.iter());
+        validate.push(quote! { range(#(#bounds),*) });
+        if let Some(n) = &min {
+            schema.push(quote! { minimum = #n });
+        }
+        if let Some(n) = &max {
+            schema.push(quote! { maximum = #n });
+        }
+    }
+
+    if validate.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        #[cfg_attr(feature = "validate", validate(#(#validate),*))]
+        #[cfg_attr(feature = "api", schema(#(#schema),*))]
+    }
+}
+
+/// `#[validate(...)]` attributes for a request field's `validate` rules.
+fn validator_attrs(rules: &[&TokenStream]) -> TokenStream {
+    quote! { #(#[cfg_attr(feature = "validate", validate(#rules))])* }
+}
+
+/// `IntoResponse` for the Response DTO and its `{Name}Created` wrapper.
+///
+/// Empty unless `#[entity(api(...))]` is set.
+fn generate_into_response(entity: &EntityDef) -> TokenStream {
+    if !entity.has_api() {
+        return TokenStream::new();
+    }
+
+    let vis = &entity.vis;
+    let response = entity.ident_with("", "Response");
+    let created = entity.ident_with("", "Created");
+    let doc = format!(
+        "[`{}`] answered with `201 Created` instead of `200 OK`.",
+        response
+    );
+
+    quote! {
+        impl axum::response::IntoResponse for #response {
+            fn into_response(self) -> axum::response::Response {
+                axum::Json(self).into_response()
+            }
+        }
+
+        #[doc = #doc]
+        #[derive(Debug, Clone)]
+        #vis struct #created(pub #response);
+
+        impl axum::response::IntoResponse for #created {
+            fn into_response(self) -> axum::response::Response {
+                (axum::http::StatusCode::CREATED, axum::Json(self.0)).into_response()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn response_implements_into_response() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", api(tag = "Users"))]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(output.contains("impl axum :: response :: IntoResponse for UserResponse"));
+        assert!(output.contains("pub struct UserCreated (pub UserResponse)"));
+        assert!(output.contains("StatusCode :: CREATED"));
+        assert!(!output.contains("feature = \"axum\""));
+    }
+
+    #[test]
+    fn into_response_requires_api() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(!output.contains("axum"));
+        assert!(!output.contains("UserCreated"));
+    }
+
+    #[test]
+    fn arbitrary_is_opt_in() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(!output.contains("arbitrary"));
+
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", arbitrary)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert_eq!(
+            output
+                .matches("# [derive (arbitrary :: Arbitrary)]")
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn graphql_is_opt_in() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response, rename_api = "fullName")]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(!output.contains("graphql"));
+
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", graphql)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response, rename_api = "fullName")]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert_eq!(
+            output
+                .matches("# [derive (async_graphql :: InputObject)]")
+                .count(),
+            2
+        );
+        assert!(output.contains("# [derive (async_graphql :: SimpleObject)]"));
+        assert_eq!(
+            output.matches("# [graphql (name = \"fullName\")]").count(),
+            3
+        );
+    }
+
+    #[test]
+    fn bounds_emit_validate_and_schema() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response, min_len = 1, max_len = 255)]
+                pub name: String,
+                #[field(create, response, min = -1, max = 150)]
+                pub age: i32,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(output.contains("validate (length (min = 1 , max = 255))"));
+        assert!(output.contains("schema (min_length = 1 , max_length = 255)"));
+        assert!(output.contains("validate (range (min = - 1 , max = 150))"));
+        assert!(output.contains("schema (minimum = - 1 , maximum = 150)"));
+        assert_eq!(output.matches("max_length = 255").count(), 2);
+    }
+
+    #[test]
+    fn multipart_field_documented_as_binary() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "assets", api(tag = "Assets", multipart_field = "file", handlers))]
+            pub struct Asset {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub title: String,
+                #[field(create, response)]
+                pub file: Vec<u8>,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert_eq!(
+            output
+                .matches("schema (value_type = String , format = Binary))] pub file")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn validators_are_routed_per_request() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update)]
+                #[validate(does_not_contain(pattern = " "))]
+                #[validate_create(length(min = 8))]
+                #[validate_update(length(min = 12))]
+                pub password: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let create = generate_create_dto(&entity).to_string();
+        let update = generate_update_dto(&entity).to_string();
+        assert!(create.contains("validate (does_not_contain (pattern = \" \"))"));
+        assert!(create.contains("validate (length (min = 8))"));
+        assert!(!create.contains("min = 12"));
+        assert!(update.contains("validate (does_not_contain (pattern = \" \"))"));
+        assert!(update.contains("validate (length (min = 12))"));
+        assert!(!update.contains("min = 8"));
+    }
+
+    #[test]
+    fn strict_requests_deny_unknown_fields_on_requests_only() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", strict_requests)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert_eq!(output.matches("# [serde (deny_unknown_fields)]").count(), 2);
+        let response = generate_response_dto(&entity).to_string();
+        assert!(!response.contains("deny_unknown_fields"));
+    }
+
+    #[test]
+    fn double_option_update_field() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", double_option_updates)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(update, response)]
+                pub bio: Option<String>,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let update = generate_update_dto(&entity).to_string();
+        assert!(update.contains(
+            "# [serde (default , skip_serializing_if = \"Option::is_none\" , with = \"entity_derive::double_option\")] pub bio : Option < Option < String > >"
+        ));
+    }
+
+    #[test]
+    fn serialize_as_string_uses_serde_adapter() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "orders")]
+            pub struct Order {
+                #[id]
+                #[field(response, serialize_as_string)]
+                pub id: i64,
+                #[field(create, response, serialize_as_string)]
+                pub parent_id: Option<i64>,
+                #[field(create, response)]
+                pub total: i64,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let response = generate_response_dto(&entity).to_string();
+        assert!(response.contains(
+            "# [serde (with = \"entity_derive::as_string\")] # [cfg_attr (feature = \"api\" , schema (value_type = String))] pub id : i64"
+        ));
+        assert!(response.contains(
+            "# [serde (with = \"entity_derive::as_string::option\")] # [cfg_attr (feature = \"api\" , schema (value_type = Option < String >))] pub parent_id : Option < i64 >"
+        ));
+        assert!(response.contains(", pub total : i64"));
+        let requests = generate(&entity).to_string().replace(&response, "");
+        assert!(!requests.contains("as_string"));
+    }
+
+    #[test]
+    fn transparent_fields_documented_as_inner_type() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "accounts")]
+            pub struct Account {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                #[column(transparent = "String")]
+                pub email: Email,
+                #[field(create, update, response)]
+                #[column(transparent = "i64")]
+                pub balance: Option<Cents>,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(output.contains(
+            "# [cfg_attr (feature = \"api\" , schema (value_type = String))] pub email : Email"
+        ));
+        assert!(output.contains(
+            "# [cfg_attr (feature = \"api\" , schema (value_type = Option < String >))] pub email : Option < Email >"
+        ));
+        assert_eq!(
+            output
+                .matches("schema (value_type = Option < i64 >))] pub balance : Option < Cents >")
+                .count(),
+            3
+        );
     }
 }
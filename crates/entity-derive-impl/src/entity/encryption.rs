@@ -0,0 +1,391 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Field-level encryption code generation.
+//!
+//! For entities with `#[column(encrypt)]` fields, generates:
+//!
+//! | Item | Purpose |
+//! |------|---------|
+//! | `Insertable{Entity}::encrypt` | Encrypt marked fields before INSERT |
+//! | `{Entity}Row::decrypt` | Decrypt marked fields after SELECT |
+//! | `{Entity}EncryptedRepository<E>` | CRUD over a pool and an `Encryptor` |
+//!
+//! Encrypted fields must be `String` or `Option<String>`. The plain
+//! `{Entity}Repository` implementation on `PgPool` is unchanged and returns
+//! ciphertext; use the encrypted repository wherever plaintext is needed.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! #[derive(Entity)]
+//! #[entity(table = "patients")]
+//! pub struct Patient {
+//!     #[id]
+//!     pub id: Uuid,
+//!
+//!     #[field(create, update, response)]
+//!     #[column(encrypt)]
+//!     pub ssn: String,
+//! }
+//!
+//! let patients = PatientEncryptedRepository::new(pool, encryptor);
+//! let patient = patients.create(dto).await?; // ssn stored encrypted
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::{
+    parse::{EntityDef, FieldDef, SqlLevel},
+    sql::postgres::{
+        Context,
+        helpers::{insert_bindings, update_bindings, update_param_count, update_set_clause}
+    }
+};
+use crate::utils::marker;
+
+/// Generate `encrypt`/`decrypt` helpers and the encrypted repository.
+///
+/// Returns empty `TokenStream` if the entity has no encrypted fields or
+/// `sql = "none"`.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    let fields = entity.encrypted_fields();
+    if fields.is_empty() || entity.sql == SqlLevel::None {
+        return TokenStream::new();
+    }
+
+    let row_name = entity.ident_with("", "Row");
+    let insertable_name = entity.ident_with("Insertable", "");
+    let encrypt = transform_fields(&fields, "encrypt");
+    let decrypt = transform_fields(&fields, "decrypt");
+    let repository = generate_repository(entity);
+
+    quote! {
+        impl #insertable_name {
+            /// Encrypt `#[column(encrypt)]` fields before writing.
+            pub fn encrypt(
+                mut self,
+                encryptor: &dyn entity_derive::crypto::Encryptor
+            ) -> Result<Self, entity_derive::crypto::CryptoError> {
+                #(#encrypt)*
+                Ok(self)
+            }
+        }
+
+        impl #row_name {
+            /// Decrypt `#[column(encrypt)]` fields after reading.
+            pub fn decrypt(
+                mut self,
+                encryptor: &dyn entity_derive::crypto::Encryptor
+            ) -> Result<Self, entity_derive::crypto::CryptoError> {
+                #(#decrypt)*
+                Ok(self)
+            }
+        }
+
+        #repository
+    }
+}
+
+/// Statements passing each field of `self` through `encryptor.{op}`.
+fn transform_fields(fields: &[&FieldDef], op: &str) -> Vec<TokenStream> {
+    let op = format_ident!("{}", op);
+    fields
+        .iter()
+        .map(|f| {
+            let name = f.name();
+            if f.is_option() {
+                quote! {
+                    if let Some(value) = self.#name.as_deref() {
+                        self.#name = Some(encryptor.#op(value)?);
+                    }
+                }
+            } else {
+                quote! { self.#name = encryptor.#op(&self.#name)?; }
+            }
+        })
+        .collect()
+}
+
+/// Generate `{Entity}EncryptedRepository`.
+///
+/// Only generated for `sql = "full"`, since it issues its own queries.
+fn generate_repository(entity: &EntityDef) -> TokenStream {
+    if entity.sql != SqlLevel::Full {
+        return TokenStream::new();
+    }
+
+    let ctx = Context::new(entity);
+    let vis = &entity.vis;
+    let entity_name = ctx.entity_name;
+    let repo_name = entity.ident_with("", "EncryptedRepository");
+    let marker = marker::generated();
+
+    let create_method = generate_create(&ctx);
+    let find_by_id_method = generate_find_by_id(&ctx);
+    let update_method = generate_update(&ctx);
+    let list_method = generate_list(&ctx);
+
+    let doc = format!(
+        "Repository for [`{entity_name}`] that encrypts `#[column(encrypt)]` fields on write \
+         and decrypts them on read."
+    );
+
+    quote! {
+        #marker
+        #[doc = #doc]
+        #[derive(Debug, Clone)]
+        #vis struct #repo_name<E> {
+            pool: sqlx::PgPool,
+            encryptor: E,
+        }
+
+        impl<E: entity_derive::crypto::Encryptor> #repo_name<E> {
+            /// Create an encrypted repository over a connection pool.
+            pub fn new(pool: sqlx::PgPool, encryptor: E) -> Self {
+                Self { pool, encryptor }
+            }
+
+            /// Get reference to the underlying pool.
+            pub fn pool(&self) -> &sqlx::PgPool {
+                &self.pool
+            }
+
+            /// Get reference to the encryptor.
+            pub fn encryptor(&self) -> &E {
+                &self.encryptor
+            }
+
+            #create_method
+            #find_by_id_method
+            #update_method
+            #list_method
+        }
+    }
+}
+
+/// Generate encrypted `create`.
+fn generate_create(ctx: &Context<'_>) -> TokenStream {
+    let entity = ctx.entity;
+    if entity.create_fields().is_empty() {
+        return TokenStream::new();
+    }
+
+    let Context {
+        entity_name,
+        row_name,
+        insertable_name,
+        create_dto,
+        table,
+        columns_str,
+        placeholders_str,
+        returning_str,
+        ..
+    } = ctx;
+    let bindings = insert_bindings(entity.all_fields());
+
+    quote! {
+        /// Create an entity, encrypting marked fields.
+        pub async fn create(&self, dto: #create_dto) -> Result<#entity_name, sqlx::Error> {
+            let entity = #entity_name::from(dto);
+            let insertable = #insertable_name::from(&entity)
+                .encrypt(&self.encryptor)
+                .map_err(sqlx::Error::Encode)?;
+            let row: #row_name = sqlx::query_as(
+                concat!("INSERT INTO ", #table, " (", #columns_str, ") VALUES (", #placeholders_str, ") RETURNING ", #returning_str)
+            )
+                #(#bindings)*
+                .fetch_one(&self.pool).await?;
+            let row = row.decrypt(&self.encryptor).map_err(sqlx::Error::Decode)?;
+            Ok(#entity_name::from(row))
+        }
+    }
+}
+
+/// Generate decrypting `find_by_id`.
+fn generate_find_by_id(ctx: &Context<'_>) -> TokenStream {
+    let Context {
+        entity_name,
+        row_name,
+        table,
+        select_str,
+        id_column,
+        id_type,
+        soft_delete,
+        ..
+    } = ctx;
+    let deleted_filter = if *soft_delete {
+        " AND deleted_at IS NULL"
+    } else {
+        ""
+    };
+
+    quote! {
+        /// Find an entity by ID, decrypting marked fields.
+        pub async fn find_by_id(&self, id: #id_type) -> Result<Option<#entity_name>, sqlx::Error> {
+            let row: Option<#row_name> = sqlx::query_as(
+                &format!("SELECT {} FROM {} WHERE {} = $1{}", #select_str, #table, #id_column, #deleted_filter)
+            ).bind(&id).fetch_optional(&self.pool).await?;
+            row.map(|row| row.decrypt(&self.encryptor).map(#entity_name::from))
+                .transpose()
+                .map_err(sqlx::Error::Decode)
+        }
+    }
+}
+
+/// Generate encrypted `update`.
+///
+/// Update DTO values for encrypted fields are encrypted before binding.
+fn generate_update(ctx: &Context<'_>) -> TokenStream {
+    let entity = ctx.entity;
+    let update_fields = entity.update_fields();
+    if update_fields.is_empty() {
+        return TokenStream::new();
+    }
+
+    let Context {
+        entity_name,
+        row_name,
+        update_dto,
+        table,
+        returning_str,
+        id_column,
+        id_type,
+        dialect,
+        ..
+    } = ctx;
+    let set_clause = update_set_clause(dialect, &update_fields);
+    let where_placeholder = dialect.placeholder(update_param_count(&update_fields) + 1);
+    let bindings = update_bindings(&update_fields);
+    let encrypt_dto = update_fields.iter().filter(|f| f.is_encrypted()).map(|f| {
+        let name = f.name();
+        quote! {
+            dto.#name = dto.#name
+                .map(|value| self.encryptor.encrypt(&value))
+                .transpose()
+                .map_err(sqlx::Error::Encode)?;
+        }
+    });
+
+    quote! {
+        /// Update an entity, encrypting marked fields.
+        pub async fn update(&self, id: #id_type, mut dto: #update_dto) -> Result<#entity_name, sqlx::Error> {
+            #(#encrypt_dto)*
+            let row: #row_name = sqlx::query_as(
+                &format!("UPDATE {} SET {} WHERE {} = {} RETURNING {}",
+                    #table, #set_clause, #id_column, #where_placeholder, #returning_str)
+            )
+                #(#bindings)*
+                .bind(&id)
+                .fetch_one(&self.pool).await?;
+            let row = row.decrypt(&self.encryptor).map_err(sqlx::Error::Decode)?;
+            Ok(#entity_name::from(row))
+        }
+    }
+}
+
+/// Generate decrypting `list`.
+fn generate_list(ctx: &Context<'_>) -> TokenStream {
+    let Context {
+        entity_name,
+        row_name,
+        table,
+        select_str,
+        list_order,
+        soft_delete,
+        ..
+    } = ctx;
+    let where_clause = if *soft_delete {
+        "WHERE deleted_at IS NULL "
+    } else {
+        ""
+    };
+
+    quote! {
+        /// List entities with pagination, decrypting marked fields.
+        pub async fn list(&self, limit: i64, offset: i64) -> Result<Vec<#entity_name>, sqlx::Error> {
+            let rows: Vec<#row_name> = sqlx::query_as(
+                &format!("SELECT {} FROM {} {}ORDER BY {} LIMIT $1 OFFSET $2",
+                    #select_str, #table, #where_clause, #list_order)
+            ).bind(limit).bind(offset).fetch_all(&self.pool).await?;
+            rows.into_iter()
+                .map(|row| row.decrypt(&self.encryptor).map(#entity_name::from))
+                .collect::<Result<_, _>>()
+                .map_err(sqlx::Error::Decode)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: syn::DeriveInput) -> EntityDef {
+        EntityDef::from_derive_input(&input).unwrap()
+    }
+
+    #[test]
+    fn no_encrypted_fields_returns_empty() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub name: String,
+            }
+        });
+        assert!(generate(&entity).is_empty());
+    }
+
+    #[test]
+    fn encrypted_field_generates_helpers_and_repository() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "patients")]
+            pub struct Patient {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                #[column(encrypt)]
+                pub ssn: String,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("pub fn encrypt"));
+        assert!(output.contains("pub fn decrypt"));
+        assert!(output.contains("PatientEncryptedRepository"));
+        assert!(output.contains("pub async fn update"));
+    }
+
+    #[test]
+    fn optional_field_encrypts_inner_value() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "patients")]
+            pub struct Patient {
+                #[id]
+                pub id: uuid::Uuid,
+                #[column(encrypt)]
+                pub notes: Option<String>,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("as_deref"));
+    }
+
+    #[test]
+    fn trait_level_skips_repository() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "patients", sql = "trait")]
+            pub struct Patient {
+                #[id]
+                pub id: uuid::Uuid,
+                #[column(encrypt)]
+                pub ssn: String,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("pub fn decrypt"));
+        assert!(!output.contains("EncryptedRepository"));
+    }
+}
@@ -55,12 +55,30 @@
 //! | `#[auto]` | Yes | `Default::default()` |
 //! | `#[field(create)]` | Yes | From CreateRequest DTO |
 //! | `#[field(skip)]` | Yes | `Default::default()` |
+//!
+//! `#[column(transparent = "...")]` newtypes are held as their inner type,
+//! converted from the entity with `From`, and bound as that primitive.
+//!
+//! # Column Order
+//!
+//! `Insertable{Name}::COLUMNS` lists the columns the generated `INSERT`
+//! writes, in the order it binds them: field declaration order, using each
+//! field's column name and leaving out database-generated fields
+//! (`#[field(returning = "...")]`, `#[column(tsvector)]`). This order is a
+//! stable contract, so it can drive a custom `COPY`:
+//!
+//! ```rust,ignore
+//! let sql = format!(
+//!     "COPY users ({}) FROM STDIN WITH (FORMAT csv)",
+//!     InsertableUser::COLUMNS.join(", ")
+//! );
+//! ```
 
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use super::parse::{EntityDef, SqlLevel};
-use crate::utils::marker;
+use super::parse::{DeriveTarget, EntityDef, SqlLevel};
+use crate::utils::{derives, marker};
 
 /// Generates the `Insertable{Name}` struct for INSERT operations.
 ///
@@ -75,15 +93,28 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
     let insertable_name = entity.ident_with("Insertable", "");
     let field_defs = entity.all_fields().iter().map(|f| {
         let name = f.name();
-        let ty = f.ty();
+        let ty = f.db_ty();
         quote! { pub #name: #ty }
     });
 
+    let columns = entity
+        .all_fields()
+        .iter()
+        .filter(|f| !f.is_db_generated())
+        .map(|f| f.column_name());
+
     let marker = marker::generated();
+    let extra_derives = derives::extra(entity, DeriveTarget::Insertable);
 
     quote! {
         #marker
         #[derive(Debug, Clone)]
+        #extra_derives
         #vis struct #insertable_name { #(#field_defs),* }
+
+        impl #insertable_name {
+            /// Columns written by `INSERT`, in binding order.
+            pub const COLUMNS: &'static [&'static str] = &[#(#columns),*];
+        }
     }
 }
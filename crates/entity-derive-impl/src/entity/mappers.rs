@@ -17,8 +17,22 @@
 //! | `&User` | `InsertableUser` | Borrowed entity → INSERT (clones) |
 //! | `User` | `UserResponse` | Domain entity → API response |
 //! | `&User` | `UserResponse` | Borrowed entity → Response (clones) |
+//! | `UserRow` | `UserResponse` | DB query result → API response |
+//! | `&UserRow` | `UserResponse` | Borrowed row → Response (clones) |
 //! | `CreateUserRequest` | `User` | Create DTO → New entity |
 //!
+//! Alongside the `Row → Response` conversions, `UserResponse::from_rows`
+//! maps a whole `Vec<UserRow>` into a `Vec` allocated once up front.
+//! `User::from_rows` does the same for entities and is what the generated
+//! repository methods use; with `#[entity(streams)]`,
+//! `User::from_row_stream` maps a `Stream` of `Result<UserRow, E>`, such as
+//! sqlx's `fetch`, to a `Stream` of `Result<User, E>`:
+//!
+//! ```rust,ignore
+//! let users = User::from_rows(sqlx::query_as(sql).fetch_all(&pool).await?);
+//! let stream = User::from_row_stream(sqlx::query_as(sql).fetch(&pool));
+//! ```
+//!
 //! # Data Flow
 //!
 //! ```text
@@ -29,6 +43,9 @@
 //!
 //! Read Flow:
 //!   DB SELECT → UserRow → User → UserResponse → API
+//!
+//! Read Flow (direct):
+//!   DB SELECT → &UserRow → UserResponse → API
 //! ```
 //!
 //! # Field Handling
@@ -46,21 +63,29 @@
 //!
 //! - Only `#[field(response)]` and `#[id]` fields are included
 //! - `#[field(skip)]` fields are excluded
+//! - `#[field(mask = "...")]` fields are passed through the mask (also for
+//!   `UserRow → UserResponse`)
+//! - `#[field(as_str)]` fields are converted with `to_string()`
 //!
 //! # Conditional Generation
 //!
 //! | Mapper | Condition |
 //! |--------|-----------|
-//! | `Row → Entity` | `sql != "none"` |
+//! | `Row → Entity`, `from_rows` | `sql != "none"` |
+//! | `from_row_stream` | `sql != "none"` and `streams` |
 //! | `Entity → Insertable` | `sql != "none"` |
 //! | `Entity → Response` | Has response fields |
+//! | `Row → Response` | `sql != "none"` and has response fields |
 //! | `CreateRequest → Entity` | Has create fields |
 
 use proc_macro2::TokenStream;
 use quote::quote;
 
 use super::parse::{EntityDef, SqlLevel};
-use crate::utils::{fields, marker};
+use crate::utils::{
+    fields::{self, Conversion},
+    marker
+};
 
 /// Generates all `From` implementations for the entity.
 ///
@@ -69,16 +94,20 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
     let row_to_entity = generate_row_to_entity(entity);
     let entity_to_insertable = generate_entity_to_insertable(entity);
     let entity_to_response = generate_entity_to_response(entity);
+    let row_to_response = generate_row_to_response(entity);
     let create_to_entity = generate_create_to_entity(entity);
 
     quote! {
         #row_to_entity
         #entity_to_insertable
         #entity_to_response
+        #row_to_response
         #create_to_entity
     }
 }
 
+/// Generates `From<Row>` for the entity, `{Entity}::from_rows` and, with
+/// `streams`, `{Entity}::from_row_stream`.
 fn generate_row_to_entity(entity: &EntityDef) -> TokenStream {
     if entity.sql == SqlLevel::None {
         return TokenStream::new();
@@ -86,8 +115,21 @@ fn generate_row_to_entity(entity: &EntityDef) -> TokenStream {
 
     let entity_name = entity.name();
     let row_name = entity.ident_with("", "Row");
-    let assigns = fields::assigns(entity.all_fields(), "row");
+    let assigns = fields::assigns(entity.all_fields(), "row", Conversion::FromDb);
     let marker = marker::generated();
+    let from_row_stream = if entity.has_streams() {
+        quote! {
+            /// Map a stream of query results to entities.
+            pub fn from_row_stream<S, E>(rows: S) -> impl entity_derive::futures::Stream<Item = Result<Self, E>>
+            where
+                S: entity_derive::futures::Stream<Item = Result<#row_name, E>>
+            {
+                entity_derive::futures::StreamExt::map(rows, |row| row.map(Self::from))
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
 
     quote! {
         #marker
@@ -96,6 +138,19 @@ fn generate_row_to_entity(entity: &EntityDef) -> TokenStream {
                 Self { #(#assigns),* }
             }
         }
+
+        impl #entity_name {
+            /// Map query results to entities, allocating the output once.
+            pub fn from_rows(rows: Vec<#row_name>) -> Vec<Self> {
+                let mut entities = Vec::with_capacity(rows.len());
+                for row in rows {
+                    entities.push(Self::from(row));
+                }
+                entities
+            }
+
+            #from_row_stream
+        }
     }
 }
 
@@ -106,8 +161,8 @@ fn generate_entity_to_insertable(entity: &EntityDef) -> TokenStream {
 
     let entity_name = entity.name();
     let insertable_name = entity.ident_with("Insertable", "");
-    let assigns = fields::assigns(entity.all_fields(), "entity");
-    let assigns_clone = fields::assigns_clone(entity.all_fields(), "entity");
+    let assigns = fields::assigns(entity.all_fields(), "entity", Conversion::IntoDb);
+    let assigns_clone = fields::assigns_clone(entity.all_fields(), "entity", Conversion::IntoDb);
     let marker = marker::generated();
 
     quote! {
@@ -135,8 +190,9 @@ fn generate_entity_to_response(entity: &EntityDef) -> TokenStream {
 
     let entity_name = entity.name();
     let response_name = entity.ident_with("", "Response");
-    let assigns = fields::assigns_from_refs(&response_fields, "entity");
-    let assigns_clone = fields::assigns_clone_from_refs(&response_fields, "entity");
+    let assigns = fields::response_assigns(&response_fields, "entity", Conversion::None);
+    let assigns_clone =
+        fields::response_assigns_clone(&response_fields, "entity", Conversion::None);
     let marker = marker::generated();
 
     quote! {
@@ -156,6 +212,53 @@ fn generate_entity_to_response(entity: &EntityDef) -> TokenStream {
     }
 }
 
+/// Generates `From<Row>` and `From<&Row>` for the Response DTO.
+///
+/// Lets read paths map query results straight to responses without
+/// materializing the entity; the borrowing variant clones only the
+/// response fields. Also generates `{Entity}Response::from_rows` for whole
+/// result sets.
+fn generate_row_to_response(entity: &EntityDef) -> TokenStream {
+    let response_fields = entity.response_fields();
+    if entity.sql == SqlLevel::None || response_fields.is_empty() {
+        return TokenStream::new();
+    }
+
+    let row_name = entity.ident_with("", "Row");
+    let response_name = entity.ident_with("", "Response");
+    let assigns = fields::response_assigns(&response_fields, "row", Conversion::FromDb);
+    let assigns_clone =
+        fields::response_assigns_clone(&response_fields, "row", Conversion::FromDb);
+    let marker = marker::generated();
+
+    quote! {
+        #marker
+        impl From<#row_name> for #response_name {
+            fn from(row: #row_name) -> Self {
+                Self { #(#assigns),* }
+            }
+        }
+
+        #marker
+        impl From<&#row_name> for #response_name {
+            fn from(row: &#row_name) -> Self {
+                Self { #(#assigns_clone),* }
+            }
+        }
+
+        impl #response_name {
+            /// Map query results to responses, allocating the output once.
+            pub fn from_rows(rows: Vec<#row_name>) -> Vec<Self> {
+                let mut responses = Vec::with_capacity(rows.len());
+                for row in rows {
+                    responses.push(Self::from(row));
+                }
+                responses
+            }
+        }
+    }
+}
+
 fn generate_create_to_entity(entity: &EntityDef) -> TokenStream {
     let create_fields = entity.create_fields();
     if create_fields.is_empty() {
@@ -164,7 +267,12 @@ fn generate_create_to_entity(entity: &EntityDef) -> TokenStream {
 
     let entity_name = entity.name();
     let create_name = entity.ident_with("Create", "Request");
-    let assigns = fields::create_assigns(entity.all_fields(), &create_fields, entity.uuid);
+    let assigns = fields::create_assigns(
+        entity.all_fields(),
+        &create_fields,
+        entity.uuid,
+        entity.id_prefix()
+    );
     let marker = marker::generated();
 
     quote! {
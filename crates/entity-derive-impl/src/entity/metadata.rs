@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! `entity_core::Entity` implementation.
+//!
+//! Every entity implements the shared `Entity` trait with its table
+//! metadata, so generic code can be written over any generated entity:
+//!
+//! ```rust,ignore
+//! impl entity_derive::Entity for User {
+//!     type Id = Uuid;
+//!     const TABLE: &'static str = "users";
+//!     const SCHEMA: &'static str = "public";
+//!     const COLUMNS: &'static [&'static str] = &["id", "name", "email"];
+//! }
+//! ```
+//!
+//! `COLUMNS` lists the stored column names in field order, as used by the
+//! generated queries, so renamed fields appear under their column name;
+//! computed `returning` fields are left out.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::parse::EntityDef;
+
+/// Generate the `Entity` trait implementation.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    let entity_name = entity.name();
+    let id_type = entity.id_field().ty();
+    let table = &entity.table;
+    let schema = &entity.schema;
+    let columns = entity
+        .all_fields()
+        .iter()
+        .filter(|f| !f.is_computed())
+        .map(|f| f.column_name());
+
+    quote! {
+        impl entity_derive::Entity for #entity_name {
+            type Id = #id_type;
+            const TABLE: &'static str = #table;
+            const SCHEMA: &'static str = #schema;
+            const COLUMNS: &'static [&'static str] = &[#(#columns),*];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn implements_entity_metadata() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", schema = "core")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub email: String,
+                #[field(response, returning = "lower(email)")]
+                pub email_lower: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(output.contains("impl entity_derive :: Entity for User"));
+        assert!(output.contains("type Id = uuid :: Uuid"));
+        assert!(output.contains("const TABLE : & 'static str = \"users\""));
+        assert!(output.contains("const SCHEMA : & 'static str = \"core\""));
+        assert!(output.contains("& [\"id\" , \"email\"]"));
+    }
+
+    #[test]
+    fn columns_use_renamed_column_names() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response, rename_column = "usr_mail")]
+                pub email: String,
+                #[field(create, response)]
+                #[column(name = "display_name")]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let output = generate(&entity).to_string();
+        assert!(output.contains("& [\"id\" , \"usr_mail\" , \"display_name\"]"));
+    }
+}
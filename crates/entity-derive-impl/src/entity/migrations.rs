@@ -4,7 +4,8 @@
 //! Migration generation for entity-derive.
 //!
 //! Generates `MIGRATION_UP` and `MIGRATION_DOWN` constants containing
-//! SQL DDL statements for creating/dropping tables.
+//! SQL DDL statements for creating/dropping tables, and `SCHEMA_VERSION`,
+//! a hash of the column definitions for drift detection.
 //!
 //! # Features
 //!
@@ -49,7 +50,8 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
     match entity.dialect {
         DatabaseDialect::Postgres => postgres::generate(entity),
         DatabaseDialect::ClickHouse => TokenStream::new(), // TODO: future
-        DatabaseDialect::MongoDB => TokenStream::new()     // N/A for document DB
+        DatabaseDialect::MongoDB => TokenStream::new(),    // N/A for document DB
+        DatabaseDialect::Sqlite => TokenStream::new()      // TODO: future
     }
 }
 
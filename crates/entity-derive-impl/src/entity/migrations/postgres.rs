@@ -3,7 +3,8 @@
 
 //! PostgreSQL migration generation.
 //!
-//! Generates `MIGRATION_UP` and `MIGRATION_DOWN` constants for PostgreSQL.
+//! Generates `MIGRATION_UP`, `MIGRATION_DOWN` and `SCHEMA_VERSION`
+//! constants for PostgreSQL.
 
 mod ddl;
 
@@ -20,6 +21,7 @@ use crate::{entity::parse::EntityDef, utils::marker};
 /// impl User {
 ///     pub const MIGRATION_UP: &'static str = "CREATE TABLE...";
 ///     pub const MIGRATION_DOWN: &'static str = "DROP TABLE...";
+///     pub const SCHEMA_VERSION: &'static str = "3f2a9c0d1e4b5a67";
 /// }
 /// ```
 pub fn generate(entity: &EntityDef) -> TokenStream {
@@ -28,6 +30,7 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
 
     let up_sql = ddl::generate_up(entity);
     let down_sql = ddl::generate_down(entity);
+    let schema_version = ddl::schema_version(entity);
 
     let marker = marker::generated();
 
@@ -47,6 +50,13 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
             ///
             /// Uses CASCADE to drop dependent objects.
             #vis const MIGRATION_DOWN: &'static str = #down_sql;
+
+            /// Hash of this entity's table name and column definitions.
+            ///
+            /// Changes whenever a column is added, removed, renamed, retyped
+            /// or has its constraints changed. Store it next to the applied
+            /// migration and compare at startup to detect schema drift.
+            #vis const SCHEMA_VERSION: &'static str = #schema_version;
         }
     }
 }
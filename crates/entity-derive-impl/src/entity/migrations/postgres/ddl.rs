@@ -4,6 +4,23 @@
 //! DDL (Data Definition Language) generation for PostgreSQL.
 //!
 //! Generates CREATE TABLE, CREATE INDEX, and DROP TABLE statements.
+//!
+//! With `unique_among_active`, uniqueness is enforced by partial unique
+//! indexes `WHERE deleted_at IS NULL` instead of `UNIQUE` constraints, so
+//! soft-deleted rows do not block reusing their values.
+//!
+//! With `schema_create`, the migration starts with
+//! `CREATE SCHEMA IF NOT EXISTS` so the table can be created on a fresh
+//! database. A `#[column(citext)]` column adds
+//! `CREATE EXTENSION IF NOT EXISTS citext`.
+//!
+//! `#[column(storage = "...")]` adds `ALTER TABLE ... ALTER COLUMN ... SET
+//! STORAGE` right after `CREATE TABLE`, so the mode applies before any row
+//! is written.
+//!
+//! [`schema_version`] hashes the table name and column definitions into a
+//! short hex string, so schema drift can be detected without comparing
+//! the whole migration.
 
 use convert_case::{Case, Casing};
 
@@ -15,18 +32,45 @@ use crate::entity::{
 /// Generate the complete UP migration SQL.
 ///
 /// Includes:
+/// - CREATE SCHEMA for `schema_create`
+/// - CREATE EXTENSION for `citext` columns
 /// - CREATE TABLE with columns and constraints
+/// - ALTER COLUMN ... SET STORAGE for `#[column(storage)]` columns
 /// - CREATE INDEX for single-column indexes
 /// - CREATE INDEX for composite indexes
 pub fn generate_up(entity: &EntityDef) -> String {
     let mut sql = String::new();
 
+    // Non-public schemas may not exist yet
+    if entity.creates_schema() {
+        sql.push_str(&format!("CREATE SCHEMA IF NOT EXISTS {};\n", entity.schema));
+    }
+
+    // CITEXT ships as an extension
+    if entity.all_fields().iter().any(|f| f.column().citext) {
+        sql.push_str("CREATE EXTENSION IF NOT EXISTS citext;\n");
+    }
+
     // CREATE TABLE
     sql.push_str(&generate_create_table(entity));
 
+    // TOAST storage modes
+    for field in entity.all_fields() {
+        if let Some(storage) = field.column().storage
+            && !field.is_computed()
+        {
+            sql.push_str(&format!(
+                "ALTER TABLE {} ALTER COLUMN {} SET STORAGE {};\n",
+                entity.full_table_name(),
+                field.column_name(),
+                storage.as_sql()
+            ));
+        }
+    }
+
     // Single-column indexes
     for field in entity.all_fields() {
-        if field.column().has_index() {
+        if has_single_index(entity, field) && !field.is_computed() {
             sql.push_str(&generate_single_index(entity, field));
         }
     }
@@ -47,22 +91,54 @@ pub fn generate_down(entity: &EntityDef) -> String {
     )
 }
 
-/// Generate CREATE TABLE statement.
-fn generate_create_table(entity: &EntityDef) -> String {
-    let mapper = PostgresTypeMapper;
-    let full_table = entity.full_table_name();
+/// Generate the schema version: a hash of the table and its columns.
+///
+/// 64-bit FNV-1a over the qualified table name and each column definition
+/// as it appears in `CREATE TABLE`, formatted as 16 lowercase hex digits.
+/// Stable across builds and compiler versions; changes whenever a column is
+/// added, removed, renamed, retyped or has its constraints changed.
+/// Indexes and storage modes do not take part.
+pub fn schema_version(entity: &EntityDef) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut input = entity.full_table_name();
+    for column in column_defs(entity) {
+        input.push('\n');
+        input.push_str(column.trim());
+    }
+
+    let hash = input.bytes().fold(OFFSET, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    });
+    format!("{:016x}", hash)
+}
 
-    let columns: Vec<String> = entity
+/// Column definitions of the table, in field order.
+fn column_defs(entity: &EntityDef) -> Vec<String> {
+    let mapper = PostgresTypeMapper;
+    entity
         .all_fields()
         .iter()
+        .filter(|f| !f.is_computed())
         .map(|f| generate_column_def(f, &mapper, entity))
-        .collect();
+        .collect()
+}
 
-    format!(
-        "CREATE TABLE IF NOT EXISTS {} (\n{}\n);\n",
-        full_table,
-        columns.join(",\n")
-    )
+/// Generate CREATE TABLE statement.
+fn generate_create_table(entity: &EntityDef) -> String {
+    let full_table = entity.full_table_name();
+    let columns = column_defs(entity);
+
+    // A temporary table is per-connection, so IF NOT EXISTS would silently
+    // reuse a stale one left on a pooled connection.
+    let create = if entity.is_temporary() {
+        "CREATE TEMPORARY TABLE"
+    } else {
+        "CREATE TABLE IF NOT EXISTS"
+    };
+
+    format!("{} {} (\n{}\n);\n", create, full_table, columns.join(",\n"))
 }
 
 /// Generate a single column definition.
@@ -72,13 +148,25 @@ fn generate_column_def(
     entity: &EntityDef
 ) -> String {
     let column_name = field.column_name();
-    let sql_type = mapper.map_type(field.ty(), field.column());
+    if let Some(config) = field.ts_config() {
+        return format!(
+            "    {} TSVECTOR GENERATED ALWAYS AS ({}) STORED",
+            column_name,
+            tsvector_expr(entity, field, config)
+        );
+    }
+    let sql_type = mapper.map_type(&field.db_ty(), field.column());
 
     let mut parts = vec![format!("    {}", column_name)];
 
     // Type with array suffix
     parts.push(sql_type.to_sql_string());
 
+    // COLLATE belongs right after the type
+    if let Some(ref collation) = field.column().collation {
+        parts.push(format!("COLLATE \"{}\"", collation));
+    }
+
     // PRIMARY KEY for #[id] fields
     if field.is_id() {
         parts.push("PRIMARY KEY".to_string());
@@ -87,8 +175,8 @@ fn generate_column_def(
         parts.push("NOT NULL".to_string());
     }
 
-    // UNIQUE constraint
-    if field.is_unique() {
+    // UNIQUE constraint, unless a unique index is created instead
+    if field.is_unique() && !has_single_index(entity, field) {
         parts.push("UNIQUE".to_string());
     }
 
@@ -121,7 +209,57 @@ fn generate_column_def(
     parts.join(" ")
 }
 
+/// Expression of a generated `tsvector` column.
+///
+/// Each source is weighted by position, `A` for the first through `D`,
+/// which the fourth and later sources share:
+///
+/// ```sql
+/// setweight(to_tsvector('simple', coalesce(title, '')), 'A')
+///     || setweight(to_tsvector('simple', coalesce(body, '')), 'B')
+/// ```
+fn tsvector_expr(entity: &EntityDef, field: &FieldDef, config: &str) -> String {
+    const WEIGHTS: [char; 4] = ['A', 'B', 'C', 'D'];
+
+    field
+        .column()
+        .sources
+        .iter()
+        .enumerate()
+        .map(|(position, source)| {
+            let column = entity
+                .all_fields()
+                .iter()
+                .find(|f| f.name_str() == *source)
+                .map_or_else(|| source.clone(), FieldDef::column_name);
+            format!(
+                "setweight(to_tsvector('{}', coalesce({}, '')), '{}')",
+                config,
+                column,
+                WEIGHTS[position.min(WEIGHTS.len() - 1)]
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" || ")
+}
+
+/// Check if a column gets its own index.
+///
+/// Either requested with `#[column(index)]`, or a unique column under
+/// `unique_among_active`, whose uniqueness needs a partial index.
+fn has_single_index(entity: &EntityDef, field: &FieldDef) -> bool {
+    field.column().has_index() || (field.is_unique() && entity.is_unique_among_active())
+}
+
+/// Predicate limiting unique indexes to non-deleted rows.
+fn active_predicate(entity: &EntityDef, unique: bool) -> Option<&'static str> {
+    (unique && entity.is_unique_among_active()).then_some("deleted_at IS NULL")
+}
+
 /// Generate CREATE INDEX for a single column.
+///
+/// `#[column(unique, index)]` creates a `UNIQUE` index, which replaces the
+/// column's `UNIQUE` constraint.
 fn generate_single_index(entity: &EntityDef, field: &FieldDef) -> String {
     let table = &entity.table;
     let schema = &entity.schema;
@@ -130,10 +268,14 @@ fn generate_single_index(entity: &EntityDef, field: &FieldDef) -> String {
     let index_type = field.column().index.unwrap_or_default();
     let index_name = format!("idx_{}_{}", table, column);
     let using = index_type.as_sql_using();
+    let unique_str = if field.is_unique() { "UNIQUE " } else { "" };
+    let where_str = active_predicate(entity, field.is_unique())
+        .map(|predicate| format!(" WHERE {}", predicate))
+        .unwrap_or_default();
 
     format!(
-        "CREATE INDEX IF NOT EXISTS {} ON {}.{}{} ({});\n",
-        index_name, schema, table, using, column
+        "CREATE {}INDEX IF NOT EXISTS {} ON {}.{}{} ({}){};\n",
+        unique_str, index_name, schema, table, using, column, where_str
     )
 }
 
@@ -152,8 +294,13 @@ fn generate_composite_index(entity: &EntityDef, idx: &CompositeIndexDef) -> Stri
         unique_str, index_name, schema, table, using, columns
     );
 
-    if let Some(ref where_clause) = idx.where_clause {
-        sql.push_str(&format!(" WHERE {}", where_clause));
+    match (&idx.where_clause, active_predicate(entity, idx.unique)) {
+        (Some(where_clause), Some(active)) => {
+            sql.push_str(&format!(" WHERE ({}) AND {}", where_clause, active));
+        }
+        (Some(where_clause), None) => sql.push_str(&format!(" WHERE {}", where_clause)),
+        (None, Some(active)) => sql.push_str(&format!(" WHERE {}", active)),
+        (None, None) => {}
     }
 
     sql.push_str(";\n");
@@ -242,6 +389,80 @@ mod tests {
         assert!(sql.contains("name TEXT NOT NULL"));
     }
 
+    #[test]
+    fn generate_up_with_storage() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "documents", migrations)]
+            pub struct Document {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                #[column(storage = "external")]
+                pub body: String,
+                #[field(create, response)]
+                pub title: String,
+            }
+        });
+        let sql = generate_up(&entity);
+        let create = sql.find("CREATE TABLE").unwrap();
+        let alter = sql
+            .find("ALTER TABLE public.documents ALTER COLUMN body SET STORAGE EXTERNAL;")
+            .unwrap();
+        assert!(create < alter);
+        assert!(!sql.contains("COLUMN title"));
+    }
+
+    #[test]
+    fn schema_version_is_stable_hex() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub name: String,
+            }
+        });
+        let version = schema_version(&entity);
+        assert_eq!(version.len(), 16);
+        assert!(version.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(version, schema_version(&entity));
+    }
+
+    #[test]
+    fn schema_version_tracks_columns_not_indexes() {
+        let base = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub name: String,
+            }
+        });
+        let retyped = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub name: Option<String>,
+            }
+        });
+        let indexed = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                #[column(index)]
+                pub name: String,
+            }
+        });
+        assert_ne!(schema_version(&base), schema_version(&retyped));
+        assert_eq!(schema_version(&base), schema_version(&indexed));
+    }
+
     #[test]
     fn generate_down_basic() {
         let entity = parse_entity(quote::quote! {
@@ -367,6 +588,66 @@ mod tests {
         assert!(sql.contains("VARCHAR(100)"));
     }
 
+    #[test]
+    fn generate_up_with_citext_and_collation() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                #[column(citext, unique)]
+                pub email: String,
+                #[field(create, response)]
+                #[column(collation = "en-US-x-icu")]
+                pub name: String,
+            }
+        });
+        let sql = generate_up(&entity);
+        assert!(sql.starts_with("CREATE EXTENSION IF NOT EXISTS citext;\n"));
+        assert!(sql.contains("email CITEXT NOT NULL UNIQUE"));
+        assert!(sql.contains("name TEXT COLLATE \"en-US-x-icu\" NOT NULL"));
+    }
+
+    #[test]
+    fn generate_up_with_schema_create() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "users", schema = "core", schema_create, migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        let sql = generate_up(&entity);
+        assert!(sql.starts_with(
+            "CREATE SCHEMA IF NOT EXISTS core;\nCREATE TABLE IF NOT EXISTS core.users"
+        ));
+    }
+
+    #[test]
+    fn generate_up_schema_create_skips_public() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "users", schema_create, migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(!generate_up(&entity).contains("CREATE SCHEMA"));
+    }
+
+    #[test]
+    fn generate_up_without_citext_has_no_extension() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(!generate_up(&entity).contains("EXTENSION"));
+    }
+
     #[test]
     fn generate_up_with_belongs_to() {
         let entity = parse_entity(quote::quote! {
@@ -509,4 +790,148 @@ mod tests {
         let sql = generate_up(&entity);
         assert!(sql.contains("CREATE INDEX IF NOT EXISTS idx_users_name_email"));
     }
+
+    #[test]
+    fn generate_up_with_unique_index() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[column(unique, index)]
+                pub email: String,
+            }
+        });
+        let sql = generate_up(&entity);
+        assert!(!sql.contains("NOT NULL UNIQUE"));
+        assert!(sql.contains(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_users_email ON public.users (email);"
+        ));
+    }
+
+    #[test]
+    fn temporary_table() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations, temporary)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[column(index)]
+                pub name: String,
+            }
+        });
+        let sql = generate_up(&entity);
+        assert!(sql.contains("CREATE TEMPORARY TABLE pg_temp.users ("));
+        assert!(!sql.contains("CREATE TABLE IF NOT EXISTS"));
+        assert!(sql.contains("ON pg_temp.users"));
+    }
+
+    #[test]
+    fn computed_field_has_no_column() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                pub email: String,
+                #[field(response, returning = "lower(email)")]
+                pub normalized_email: String,
+            }
+        });
+        let sql = generate_up(&entity);
+        assert!(sql.contains("email TEXT"));
+        assert!(!sql.contains("normalized_email"));
+    }
+
+    #[test]
+    fn json_value_columns() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "audit_logs", migrations)]
+            pub struct AuditLog {
+                #[id]
+                pub id: uuid::Uuid,
+                pub new_data: serde_json::Value,
+                pub old_data: Option<serde_json::Value>,
+            }
+        });
+        let sql = generate_up(&entity);
+        assert!(sql.contains("new_data JSONB NOT NULL"));
+        assert!(sql.contains("old_data JSONB"));
+        assert!(!sql.contains("old_data JSONB NOT NULL"));
+    }
+
+    #[test]
+    fn unique_among_active_uses_partial_indexes() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(
+                table = "users",
+                migrations,
+                soft_delete,
+                unique_among_active,
+                unique_index(org_id, name, where = "name <> ''")
+            )]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[column(unique)]
+                pub email: String,
+                pub org_id: uuid::Uuid,
+                pub name: String,
+                pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+            }
+        });
+        let sql = generate_up(&entity);
+        assert!(sql.contains("email TEXT NOT NULL,"));
+        assert!(!sql.contains("UNIQUE,"));
+        assert!(sql.contains(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_users_email ON public.users (email) \
+             WHERE deleted_at IS NULL;"
+        ));
+        assert!(sql.contains("(org_id, name) WHERE (name <> '') AND deleted_at IS NULL;"));
+    }
+
+    #[test]
+    fn soft_delete_alone_keeps_unique_constraint() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "users", migrations, soft_delete)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[column(unique)]
+                pub email: String,
+                pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+            }
+        });
+        let sql = generate_up(&entity);
+        assert!(sql.contains("email TEXT NOT NULL UNIQUE"));
+        assert!(!sql.contains("deleted_at IS NULL"));
+    }
+
+    #[test]
+    fn tsvector_generated_column_and_gin_index() {
+        let entity = parse_entity(quote::quote! {
+            #[entity(table = "articles", migrations)]
+            pub struct Article {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub title: String,
+                #[field(create, response)]
+                #[column(name = "content")]
+                pub body: Option<String>,
+                #[field(response)]
+                #[column(tsvector = "english", sources = [title, body])]
+                pub search: String,
+            }
+        });
+        let sql = generate_up(&entity);
+        assert!(sql.contains(
+            "search TSVECTOR GENERATED ALWAYS AS (\
+             setweight(to_tsvector('english', coalesce(title, '')), 'A') || \
+             setweight(to_tsvector('english', coalesce(content, '')), 'B')) STORED\n"
+        ));
+        assert!(sql.contains(
+            "CREATE INDEX IF NOT EXISTS idx_articles_search ON public.articles USING gin (search);"
+        ));
+    }
 }
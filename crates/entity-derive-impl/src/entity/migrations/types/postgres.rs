@@ -10,7 +10,7 @@
 //! | Rust Type | PostgreSQL Type | Notes |
 //! |-----------|-----------------|-------|
 //! | `Uuid` | `UUID` | |
-//! | `String` | `TEXT` | Or `VARCHAR(n)` with `#[column(varchar = n)]` |
+//! | `String` | `TEXT` | Or `VARCHAR(n)` with `#[column(varchar = n)]`, `CITEXT` with `#[column(citext)]` |
 //! | `i16` | `SMALLINT` | |
 //! | `i32` | `INTEGER` | |
 //! | `i64` | `BIGINT` | |
@@ -23,9 +23,20 @@
 //! | `NaiveDateTime` | `TIMESTAMP` | |
 //! | `Option<T>` | `T` | Nullable |
 //! | `Vec<T>` | `T[]` | PostgreSQL array |
-//! | `serde_json::Value` | `JSONB` | |
+//! | `serde_json::Value` | `JSONB` | Also `sqlx::types::JsonValue` and `Json<T>` |
 //! | `Decimal` | `DECIMAL` | |
 //! | `IpAddr` | `INET` | |
+//! | `PgRange<DateTime<Utc>>` | `TSTZRANGE` | |
+//! | `PgRange<NaiveDateTime>` | `TSRANGE` | |
+//! | `PgRange<NaiveDate>` | `DATERANGE` | |
+//! | `PgRange<i32>` | `INT4RANGE` | |
+//! | `PgRange<i64>` | `INT8RANGE` | |
+//! | `PgRange<Decimal>` | `NUMRANGE` | Also `BigDecimal` |
+//!
+//! JSON fields are bound and decoded by sqlx directly, which requires its
+//! `json` feature (on by default). `Option<serde_json::Value>` is a nullable
+//! `JSONB` column: `None` is SQL `NULL`, while `Some(Value::Null)` stores the
+//! JSON literal `null`.
 
 use syn::Type;
 
@@ -70,7 +81,10 @@ impl TypeMapper for PostgresTypeMapper {
         }
 
         // Map core types
-        let name = map_type_name(ty, column);
+        let name = match extract_range_inner(ty) {
+            Some(inner) => map_range_name(inner),
+            None => map_type_name(ty, column)
+        };
 
         SqlType {
             name,
@@ -90,7 +104,9 @@ fn map_type_name(ty: &Type, column: &ColumnConfig) -> String {
 
         // Strings
         "String" | "str" => {
-            if let Some(len) = column.varchar {
+            if column.citext {
+                "CITEXT".to_string()
+            } else if let Some(len) = column.varchar {
                 format!("VARCHAR({})", len)
             } else {
                 "TEXT".to_string()
@@ -121,7 +137,12 @@ fn map_type_name(ty: &Type, column: &ColumnConfig) -> String {
         "NaiveDateTime" | "chrono::NaiveDateTime" => "TIMESTAMP".to_string(),
 
         // JSON
-        "Value" | "serde_json::Value" | "Json" | "sqlx::types::Json" => "JSONB".to_string(),
+        "Value"
+        | "serde_json::Value"
+        | "JsonValue"
+        | "sqlx::types::JsonValue"
+        | "Json"
+        | "sqlx::types::Json" => "JSONB".to_string(),
 
         // Decimal
         "Decimal" | "rust_decimal::Decimal" | "BigDecimal" | "bigdecimal::BigDecimal" => {
@@ -140,6 +161,23 @@ fn map_type_name(ty: &Type, column: &ColumnConfig) -> String {
     }
 }
 
+/// Map the element type of `PgRange<T>` to a PostgreSQL range type name.
+fn map_range_name(inner: &Type) -> String {
+    match type_path_string(inner).as_str() {
+        "DateTime" | "chrono::DateTime" => "TSTZRANGE".to_string(),
+        "NaiveDateTime" | "chrono::NaiveDateTime" => "TSRANGE".to_string(),
+        "NaiveDate" | "chrono::NaiveDate" => "DATERANGE".to_string(),
+        "i32" => "INT4RANGE".to_string(),
+        "i64" => "INT8RANGE".to_string(),
+        "Decimal" | "rust_decimal::Decimal" | "BigDecimal" | "bigdecimal::BigDecimal" => {
+            "NUMRANGE".to_string()
+        }
+
+        // Fallback to TEXT for unknown element types
+        _ => "TEXT".to_string()
+    }
+}
+
 /// Extract the type path as a string.
 fn type_path_string(ty: &Type) -> String {
     if let Type::Path(type_path) = ty {
@@ -173,6 +211,19 @@ fn extract_option_inner(ty: &Type) -> Option<&Type> {
     None
 }
 
+/// Extract the element type from PgRange<T>.
+fn extract_range_inner(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "PgRange"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+    {
+        return Some(inner);
+    }
+    None
+}
+
 /// Extract the inner type from Vec<T>.
 fn extract_vec_inner(ty: &Type) -> Option<&Type> {
     if let Type::Path(type_path) = ty
@@ -226,6 +277,18 @@ mod tests {
         assert_eq!(ty.name, "VARCHAR(255)");
     }
 
+    #[test]
+    fn map_string_citext() {
+        let column = ColumnConfig {
+            citext: true,
+            varchar: Some(255),
+            ..Default::default()
+        };
+        let ty = map_type_with_column(quote::quote! { Option<String> }, column);
+        assert_eq!(ty.name, "CITEXT");
+        assert!(ty.nullable);
+    }
+
     #[test]
     fn map_integers() {
         assert_eq!(map_type(quote::quote! { i16 }).name, "SMALLINT");
@@ -255,6 +318,25 @@ mod tests {
         assert_eq!(map_type(quote::quote! { NaiveDate }).name, "DATE");
     }
 
+    #[test]
+    fn map_ranges() {
+        assert_eq!(
+            map_type(quote::quote! { PgRange<DateTime<Utc>> }).name,
+            "TSTZRANGE"
+        );
+        assert_eq!(
+            map_type(quote::quote! { sqlx::postgres::types::PgRange<i64> }).name,
+            "INT8RANGE"
+        );
+        assert_eq!(
+            map_type(quote::quote! { PgRange<NaiveDate> }).name,
+            "DATERANGE"
+        );
+        let ty = map_type(quote::quote! { Option<PgRange<i32>> });
+        assert_eq!(ty.name, "INT4RANGE");
+        assert!(ty.nullable);
+    }
+
     #[test]
     fn map_option_nullable() {
         let ty = map_type(quote::quote! { Option<String> });
@@ -289,6 +371,22 @@ mod tests {
     #[test]
     fn map_json() {
         assert_eq!(map_type(quote::quote! { serde_json::Value }).name, "JSONB");
+        assert_eq!(
+            map_type(quote::quote! { sqlx::types::JsonValue }).name,
+            "JSONB"
+        );
+        assert_eq!(
+            map_type(quote::quote! { sqlx::types::Json<Settings> }).name,
+            "JSONB"
+        );
+    }
+
+    #[test]
+    fn map_option_json_is_nullable() {
+        let ty = map_type(quote::quote! { Option<serde_json::Value> });
+        assert_eq!(ty.name, "JSONB");
+        assert!(ty.nullable);
+        assert_eq!(ty.to_sql_string(), "JSONB");
     }
 
     #[test]
@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Typed sort order generation.
+//!
+//! Fields marked `#[sortable]` become variants of a closed `{Entity}OrderBy`
+//! enum, one per direction. The repository's `list_ordered` interpolates
+//! only `as_sql()` of a variant into `ORDER BY`, so no caller input ever
+//! reaches the SQL text.
+//!
+//! # Generated Code
+//!
+//! ```rust,ignore
+//! #[derive(Entity)]
+//! #[entity(table = "users")]
+//! pub struct User {
+//!     #[id]
+//!     pub id: Uuid,
+//!
+//!     #[field(create, response)]
+//!     #[sortable]
+//!     pub name: String,
+//!
+//!     #[field(response)]
+//!     #[auto]
+//!     #[sortable]
+//!     pub created_at: DateTime<Utc>,
+//! }
+//! ```
+//!
+//! Generates:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+//! pub enum UserOrderBy {
+//!     NameAsc,
+//!     NameDesc,
+//!     CreatedAtAsc,
+//!     CreatedAtDesc,
+//! }
+//!
+//! impl UserOrderBy {
+//!     pub const fn column(&self) -> &'static str;
+//!     pub const fn direction(&self) -> SortDirection;
+//!     pub const fn as_sql(&self) -> &'static str; // "name ASC"
+//! }
+//! ```
+
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::parse::EntityDef;
+use crate::utils::marker;
+
+/// Generates the OrderBy enum for the entity.
+///
+/// Returns an empty `TokenStream` if no fields have `#[sortable]`.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    if !entity.has_sortable() {
+        return TokenStream::new();
+    }
+
+    let vis = &entity.vis;
+    let order_name = entity.ident_with("", "OrderBy");
+
+    let mut variants = Vec::new();
+    let mut columns = Vec::new();
+    let mut directions = Vec::new();
+    let mut sql = Vec::new();
+    for field in entity.sortable_fields() {
+        let base = field.name_str().to_case(Case::Pascal);
+        let column = field.column_name();
+        for (suffix, direction) in [("Asc", quote! { Asc }), ("Desc", quote! { Desc })] {
+            let variant = format_ident!("{}{}", base, suffix);
+            let doc = format!("Order by `{}` {}.", column, suffix.to_uppercase());
+            variants.push(quote! {
+                #[doc = #doc]
+                #variant
+            });
+            columns.push(quote! { Self::#variant => #column });
+            directions.push(quote! { Self::#variant => entity_derive::SortDirection::#direction });
+            let clause = format!("{} {}", column, suffix.to_uppercase());
+            sql.push(quote! { Self::#variant => #clause });
+        }
+    }
+
+    let marker = marker::generated();
+    let doc = format!(
+        "Sort order for [`{}`] lists, one variant per `#[sortable]` field and direction.",
+        entity.name()
+    );
+
+    quote! {
+        #marker
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #order_name {
+            #(#variants),*
+        }
+
+        impl #order_name {
+            /// Column to order by.
+            pub const fn column(&self) -> &'static str {
+                match self {
+                    #(#columns),*
+                }
+            }
+
+            /// Direction to order in.
+            pub const fn direction(&self) -> entity_derive::SortDirection {
+                match self {
+                    #(#directions),*
+                }
+            }
+
+            /// `ORDER BY` term, e.g. `"name ASC"`.
+            pub const fn as_sql(&self) -> &'static str {
+                match self {
+                    #(#sql),*
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: syn::DeriveInput) -> EntityDef {
+        EntityDef::from_derive_input(&input).unwrap()
+    }
+
+    #[test]
+    fn no_sortable_fields_returns_empty() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub name: String,
+            }
+        });
+        assert!(generate(&entity).is_empty());
+    }
+
+    #[test]
+    fn sortable_fields_generate_variants() {
+        let entity = parse(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                #[sortable]
+                pub name: String,
+                #[field(response)]
+                #[auto]
+                #[sortable]
+                pub created_at: chrono::DateTime<chrono::Utc>,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("enum UserOrderBy"));
+        assert!(output.contains("NameAsc"));
+        assert!(output.contains("NameDesc"));
+        assert!(output.contains("CreatedAtDesc"));
+        assert!(output.contains("\"created_at DESC\""));
+    }
+}
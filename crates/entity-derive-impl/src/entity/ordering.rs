@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Identity-based equality and ordering.
+//!
+//! For entities with `#[entity(ord_by_id)]`, generates `PartialEq`, `Eq`,
+//! `PartialOrd` and `Ord` that compare only the `#[id]` field. Two values
+//! with the same id are the same entity, whatever their other fields hold.
+//!
+//! With the default UUID v7 ids, which embed a millisecond timestamp,
+//! ordering by id is chronological, so collections can be sorted by
+//! creation time without a timestamp column.
+//!
+//! The id type must implement `Ord`. Do not also derive `PartialEq` on the
+//! entity; the generated impl would conflict with it.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! #[derive(Entity)]
+//! #[entity(table = "users", ord_by_id)]
+//! pub struct User {
+//!     #[id]
+//!     pub id: Uuid,
+//! }
+//!
+//! users.sort(); // oldest first
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::parse::EntityDef;
+
+/// Generate `Eq`/`Ord` impls keyed by the id field.
+///
+/// Returns empty `TokenStream` unless `ord_by_id` is set.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    if !entity.has_ord_by_id() {
+        return TokenStream::new();
+    }
+
+    let entity_name = entity.name();
+    let id_name = entity.id_field().name();
+
+    quote! {
+        impl PartialEq for #entity_name {
+            fn eq(&self, other: &Self) -> bool {
+                self.#id_name == other.#id_name
+            }
+        }
+
+        impl Eq for #entity_name {}
+
+        impl PartialOrd for #entity_name {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for #entity_name {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.#id_name.cmp(&other.#id_name)
+            }
+        }
+    }
+}
@@ -17,6 +17,7 @@
 //! │   └── storage.rs - DB storage config (id, auto)
 //! ├── command.rs     - Command pattern parsing (CommandDef, CommandSource)
 //! ├── dialect.rs     - Database dialect (Postgres, ClickHouse, MongoDB)
+//! ├── isolation_level.rs - Transaction isolation level
 //! ├── sql_level.rs   - SQL generation level (Full, Trait, None)
 //! └── uuid_version.rs - UUID version for IDs (V7, V4)
 //! ```
@@ -111,20 +112,22 @@ mod command;
 mod dialect;
 mod entity;
 mod field;
+mod isolation_level;
 mod returning;
 mod sql_level;
 mod uuid_version;
 
 // Re-exported for handler generation (#77)
 #[allow(unused_imports)]
-pub use api::ApiConfig;
+pub use api::{ApiConfig, ContentType, PaginationStyle};
 pub use command::{CommandDef, CommandKindHint, CommandSource};
 pub use dialect::DatabaseDialect;
-pub use entity::{CompositeIndexDef, EntityDef, ProjectionDef};
+pub use entity::{CompositeIndexDef, DeriveTarget, EntityDef, ProjectionDef};
 #[allow(unused_imports)] // Will be used for OpenAPI schema examples (#80)
 pub use field::ExampleValue;
 #[allow(unused_imports)] // Re-exported for migration generation tests
 pub use field::{ColumnConfig, FieldDef, FilterType, IndexType};
+pub use isolation_level::IsolationLevel;
 pub use returning::ReturningMode;
 pub use sql_level::SqlLevel;
 pub use uuid_version::UuidVersion;
@@ -45,6 +45,8 @@
 //! |--------|------|----------|-------------|
 //! | `tag` | string | Yes | OpenAPI tag for endpoint grouping |
 //! | `tag_description` | string | No | Tag description for docs |
+//! | `external_docs` | string | No | Tag `externalDocs` URL |
+//! | `external_docs_description` | string | No | Tag `externalDocs` description |
 //! | `handlers` | flag/list | No | CRUD handlers to generate |
 //!
 //! ## URL Configuration
@@ -80,6 +82,13 @@
 //! |--------|-------------|
 //! | `deprecated_in` | Version where API was deprecated |
 //!
+//! ## Response Headers
+//!
+//! | Option | Description |
+//! |--------|-------------|
+//! | `base_response_headers` | `["Name: value"]` sent by get and list, e.g. `Cache-Control` |
+//! | `write_response_headers` | `["Name: value"]` sent by create, update and delete |
+//!
 //! # Handler Configuration
 //!
 //! The `handlers` option controls CRUD handler generation:
@@ -131,7 +140,7 @@
 mod config;
 mod parser;
 
-pub use config::ApiConfig;
+pub use config::{ApiConfig, ContentType, PaginationStyle};
 pub use parser::parse_api_config;
 
 #[cfg(test)]
@@ -17,11 +17,29 @@
 //! │  ApiConfig                                                      │
 //! │  ├─► tag: Option<String>          # OpenAPI tag name            │
 //! │  ├─► tag_description: Option<String>                            │
+//! │  ├─► external_docs / external_docs_description  # Tag docs link │
+//! │  ├─► read_tag / write_tag         # Per-operation tag override  │
 //! │  ├─► path_prefix: Option<String>  # URL prefix                  │
+//! │  ├─► path_style: PathStyle        # Plural or singular paths    │
+//! │  ├─► operation_id_prefix          # Prefix for operationIds     │
 //! │  ├─► security: Option<String>     # Auth scheme                 │
 //! │  ├─► public_commands: Vec<Ident>  # No-auth commands            │
 //! │  ├─► version: Option<String>      # API version                 │
 //! │  ├─► deprecated_in: Option<String>                              │
+//! │  ├─► deprecated_sunset: Option<String>  # Sunset date           │
+//! │  ├─► etag: Option<String>         # ETag source field           │
+//! │  ├─► max_limit: Option<i64>       # List page size cap          │
+//! │  ├─► rate_limits: Vec<(String, u32)>  # Requests per operation  │
+//! │  ├─► rate_limit_window: Option<u64>   # Rate limit window (s)   │
+//! │  ├─► list_query: bool             # Filters as list query params│
+//! │  ├─► pagination: PaginationStyle  # Offset or cursor list contract│
+//! │  ├─► content_type: ContentType    # Request body media type     │
+//! │  ├─► multipart_field: Option      # File part of create uploads │
+//! │  ├─► request_id: bool             # X-Request-Id correlation    │
+//! │  ├─► response_envelope: Option<String>  # `{ "data": ... }` key │
+//! │  ├─► base_response_headers       # Static headers on GET        │
+//! │  ├─► write_response_headers      # Static headers on writes     │
+//! │  ├─► extensions: Vec<(String, String)>  # `x-` operation fields │
 //! │  ├─► handlers: HandlerConfig      # CRUD settings               │
 //! │  └─► OpenAPI Info Fields                                        │
 //! │      ├─► title, description, api_version                        │
@@ -33,7 +51,8 @@
 //! │  ├─► get: bool                    # GET /collection/{id}        │
 //! │  ├─► update: bool                 # PATCH /collection/{id}      │
 //! │  ├─► delete: bool                 # DELETE /collection/{id}     │
-//! │  └─► list: bool                   # GET /collection             │
+//! │  ├─► list: bool                   # GET /collection             │
+//! │  └─► list_stream: bool            # GET /collection/stream (SSE)│
 //! │                                                                 │
 //! └─────────────────────────────────────────────────────────────────┘
 //! ```
@@ -48,6 +67,7 @@
 //! | `handlers = true` | All five handlers |
 //! | `handlers = false` | No handlers |
 //! | `handlers(create, get)` | Only specified handlers |
+//! | `handlers(list, list_stream)` | List plus the SSE stream |
 //!
 //! # Security Behavior
 //!
@@ -69,9 +89,178 @@
 //! | `/api` | - | User | `/api/users` |
 //! | `/api` | `v1` | User | `/api/v1/users` |
 //! | `/api/` | `v1` | User | `/api/v1/users` (trailing slash handled) |
+//!
+//! With `path_style = "singular"` the resource segment is not pluralized:
+//! `/api/v1/user`, `/api/v1/user/{id}`.
 
+use convert_case::{Case, Casing};
 use syn::Ident;
 
+/// Page size cap for list endpoints when `max_limit` is not set.
+pub const DEFAULT_MAX_LIMIT: i64 = 1000;
+
+/// Rate limit window in seconds when `rate_limit_window` is not set.
+pub const DEFAULT_RATE_LIMIT_WINDOW: u64 = 60;
+
+/// Form of the resource segment in generated URL paths.
+///
+/// | Value | Entity | Segment |
+/// |-------|--------|---------|
+/// | `"plural"` (default) | `UserProfile` | `user-profiles` |
+/// | `"singular"` | `UserProfile` | `user-profile` |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PathStyle {
+    /// Kebab-case entity name with an `s` suffix.
+    #[default]
+    Plural,
+
+    /// Kebab-case entity name as is.
+    Singular
+}
+
+impl PathStyle {
+    /// Parse from the `path_style = "..."` attribute value.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "plural" => Some(Self::Plural),
+            "singular" => Some(Self::Singular),
+            _ => None
+        }
+    }
+}
+
+/// Pagination contract of the list handler.
+///
+/// | Value | Query parameters | Response body |
+/// |-------|------------------|---------------|
+/// | `"offset"` (default) | `limit`, `offset` | `Vec<{Entity}Response>` |
+/// | `"cursor"` | `after`, `limit` | `{Entity}Page` with `items`, `next_cursor` |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PaginationStyle {
+    /// `repository.list(limit, offset)`.
+    #[default]
+    Offset,
+
+    /// `repository.list_page(after, limit)`, keyset on the primary key.
+    Cursor
+}
+
+impl PaginationStyle {
+    /// Parse from the `pagination = "..."` attribute value.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "offset" => Some(Self::Offset),
+            "cursor" => Some(Self::Cursor),
+            _ => None
+        }
+    }
+}
+
+/// Media type of create and update request bodies.
+///
+/// | Value | Extractor |
+/// |-------|-----------|
+/// | `"application/json"` (default) | `axum::extract::Json` |
+/// | `"application/x-www-form-urlencoded"` | `axum::extract::Form` |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ContentType {
+    /// JSON body.
+    #[default]
+    Json,
+
+    /// URL-encoded form body.
+    Form
+}
+
+impl ContentType {
+    /// Parse from the `content_type = "..."` attribute value.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "application/json" => Some(Self::Json),
+            "application/x-www-form-urlencoded" => Some(Self::Form),
+            _ => None
+        }
+    }
+
+    /// Media type advertised in the OpenAPI `requestBody`.
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Form => "application/x-www-form-urlencoded"
+        }
+    }
+}
+
+/// Split a `"Name: value"` header declaration.
+///
+/// Returns the name lowercased, as `http::HeaderName::from_static`
+/// requires, and the trimmed value. Returns `None` unless the name is a
+/// non-empty RFC 9110 token and the value is visible ASCII.
+pub fn static_header(declaration: &str) -> Option<(String, String)> {
+    let (name, value) = declaration.split_once(':')?;
+    let name = name.trim();
+    let value = value.trim();
+    let is_tchar = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c);
+    if name.is_empty() || !name.chars().all(is_tchar) {
+        return None;
+    }
+    if !value
+        .chars()
+        .all(|c| c == ' ' || c == '\t' || c.is_ascii_graphic())
+    {
+        return None;
+    }
+    Some((name.to_ascii_lowercase(), value.to_string()))
+}
+
+/// Convert a `YYYY-MM-DD` date to an IMF-fixdate at `23:59:59 GMT`.
+///
+/// Returns `None` if the date is malformed or does not exist.
+pub fn http_date(date: &str) -> Option<String> {
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"
+    ];
+
+    let mut parts = date.split('-');
+    let (year, month, day) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(y), Some(m), Some(d), None) if y.len() == 4 && m.len() == 2 && d.len() == 2 => (
+            y.parse::<i64>().ok()?,
+            m.parse::<i64>().ok()?,
+            d.parse::<i64>().ok()?
+        ),
+        _ => return None
+    };
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None
+    };
+    if !(1..=days_in_month).contains(&day) {
+        return None;
+    }
+
+    // Days since 1970-01-01 (Howard Hinnant's days_from_civil).
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some(format!(
+        "{}, {:02} {} {} 23:59:59 GMT",
+        WEEKDAYS[days.rem_euclid(7) as usize],
+        day,
+        MONTHS[(month - 1) as usize],
+        year
+    ))
+}
+
 /// Configuration for selective CRUD handler generation.
 ///
 /// Controls which of the five standard CRUD handlers are generated:
@@ -109,40 +298,46 @@ use syn::Ident;
 /// | `update` | PATCH | `/entities/{id}` | Partial update |
 /// | `delete` | DELETE | `/entities/{id}` | Remove entity |
 /// | `list` | GET | `/entities` | List with pagination |
+/// | `list_stream` | GET | `/entities/stream` | Server-Sent Events of every entity |
 ///
 /// # Default Behavior
 ///
 /// All handlers are `false` by default. To generate handlers, you must
-/// explicitly enable them via one of the syntax forms above.
+/// explicitly enable them via one of the syntax forms above. `list_stream`
+/// is never part of the flag or boolean form; it must be named and requires
+/// `#[entity(streams)]`.
 #[derive(Debug, Clone, Default)]
 pub struct HandlerConfig {
     /// Generate create handler (POST /collection).
-    pub create: bool,
+    pub create:      bool,
     /// Generate get handler (GET /collection/{id}).
-    pub get:    bool,
+    pub get:         bool,
     /// Generate update handler (PATCH /collection/{id}).
-    pub update: bool,
+    pub update:      bool,
     /// Generate delete handler (DELETE /collection/{id}).
-    pub delete: bool,
+    pub delete:      bool,
     /// Generate list handler (GET /collection).
-    pub list:   bool
+    pub list:        bool,
+    /// Generate SSE list handler (GET /collection/stream).
+    pub list_stream: bool
 }
 
 impl HandlerConfig {
     /// Create config with all handlers enabled.
     pub fn all() -> Self {
         Self {
-            create: true,
-            get:    true,
-            update: true,
-            delete: true,
-            list:   true
+            create:      true,
+            get:         true,
+            update:      true,
+            delete:      true,
+            list:        true,
+            list_stream: false
         }
     }
 
     /// Check if any handler is enabled.
     pub fn any(&self) -> bool {
-        self.create || self.get || self.update || self.delete || self.list
+        self.create || self.get || self.update || self.delete || self.list || self.list_stream
     }
 }
 
@@ -209,11 +404,43 @@ pub struct ApiConfig {
     /// Provides additional context in API documentation.
     pub tag_description: Option<String>,
 
+    /// URL of external documentation for the OpenAPI tag.
+    ///
+    /// Written to the tag's `externalDocs.url`.
+    /// Example: `"https://wiki.example.com/users"`
+    pub external_docs: Option<String>,
+
+    /// Description of the tag's external documentation link.
+    ///
+    /// Requires `external_docs`.
+    pub external_docs_description: Option<String>,
+
+    /// OpenAPI tag for read operations (get, list).
+    ///
+    /// Falls back to `tag`. Example: `"Users: Read"`
+    pub read_tag: Option<String>,
+
+    /// OpenAPI tag for write operations (create, update, delete, commands).
+    ///
+    /// Falls back to `tag`. Example: `"Users: Write"`
+    pub write_tag: Option<String>,
+
     /// URL path prefix for all endpoints.
     ///
     /// Example: `"/api/v1"` results in `/api/v1/users`
     pub path_prefix: Option<String>,
 
+    /// Whether resource segments are pluralized.
+    ///
+    /// Applies to handler, OpenAPI and router paths alike.
+    pub path_style: PathStyle,
+
+    /// Prefix for every generated OpenAPI `operationId`.
+    ///
+    /// Example: `"billing_"` results in `billing_create_invoice`, keeping
+    /// client SDK method names unique across services.
+    pub operation_id_prefix: Option<String>,
+
     /// Default security scheme for endpoints.
     ///
     /// Supported values:
@@ -238,6 +465,98 @@ pub struct ApiConfig {
     /// Marks all endpoints with `deprecated = true` in OpenAPI.
     pub deprecated_in: Option<String>,
 
+    /// Date (`YYYY-MM-DD`) after which the API may stop responding.
+    ///
+    /// Implies deprecation. Handlers send it as the RFC 8594 `Sunset`
+    /// header alongside `Deprecation`.
+    pub deprecated_sunset: Option<String>,
+
+    /// Field the `ETag` header is derived from.
+    ///
+    /// An integer version counter or a `DateTime` such as `updated_at`. When
+    /// set, the get handler emits `ETag` and update/delete honor
+    /// `If-Match`.
+    pub etag: Option<String>,
+
+    /// Upper bound for the list handler's `limit` parameter.
+    ///
+    /// Requests above it are clamped. Defaults to
+    /// [`DEFAULT_MAX_LIMIT`] when not set.
+    pub max_limit: Option<i64>,
+
+    /// Declared request budget per operation and window.
+    ///
+    /// Parsed from `rate_limit(create = 10, list = 100)`. Keys are CRUD
+    /// handler names or snake_case command names. The macro only exposes
+    /// them as `{Entity}::API_RATE_LIMITS`; enforcing is left to middleware.
+    pub rate_limits: Vec<(String, u32)>,
+
+    /// Length of the rate limit window in seconds.
+    ///
+    /// Defaults to [`DEFAULT_RATE_LIMIT_WINDOW`] when not set.
+    pub rate_limit_window: Option<u64>,
+
+    /// Accept the `{Entity}Query` filters in the list handler.
+    ///
+    /// The handler extracts `{Entity}Query`, which then derives
+    /// `utoipa::IntoParams`, and calls `query` instead of `list`, so every
+    /// `#[filter]` field is a documented query parameter.
+    pub list_query: bool,
+
+    /// Pagination contract of the list handler.
+    ///
+    /// With `pagination = "cursor"`, the handler takes `after` and `limit`,
+    /// calls `list_page` and responds with `{Entity}Page`.
+    pub pagination: PaginationStyle,
+
+    /// Media type of create and update request bodies.
+    ///
+    /// Selects the axum extractor of the handlers and the `requestBody`
+    /// content in OpenAPI. Responses stay JSON.
+    pub content_type: ContentType,
+
+    /// Create field filled from a file part of a `multipart/form-data` body.
+    ///
+    /// With `multipart_field = "file"`, the create handler extracts
+    /// `axum::extract::Multipart`: the `file` part becomes the field's
+    /// `Vec<u8>` and the other parts fill the remaining create fields. The
+    /// update handler keeps `content_type`.
+    pub multipart_field: Option<String>,
+
+    /// Correlate requests with an `X-Request-Id` header.
+    ///
+    /// CRUD handlers reuse the incoming ID or generate one, record it on a
+    /// `tracing` span around the handler, and echo it on every response,
+    /// errors included.
+    pub request_id: bool,
+
+    /// Key under which handlers wrap their response body.
+    ///
+    /// With `response_envelope = "data"`, single responses are sent as
+    /// `{ "data": {...} }` and lists as `{ "data": [...] }` through
+    /// generated envelope structs, which the OpenAPI schemas reference.
+    pub response_envelope: Option<String>,
+
+    /// Static headers sent by the read handlers (get, list).
+    ///
+    /// Parsed from `base_response_headers = ["Cache-Control: max-age=60"]`
+    /// as `(name, value)` with the name lowercased. Only safe methods get
+    /// them, so caching headers never apply to writes.
+    pub base_response_headers: Vec<(String, String)>,
+
+    /// Static headers sent by the write handlers (create, update, delete).
+    ///
+    /// Same syntax as `base_response_headers`, e.g.
+    /// `["Cache-Control: no-store"]`.
+    pub write_response_headers: Vec<(String, String)>,
+
+    /// Specification extensions added to every operation.
+    ///
+    /// Parsed from `extensions(owner = "billing-team")` as
+    /// `("x-owner", "billing-team")`; underscores in the key become
+    /// hyphens.
+    pub extensions: Vec<(String, String)>,
+
     /// CRUD handlers configuration.
     ///
     /// Controls which handlers to generate:
@@ -307,6 +626,26 @@ impl ApiConfig {
         self.tag.clone().unwrap_or_else(|| entity_name.to_string())
     }
 
+    /// Get the tag for read operations (get, list).
+    ///
+    /// Uses `read_tag`, then falls back to
+    /// [`tag_or_default`](Self::tag_or_default).
+    pub fn read_tag_or_default(&self, entity_name: &str) -> String {
+        self.read_tag
+            .clone()
+            .unwrap_or_else(|| self.tag_or_default(entity_name))
+    }
+
+    /// Get the tag for write operations (create, update, delete, commands).
+    ///
+    /// Uses `write_tag`, then falls back to
+    /// [`tag_or_default`](Self::tag_or_default).
+    pub fn write_tag_or_default(&self, entity_name: &str) -> String {
+        self.write_tag
+            .clone()
+            .unwrap_or_else(|| self.tag_or_default(entity_name))
+    }
+
     /// Get the full path prefix including version.
     ///
     /// Combines `path_prefix` and `version` if both are set.
@@ -321,6 +660,42 @@ impl ApiConfig {
         }
     }
 
+    /// Get the page size cap for list endpoints.
+    pub fn max_limit_or_default(&self) -> i64 {
+        self.max_limit.unwrap_or(DEFAULT_MAX_LIMIT)
+    }
+
+    /// Get the rate limit window in seconds.
+    pub fn rate_limit_window_or_default(&self) -> u64 {
+        self.rate_limit_window.unwrap_or(DEFAULT_RATE_LIMIT_WINDOW)
+    }
+
+    /// Get the resource segment for an entity in URL paths.
+    ///
+    /// Kebab-case entity name, pluralized according to `path_style`:
+    /// `UserProfile` becomes `user-profiles` or `user-profile`.
+    pub fn resource_segment(&self, entity_name: &str) -> String {
+        let segment = entity_name.to_case(Case::Kebab);
+        match self.path_style {
+            PathStyle::Plural => format!("{}s", segment),
+            PathStyle::Singular => segment
+        }
+    }
+
+    /// Get the OpenAPI `operationId` of a CRUD operation.
+    ///
+    /// `{operation_id_prefix}{action}_{entity}`, with the entity in
+    /// snake_case: `("create", "UserProfile")` becomes
+    /// `create_user_profile`.
+    pub fn operation_id(&self, action: &str, entity_name: &str) -> String {
+        format!(
+            "{}{}_{}",
+            self.operation_id_prefix.as_deref().unwrap_or_default(),
+            action,
+            entity_name.to_case(Case::Snake)
+        )
+    }
+
     /// Check if a command is public (no auth required).
     ///
     /// # Arguments
@@ -331,8 +706,17 @@ impl ApiConfig {
     }
 
     /// Check if API is marked as deprecated.
+    ///
+    /// Either `deprecated_in` or `deprecated_sunset` marks it.
     pub fn is_deprecated(&self) -> bool {
-        self.deprecated_in.is_some()
+        self.deprecated_in.is_some() || self.deprecated_sunset.is_some()
+    }
+
+    /// `Sunset` header value for `deprecated_sunset`, as an HTTP-date.
+    ///
+    /// The API is announced to sunset at the end of the configured day.
+    pub fn sunset_http_date(&self) -> Option<String> {
+        self.deprecated_sunset.as_deref().and_then(http_date)
     }
 
     /// Check if any CRUD handler should be generated.
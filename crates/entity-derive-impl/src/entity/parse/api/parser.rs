@@ -77,11 +77,31 @@
 //! |--------|--------|------|
 //! | `tag` | `tag = "..."` | String |
 //! | `tag_description` | `tag_description = "..."` | String |
+//! | `external_docs` | `external_docs = "https://..."` | String |
+//! | `external_docs_description` | `external_docs_description = "..."` | String |
+//! | `read_tag` | `read_tag = "..."` | String |
+//! | `write_tag` | `write_tag = "..."` | String |
 //! | `path_prefix` | `path_prefix = "..."` | String |
+//! | `path_style` | `path_style = "plural" \| "singular"` | String |
+//! | `operation_id_prefix` | `operation_id_prefix = "billing_"` | String |
 //! | `security` | `security = "..."` | String |
 //! | `public` | `public = [A, B]` | List of Idents |
 //! | `version` | `version = "..."` | String |
 //! | `deprecated_in` | `deprecated_in = "..."` | String |
+//! | `deprecated_sunset` | `deprecated_sunset = "2026-12-31"` | `YYYY-MM-DD` date |
+//! | `etag` | `etag = "field"` | String |
+//! | `max_limit` | `max_limit = 500` | Positive integer |
+//! | `rate_limit` | `rate_limit(create = 10, list = 100)` | Operation = positive integer |
+//! | `rate_limit_window` | `rate_limit_window = 60` | Positive integer (seconds) |
+//! | `list_query` | `list_query` | Flag |
+//! | `pagination` | `pagination = "offset" \| "cursor"` | String |
+//! | `content_type` | `content_type = "application/x-www-form-urlencoded"` | String |
+//! | `multipart_field` | `multipart_field = "file"` | String |
+//! | `request_id` | `request_id` | Flag |
+//! | `response_envelope` | `response_envelope = "data"` | String |
+//! | `base_response_headers` | `base_response_headers = ["Name: value"]` | List of Strings |
+//! | `write_response_headers` | `write_response_headers = ["Name: value"]` | List of Strings |
+//! | `extensions` | `extensions(owner = "billing-team")` | Key = String |
 //! | `handlers` | `handlers` / `handlers(...)` / `handlers = bool` | Flag/List/Bool |
 //! | `title` | `title = "..."` | String |
 //! | `description` | `description = "..."` | String |
@@ -94,7 +114,9 @@
 
 use syn::Ident;
 
-use super::config::{ApiConfig, HandlerConfig};
+use super::config::{
+    ApiConfig, ContentType, HandlerConfig, PaginationStyle, PathStyle, http_date, static_header
+};
 
 /// Parses the `#[entity(api(...))]` attribute into an [`ApiConfig`].
 ///
@@ -183,10 +205,38 @@ pub fn parse_api_config(meta: &syn::Meta) -> syn::Result<ApiConfig> {
                 let value: syn::LitStr = nested.value()?.parse()?;
                 config.tag_description = Some(value.value());
             }
+            "external_docs" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.external_docs = Some(value.value());
+            }
+            "external_docs_description" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.external_docs_description = Some(value.value());
+            }
+            "read_tag" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.read_tag = Some(value.value());
+            }
+            "write_tag" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.write_tag = Some(value.value());
+            }
             "path_prefix" => {
                 let value: syn::LitStr = nested.value()?.parse()?;
                 config.path_prefix = Some(value.value());
             }
+            "path_style" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.path_style = PathStyle::from_str(&value.value()).ok_or_else(|| {
+                    syn::Error::new(
+                        value.span(),
+                        format!(
+                            "unknown path_style '{}', expected: plural, singular",
+                            value.value()
+                        )
+                    )
+                })?;
+            }
             "security" => {
                 let value: syn::LitStr = nested.value()?.parse()?;
                 config.security = Some(value.value());
@@ -201,6 +251,10 @@ pub fn parse_api_config(meta: &syn::Meta) -> syn::Result<ApiConfig> {
                     )?;
                 config.public_commands = commands.into_iter().collect();
             }
+            "operation_id_prefix" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.operation_id_prefix = Some(value.value());
+            }
             "version" => {
                 let value: syn::LitStr = nested.value()?.parse()?;
                 config.version = Some(value.value());
@@ -209,6 +263,103 @@ pub fn parse_api_config(meta: &syn::Meta) -> syn::Result<ApiConfig> {
                 let value: syn::LitStr = nested.value()?.parse()?;
                 config.deprecated_in = Some(value.value());
             }
+            "deprecated_sunset" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                if http_date(&value.value()).is_none() {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        format!(
+                            "invalid deprecated_sunset '{}', expected a YYYY-MM-DD date",
+                            value.value()
+                        )
+                    ));
+                }
+                config.deprecated_sunset = Some(value.value());
+            }
+            "etag" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.etag = Some(value.value());
+            }
+            "max_limit" => {
+                let value: syn::LitInt = nested.value()?.parse()?;
+                let max_limit: i64 = value.base10_parse()?;
+                if max_limit < 1 {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        "max_limit must be at least 1"
+                    ));
+                }
+                config.max_limit = Some(max_limit);
+            }
+            "rate_limit" => {
+                config.rate_limits = parse_rate_limits(&nested)?;
+            }
+            "extensions" => {
+                config.extensions = parse_extensions(&nested)?;
+            }
+            "rate_limit_window" => {
+                let value: syn::LitInt = nested.value()?.parse()?;
+                let window: u64 = value.base10_parse()?;
+                if window < 1 {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        "rate_limit_window must be at least 1"
+                    ));
+                }
+                config.rate_limit_window = Some(window);
+            }
+            "content_type" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.content_type = ContentType::from_str(&value.value()).ok_or_else(|| {
+                    syn::Error::new(
+                        value.span(),
+                        format!(
+                            "unsupported content_type '{}', expected: application/json, \
+                             application/x-www-form-urlencoded",
+                            value.value()
+                        )
+                    )
+                })?;
+            }
+            "multipart_field" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.multipart_field = Some(value.value());
+            }
+            "list_query" => {
+                config.list_query = true;
+            }
+            "pagination" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                config.pagination =
+                    PaginationStyle::from_str(&value.value()).ok_or_else(|| {
+                        syn::Error::new(
+                            value.span(),
+                            format!(
+                                "unknown pagination '{}', expected: offset, cursor",
+                                value.value()
+                            )
+                        )
+                    })?;
+            }
+            "request_id" => {
+                config.request_id = true;
+            }
+            "response_envelope" => {
+                let value: syn::LitStr = nested.value()?.parse()?;
+                if value.value().is_empty() {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        "response_envelope key must not be empty"
+                    ));
+                }
+                config.response_envelope = Some(value.value());
+            }
+            "base_response_headers" => {
+                config.base_response_headers = parse_static_headers(&nested)?;
+            }
+            "write_response_headers" => {
+                config.write_response_headers = parse_static_headers(&nested)?;
+            }
             "handlers" => {
                 if nested.input.peek(syn::Token![=]) {
                     let _: syn::Token![=] = nested.input.parse()?;
@@ -230,12 +381,13 @@ pub fn parse_api_config(meta: &syn::Meta) -> syn::Result<ApiConfig> {
                             "update" => config.handlers.update = true,
                             "delete" => config.handlers.delete = true,
                             "list" => config.handlers.list = true,
+                            "list_stream" => config.handlers.list_stream = true,
                             other => {
                                 return Err(syn::Error::new(
                                     handler.span(),
                                     format!(
                                         "unknown handler '{}', expected: create, get, update, \
-                                         delete, list",
+                                         delete, list, list_stream",
                                         other
                                     )
                                 ));
@@ -282,8 +434,13 @@ pub fn parse_api_config(meta: &syn::Meta) -> syn::Result<ApiConfig> {
                 return Err(syn::Error::new(
                     ident.span(),
                     format!(
-                        "unknown api option '{}', expected: tag, tag_description, path_prefix, \
-                         security, public, version, deprecated_in, handlers, title, description, \
+                        "unknown api option '{}', expected: tag, tag_description, \
+                         external_docs, external_docs_description, read_tag, write_tag, \
+                         path_prefix, path_style, operation_id_prefix, security, public, \
+                         version, deprecated_in, deprecated_sunset, etag, max_limit, \
+                         rate_limit, rate_limit_window, list_query, pagination, content_type, \
+                         multipart_field, request_id, response_envelope, base_response_headers, \
+                         write_response_headers, extensions, handlers, title, description, \
                          api_version, license, license_url, contact_name, contact_email, \
                          contact_url",
                         ident_str
@@ -295,9 +452,97 @@ pub fn parse_api_config(meta: &syn::Meta) -> syn::Result<ApiConfig> {
         Ok(())
     })?;
 
+    if config.external_docs_description.is_some() && config.external_docs.is_none() {
+        return Err(syn::Error::new_spanned(
+            meta,
+            "external_docs_description requires external_docs = \"<url>\""
+        ));
+    }
+
     Ok(config)
 }
 
+/// Parse `= ["Name: value", ...]` into lowercased header pairs.
+fn parse_static_headers(
+    nested: &syn::meta::ParseNestedMeta<'_>
+) -> syn::Result<Vec<(String, String)>> {
+    let _: syn::Token![=] = nested.input.parse()?;
+    let content;
+    syn::bracketed!(content in nested.input);
+    let declarations =
+        syn::punctuated::Punctuated::<syn::LitStr, syn::Token![,]>::parse_terminated(&content)?;
+    declarations
+        .iter()
+        .map(|lit| {
+            static_header(&lit.value()).ok_or_else(|| {
+                syn::Error::new(
+                    lit.span(),
+                    "expected \"Name: value\" with a valid header name and visible ASCII value"
+                )
+            })
+        })
+        .collect()
+}
+
+/// Parse `rate_limit(operation = N, ...)` into `(operation, N)` pairs.
+///
+/// Operations are checked against the entity later, once its handlers and
+/// commands are known.
+fn parse_rate_limits(nested: &syn::meta::ParseNestedMeta<'_>) -> syn::Result<Vec<(String, u32)>> {
+    let mut limits: Vec<(String, u32)> = Vec::new();
+    nested.parse_nested_meta(|op| {
+        let ident = op
+            .path
+            .get_ident()
+            .ok_or_else(|| syn::Error::new_spanned(&op.path, "expected operation name"))?;
+        let value: syn::LitInt = op.value()?.parse()?;
+        let requests: u32 = value.base10_parse()?;
+        if requests < 1 {
+            return Err(syn::Error::new(
+                value.span(),
+                "rate_limit must allow at least 1 request"
+            ));
+        }
+        let operation = ident.to_string();
+        if limits.iter().any(|(name, _)| *name == operation) {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!("duplicate rate_limit for '{}'", operation)
+            ));
+        }
+        limits.push((operation, requests));
+        Ok(())
+    })?;
+    Ok(limits)
+}
+
+/// Parse `extensions(key = "value", ...)` into `("x-key", value)` pairs.
+///
+/// Underscores in the key become hyphens, so `team_name` is written as
+/// `x-team-name`.
+fn parse_extensions(
+    nested: &syn::meta::ParseNestedMeta<'_>
+) -> syn::Result<Vec<(String, String)>> {
+    let mut extensions: Vec<(String, String)> = Vec::new();
+    nested.parse_nested_meta(|ext| {
+        let ident = ext
+            .path
+            .get_ident()
+            .ok_or_else(|| syn::Error::new_spanned(&ext.path, "expected extension name"))?;
+        let value: syn::LitStr = ext.value()?.parse()?;
+        let key = format!("x-{}", ident.to_string().replace('_', "-"));
+        if extensions.iter().any(|(name, _)| *name == key) {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!("duplicate extension '{}'", key)
+            ));
+        }
+        extensions.push((key, value.value()));
+        Ok(())
+    })?;
+    Ok(extensions)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -337,6 +582,22 @@ mod tests {
         assert_eq!(config.tag_description, Some("User management".to_string()));
     }
 
+    #[test]
+    fn parse_read_write_tags() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users", read_tag = "Users: Read", write_tag = "Users: Write")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.read_tag_or_default("User"), "Users: Read");
+        assert_eq!(config.write_tag_or_default("User"), "Users: Write");
+    }
+
+    #[test]
+    fn read_write_tags_fall_back_to_tag() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.read_tag_or_default("User"), "Users");
+        assert_eq!(config.write_tag_or_default("User"), "Users");
+    }
+
     #[test]
     fn parse_path_prefix() {
         let attr: syn::Attribute = syn::parse_quote!(#[api(path_prefix = "/api/v1")]);
@@ -414,6 +675,21 @@ mod tests {
         assert!(config.handlers.list);
     }
 
+    #[test]
+    fn parse_handlers_list_stream() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(handlers(list, list_stream))]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert!(config.handlers.list);
+        assert!(config.handlers.list_stream);
+        assert!(config.handlers.any());
+    }
+
+    #[test]
+    fn parse_handlers_flag_excludes_list_stream() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(handlers)]);
+        assert!(!parse_api_config(&attr.meta).unwrap().handlers.list_stream);
+    }
+
     #[test]
     fn parse_handlers_invalid() {
         let attr: syn::Attribute = syn::parse_quote!(#[api(handlers(invalid))]);
@@ -482,6 +758,303 @@ mod tests {
         assert_eq!(config.contact_url, Some("https://example.com".to_string()));
     }
 
+    #[test]
+    fn parse_etag() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(etag = "updated_at")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.etag, Some("updated_at".to_string()));
+    }
+
+    #[test]
+    fn parse_operation_id_prefix() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(operation_id_prefix = "billing_")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.operation_id_prefix, Some("billing_".to_string()));
+        assert_eq!(
+            config.operation_id("create", "InvoiceLine"),
+            "billing_create_invoice_line"
+        );
+
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.operation_id("list", "User"), "list_user");
+    }
+
+    #[test]
+    fn parse_list_query() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users", list_query)]);
+        assert!(parse_api_config(&attr.meta).unwrap().list_query);
+
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users")]);
+        assert!(!parse_api_config(&attr.meta).unwrap().list_query);
+    }
+
+    #[test]
+    fn parse_request_id() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users", request_id)]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert!(config.request_id);
+
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users")]);
+        assert!(!parse_api_config(&attr.meta).unwrap().request_id);
+    }
+
+    #[test]
+    fn parse_response_envelope() {
+        let attr: syn::Attribute =
+            syn::parse_quote!(#[api(tag = "Users", response_envelope = "data")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.response_envelope.as_deref(), Some("data"));
+
+        let attr: syn::Attribute = syn::parse_quote!(#[api(response_envelope = "")]);
+        assert!(parse_api_config(&attr.meta).is_err());
+    }
+
+    #[test]
+    fn parse_external_docs() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(
+            tag = "Users",
+            external_docs = "https://wiki.example.com/users",
+            external_docs_description = "Users wiki"
+        )]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(
+            config.external_docs.as_deref(),
+            Some("https://wiki.example.com/users")
+        );
+        assert_eq!(
+            config.external_docs_description.as_deref(),
+            Some("Users wiki")
+        );
+
+        let attr: syn::Attribute =
+            syn::parse_quote!(#[api(tag = "Users", external_docs_description = "Users wiki")]);
+        let result = parse_api_config(&attr.meta);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("requires external_docs")
+        );
+    }
+
+    #[test]
+    fn parse_static_response_headers() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(
+            tag = "Users",
+            base_response_headers = ["Cache-Control: max-age=60", "Vary: Accept"],
+            write_response_headers = ["Cache-Control: no-store"]
+        )]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(
+            config.base_response_headers,
+            [
+                ("cache-control".to_string(), "max-age=60".to_string()),
+                ("vary".to_string(), "Accept".to_string())
+            ]
+        );
+        assert_eq!(
+            config.write_response_headers,
+            [("cache-control".to_string(), "no-store".to_string())]
+        );
+
+        for invalid in ["no colon", ": empty name", "Bad Name: x", "X-Nl: a\nb"] {
+            let attr: syn::Attribute =
+                syn::parse_quote!(#[api(base_response_headers = [#invalid])]);
+            assert!(parse_api_config(&attr.meta).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn parse_max_limit() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(max_limit = 250)]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.max_limit_or_default(), 250);
+    }
+
+    #[test]
+    fn parse_rate_limit() {
+        let attr: syn::Attribute =
+            syn::parse_quote!(#[api(rate_limit(create = 10, list = 100), rate_limit_window = 30)]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(
+            config.rate_limits,
+            vec![("create".to_string(), 10), ("list".to_string(), 100)]
+        );
+        assert_eq!(config.rate_limit_window_or_default(), 30);
+    }
+
+    #[test]
+    fn parse_rate_limit_defaults() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert!(config.rate_limits.is_empty());
+        assert_eq!(config.rate_limit_window_or_default(), 60);
+    }
+
+    #[test]
+    fn parse_rate_limit_rejects_zero_and_duplicates() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(rate_limit(create = 0))]);
+        let err = parse_api_config(&attr.meta).unwrap_err();
+        assert!(err.to_string().contains("at least 1 request"));
+
+        let attr: syn::Attribute = syn::parse_quote!(#[api(rate_limit(get = 5, get = 6))]);
+        let err = parse_api_config(&attr.meta).unwrap_err();
+        assert!(err.to_string().contains("duplicate rate_limit for 'get'"));
+
+        let attr: syn::Attribute = syn::parse_quote!(#[api(rate_limit_window = 0)]);
+        assert!(parse_api_config(&attr.meta).is_err());
+    }
+
+    #[test]
+    fn parse_extensions() {
+        let attr: syn::Attribute =
+            syn::parse_quote!(#[api(extensions(owner = "billing-team", team_name = "Billing"))]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(
+            config.extensions,
+            vec![
+                ("x-owner".to_string(), "billing-team".to_string()),
+                ("x-team-name".to_string(), "Billing".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_extensions_rejects_duplicates_and_non_strings() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(extensions(owner = "a", owner = "b"))]);
+        let err = parse_api_config(&attr.meta).unwrap_err();
+        assert!(err.to_string().contains("duplicate extension 'x-owner'"));
+
+        let attr: syn::Attribute = syn::parse_quote!(#[api(extensions(owner = 1))]);
+        assert!(parse_api_config(&attr.meta).is_err());
+    }
+
+    #[test]
+    fn parse_max_limit_zero_rejected() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(max_limit = 0)]);
+        let result = parse_api_config(&attr.meta);
+        assert!(result.unwrap_err().to_string().contains("at least 1"));
+    }
+
+    #[test]
+    fn parse_pagination_cursor() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(pagination = "cursor")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.pagination, PaginationStyle::Cursor);
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.pagination, PaginationStyle::Offset);
+    }
+
+    #[test]
+    fn parse_pagination_invalid() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(pagination = "page")]);
+        let result = parse_api_config(&attr.meta);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("unknown pagination 'page'")
+        );
+    }
+
+    #[test]
+    fn parse_content_type_form() {
+        let attr: syn::Attribute =
+            syn::parse_quote!(#[api(content_type = "application/x-www-form-urlencoded")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.content_type, ContentType::Form);
+        assert_eq!(
+            config.content_type.media_type(),
+            "application/x-www-form-urlencoded"
+        );
+    }
+
+    #[test]
+    fn parse_content_type_default_json() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(tag = "Users")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.content_type, ContentType::Json);
+    }
+
+    #[test]
+    fn parse_multipart_field() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(multipart_field = "file")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.multipart_field.as_deref(), Some("file"));
+        assert_eq!(config.content_type, ContentType::Json);
+    }
+
+    #[test]
+    fn parse_content_type_unsupported() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(content_type = "multipart/form-data")]);
+        let result = parse_api_config(&attr.meta);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("unsupported content_type")
+        );
+    }
+
+    #[test]
+    fn parse_deprecated_sunset() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(deprecated_sunset = "2026-12-31")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert!(config.is_deprecated());
+        assert_eq!(
+            config.sunset_http_date().as_deref(),
+            Some("Thu, 31 Dec 2026 23:59:59 GMT")
+        );
+    }
+
+    #[test]
+    fn parse_deprecated_sunset_invalid() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(deprecated_sunset = "2026-02-30")]);
+        let result = parse_api_config(&attr.meta);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("invalid deprecated_sunset")
+        );
+    }
+
+    #[test]
+    fn http_date_weekdays() {
+        assert_eq!(
+            http_date("1970-01-01").as_deref(),
+            Some("Thu, 01 Jan 1970 23:59:59 GMT")
+        );
+        assert_eq!(
+            http_date("2024-02-29").as_deref(),
+            Some("Thu, 29 Feb 2024 23:59:59 GMT")
+        );
+        assert!(http_date("2023-02-29").is_none());
+        assert!(http_date("2026-1-31").is_none());
+    }
+
+    #[test]
+    fn parse_path_style() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(path_style = "singular")]);
+        let config = parse_api_config(&attr.meta).unwrap();
+        assert_eq!(config.path_style, PathStyle::Singular);
+        assert_eq!(config.resource_segment("UserProfile"), "user-profile");
+    }
+
+    #[test]
+    fn parse_path_style_invalid() {
+        let attr: syn::Attribute = syn::parse_quote!(#[api(path_style = "dual")]);
+        let result = parse_api_config(&attr.meta);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("unknown path_style")
+        );
+    }
+
     #[test]
     fn parse_unknown_option() {
         let attr: syn::Attribute = syn::parse_quote!(#[api(unknown_option = "value")]);
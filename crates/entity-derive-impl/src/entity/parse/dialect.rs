@@ -20,6 +20,7 @@ use darling::FromMeta;
 /// | PostgreSQL | ACID SQL | Row-level security, SSL, audit | Transactions |
 /// | ClickHouse | OLAP | Multi-DC replication | Analytics |
 /// | MongoDB | Document | E2E encryption, LDAP, RBAC | Documents |
+/// | SQLite | Embedded SQL | File permissions | Tests, local tools |
 ///
 /// # Examples
 ///
@@ -27,6 +28,7 @@ use darling::FromMeta;
 /// #[entity(table = "users", dialect = "postgres")]
 /// #[entity(table = "events", dialect = "clickhouse")]
 /// #[entity(collection = "users", dialect = "mongodb")]
+/// #[entity(table = "users", dialect = "postgres", alt_dialect = "sqlite")]
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DatabaseDialect {
@@ -50,7 +52,14 @@ pub enum DatabaseDialect {
     /// - Document-based (BSON)
     /// - Client: `mongodb::Client`
     /// - Features: E2E encryption, sharding, LDAP
-    MongoDB
+    MongoDB,
+
+    /// SQLite - embedded SQL database.
+    ///
+    /// - Placeholders: `?1, ?2, ?3, ...`
+    /// - Client: `sqlx::SqlitePool`
+    /// - Features: RETURNING (3.35+), no schemas
+    Sqlite
 }
 
 impl DatabaseDialect {
@@ -59,7 +68,8 @@ impl DatabaseDialect {
     pub fn placeholder(&self, index: usize) -> String {
         match self {
             Self::Postgres | Self::ClickHouse => format!("${index}"),
-            Self::MongoDB => format!("${index}") // For aggregation pipelines
+            Self::MongoDB => format!("${index}"), // For aggregation pipelines
+            Self::Sqlite => format!("?{index}")
         }
     }
 
@@ -67,32 +77,39 @@ impl DatabaseDialect {
     #[must_use]
     pub fn placeholders(&self, count: usize) -> String {
         (1..=count)
-            .map(|i| format!("${i}"))
+            .map(|i| self.placeholder(i))
             .collect::<Vec<_>>()
             .join(", ")
     }
 
-    /// Generate SET clause for UPDATE statement.
+    /// Get the feature flag name for this dialect.
     #[must_use]
-    pub fn set_clause(&self, fields: &[&str]) -> String {
+    pub fn feature_flag(&self) -> &'static str {
         match self {
-            Self::Postgres | Self::ClickHouse => fields
-                .iter()
-                .enumerate()
-                .map(|(i, f)| format!("{} = ${}", f, i + 1))
-                .collect::<Vec<_>>()
-                .join(", "),
-            Self::MongoDB => fields.join(", ") // MongoDB uses $set operator
+            Self::Postgres => "postgres",
+            Self::ClickHouse => "clickhouse",
+            Self::MongoDB => "mongodb",
+            Self::Sqlite => "sqlite"
         }
     }
 
-    /// Get the feature flag name for this dialect.
+    /// SQL expression for the current timestamp.
     #[must_use]
-    pub fn feature_flag(&self) -> &'static str {
+    pub fn current_timestamp(&self) -> &'static str {
         match self {
-            Self::Postgres => "postgres",
-            Self::ClickHouse => "clickhouse",
-            Self::MongoDB => "mongodb"
+            Self::Sqlite => "CURRENT_TIMESTAMP",
+            Self::Postgres | Self::ClickHouse | Self::MongoDB => "NOW()"
+        }
+    }
+
+    /// Case-insensitive `LIKE` operator.
+    ///
+    /// SQLite's `LIKE` already ignores ASCII case.
+    #[must_use]
+    pub fn ilike(&self) -> &'static str {
+        match self {
+            Self::Sqlite => "LIKE",
+            Self::Postgres | Self::ClickHouse | Self::MongoDB => "ILIKE"
         }
     }
 }
@@ -103,6 +120,7 @@ impl FromMeta for DatabaseDialect {
             "postgres" | "postgresql" | "pg" => Ok(Self::Postgres),
             "clickhouse" | "ch" => Ok(Self::ClickHouse),
             "mongodb" | "mongo" => Ok(Self::MongoDB),
+            "sqlite" | "sqlite3" => Ok(Self::Sqlite),
             _ => Err(darling::Error::unknown_value(value))
         }
     }
@@ -136,24 +154,10 @@ mod tests {
     }
 
     #[test]
-    fn set_clause_postgres() {
-        let d = DatabaseDialect::Postgres;
-        let fields = ["name", "email"];
-        assert_eq!(d.set_clause(&fields), "name = $1, email = $2");
-    }
-
-    #[test]
-    fn set_clause_clickhouse() {
-        let d = DatabaseDialect::ClickHouse;
-        let fields = ["name", "email"];
-        assert_eq!(d.set_clause(&fields), "name = $1, email = $2");
-    }
-
-    #[test]
-    fn set_clause_mongodb() {
-        let d = DatabaseDialect::MongoDB;
-        let fields = ["name", "email"];
-        assert_eq!(d.set_clause(&fields), "name, email");
+    fn sqlite_placeholders() {
+        let d = DatabaseDialect::Sqlite;
+        assert_eq!(d.placeholder(1), "?1");
+        assert_eq!(d.placeholders(3), "?1, ?2, ?3");
     }
 
     #[test]
@@ -161,6 +165,18 @@ mod tests {
         assert_eq!(DatabaseDialect::Postgres.feature_flag(), "postgres");
         assert_eq!(DatabaseDialect::ClickHouse.feature_flag(), "clickhouse");
         assert_eq!(DatabaseDialect::MongoDB.feature_flag(), "mongodb");
+        assert_eq!(DatabaseDialect::Sqlite.feature_flag(), "sqlite");
+    }
+
+    #[test]
+    fn sqlite_syntax() {
+        assert_eq!(
+            DatabaseDialect::Sqlite.current_timestamp(),
+            "CURRENT_TIMESTAMP"
+        );
+        assert_eq!(DatabaseDialect::Postgres.current_timestamp(), "NOW()");
+        assert_eq!(DatabaseDialect::Sqlite.ilike(), "LIKE");
+        assert_eq!(DatabaseDialect::Postgres.ilike(), "ILIKE");
     }
 
     #[test]
@@ -203,10 +219,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_meta_sqlite() {
+        assert_eq!(
+            DatabaseDialect::from_string("sqlite").unwrap(),
+            DatabaseDialect::Sqlite
+        );
+        assert_eq!(
+            DatabaseDialect::from_string("SQLite3").unwrap(),
+            DatabaseDialect::Sqlite
+        );
+    }
+
     #[test]
     fn from_meta_invalid() {
         assert!(DatabaseDialect::from_string("mysql").is_err());
-        assert!(DatabaseDialect::from_string("sqlite").is_err());
         assert!(DatabaseDialect::from_string("oracle").is_err());
     }
 
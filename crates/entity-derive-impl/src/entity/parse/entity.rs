@@ -52,6 +52,7 @@
 //! | `attrs.rs` | `EntityAttrs` darling parsing struct |
 //! | `helpers.rs` | Helper functions for parsing relations and API |
 //! | `projection.rs` | Projection definition and parsing |
+//! | `derives.rs` | Extra derives for generated types |
 //! | `tests.rs` | Comprehensive unit tests |
 //!
 //! # Entity Attributes
@@ -74,6 +75,7 @@
 //! | `uuid` | `V7` | UUID version for IDs |
 //! | `error` | `sqlx::Error` | Custom error type |
 //! | `returning` | `Full` | RETURNING clause mode |
+//! | `derive(Target: ...)` | — | Extra derives for a generated type |
 //!
 //! ## Feature Flags
 //!
@@ -141,12 +143,14 @@ mod accessors;
 mod attrs;
 mod constructor;
 mod def;
+mod derives;
 mod helpers;
 mod index;
 mod projection;
 
 pub use attrs::EntityAttrs;
 pub use def::EntityDef;
+pub use derives::{DeriveDef, DeriveTarget, parse_derive_attrs};
 pub use index::CompositeIndexDef;
 pub use projection::{ProjectionDef, parse_projection_attrs};
 
@@ -45,6 +45,7 @@
 //! | `all_fields()` | All fields | `Row`, `Insertable` |
 //! | `relation_fields()` | `#[belongs_to]` fields | Relation methods |
 //! | `filter_fields()` | `#[filter]` fields | Query struct |
+//! | `encrypted_fields()` | `#[column(encrypt)]` fields | Encryption helpers |
 //!
 //! # Naming Methods
 //!
@@ -59,8 +60,11 @@ use proc_macro2::Span;
 use syn::Ident;
 
 use super::{
-    super::{api::ApiConfig, command::CommandDef, field::FieldDef},
-    EntityDef
+    super::{
+        api::ApiConfig, command::CommandDef, dialect::DatabaseDialect, field::FieldDef,
+        isolation_level::IsolationLevel, returning::ReturningMode
+    },
+    DeriveTarget, EntityDef
 };
 
 impl EntityDef {
@@ -92,7 +96,12 @@ impl EntityDef {
     /// - NOT marked with `#[id]` (can't update primary key)
     /// - NOT marked with `#[auto]` (timestamps auto-update)
     /// - NOT marked with `#[field(skip)]`
+    ///
+    /// Always empty for `#[entity(immutable)]` entities.
     pub fn update_fields(&self) -> Vec<&FieldDef> {
+        if self.immutable {
+            return Vec::new();
+        }
         self.fields
             .iter()
             .filter(|f| f.in_update() && !f.is_id() && !f.is_auto())
@@ -103,8 +112,8 @@ impl EntityDef {
     ///
     /// Returns fields where:
     /// - `#[field(response)]` is present, OR
-    /// - `#[id]` is present (IDs always in response)
-    /// - NOT marked with `#[field(skip)]`
+    /// - `#[id]` is present
+    /// - NOT marked with `#[field(skip)]` or `#[field(skip_response)]`
     pub fn response_fields(&self) -> Vec<&FieldDef> {
         self.fields.iter().filter(|f| f.in_response()).collect()
     }
@@ -133,11 +142,30 @@ impl EntityDef {
         self.fields.iter().filter(|f| f.has_filter()).collect()
     }
 
+    /// Get fields with `#[sortable]` attribute.
+    ///
+    /// Used to generate the OrderBy enum and `list_ordered` method.
+    pub fn sortable_fields(&self) -> Vec<&FieldDef> {
+        self.fields.iter().filter(|f| f.is_sortable()).collect()
+    }
+
+    /// Get fields with `#[column(encrypt)]`.
+    ///
+    /// Used to generate encrypt/decrypt helpers and the encrypted repository.
+    pub fn encrypted_fields(&self) -> Vec<&FieldDef> {
+        self.fields.iter().filter(|f| f.is_encrypted()).collect()
+    }
+
     /// Check if this entity has any filterable fields.
     pub fn has_filters(&self) -> bool {
         self.fields.iter().any(|f| f.has_filter())
     }
 
+    /// Check if this entity has any sortable fields.
+    pub fn has_sortable(&self) -> bool {
+        self.fields.iter().any(|f| f.is_sortable())
+    }
+
     /// Get has-many relations defined via `#[has_many(Entity)]`.
     ///
     /// Returns entity identifiers for one-to-many relationships.
@@ -208,6 +236,11 @@ impl EntityDef {
         self.commands
     }
 
+    /// Check if commands should be hashable by kind and target id.
+    pub fn has_command_dedup(&self) -> bool {
+        self.command_dedup
+    }
+
     /// Get command definitions.
     pub fn command_defs(&self) -> &[CommandDef] {
         &self.command_defs
@@ -223,11 +256,154 @@ impl EntityDef {
         self.streams
     }
 
+    /// Check if cache-aside repository methods should be generated.
+    pub fn has_cache(&self) -> bool {
+        self.cache
+    }
+
+    /// Check if `find_by_ids_map` should be generated.
+    pub fn has_ids_map(&self) -> bool {
+        self.ids_map
+    }
+
+    /// Check if `create` returns only the id (`returning = "minimal"`).
+    pub fn create_returns_id(&self) -> bool {
+        self.returning == ReturningMode::Minimal
+    }
+
+    /// Check if `Eq`/`Ord` by id should be generated.
+    pub fn has_ord_by_id(&self) -> bool {
+        self.ord_by_id
+    }
+
+    /// Check if `diff` should be generated.
+    pub fn is_diffable(&self) -> bool {
+        self.diffable
+    }
+
+    /// Check if `delete` cascades to soft-deletable `has_many` children.
+    pub fn has_cascade_soft_delete(&self) -> bool {
+        self.cascade_soft_delete
+    }
+
+    /// Check if uniqueness only applies to non-deleted rows.
+    pub fn is_unique_among_active(&self) -> bool {
+        self.unique_among_active
+    }
+
+    /// Check if `MIGRATION_UP` creates the schema before the table.
+    pub fn creates_schema(&self) -> bool {
+        self.schema_create
+    }
+
+    /// Check if the table is a temporary (test-only) table.
+    pub fn is_temporary(&self) -> bool {
+        self.temporary
+    }
+
+    /// Get the conflict key fields of `bulk_upsert`.
+    ///
+    /// Empty if `#[entity(upsert = "...")]` is not set.
+    pub fn upsert_key(&self) -> &[String] {
+        &self.upsert
+    }
+
+    /// Get the `ORDER BY` terms of `list`, `list_with_deleted` and `query`.
+    ///
+    /// From `#[entity(order_by = "...")]`, otherwise the id column
+    /// descending. A custom order that does not list the id column ends
+    /// with `id DESC` as a unique tiebreaker, so offset pages neither repeat
+    /// nor skip rows with equal sort keys.
+    pub fn list_order(&self) -> String {
+        let id_column = self.id_field().column_name();
+        let tiebreaker = format!("{} DESC", id_column);
+        if self.order_by.is_empty() {
+            return tiebreaker;
+        }
+        let has_id = self
+            .order_by
+            .iter()
+            .any(|term| term.split_whitespace().next() == Some(id_column.as_str()));
+        if has_id {
+            self.order_by.join(", ")
+        } else {
+            format!("{}, {}", self.order_by.join(", "), tiebreaker)
+        }
+    }
+
+    /// Get the ordering fields of `list_after`, most significant first.
+    ///
+    /// Empty if `#[entity(cursor = "...")]` is not set.
+    pub fn cursor_fields(&self) -> Vec<&FieldDef> {
+        self.cursor
+            .iter()
+            .filter_map(|name| self.fields.iter().find(|f| f.name_str() == *name))
+            .collect()
+    }
+
+    /// Get the `#[column(tsvector)]` field backing `search`, if any.
+    pub fn search_field(&self) -> Option<&FieldDef> {
+        self.fields.iter().find(|f| f.is_tsvector())
+    }
+
+    /// Check if request DTOs deny unknown fields.
+    pub fn has_strict_requests(&self) -> bool {
+        self.strict_requests
+    }
+
+    /// Check if DTOs derive `arbitrary::Arbitrary`.
+    pub fn has_arbitrary(&self) -> bool {
+        self.arbitrary
+    }
+
+    /// Check if DTOs derive `async_graphql` object types.
+    pub fn has_graphql(&self) -> bool {
+        self.graphql
+    }
+
+    /// Get the slow query threshold in milliseconds.
+    ///
+    /// `None` unless `#[entity(log_slow_queries = N)]` is set.
+    pub fn slow_query_threshold(&self) -> Option<u64> {
+        self.log_slow_queries
+    }
+
+    /// Check if code is generated for `dialect`, as `dialect` or
+    /// `alt_dialect`.
+    pub fn has_dialect(&self, dialect: DatabaseDialect) -> bool {
+        self.dialect == dialect || self.alt_dialect == Some(dialect)
+    }
+
+    /// Get the prefix of generated string IDs, if any.
+    pub fn id_prefix(&self) -> Option<&str> {
+        self.id_prefix.as_deref()
+    }
+
+    /// Check if `{Entity}Repo` should be generated.
+    pub fn has_repo_struct(&self) -> bool {
+        self.repo_struct
+    }
+
+    /// Check if the Row implements `sqlx::FromRow` by hand.
+    ///
+    /// True with `#[entity(manual_from_row)]` or any
+    /// `#[column(decode_with = "...")]` field.
+    pub fn has_manual_from_row(&self) -> bool {
+        self.manual_from_row || self.fields.iter().any(|f| f.decode_with().is_some())
+    }
+
     /// Check if transaction support should be generated.
     pub fn has_transactions(&self) -> bool {
         self.transactions
     }
 
+    /// Get the isolation level transactions with this entity require.
+    ///
+    /// `None` unless `#[entity(isolation = "...")]` is set.
+    pub fn isolation_level(&self) -> Option<IsolationLevel> {
+        self.isolation
+    }
+
     /// Check if API generation is enabled.
     #[allow(dead_code)]
     pub fn has_api(&self) -> bool {
@@ -240,9 +416,32 @@ impl EntityDef {
         &self.api_config
     }
 
+    /// Get extra derives requested for a generated type.
+    ///
+    /// Collects paths from every `derive(Target: ...)` entry matching
+    /// `target`, in declaration order.
+    pub fn derives_for(&self, target: DeriveTarget) -> Vec<&syn::Path> {
+        self.derives
+            .iter()
+            .filter(|d| d.target == target)
+            .flat_map(|d| d.paths.iter())
+            .collect()
+    }
+
+    /// Get the result column name a Row field is decoded from.
+    ///
+    /// Returns `{row_prefix}{field}` when a row prefix is set, otherwise the
+    /// plain field name.
+    #[must_use]
+    pub fn row_column(&self, field: &FieldDef) -> String {
+        match &self.row_prefix {
+            Some(prefix) => format!("{}{}", prefix, field.name_str()),
+            None => field.name_str()
+        }
+    }
+
     /// Get the documentation comment if present.
     #[must_use]
-    #[allow(dead_code)]
     pub fn doc(&self) -> Option<&str> {
         self.doc.as_deref()
     }
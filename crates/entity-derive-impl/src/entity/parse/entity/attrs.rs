@@ -13,22 +13,46 @@
 //! |-----------|----------|---------|-------------|
 //! | `table` | Yes | — | Database table name |
 //! | `schema` | No | `"public"` | Database schema |
+//! | `schema_create` | No | `false` | Create a non-`public` schema in `MIGRATION_UP` |
 //! | `sql` | No | `Full` | SQL generation level |
 //! | `dialect` | No | `Postgres` | Database dialect |
+//! | `alt_dialect` | No | — | Second dialect with its own feature-gated impl |
 //! | `uuid` | No | `V7` | UUID version for IDs |
+//! | `id_prefix` | No | — | Prefixed `String` ids such as `usr_0190...` |
 //! | `error` | No | `sqlx::Error` | Custom error type |
 //! | `soft_delete` | No | `false` | Enable soft delete |
 //! | `returning` | No | `Full` | RETURNING clause mode |
 //! | `events` | No | `false` | Generate lifecycle events |
 //! | `hooks` | No | `false` | Generate lifecycle hooks trait |
 //! | `commands` | No | `false` | Generate CQRS command pattern |
+//! | `command_dedup` | No | `false` | Hash commands by kind and target id |
 //! | `policy` | No | `false` | Generate authorization policy trait |
 //! | `streams` | No | `false` | Enable real-time streaming via LISTEN/NOTIFY |
+//! | `ids_map` | No | `false` | Generate `find_by_ids_map` keyed by id |
+//! | `immutable` | No | `false` | Skip all update generation |
+//! | `repo_struct` | No | `false` | Generate a cloneable `{Entity}Repo` owning the pool |
+//! | `manual_from_row` | No | `false` | Implement `sqlx::FromRow` for the Row with explicit `try_get` calls |
+//! | `ord_by_id` | No | `false` | Order and compare entities by `#[id]` |
+//! | `diffable` | No | `false` | Generate `diff` listing changed fields |
+//! | `cascade_soft_delete` | No | `false` | Soft-delete soft-deletable `has_many` children with the parent |
+//! | `unique_among_active` | No | `false` | With `soft_delete`, unique columns are unique among non-deleted rows only |
+//! | `temporary` | No | `false` | Create the table as `TEMPORARY` (tests only) |
+//! | `upsert` | No | — | Conflict key columns for `bulk_upsert` |
+//! | `cursor` | No | — | Ordering columns of the keyset-paginated `list_after` |
+//! | `order_by` | No | `id DESC` | `ORDER BY` of `list` and `query`, ending with an id tiebreaker |
+//! | `strict_requests` | No | `false` | Reject unknown fields in Create/Update DTOs |
+//! | `arbitrary` | No | `false` | Derive `arbitrary::Arbitrary` on DTOs |
+//! | `graphql` | No | `false` | Derive `async_graphql` object types on DTOs |
+//! | `double_option_updates` | No | `false` | `Option<Option<T>>` for nullable Update DTO fields |
+//! | `log_slow_queries` | No | — | Warn through `tracing` on repository calls slower than N ms |
+//! | `isolation` | No | — | Isolation level the `with_*()` transaction builder method requires |
 
 use darling::FromDeriveInput;
 use syn::{Ident, Visibility};
 
-use crate::entity::parse::{DatabaseDialect, ReturningMode, SqlLevel, UuidVersion};
+use crate::entity::parse::{
+    DatabaseDialect, IsolationLevel, ReturningMode, SqlLevel, UuidVersion
+};
 
 /// Returns the default schema name.
 ///
@@ -85,6 +109,20 @@ pub struct EntityAttrs {
     #[darling(default = "default_schema")]
     pub schema: String,
 
+    /// Start `MIGRATION_UP` with `CREATE SCHEMA IF NOT EXISTS`.
+    ///
+    /// Requires `migrations`. Ignored for `"public"`, which every database
+    /// already has.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", schema = "core", schema_create, migrations)]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub schema_create: bool,
+
     /// SQL generation level.
     ///
     /// Defaults to [`SqlLevel::Full`] if not specified.
@@ -97,16 +135,50 @@ pub struct EntityAttrs {
     #[darling(default)]
     pub dialect: DatabaseDialect,
 
+    /// Second dialect to generate a repository implementation for.
+    ///
+    /// Only `"sqlite"` next to `dialect = "postgres"` is supported. Each
+    /// implementation is gated by its dialect's feature, so one definition
+    /// serves PostgreSQL in production and SQLite in tests:
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", dialect = "postgres", alt_dialect = "sqlite")]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub alt_dialect: Option<DatabaseDialect>,
+
     /// UUID version for ID generation.
     ///
     /// Defaults to [`UuidVersion::V7`] if not specified.
     #[darling(default)]
     pub uuid: UuidVersion,
 
+    /// Prefix of generated string IDs.
+    ///
+    /// The `#[id]` field must be a `String`. New entities get the prefix
+    /// followed by a UUID of the `uuid` version in simple (hex) form, and
+    /// the column is `TEXT`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", uuid = "v7", id_prefix = "usr_")]
+    /// pub struct User {
+    ///     #[id]
+    ///     pub id: String, // "usr_0190b5c8e4a27c3f9d1e6a0b2c4d6e8f"
+    /// }
+    /// ```
+    #[darling(default)]
+    pub id_prefix: Option<String>,
+
     /// Custom error type for repository implementation.
     ///
     /// Defaults to `sqlx::Error` if not specified.
-    /// The custom type must implement `From<sqlx::Error>`.
+    /// The custom type must implement `From<sqlx::Error>`. Implement
+    /// `entity_core::error::FromSqlxError` and invoke
+    /// `impl_from_sqlx_error!(AppError)` to have that conversion classify
+    /// not-found, unique and foreign key errors for you.
     ///
     /// # Examples
     ///
@@ -139,9 +211,10 @@ pub struct EntityAttrs {
     /// RETURNING clause mode for INSERT/UPDATE operations.
     ///
     /// Controls what data is fetched back from the database:
-    /// - `full` (default): Use `RETURNING *` to get all fields
+    /// - `full` (default): `RETURNING` every column to get all fields
     /// - `id`: Use `RETURNING id` to get only the primary key
     /// - `none`: No RETURNING clause, return pre-built entity
+    /// - `minimal`: No RETURNING clause, `create` returns only the id
     ///
     /// # Example
     ///
@@ -205,6 +278,24 @@ pub struct EntityAttrs {
     #[darling(default)]
     pub commands: bool,
 
+    /// Key commands by kind and target id for deduplication.
+    ///
+    /// Requires `commands`. Generates `{Entity}Command::dedup_key()` and a
+    /// `Hash` impl over the same key, so an outbox can drop a command that
+    /// repeats one it already holds. Payload fields are not part of the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", commands, command_dedup)]
+    /// #[command(Deactivate, requires_id)]
+    /// pub struct User { ... }
+    ///
+    /// let (kind, id) = cmd.dedup_key();
+    /// ```
+    #[darling(default)]
+    pub command_dedup: bool,
+
     /// Generate authorization policy trait.
     ///
     /// When enabled, generates:
@@ -247,11 +338,26 @@ pub struct EntityAttrs {
     #[darling(default)]
     pub transactions: bool,
 
+    /// Isolation level for transactions that use this entity.
+    ///
+    /// The generated `with_{entities}()` builder method raises the
+    /// transaction to at least this level. Requires `transactions`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "accounts", transactions, isolation = "serializable")]
+    /// pub struct Account { ... }
+    /// ```
+    #[darling(default)]
+    pub isolation: Option<IsolationLevel>,
+
     /// Enable migration generation.
     ///
     /// When enabled, generates:
     /// - `{Entity}::MIGRATION_UP` — SQL to create the table
     /// - `{Entity}::MIGRATION_DOWN` — SQL to drop the table
+    /// - `{Entity}::SCHEMA_VERSION` — hash of the column definitions
     ///
     /// # Example
     ///
@@ -269,5 +375,330 @@ pub struct EntityAttrs {
     /// // User::MIGRATION_DOWN → DROP TABLE core.users CASCADE
     /// ```
     #[darling(default)]
-    pub migrations: bool
+    pub migrations: bool,
+
+    /// Column prefix for the generated Row struct.
+    ///
+    /// When set, every Row field maps to a `{prefix}{column}` result column,
+    /// so the Row can be decoded from joined queries with aliased columns.
+    /// Generated repository queries alias their columns accordingly.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", row_prefix = "u_")]
+    /// pub struct User { ... }
+    ///
+    /// // UserRow decodes: SELECT u.id AS u_id, u.name AS u_name ...
+    /// ```
+    #[darling(default)]
+    pub row_prefix: Option<String>,
+
+    /// Implement `sqlx::FromRow` for the Row by hand instead of deriving it.
+    ///
+    /// The generated impl reads every field with `row.try_get(column)`, so
+    /// a field can opt into custom decoding with
+    /// `#[column(decode_with = "...")]`. Any such field enables this too.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "accounts", manual_from_row)]
+    /// pub struct Account { ... }
+    ///
+    /// // impl<'r> sqlx::FromRow<'r, PgRow> for AccountRow {
+    /// //     fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+    /// //         Ok(Self { id: sqlx::Row::try_get(row, "id")?, ... })
+    /// //     }
+    /// // }
+    /// ```
+    #[darling(default)]
+    pub manual_from_row: bool,
+
+    /// Audit log table name.
+    ///
+    /// When set, generates `{Entity}AuditRepository` whose mutations run in
+    /// a transaction together with an insert into this table.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", audit = "audit_logs")]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub audit: Option<String>,
+
+    /// Generate cache-aside repository methods.
+    ///
+    /// When enabled, the repository trait gains `find_by_id_cached`,
+    /// `update_cached` and `delete_cached`, which read through and
+    /// invalidate an `entity_core::cache::EntityCache`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", cache)]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub cache: bool,
+
+    /// Generate the `find_by_ids_map` batch lookup.
+    ///
+    /// The repository trait gains `find_by_ids_map(ids)`, returning a
+    /// `HashMap` keyed by id. Opt-in because the id type must then
+    /// implement `Hash`, `Eq` and `Clone`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", ids_map)]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub ids_map: bool,
+
+    /// Forbid updates to this entity.
+    ///
+    /// When enabled, no `Update{Entity}Request`, `update` method or PATCH
+    /// handler is generated, even for `#[field(update)]` fields. Create,
+    /// read and delete are unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "audit_events", immutable)]
+    /// pub struct AuditEvent { ... }
+    /// ```
+    #[darling(default)]
+    pub immutable: bool,
+
+    /// Compare and order entities by their `#[id]` field.
+    ///
+    /// Generates `PartialEq`, `Eq`, `PartialOrd` and `Ord` that look only at
+    /// the id. With UUID v7 ids this sorts entities by creation time.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", ord_by_id)]
+    /// pub struct User { ... }
+    ///
+    /// users.sort(); // oldest first
+    /// ```
+    #[darling(default)]
+    pub ord_by_id: bool,
+
+    /// Generate `{Entity}Repo`, a cloneable struct owning the pool.
+    ///
+    /// Requires `sql = "full"` with PostgreSQL or SQLite. The struct
+    /// implements the repository trait by forwarding to the pool's impl.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", repo_struct)]
+    /// pub struct User { ... }
+    ///
+    /// let repo = UserRepo::new(pool);
+    /// let user = repo.find_by_id(id).await?;
+    /// ```
+    #[darling(default)]
+    pub repo_struct: bool,
+
+    /// Generate `diff(&self, other: &Self)` for change logs.
+    ///
+    /// Returns `(field, old, new)` for each field whose `Debug`
+    /// representation differs, so fields need `Debug` but not `PartialEq`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", diffable)]
+    /// pub struct User { ... }
+    ///
+    /// let changes = before.diff(&after);
+    /// ```
+    #[darling(default)]
+    pub diffable: bool,
+
+    /// Soft-delete `has_many` children together with the parent.
+    ///
+    /// Requires `soft_delete`. The generated `delete` marks the parent and,
+    /// in the same transaction, every not-yet-deleted row of each
+    /// `#[has_many]` child that is itself `soft_delete`. Other children are
+    /// left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", soft_delete, cascade_soft_delete)]
+    /// #[has_many(Post)]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub cascade_soft_delete: bool,
+
+    /// Enforce `#[column(unique)]` among non-deleted rows only.
+    ///
+    /// Requires `soft_delete`. The migration replaces each column's
+    /// `UNIQUE` constraint with a partial unique index
+    /// `WHERE deleted_at IS NULL`, and unique composite indexes get the
+    /// same predicate, so a value frees up once its row is soft-deleted.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", migrations, soft_delete, unique_among_active)]
+    /// pub struct User {
+    ///     #[column(unique)]
+    ///     pub email: String,
+    ///     ...
+    /// }
+    /// ```
+    #[darling(default)]
+    pub unique_among_active: bool,
+
+    /// Create the table as a per-connection temporary table.
+    ///
+    /// Intended for tests only. Requires `migrations`: `MIGRATION_UP` emits
+    /// `CREATE TEMPORARY TABLE` without `IF NOT EXISTS`, so a stale table
+    /// left on a pooled connection fails loudly instead of being reused.
+    /// Temporary tables live in `pg_temp`, which becomes the entity's
+    /// schema, so `schema` cannot be set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", migrations, temporary)]
+    /// pub struct User { ... }
+    ///
+    /// sqlx::query(User::MIGRATION_UP).execute(&mut *conn).await?;
+    /// ```
+    #[darling(default)]
+    pub temporary: bool,
+
+    /// Conflict key for the generated `bulk_upsert`.
+    ///
+    /// A comma-separated list of fields backed by a unique constraint or
+    /// index. `bulk_upsert` inserts all DTOs in one transaction and, for rows
+    /// whose key already exists, overwrites the other create fields. The key
+    /// fields must be `Hash + Eq`. PostgreSQL only.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", upsert = "email")]
+    /// #[entity(table = "memberships", upsert = "org_id, user_id")]
+    /// ```
+    #[darling(default)]
+    pub upsert: Option<String>,
+
+    /// Ordering columns for the generated `list_after`.
+    ///
+    /// A comma-separated list of fields that together identify a row, most
+    /// significant first and usually ending with the id. Generates an
+    /// `{Entity}Cursor` holding those fields and a keyset-paginated
+    /// `list_after(cursor, limit)` ordered ascending by them. Each field
+    /// must be non-nullable and listed once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "posts", cursor = "created_at, id")]
+    /// ```
+    #[darling(default)]
+    pub cursor: Option<String>,
+
+    /// Sort order of `list`, `list_with_deleted` and `query`.
+    ///
+    /// A comma-separated list of `field [ASC|DESC]` terms; the direction
+    /// defaults to `ASC`. Without it, lists are ordered by the id column
+    /// descending. Unless the id column is listed, `id DESC` is appended as
+    /// a tiebreaker to keep offset pages stable.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", order_by = "name ASC, created_at DESC")]
+    /// ```
+    #[darling(default)]
+    pub order_by: Option<String>,
+
+    /// Reject unknown fields when deserializing request DTOs.
+    ///
+    /// Adds `#[serde(deny_unknown_fields)]` to `Create{Entity}Request` and
+    /// `Update{Entity}Request`, so a misspelled or unexpected key fails
+    /// deserialization (a `400`/`422` from the extractor) instead of being
+    /// ignored. The Response DTO is unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", strict_requests)]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub strict_requests: bool,
+
+    /// Derive `arbitrary::Arbitrary` on the DTOs.
+    ///
+    /// For fuzzing and property tests. Every DTO field type must implement
+    /// `Arbitrary`, so enable the `arbitrary` feature of `uuid`, `chrono`
+    /// and similar crates.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", arbitrary)]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub arbitrary: bool,
+
+    /// Derive `async_graphql` object types on the DTOs.
+    ///
+    /// Create/Update get `InputObject` and Response gets `SimpleObject`.
+    /// Needs the `uuid` and `chrono` features of `async-graphql`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", graphql)]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub graphql: bool,
+
+    /// Tell an absent key from an explicit `null` in Update DTOs.
+    ///
+    /// Nullable update fields become `Option<Option<T>>`: `None` leaves the
+    /// column unchanged and `Some(None)` sets it to `NULL`. Fields with
+    /// `update_type`, `update_with`, `write_once` or encryption keep their
+    /// usual type.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", double_option_updates)]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub double_option_updates: bool,
+
+    /// Slow query threshold in milliseconds.
+    ///
+    /// Generated repository methods time each call and emit
+    /// `tracing::warn!` with the entity, operation and elapsed time when it
+    /// takes this long or longer. Requires `tracing` as a dependency.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[entity(table = "users", log_slow_queries = 100)]
+    /// pub struct User { ... }
+    /// ```
+    #[darling(default)]
+    pub log_slow_queries: Option<u64>
 }
@@ -27,6 +27,8 @@
 //! │      │                                                              │
 //! │      ├─► parse_api_attr() ──► ApiConfig                             │
 //! │      │                                                              │
+//! │      ├─► parse_derive_attrs() ──► Vec<DeriveDef>                    │
+//! │      │                                                              │
 //! │      ├─► extract_doc_comments() ──► Option<String>                  │
 //! │      │                                                              │
 //! │      └─► Find #[id] field index ──► usize                           │
@@ -46,6 +48,43 @@
 //! | Must be struct | "Entity can only be derived for structs" |
 //! | Must have named fields | "Entity requires named fields" |
 //! | Must have `#[id]` field | "Entity must have exactly one field with #[id]" |
+//! | `soft_delete` needs `deleted_at` | "soft_delete requires a `deleted_at: Option<DateTime<Utc>>` field" |
+//! | `deleted_at` must be nullable | "soft_delete column `deleted_at` must be `Option<...>`" |
+//! | `cascade_soft_delete` needs `soft_delete` | "cascade_soft_delete requires soft_delete" |
+//! | `unique_among_active` needs `soft_delete` | "unique_among_active requires soft_delete" |
+//! | `repo_struct` needs a generated impl | "repo_struct requires sql = \"full\"" |
+//! | `repo_struct` needs an sqlx pool | "repo_struct requires dialect = \"postgres\" or \"sqlite\"" |
+//! | `schema_create` needs `migrations` | "schema_create requires migrations" |
+//! | `temporary` needs `migrations` | "temporary requires migrations" |
+//! | `isolation` needs `transactions` | "isolation requires transactions" |
+//! | `command_dedup` needs `commands` | "command_dedup requires commands" |
+//! | `id_prefix` is not empty | "id_prefix must not be empty" |
+//! | `id_prefix` needs a `String` id | "id_prefix requires a `String` #[id] field" |
+//! | `temporary` forbids `schema` | "temporary tables live in pg_temp; remove schema" |
+//! | `#[field(flatten)]` needs `sql = "trait"` | "#[field(flatten)] requires sql = \"trait\"" |
+//! | `#[field(returning)]` is read-only | "#[field(returning)] is computed by the database; remove create/update/filter" |
+//! | `#[field(returning)]` needs Postgres | "#[field(returning)] requires dialect = \"postgres\"" |
+//! | `tsvector` is read-only | "#[column(tsvector)] is generated by the database; remove create/update/filter" |
+//! | `tsvector` needs sources | "#[column(tsvector)] requires sources = [...]" |
+//! | `tsvector` sources exist | "tsvector source `x` is not a stored field" |
+//! | One `tsvector` per entity | "only one #[column(tsvector)] field is supported" |
+//! | `tsvector` needs Postgres | "#[column(tsvector)] requires dialect = \"postgres\"" |
+//! | `#[filter(overlaps)]` needs `PgRange<T>` | "#[filter(overlaps)] requires a PgRange<T> field" |
+//! | `#[filter(overlaps)]` needs Postgres | "#[filter(overlaps)] requires dialect = \"postgres\"" |
+//! | `#[filter(overlaps)]` is not a query string | "#[filter(overlaps)] is not supported with api(list_query)" |
+//! | `min_len` ≤ `max_len`, `min` ≤ `max` | "min_len is greater than max_len" |
+//! | `upsert` names create fields | "upsert key `x` is not a create field" |
+//! | `upsert` needs Postgres | "upsert requires dialect = \"postgres\"" |
+//! | `order_by` names fields | "order_by column `x` is not a field" |
+//! | `order_by` directions | "order_by direction `x` must be ASC or DESC" |
+//! | `cursor` names plain columns | "cursor column `x` is not a field", "cursor column `x` cannot be encrypted, transparent or flattened" |
+//! | `multipart_field` names a create field | "api(multipart_field = \"x\") does not name a create field" |
+//! | `multipart_field` holds bytes | "api(multipart_field) requires a `Vec<u8>` field" |
+//! | Cursor pagination lists unfiltered | "api(pagination = \"cursor\") is not supported with api(list_query)" |
+//! | `rate_limit` names generated operations | "api(rate_limit) names unknown operation 'x'" |
+//! | `alt_dialect` is SQLite next to Postgres | "alt_dialect = \"sqlite\" requires dialect = \"postgres\"" |
+//! | SQLite rejects Postgres-only features | "streams is not supported with sqlite" |
+//! | Projections skip nullable transparent fields | "projection `Public` cannot include nullable transparent field `email`" |
 //! | Required attributes | darling errors for missing `table` |
 //!
 //! # Error Handling
@@ -55,14 +94,20 @@
 //! - Span information for error messages
 //! - Integration with proc-macro-error for nice diagnostics
 
+use convert_case::{Case, Casing};
 use darling::FromDeriveInput;
 use syn::DeriveInput;
 
 use super::{
-    super::{command::parse_command_attrs, field::FieldDef},
-    EntityAttrs, EntityDef,
+    super::{
+        ApiConfig, DatabaseDialect, FilterType, PaginationStyle, SqlLevel,
+        command::{CommandDef, parse_command_attrs},
+        field::FieldDef
+    },
+    EntityAttrs, EntityDef, ProjectionDef,
+    attrs::default_schema,
     helpers::{parse_api_attr, parse_has_many_attrs, parse_index_attrs},
-    parse_projection_attrs
+    parse_derive_attrs, parse_projection_attrs
 };
 use crate::utils::docs::extract_doc_comments;
 
@@ -106,7 +151,7 @@ impl EntityDef {
     pub fn from_derive_input(input: &DeriveInput) -> darling::Result<Self> {
         let attrs = EntityAttrs::from_derive_input(input)?;
 
-        let fields: Vec<FieldDef> = match &input.data {
+        let mut fields: Vec<FieldDef> = match &input.data {
             syn::Data::Struct(data) => match &data.fields {
                 syn::Fields::Named(named) => named
                     .named
@@ -126,11 +171,21 @@ impl EntityDef {
             }
         };
 
+        if attrs.double_option_updates {
+            for field in fields.iter_mut().filter(|f| f.allows_double_option()) {
+                field.expose.double_option = true;
+            }
+        }
+
         let has_many = parse_has_many_attrs(&input.attrs);
         let projections = parse_projection_attrs(&input.attrs);
         let command_defs = parse_command_attrs(&input.attrs);
-        let api_config = parse_api_attr(&input.attrs);
+        let mut api_config = parse_api_attr(&input.attrs);
+        if attrs.immutable {
+            api_config.handlers.update = false;
+        }
         let indexes = parse_index_attrs(&input.attrs);
+        let derives = parse_derive_attrs(&input.attrs)?;
         let doc = extract_doc_comments(&input.attrs);
 
         let id_field_index = fields.iter().position(|f| f.is_id()).ok_or_else(|| {
@@ -138,14 +193,130 @@ impl EntityDef {
                 .with_span(&input.ident)
         })?;
 
+        if attrs.soft_delete {
+            validate_soft_delete(&fields, &input.ident)?;
+        }
+        if attrs.cascade_soft_delete && !attrs.soft_delete {
+            return Err(
+                darling::Error::custom("cascade_soft_delete requires soft_delete")
+                    .with_span(&input.ident)
+            );
+        }
+        if attrs.unique_among_active && !attrs.soft_delete {
+            return Err(
+                darling::Error::custom("unique_among_active requires soft_delete")
+                    .with_span(&input.ident)
+            );
+        }
+        if attrs.isolation.is_some() && !attrs.transactions {
+            return Err(
+                darling::Error::custom("isolation requires transactions").with_span(&input.ident)
+            );
+        }
+        if attrs.repo_struct {
+            if attrs.sql != SqlLevel::Full {
+                return Err(
+                    darling::Error::custom("repo_struct requires sql = \"full\"")
+                        .with_span(&input.ident)
+                );
+            }
+            if !matches!(
+                attrs.dialect,
+                DatabaseDialect::Postgres | DatabaseDialect::Sqlite
+            ) {
+                return Err(darling::Error::custom(
+                    "repo_struct requires dialect = \"postgres\" or \"sqlite\""
+                )
+                .with_span(&input.ident));
+            }
+        }
+        if attrs.schema_create && !attrs.migrations {
+            return Err(darling::Error::custom("schema_create requires migrations")
+                .with_span(&input.ident));
+        }
+        if attrs.command_dedup && !attrs.commands {
+            return Err(
+                darling::Error::custom("command_dedup requires commands").with_span(&input.ident)
+            );
+        }
+        if let Some(prefix) = &attrs.id_prefix {
+            validate_id_prefix(prefix, &fields[id_field_index], &input.ident)?;
+        }
+        if attrs.temporary {
+            if !attrs.migrations {
+                return Err(darling::Error::custom("temporary requires migrations")
+                    .with_span(&input.ident));
+            }
+            if attrs.schema != default_schema() {
+                return Err(darling::Error::custom(
+                    "temporary tables live in pg_temp; remove schema"
+                )
+                .with_span(&input.ident));
+            }
+        }
+        if attrs.sql != SqlLevel::Trait {
+            validate_no_flatten(&fields)?;
+        }
+        validate_computed(&fields, attrs.dialect)?;
+        validate_tsvector(&fields, attrs.dialect)?;
+        validate_overlaps(&fields, attrs.dialect, api_config.list_query)?;
+        validate_projections(&projections, &fields, &input.ident)?;
+        validate_bounds(&fields)?;
+        let upsert = match &attrs.upsert {
+            Some(key) => parse_upsert_key(key, &fields, attrs.dialect, &input.ident)?,
+            None => Vec::new()
+        };
+        let order_by = match &attrs.order_by {
+            Some(spec) => parse_order_by(spec, &fields, &input.ident)?,
+            None => Vec::new()
+        };
+        let cursor = match &attrs.cursor {
+            Some(columns) => parse_cursor_columns(columns, &fields, &input.ident)?,
+            None => Vec::new()
+        };
+        if let Some(alt) = attrs.alt_dialect {
+            validate_alt_dialect(attrs.dialect, alt, &input.ident)?;
+        }
+        if attrs.dialect == DatabaseDialect::Sqlite
+            || attrs.alt_dialect == Some(DatabaseDialect::Sqlite)
+        {
+            validate_sqlite(&attrs, &fields, &has_many, &input.ident)?;
+        }
+        if let Some(etag) = &api_config.etag {
+            validate_etag(&fields, etag, &input.ident)?;
+        }
+        if let Some(multipart) = &api_config.multipart_field {
+            validate_multipart_field(&fields, multipart, &input.ident)?;
+        }
+        validate_rate_limits(&api_config, &command_defs, &input.ident)?;
+        if api_config.list_query && !fields.iter().any(|f| f.has_filter()) {
+            return Err(darling::Error::custom(
+                "api(list_query) requires at least one #[filter] field"
+            )
+            .with_span(&input.ident));
+        }
+        if api_config.list_query && api_config.pagination == PaginationStyle::Cursor {
+            return Err(darling::Error::custom(
+                "api(pagination = \"cursor\") is not supported with api(list_query)"
+            )
+            .with_span(&input.ident));
+        }
+
         Ok(Self {
             ident: attrs.ident,
             vis: attrs.vis,
             table: attrs.table,
-            schema: attrs.schema,
+            schema: if attrs.temporary {
+                "pg_temp".to_string()
+            } else {
+                attrs.schema.clone()
+            },
+            schema_create: attrs.schema_create && attrs.schema != default_schema(),
             sql: attrs.sql,
             dialect: attrs.dialect,
+            alt_dialect: attrs.alt_dialect,
             uuid: attrs.uuid,
+            id_prefix: attrs.id_prefix,
             error: attrs.error,
             fields,
             id_field_index,
@@ -156,14 +327,515 @@ impl EntityDef {
             events: attrs.events,
             hooks: attrs.hooks,
             commands: attrs.commands,
+            command_dedup: attrs.command_dedup,
             command_defs,
             policy: attrs.policy,
             streams: attrs.streams,
             transactions: attrs.transactions,
+            isolation: attrs.isolation,
             api_config,
             doc,
             migrations: attrs.migrations,
-            indexes
+            indexes,
+            derives,
+            row_prefix: attrs.row_prefix,
+            audit: attrs.audit,
+            cache: attrs.cache,
+            ids_map: attrs.ids_map,
+            immutable: attrs.immutable,
+            ord_by_id: attrs.ord_by_id,
+            repo_struct: attrs.repo_struct,
+            manual_from_row: attrs.manual_from_row,
+            diffable: attrs.diffable,
+            cascade_soft_delete: attrs.cascade_soft_delete,
+            unique_among_active: attrs.unique_among_active,
+            temporary: attrs.temporary,
+            upsert,
+            cursor,
+            order_by,
+            strict_requests: attrs.strict_requests,
+            arbitrary: attrs.arbitrary,
+            graphql: attrs.graphql,
+            log_slow_queries: attrs.log_slow_queries
         })
     }
 }
+
+/// Reject `#[field(flatten)]` unless queries are hand-written.
+///
+/// The generated SQL lists the entity's own columns and cannot expand a
+/// value object's, and without a Row there is nothing to flatten into.
+fn validate_no_flatten(fields: &[FieldDef]) -> darling::Result<()> {
+    match fields.iter().find(|f| f.is_flattened()) {
+        Some(field) => Err(
+            darling::Error::custom("#[field(flatten)] requires sql = \"trait\"")
+                .with_span(field.name())
+        ),
+        None => Ok(())
+    }
+}
+
+/// Reject nullable transparent fields in projections.
+///
+/// Projections decode transparent newtypes with `#[sqlx(try_from)]`, which
+/// cannot convert inside an `Option`.
+fn validate_projections(
+    projections: &[ProjectionDef],
+    fields: &[FieldDef],
+    entity: &syn::Ident
+) -> darling::Result<()> {
+    for projection in projections {
+        let nullable = fields.iter().find(|f| {
+            projection.fields.contains(f.name())
+                && f.transparent_inner().is_some()
+                && f.is_option()
+        });
+        if let Some(field) = nullable {
+            return Err(darling::Error::custom(format!(
+                "projection `{}` cannot include nullable transparent field `{}`",
+                projection.name,
+                field.name()
+            ))
+            .with_span(entity));
+        }
+    }
+    Ok(())
+}
+
+/// Validate `#[field(returning = "...")]` computed columns.
+///
+/// The expression is not a stored column, so it cannot be written or used
+/// in a generated `WHERE`, and it is passed through as PostgreSQL SQL.
+fn validate_computed(fields: &[FieldDef], dialect: DatabaseDialect) -> darling::Result<()> {
+    let Some(field) = fields.iter().find(|f| f.is_computed()) else {
+        return Ok(());
+    };
+    if dialect != DatabaseDialect::Postgres {
+        return Err(
+            darling::Error::custom("#[field(returning)] requires dialect = \"postgres\"")
+                .with_span(field.name())
+        );
+    }
+    match fields
+        .iter()
+        .find(|f| f.is_computed() && (f.in_create() || f.in_update() || f.has_filter()))
+    {
+        Some(field) => Err(darling::Error::custom(
+            "#[field(returning)] is computed by the database; remove create/update/filter"
+        )
+        .with_span(field.name())),
+        None => Ok(())
+    }
+}
+
+/// Validate `#[column(tsvector, sources = [...])]` search columns.
+///
+/// The column is generated by PostgreSQL from stored source columns, so it
+/// cannot be written, and a single one per entity backs `search`.
+fn validate_tsvector(fields: &[FieldDef], dialect: DatabaseDialect) -> darling::Result<()> {
+    if let Some(field) = fields
+        .iter()
+        .find(|f| !f.is_tsvector() && !f.column().sources.is_empty())
+    {
+        return Err(
+            darling::Error::custom("sources requires #[column(tsvector)]").with_span(field.name())
+        );
+    }
+    let mut search = fields.iter().filter(|f| f.is_tsvector());
+    let Some(field) = search.next() else {
+        return Ok(());
+    };
+    if let Some(extra) = search.next() {
+        return Err(
+            darling::Error::custom("only one #[column(tsvector)] field is supported")
+                .with_span(extra.name())
+        );
+    }
+    if dialect != DatabaseDialect::Postgres {
+        return Err(
+            darling::Error::custom("#[column(tsvector)] requires dialect = \"postgres\"")
+                .with_span(field.name())
+        );
+    }
+    if field.in_create() || field.in_update() || field.has_filter() || field.is_computed() {
+        return Err(darling::Error::custom(
+            "#[column(tsvector)] is generated by the database; remove create/update/filter"
+        )
+        .with_span(field.name()));
+    }
+    if field.column().sources.is_empty() {
+        return Err(
+            darling::Error::custom("#[column(tsvector)] requires sources = [...]")
+                .with_span(field.name())
+        );
+    }
+    for source in &field.column().sources {
+        if !fields
+            .iter()
+            .any(|f| f.name_str() == *source && !f.is_db_generated())
+        {
+            return Err(darling::Error::custom(format!(
+                "tsvector source `{}` is not a stored field",
+                source
+            ))
+            .with_span(field.name()));
+        }
+    }
+    Ok(())
+}
+
+/// Validate `#[filter(overlaps)]` range filters.
+///
+/// The filter binds a `PgRange` for the `&&` operator, so it needs a range
+/// column on PostgreSQL. Its `(start, end)` pair has no query string form.
+fn validate_overlaps(
+    fields: &[FieldDef],
+    dialect: DatabaseDialect,
+    list_query: bool
+) -> darling::Result<()> {
+    let Some(field) = fields
+        .iter()
+        .find(|f| f.filter().filter_type == FilterType::Overlaps)
+    else {
+        return Ok(());
+    };
+    let message = if field.range_element().is_none() {
+        "#[filter(overlaps)] requires a PgRange<T> field"
+    } else if dialect != DatabaseDialect::Postgres {
+        "#[filter(overlaps)] requires dialect = \"postgres\""
+    } else if list_query {
+        "#[filter(overlaps)] is not supported with api(list_query)"
+    } else {
+        return Ok(());
+    };
+    Err(darling::Error::custom(message).with_span(field.name()))
+}
+
+/// Validate that lower bounds do not exceed upper bounds.
+fn validate_bounds(fields: &[FieldDef]) -> darling::Result<()> {
+    for field in fields {
+        if let (Some(min), Some(max)) = field.length_bounds()
+            && min > max
+        {
+            return Err(
+                darling::Error::custom("min_len is greater than max_len").with_span(field.name())
+            );
+        }
+        if let (Some(min), Some(max)) = field.range_bounds()
+            && min > max
+        {
+            return Err(darling::Error::custom("min is greater than max").with_span(field.name()));
+        }
+    }
+    Ok(())
+}
+
+/// Parse and validate the `upsert = "a, b"` conflict key.
+///
+/// Every key field must be written by `create`, otherwise the inserted row
+/// has nothing to conflict on. The multi-row `ON CONFLICT` statement is
+/// generated for PostgreSQL only.
+fn parse_upsert_key(
+    key: &str,
+    fields: &[FieldDef],
+    dialect: DatabaseDialect,
+    entity: &syn::Ident
+) -> darling::Result<Vec<String>> {
+    if dialect != DatabaseDialect::Postgres {
+        return Err(
+            darling::Error::custom("upsert requires dialect = \"postgres\"").with_span(entity)
+        );
+    }
+    let columns: Vec<String> = key.split(',').map(|c| c.trim().to_string()).collect();
+    for column in &columns {
+        if !fields
+            .iter()
+            .any(|f| f.name_str() == *column && f.in_create())
+        {
+            return Err(darling::Error::custom(format!(
+                "upsert key `{}` is not a create field",
+                column
+            ))
+            .with_span(entity));
+        }
+    }
+    Ok(columns)
+}
+
+/// Parse and validate the `order_by = "a ASC, b DESC"` list order.
+///
+/// Each term names a stored field, optionally followed by `ASC` or `DESC`
+/// in any case. Terms are normalized to `column DIRECTION`, using the
+/// field's column name.
+fn parse_order_by(
+    spec: &str,
+    fields: &[FieldDef],
+    entity: &syn::Ident
+) -> darling::Result<Vec<String>> {
+    let mut terms = Vec::new();
+    for term in spec.split(',') {
+        let mut parts = term.split_whitespace();
+        let name = parts.next().unwrap_or_default();
+        let Some(field) = fields
+            .iter()
+            .find(|f| f.name_str() == name && !f.is_flattened())
+        else {
+            return Err(darling::Error::custom(format!(
+                "order_by column `{}` is not a field",
+                name
+            ))
+            .with_span(entity));
+        };
+        let direction = match parts.next() {
+            None => "ASC".to_string(),
+            Some(dir) if dir.eq_ignore_ascii_case("asc") || dir.eq_ignore_ascii_case("desc") => {
+                dir.to_ascii_uppercase()
+            }
+            Some(dir) => {
+                return Err(darling::Error::custom(format!(
+                    "order_by direction `{}` must be ASC or DESC",
+                    dir
+                ))
+                .with_span(entity));
+            }
+        };
+        if let Some(extra) = parts.next() {
+            return Err(darling::Error::custom(format!(
+                "order_by direction `{}` must be ASC or DESC",
+                extra
+            ))
+            .with_span(entity));
+        }
+        terms.push(format!("{} {}", field.column_name(), direction));
+    }
+    Ok(terms)
+}
+
+/// Parse and validate the `cursor = "a, b"` ordering columns.
+///
+/// Cursor values are bound as-is and compared in SQL, so every column must
+/// be a plain stored field: encrypted values do not order meaningfully and
+/// transparent or flattened fields have no single bindable value. A row
+/// comparison against `NULL` is never true, so nullable columns would skip
+/// rows, and a repeated column adds nothing to the ordering.
+fn parse_cursor_columns(
+    columns: &str,
+    fields: &[FieldDef],
+    entity: &syn::Ident
+) -> darling::Result<Vec<String>> {
+    let columns: Vec<String> = columns.split(',').map(|c| c.trim().to_string()).collect();
+    for (i, column) in columns.iter().enumerate() {
+        let Some(field) = fields.iter().find(|f| f.name_str() == *column) else {
+            return Err(darling::Error::custom(format!(
+                "cursor column `{}` is not a field",
+                column
+            ))
+            .with_span(entity));
+        };
+        if field.is_encrypted() || field.transparent_inner().is_some() || field.is_flattened() {
+            return Err(darling::Error::custom(format!(
+                "cursor column `{}` cannot be encrypted, transparent or flattened",
+                column
+            ))
+            .with_span(entity));
+        }
+        if field.is_option() {
+            return Err(darling::Error::custom(format!(
+                "cursor column `{}` cannot be an Option",
+                column
+            ))
+            .with_span(field.name()));
+        }
+        if columns[..i].contains(column) {
+            return Err(darling::Error::custom(format!(
+                "cursor column `{}` is listed more than once",
+                column
+            ))
+            .with_span(field.name()));
+        }
+    }
+    Ok(columns)
+}
+
+/// Validate that `alt_dialect` pairs SQLite with PostgreSQL.
+fn validate_alt_dialect(
+    dialect: DatabaseDialect,
+    alt: DatabaseDialect,
+    entity: &syn::Ident
+) -> darling::Result<()> {
+    if alt == DatabaseDialect::Sqlite && dialect == DatabaseDialect::Postgres {
+        return Ok(());
+    }
+    Err(
+        darling::Error::custom("alt_dialect = \"sqlite\" requires dialect = \"postgres\"")
+            .with_span(entity)
+    )
+}
+
+/// Reject features whose generated SQL only exists for PostgreSQL.
+///
+/// LISTEN/NOTIFY streams, relation loaders and cascades, computed
+/// expressions, full-text search, range overlap filters and `bulk_upsert`
+/// are emitted for `sqlx::Postgres` only.
+fn validate_sqlite(
+    attrs: &EntityAttrs,
+    fields: &[FieldDef],
+    has_many: &[syn::Ident],
+    entity: &syn::Ident
+) -> darling::Result<()> {
+    let unsupported = if attrs.streams {
+        Some("streams")
+    } else if attrs.cascade_soft_delete {
+        Some("cascade_soft_delete")
+    } else if !has_many.is_empty() || fields.iter().any(|f| f.belongs_to().is_some()) {
+        Some("relations")
+    } else if fields.iter().any(|f| f.is_computed()) {
+        Some("#[field(returning)]")
+    } else if fields.iter().any(|f| f.is_tsvector()) {
+        Some("#[column(tsvector)]")
+    } else if fields
+        .iter()
+        .any(|f| f.filter().filter_type == FilterType::Overlaps)
+    {
+        Some("#[filter(overlaps)]")
+    } else if attrs.upsert.is_some() {
+        Some("upsert")
+    } else {
+        None
+    };
+    match unsupported {
+        Some(feature) => Err(darling::Error::custom(format!(
+            "{feature} is not supported with sqlite"
+        ))
+        .with_span(entity)),
+        None => Ok(())
+    }
+}
+
+/// Validate `id_prefix` against the `#[id]` field.
+///
+/// Generated ids are strings, so the field must be `String`.
+fn validate_id_prefix(prefix: &str, id: &FieldDef, entity: &syn::Ident) -> darling::Result<()> {
+    if prefix.is_empty() {
+        return Err(darling::Error::custom("id_prefix must not be empty").with_span(entity));
+    }
+    let is_string = matches!(
+        id.ty(),
+        syn::Type::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == "String")
+    );
+    if !is_string {
+        return Err(
+            darling::Error::custom("id_prefix requires a `String` #[id] field").with_span(id.ty())
+        );
+    }
+    Ok(())
+}
+
+/// Validate that `api(etag = "...")` names an integer or `DateTime` field.
+///
+/// Only those render as header-safe tags; strings may hold quotes and
+/// spaces, and `Option` has no stable textual form.
+fn validate_etag(fields: &[FieldDef], etag: &str, entity: &syn::Ident) -> darling::Result<()> {
+    let Some(field) = fields.iter().find(|f| f.name_str() == etag) else {
+        return Err(darling::Error::custom(format!(
+            "api(etag = \"{}\") does not name a field",
+            etag
+        ))
+        .with_span(entity));
+    };
+    let supported = matches!(
+        field.ty(),
+        syn::Type::Path(path) if path.path.segments.last().is_some_and(|s| matches!(
+            s.ident.to_string().as_str(),
+            "i16" | "i32" | "i64" | "u16" | "u32" | "u64" | "DateTime"
+        ))
+    );
+    if !supported {
+        return Err(
+            darling::Error::custom("api(etag) field must be an integer or `DateTime`")
+                .with_span(field.ty())
+        );
+    }
+    Ok(())
+}
+
+/// Validate that `api(multipart_field)` names a `Vec<u8>` create field.
+fn validate_multipart_field(
+    fields: &[FieldDef],
+    multipart: &str,
+    entity: &syn::Ident
+) -> darling::Result<()> {
+    let Some(field) = fields
+        .iter()
+        .find(|f| f.in_create() && f.name_str() == multipart)
+    else {
+        return Err(darling::Error::custom(format!(
+            "api(multipart_field = \"{}\") does not name a create field",
+            multipart
+        ))
+        .with_span(entity));
+    };
+    let ty = field.create_ty();
+    if quote::quote!(#ty).to_string().replace(' ', "") != "Vec<u8>" {
+        return Err(
+            darling::Error::custom("api(multipart_field) requires a `Vec<u8>` field")
+                .with_span(ty)
+        );
+    }
+    Ok(())
+}
+
+/// Validate that every `api(rate_limit(...))` key is a generated operation.
+///
+/// Valid keys are the enabled CRUD handlers and the snake_case command names.
+fn validate_rate_limits(
+    api_config: &ApiConfig,
+    commands: &[CommandDef],
+    entity: &syn::Ident
+) -> darling::Result<()> {
+    let handlers = api_config.handlers();
+    for (operation, _) in &api_config.rate_limits {
+        let known = match operation.as_str() {
+            "create" => handlers.create,
+            "get" => handlers.get,
+            "update" => handlers.update,
+            "delete" => handlers.delete,
+            "list" => handlers.list,
+            "list_stream" => handlers.list_stream,
+            other => commands
+                .iter()
+                .any(|cmd| cmd.name.to_string().to_case(Case::Snake) == other)
+        };
+        if !known {
+            return Err(darling::Error::custom(format!(
+                "api(rate_limit) names unknown operation '{}'; expected an enabled handler \
+                 or a command in snake_case",
+                operation
+            ))
+            .with_span(entity));
+        }
+    }
+    Ok(())
+}
+
+/// Validate that a `soft_delete` entity declares a nullable `deleted_at`.
+///
+/// Generated SQL filters with `deleted_at IS NULL` and sets
+/// `deleted_at = NOW()`, so the column must exist and be an `Option`.
+fn validate_soft_delete(fields: &[FieldDef], entity: &syn::Ident) -> darling::Result<()> {
+    let Some(field) = fields.iter().find(|f| f.name_str() == "deleted_at") else {
+        return Err(darling::Error::custom(
+            "soft_delete requires a `deleted_at: Option<DateTime<Utc>>` field"
+        )
+        .with_span(entity));
+    };
+
+    if !field.is_option() {
+        return Err(darling::Error::custom(
+            "soft_delete column `deleted_at` must be `Option<...>`, e.g. `Option<DateTime<Utc>>`"
+        )
+        .with_span(field.ty()));
+    }
+
+    Ok(())
+}
@@ -61,9 +61,10 @@ use syn::{Ident, Visibility};
 use super::{
     super::{
         api::ApiConfig, command::CommandDef, dialect::DatabaseDialect, field::FieldDef,
-        returning::ReturningMode, sql_level::SqlLevel, uuid_version::UuidVersion
+        isolation_level::IsolationLevel, returning::ReturningMode, sql_level::SqlLevel,
+        uuid_version::UuidVersion
     },
-    CompositeIndexDef, ProjectionDef
+    CompositeIndexDef, DeriveDef, ProjectionDef
 };
 
 /// Complete parsed entity definition.
@@ -112,15 +113,24 @@ pub struct EntityDef {
     /// Database schema name (e.g., `"public"`, `"core"`).
     pub schema: String,
 
+    /// Whether `MIGRATION_UP` creates the schema; never set for `public`.
+    pub schema_create: bool,
+
     /// SQL generation level controlling what code is generated.
     pub sql: SqlLevel,
 
     /// Database dialect for code generation.
     pub dialect: DatabaseDialect,
 
+    /// Second dialect with its own feature-gated repository implementation.
+    pub alt_dialect: Option<DatabaseDialect>,
+
     /// UUID version for ID generation.
     pub uuid: UuidVersion,
 
+    /// Prefix of generated string IDs from `#[entity(id_prefix = "...")]`.
+    pub id_prefix: Option<String>,
+
     /// Custom error type for repository implementation.
     ///
     /// Defaults to `sqlx::Error`. Custom types must implement
@@ -174,6 +184,9 @@ pub struct EntityDef {
     /// When `true`, processes `#[command(...)]` attributes.
     pub commands: bool,
 
+    /// Whether commands get `dedup_key` and `Hash`.
+    pub command_dedup: bool,
+
     /// Command definitions parsed from `#[command(...)]` attributes.
     ///
     /// Each entry describes a business command (e.g., Register, UpdateEmail).
@@ -195,6 +208,9 @@ pub struct EntityDef {
     /// methods.
     pub transactions: bool,
 
+    /// Isolation level required by the transaction builder method.
+    pub isolation: Option<IsolationLevel>,
+
     /// API configuration for HTTP handler generation.
     ///
     /// When enabled via `#[entity(api(...))]`, generates axum handlers
@@ -215,5 +231,87 @@ pub struct EntityDef {
     /// Composite index definitions from `#[entity(index(...))]`.
     ///
     /// Each entry defines an index spanning multiple columns.
-    pub indexes: Vec<CompositeIndexDef>
+    pub indexes: Vec<CompositeIndexDef>,
+
+    /// Extra derives from `#[entity(derive(Target: ...))]`.
+    ///
+    /// Appended to the derive list of the targeted generated type.
+    pub derives: Vec<DeriveDef>,
+
+    /// Column prefix from `#[entity(row_prefix = "...")]`.
+    ///
+    /// Applied to every column the Row struct decodes.
+    pub row_prefix: Option<String>,
+
+    /// Audit log table from `#[entity(audit = "...")]`.
+    ///
+    /// When set, audited create/update/delete wrappers are generated.
+    pub audit: Option<String>,
+
+    /// Whether to generate cache-aside repository methods.
+    ///
+    /// When `true`, the repository trait gains `*_cached` methods.
+    pub cache: bool,
+
+    /// Whether `find_by_ids_map` is generated.
+    pub ids_map: bool,
+
+    /// Whether updates are forbidden.
+    ///
+    /// When `true`, [`update_fields`](Self::update_fields) is always empty,
+    /// so no update DTO, method or handler is generated.
+    pub immutable: bool,
+
+    /// Whether equality and ordering are generated from the id.
+    pub ord_by_id: bool,
+
+    /// Whether `{Entity}Repo` is generated.
+    pub repo_struct: bool,
+
+    /// Whether `#[entity(manual_from_row)]` is set.
+    ///
+    /// Use [`has_manual_from_row`](Self::has_manual_from_row), which also
+    /// accounts for `decode_with` fields.
+    pub manual_from_row: bool,
+
+    /// Whether `diff` is generated.
+    pub diffable: bool,
+
+    /// Whether `delete` also soft-deletes soft-deletable `has_many`
+    /// children.
+    pub cascade_soft_delete: bool,
+
+    /// Whether unique columns are unique among non-deleted rows only.
+    pub unique_among_active: bool,
+
+    /// Whether the table is created as `TEMPORARY` in `pg_temp`.
+    pub temporary: bool,
+
+    /// Conflict key fields from `#[entity(upsert = "...")]`.
+    ///
+    /// Empty unless set; when set, `bulk_upsert` is generated.
+    pub upsert: Vec<String>,
+
+    /// Ordering fields from `#[entity(cursor = "...")]`.
+    ///
+    /// Empty unless set; when set, `{Entity}Cursor` and `list_after` are
+    /// generated.
+    pub cursor: Vec<String>,
+
+    /// `ORDER BY` terms from `#[entity(order_by = "...")]`.
+    ///
+    /// Normalized to `column ASC` or `column DESC`; empty unless set.
+    pub order_by: Vec<String>,
+
+    /// Whether request DTOs reject unknown fields.
+    pub strict_requests: bool,
+
+    /// Whether DTOs derive `arbitrary::Arbitrary`.
+    pub arbitrary: bool,
+
+    /// Whether DTOs derive `async_graphql` object types.
+    pub graphql: bool,
+
+    /// Slow query threshold in milliseconds from `log_slow_queries`.
+    pub log_slow_queries: Option<u64>
 }
@@ -0,0 +1,233 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Extra derive definitions for generated types.
+//!
+//! The macro controls the derive list of every generated struct. This module
+//! parses `#[entity(derive(Target: Trait, ...))]`, an escape hatch that
+//! appends user-chosen derives to a specific generated type.
+//!
+//! # Syntax
+//!
+//! ```rust,ignore
+//! #[entity(
+//!     table = "users",
+//!     derive(Response: PartialEq, schemars::JsonSchema),
+//!     derive(Row: Hash)
+//! )]
+//! pub struct User { ... }
+//! ```
+//!
+//! # Targets
+//!
+//! | Target | Generated Type |
+//! |--------|----------------|
+//! | `Create` / `CreateRequest` | `Create{Entity}Request` |
+//! | `Update` / `UpdateRequest` | `Update{Entity}Request` |
+//! | `Response` | `{Entity}Response` |
+//! | `Row` | `{Entity}Row` |
+//! | `Insertable` | `Insertable{Entity}` |
+//! | `Query` | `{Entity}Query` |
+
+use syn::{Attribute, Ident, Path};
+
+/// Generated type that receives extra derives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeriveTarget {
+    /// `Create{Entity}Request` DTO.
+    CreateRequest,
+
+    /// `Update{Entity}Request` DTO.
+    UpdateRequest,
+
+    /// `{Entity}Response` DTO.
+    Response,
+
+    /// `{Entity}Row` database row struct.
+    Row,
+
+    /// `Insertable{Entity}` struct.
+    Insertable,
+
+    /// `{Entity}Query` filter struct.
+    Query
+}
+
+impl DeriveTarget {
+    /// Resolve a target from its identifier in `derive(Target: ...)`.
+    ///
+    /// Returns `None` for unknown targets.
+    pub fn from_ident(ident: &Ident) -> Option<Self> {
+        match ident.to_string().as_str() {
+            "Create" | "CreateRequest" => Some(Self::CreateRequest),
+            "Update" | "UpdateRequest" => Some(Self::UpdateRequest),
+            "Response" => Some(Self::Response),
+            "Row" => Some(Self::Row),
+            "Insertable" => Some(Self::Insertable),
+            "Query" => Some(Self::Query),
+            _ => None
+        }
+    }
+}
+
+/// Extra derives for one generated type.
+///
+/// For `derive(Response: PartialEq, Eq)`:
+/// ```rust,ignore
+/// DeriveDef {
+///     target: DeriveTarget::Response,
+///     paths: vec![Path("PartialEq"), Path("Eq")]
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeriveDef {
+    /// Generated type receiving the derives.
+    pub target: DeriveTarget,
+
+    /// Derive macro paths, in declaration order.
+    pub paths: Vec<Path>
+}
+
+/// Parse `derive(Target: Trait, ...)` entries from `#[entity(...)]`.
+///
+/// Multiple `derive(...)` entries may appear, including several for the
+/// same target.
+///
+/// # Errors
+///
+/// Returns an error spanned inside the attribute when an entry has an
+/// unknown target or is not `Target: Path, ...`.
+pub fn parse_derive_attrs(attrs: &[Attribute]) -> darling::Result<Vec<DeriveDef>> {
+    let mut derives = Vec::new();
+    let mut errors = darling::Error::accumulator();
+
+    for attr in attrs {
+        if !attr.path().is_ident("entity") {
+            continue;
+        }
+
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("derive") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                match parse_derive_content(&content) {
+                    Ok(def) => derives.push(def),
+                    Err(err) => errors.push(err.into())
+                }
+            } else if meta.input.peek(syn::Token![=]) {
+                let _: syn::Token![=] = meta.input.parse()?;
+                let _: syn::Expr = meta.input.parse()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let _: proc_macro2::TokenStream = content.parse()?;
+            }
+            Ok(())
+        });
+    }
+
+    errors.finish_with(derives)
+}
+
+/// Parse the content of a single `derive(Target: Trait, ...)` entry.
+fn parse_derive_content(input: syn::parse::ParseStream<'_>) -> syn::Result<DeriveDef> {
+    let target_ident: Ident = input.parse()?;
+    let target = DeriveTarget::from_ident(&target_ident).ok_or_else(|| {
+        syn::Error::new(
+            target_ident.span(),
+            "unknown derive target, expected: Create, Update, Response, Row, Insertable, Query"
+        )
+    })?;
+    let _: syn::Token![:] = input.parse()?;
+    let paths = syn::punctuated::Punctuated::<Path, syn::Token![,]>::parse_terminated(input)?;
+
+    Ok(DeriveDef {
+        target,
+        paths: paths.into_iter().collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn path_strings(def: &DeriveDef) -> Vec<String> {
+        def.paths
+            .iter()
+            .map(|p| p.to_token_stream().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn derive_attrs_empty() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[entity(table = "users")])];
+        assert!(parse_derive_attrs(&attrs).unwrap().is_empty());
+    }
+
+    #[test]
+    fn derive_attrs_single_target() {
+        let attrs: Vec<Attribute> =
+            vec![parse_quote!(#[entity(table = "users", derive(Response: PartialEq, Eq))])];
+        let result = parse_derive_attrs(&attrs).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].target, DeriveTarget::Response);
+        assert_eq!(path_strings(&result[0]), vec!["PartialEq", "Eq"]);
+    }
+
+    #[test]
+    fn derive_attrs_qualified_path() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(
+            #[entity(table = "users", derive(Row: schemars::JsonSchema))]
+        )];
+        let result = parse_derive_attrs(&attrs).unwrap();
+        assert_eq!(result[0].target, DeriveTarget::Row);
+        assert_eq!(path_strings(&result[0]), vec!["schemars :: JsonSchema"]);
+    }
+
+    #[test]
+    fn derive_attrs_multiple_targets() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(
+            #[entity(
+                table = "users",
+                api(tag = "Users"),
+                derive(Create: PartialEq),
+                derive(UpdateRequest: PartialEq),
+                derive(Insertable: PartialEq),
+                derive(Query: PartialEq)
+            )]
+        )];
+        let targets: Vec<DeriveTarget> = parse_derive_attrs(&attrs)
+            .unwrap()
+            .iter()
+            .map(|d| d.target)
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                DeriveTarget::CreateRequest,
+                DeriveTarget::UpdateRequest,
+                DeriveTarget::Insertable,
+                DeriveTarget::Query
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_attrs_unknown_target_rejected() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(
+            #[entity(table = "users", derive(Entity: PartialEq), derive(Row: Eq))]
+        )];
+        let err = parse_derive_attrs(&attrs).unwrap_err();
+        assert!(err.to_string().contains("unknown derive target"));
+    }
+
+    #[test]
+    fn derive_attrs_missing_colon_rejected() {
+        let attrs: Vec<Attribute> =
+            vec![parse_quote!(#[entity(table = "users", derive(Response PartialEq))])];
+        assert!(parse_derive_attrs(&attrs).is_err());
+    }
+}
@@ -47,6 +47,7 @@
 use syn::DeriveInput;
 
 use super::{EntityDef, attrs::default_error_type};
+use crate::entity::parse::{DatabaseDialect, IsolationLevel};
 
 #[test]
 fn default_error_type_is_sqlx_error() {
@@ -144,3 +145,1042 @@ fn entity_def_api_with_public_commands() {
     assert_eq!(config.security_for_command("Register"), None);
     assert_eq!(config.security_for_command("Update"), Some("bearer"));
 }
+
+#[test]
+fn entity_def_row_prefix() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", row_prefix = "u_")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.row_prefix.as_deref(), Some("u_"));
+    assert_eq!(entity.row_column(&entity.all_fields()[1]), "u_name");
+}
+
+#[test]
+fn entity_def_without_row_prefix() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.row_prefix.is_none());
+    assert_eq!(entity.row_column(entity.id_field()), "id");
+}
+
+#[test]
+fn entity_def_manual_from_row() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", manual_from_row)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.has_manual_from_row());
+}
+
+#[test]
+fn entity_def_decode_with_implies_manual_from_row() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[column(decode_with = "decode_name")]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(!entity.manual_from_row);
+    assert!(entity.has_manual_from_row());
+}
+
+#[test]
+fn entity_def_soft_delete_requires_deleted_at() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", soft_delete)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("deleted_at"));
+}
+
+#[test]
+fn entity_def_etag_requires_existing_field() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", etag = "version", handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("does not name a field"));
+}
+
+#[test]
+fn entity_def_etag_rejects_string_field() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", etag = "name", handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response)]
+            pub name: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("api(etag) field must be an integer or `DateTime`")
+    );
+}
+
+#[test]
+fn entity_def_rate_limit_accepts_handlers_and_commands() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(
+            table = "users",
+            commands,
+            api(tag = "Users", handlers(create, list), rate_limit(create = 10, register = 5))
+        )]
+        #[command(Register)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.api_config().rate_limits.len(), 2);
+}
+
+#[test]
+fn entity_def_rate_limit_rejects_unknown_operation() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", handlers(create), rate_limit(delete = 1)))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("unknown operation 'delete'"));
+}
+
+#[test]
+fn entity_def_multipart_field() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "assets", api(tag = "Assets", multipart_field = "file", handlers))]
+        pub struct Asset {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response)]
+            pub title: String,
+            #[field(create)]
+            pub file: Vec<u8>,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.api_config().multipart_field.as_deref(), Some("file"));
+}
+
+#[test]
+fn entity_def_multipart_field_must_be_create_field() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "assets", api(tag = "Assets", multipart_field = "file", handlers))]
+        pub struct Asset {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            pub file: Vec<u8>,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("api(multipart_field = \"file\") does not name a create field")
+    );
+}
+
+#[test]
+fn entity_def_multipart_field_requires_bytes() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "assets", api(tag = "Assets", multipart_field = "file", handlers))]
+        pub struct Asset {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create)]
+            pub file: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("api(multipart_field) requires a `Vec<u8>` field")
+    );
+}
+
+#[test]
+fn entity_def_list_stream() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", handlers(get, list_stream)))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.api_config().handlers().list_stream);
+    assert!(!entity.api_config().handlers().list);
+}
+
+#[test]
+fn entity_def_cursor_pagination_rejected_with_list_query() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", list_query, pagination = "cursor", handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[filter]
+            pub name: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("api(pagination = \"cursor\") is not supported with api(list_query)")
+    );
+}
+
+#[test]
+fn entity_def_list_query_requires_filters() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", api(tag = "Users", list_query, handlers))]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("requires at least one #[filter] field")
+    );
+}
+
+#[test]
+fn entity_def_soft_delete_requires_option_deleted_at() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", soft_delete)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            pub deleted_at: chrono::DateTime<chrono::Utc>,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("Option"));
+}
+
+#[test]
+fn entity_def_soft_delete_with_deleted_at() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", soft_delete)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.is_soft_delete());
+}
+
+#[test]
+fn entity_def_cache_flag() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", cache)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.has_cache());
+}
+
+#[test]
+fn entity_def_repo_struct_flag() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", repo_struct)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    assert!(
+        EntityDef::from_derive_input(&input)
+            .unwrap()
+            .has_repo_struct()
+    );
+}
+
+#[test]
+fn entity_def_repo_struct_requires_full_sql() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", sql = "trait", repo_struct)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("repo_struct requires sql = \"full\"")
+    );
+}
+
+#[test]
+fn entity_def_schema_create_skips_public() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", schema = "core", schema_create, migrations)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    assert!(
+        EntityDef::from_derive_input(&input)
+            .unwrap()
+            .creates_schema()
+    );
+
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", schema_create, migrations)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    assert!(
+        !EntityDef::from_derive_input(&input)
+            .unwrap()
+            .creates_schema()
+    );
+}
+
+#[test]
+fn entity_def_schema_create_requires_migrations() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", schema = "core", schema_create)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("schema_create requires migrations")
+    );
+}
+
+#[test]
+fn entity_def_command_dedup_flag() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", commands, command_dedup)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.has_command_dedup());
+}
+
+#[test]
+fn entity_def_command_dedup_requires_commands() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", command_dedup)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("command_dedup requires commands"));
+}
+
+#[test]
+fn entity_def_id_prefix() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", id_prefix = "usr_")]
+        pub struct User {
+            #[id]
+            pub id: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.id_prefix(), Some("usr_"));
+}
+
+#[test]
+fn entity_def_id_prefix_requires_string_id() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", id_prefix = "usr_")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("id_prefix requires a `String` #[id] field")
+    );
+
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", id_prefix = "")]
+        pub struct User {
+            #[id]
+            pub id: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("id_prefix must not be empty"));
+}
+
+#[test]
+fn entity_def_diffable_flag() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", diffable)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.is_diffable());
+}
+
+#[test]
+fn entity_def_ord_by_id_flag() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", ord_by_id)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.has_ord_by_id());
+}
+
+#[test]
+fn entity_def_cascade_soft_delete_flag() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", soft_delete, cascade_soft_delete)]
+        #[has_many(Post)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.has_cascade_soft_delete());
+}
+
+#[test]
+fn entity_def_cascade_soft_delete_requires_soft_delete() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", cascade_soft_delete)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("requires soft_delete"));
+}
+
+#[test]
+fn entity_def_immutable_has_no_update_fields() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "events", immutable, api(tag = "Events", handlers))]
+        pub struct Event {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub payload: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.update_fields().is_empty());
+    assert_eq!(entity.create_fields().len(), 1);
+    assert!(!entity.api_config().handlers().update);
+    assert!(entity.api_config().handlers().delete);
+}
+
+#[test]
+fn entity_def_temporary_uses_pg_temp() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", migrations, temporary)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert!(entity.is_temporary());
+    assert_eq!(entity.full_table_name(), "pg_temp.users");
+}
+
+#[test]
+fn entity_def_temporary_requires_migrations() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", temporary)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("requires migrations"));
+}
+
+#[test]
+fn entity_def_isolation() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "accounts", transactions, isolation = "serializable")]
+        pub struct Account {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.isolation_level(), Some(IsolationLevel::Serializable));
+}
+
+#[test]
+fn entity_def_isolation_requires_transactions() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "accounts", isolation = "serializable")]
+        pub struct Account {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("isolation requires transactions"));
+}
+
+#[test]
+fn entity_def_temporary_rejects_schema() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", schema = "core", migrations, temporary)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("remove schema"));
+}
+
+#[test]
+fn entity_def_flatten_requires_trait_sql() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response, flatten)]
+            pub address: Address,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("requires sql = \"trait\""));
+}
+
+#[test]
+fn entity_def_computed_field_is_read_only() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response, returning = "lower(email)")]
+            pub normalized_email: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("computed by the database"));
+}
+
+#[test]
+fn entity_def_tsvector_search_field() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "articles")]
+        pub struct Article {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response)]
+            pub title: String,
+            #[field(response)]
+            #[column(tsvector, sources = [title])]
+            pub search: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.search_field().unwrap().name_str(), "search");
+}
+
+#[test]
+fn entity_def_tsvector_is_read_only() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "articles")]
+        pub struct Article {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response)]
+            #[column(tsvector, sources = [id])]
+            pub search: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("generated by the database"));
+}
+
+#[test]
+fn entity_def_tsvector_sources_must_be_stored_fields() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "articles")]
+        pub struct Article {
+            #[id]
+            pub id: uuid::Uuid,
+            #[column(tsvector, sources = [title])]
+            pub search: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("tsvector source `title` is not a stored field")
+    );
+
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "articles")]
+        pub struct Article {
+            #[id]
+            pub id: uuid::Uuid,
+            #[column(tsvector)]
+            pub search: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("requires sources"));
+}
+
+#[test]
+fn entity_def_tsvector_rejected_with_sqlite() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "articles", dialect = "postgres", alt_dialect = "sqlite")]
+        pub struct Article {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response)]
+            pub title: String,
+            #[column(tsvector, sources = [title])]
+            pub search: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("#[column(tsvector)] is not supported with sqlite")
+    );
+}
+
+#[test]
+fn entity_def_overlaps_requires_range_field() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "bookings")]
+        pub struct Booking {
+            #[id]
+            pub id: uuid::Uuid,
+            #[filter(overlaps)]
+            pub starts_at: chrono::DateTime<chrono::Utc>,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("requires a PgRange<T> field"));
+}
+
+#[test]
+fn entity_def_overlaps_rejected_with_list_query() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "bookings", api(tag = "Bookings", list_query, handlers))]
+        pub struct Booking {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response)]
+            #[filter(overlaps)]
+            pub during: sqlx::postgres::types::PgRange<chrono::DateTime<chrono::Utc>>,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("not supported with api(list_query)")
+    );
+}
+
+#[test]
+fn entity_def_computed_field_requires_postgres() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", dialect = "clickhouse")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response, returning = "lower(email)")]
+            pub normalized_email: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("requires dialect = \"postgres\""));
+}
+
+#[test]
+fn entity_def_unique_among_active_requires_soft_delete() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", unique_among_active)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("unique_among_active requires soft_delete")
+    );
+}
+
+#[test]
+fn entity_def_upsert_key() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "members", upsert = "org_id, email")]
+        pub struct Member {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, response)]
+            pub org_id: uuid::Uuid,
+            #[field(create, response)]
+            pub email: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.upsert_key(), ["org_id", "email"]);
+}
+
+#[test]
+fn entity_def_upsert_key_must_be_create_field() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", upsert = "email")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            pub email: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("upsert key `email` is not a create field")
+    );
+}
+
+#[test]
+fn entity_def_order_by() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", order_by = "name ASC, created_at desc")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            pub name: String,
+            #[field(response)]
+            pub created_at: chrono::DateTime<chrono::Utc>,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.list_order(), "name ASC, created_at DESC, id DESC");
+}
+
+#[test]
+fn entity_def_order_by_keeps_listed_id() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", order_by = "name, id ASC")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            pub name: String,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.list_order(), "name ASC, id ASC");
+}
+
+#[test]
+fn entity_def_order_by_defaults_to_id() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "tags")]
+        pub struct Tag {
+            #[id]
+            pub tag_id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.list_order(), "tag_id DESC");
+}
+
+#[test]
+fn entity_def_order_by_rejects_unknown_field() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "tags", order_by = "created_at DESC")]
+        pub struct Tag {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("order_by column `created_at` is not a field")
+    );
+}
+
+#[test]
+fn entity_def_order_by_rejects_bad_direction() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "tags", order_by = "id DOWN")]
+        pub struct Tag {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("order_by direction `DOWN` must be ASC or DESC")
+    );
+}
+
+#[test]
+fn entity_def_cursor_fields() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "posts", cursor = "created_at, id")]
+        pub struct Post {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            pub created_at: chrono::DateTime<chrono::Utc>,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let names: Vec<String> = entity
+        .cursor_fields()
+        .iter()
+        .map(|f| f.name_str())
+        .collect();
+    assert_eq!(names, ["created_at", "id"]);
+}
+
+#[test]
+fn entity_def_cursor_column_must_exist() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "posts", cursor = "published_at, id")]
+        pub struct Post {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("cursor column `published_at` is not a field")
+    );
+}
+
+#[test]
+fn entity_def_cursor_rejects_transparent_column() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "accounts", cursor = "balance, id")]
+        pub struct Account {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            #[column(transparent = "i64")]
+            pub balance: Cents,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("cursor column `balance` cannot be encrypted, transparent or flattened")
+    );
+}
+
+#[test]
+fn entity_def_cursor_rejects_option_column() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "posts", cursor = "published_at, id")]
+        pub struct Post {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(response)]
+            pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("cursor column `published_at` cannot be an Option")
+    );
+}
+
+#[test]
+fn entity_def_cursor_rejects_duplicate_column() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "posts", cursor = "id, id")]
+        pub struct Post {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("cursor column `id` is listed more than once")
+    );
+}
+
+#[test]
+fn entity_def_rejects_inverted_bounds() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, min_len = 10, max_len = 5)]
+            pub name: String,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("min_len is greater than max_len"));
+
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, min = 1, max = 0)]
+            pub age: i32,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(err.to_string().contains("min is greater than max"));
+}
+
+#[test]
+fn entity_def_alt_dialect_sqlite() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", alt_dialect = "sqlite")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    assert_eq!(entity.alt_dialect, Some(DatabaseDialect::Sqlite));
+    assert!(entity.has_dialect(DatabaseDialect::Postgres));
+    assert!(entity.has_dialect(DatabaseDialect::Sqlite));
+    assert!(!entity.has_dialect(DatabaseDialect::MongoDB));
+}
+
+#[test]
+fn entity_def_alt_dialect_requires_postgres_and_sqlite() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", alt_dialect = "mongodb")]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("alt_dialect = \"sqlite\" requires")
+    );
+}
+
+#[test]
+fn entity_def_sqlite_rejects_streams() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", alt_dialect = "sqlite", streams)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+        }
+    };
+    let err = EntityDef::from_derive_input(&input).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("streams is not supported with sqlite")
+    );
+}
+
+#[test]
+fn entity_def_double_option_updates() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[entity(table = "users", double_option_updates)]
+        pub struct User {
+            #[id]
+            pub id: uuid::Uuid,
+            #[field(create, update, response)]
+            pub name: String,
+            #[field(update, response)]
+            pub bio: Option<String>,
+            #[field(update, response, write_once)]
+            pub onboarded_at: Option<String>,
+            #[field(response)]
+            pub avatar: Option<String>,
+        }
+    };
+    let entity = EntityDef::from_derive_input(&input).unwrap();
+    let double_option: Vec<String> = entity
+        .all_fields()
+        .iter()
+        .filter(|f| f.is_double_option())
+        .map(|f| f.name_str())
+        .collect();
+    assert_eq!(double_option, ["bio"]);
+}
@@ -6,7 +6,7 @@
 //! This module handles parsing of field attributes and delegates to
 //! specialized submodules for different concerns:
 //!
-//! - [`expose`] — DTO exposure (create, update, response, skip)
+//! - [`expose`] — DTO exposure (create, update, response, skip, skip_response)
 //! - [`storage`] — Database storage (id, auto, belongs_to)
 //!
 //! # Architecture
@@ -25,6 +25,33 @@
 //! #[belongs_to(User)]
 //! pub user_id: Uuid,
 //! ```
+//!
+//! # Conflicting Attributes
+//!
+//! Combinations that contradict each other are rejected with an error on
+//! the offending `#[field(...)]` flag:
+//!
+//! | Combination | Reason |
+//! |-------------|--------|
+//! | `skip` with `create`, `update` or `response` | `skip` excludes the field from every DTO |
+//! | `#[id]` with `create` or `update` | The id is generated on insert and never changed |
+//! | `#[auto]` with `create` or `update` | Auto fields are never taken from requests |
+//! | `rename_column` with `#[column(name = "...")]` | Both set the column name |
+//!
+//! # Conventional Timestamps
+//!
+//! Fields named exactly `created_at` or `updated_at` with a datetime type
+//! (`DateTime`, `NaiveDateTime`, `OffsetDateTime`, `PrimitiveDateTime`,
+//! optionally wrapped in `Option`) are treated as `#[auto]` without the
+//! attribute:
+//!
+//! ```rust,ignore
+//! #[field(response)]
+//! pub created_at: DateTime<Utc>,  // same as adding #[auto]
+//! ```
+//!
+//! Listing the field in `#[field(create)]` or `#[field(update)]` opts out,
+//! so the value is taken from requests like any other column.
 
 mod column;
 mod example;
@@ -37,6 +64,8 @@ pub use column::{ColumnConfig, IndexType, ReferentialAction};
 pub use example::ExampleValue;
 pub use expose::ExposeConfig;
 pub use filter::{FilterConfig, FilterType};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
 pub use storage::StorageConfig;
 use syn::{Attribute, Field, Ident, Type};
 pub use validation::ValidationConfig;
@@ -71,6 +100,231 @@ fn parse_belongs_to(attr: &Attribute) -> (Option<Ident>, Option<ReferentialActio
     (entity, on_delete)
 }
 
+/// Reject contradictory attribute combinations on one field.
+///
+/// The error points at the `#[field(...)]` flag that conflicts.
+fn validate_flags(
+    attrs: &[Attribute],
+    expose: &ExposeConfig,
+    storage: &StorageConfig
+) -> darling::Result<()> {
+    let exclusive: [(bool, &str, &[&str]); 4] = [
+        (expose.skip, "`skip`", &["create", "update", "response"]),
+        (storage.is_id, "`#[id]`", &["create", "update"]),
+        (storage.is_auto, "`#[auto]`", &["create", "update"]),
+        (expose.as_str, "`as_str`", &["serialize_as_string"])
+    ];
+    for (set, owner, flags) in exclusive {
+        if !set {
+            continue;
+        }
+        for flag in flags {
+            if let Some(path) = expose::find_flag(attrs, flag) {
+                return Err(darling::Error::custom(format!(
+                    "{} cannot be combined with `{}`",
+                    owner, flag
+                ))
+                .with_span(&path));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Turn `update_type = "Option<Option<T>>"` into a double-option update.
+///
+/// `T` must be the inner type of the `Option<T>` field, since the value is
+/// bound without conversion. `update_with`, `write_once` and encryption
+/// rewrite the bound value and are rejected.
+fn resolve_double_option(
+    attrs: &[Attribute],
+    ty: &Type,
+    expose: &mut ExposeConfig,
+    column: &ColumnConfig
+) -> darling::Result<()> {
+    let Some(inner) = expose
+        .update_type
+        .as_ref()
+        .and_then(option_inner)
+        .and_then(option_inner)
+    else {
+        return Ok(());
+    };
+    let error = |message: String| {
+        let error = darling::Error::custom(message);
+        match expose::find_flag(attrs, "update_type") {
+            Some(path) => error.with_span(&path),
+            None => error
+        }
+    };
+
+    let field_inner = option_inner(ty).map(|t| t.to_token_stream().to_string());
+    if field_inner != Some(inner.to_token_stream().to_string()) {
+        return Err(error(
+            "`update_type = \"Option<Option<T>>\"` requires an `Option<T>` field".to_string()
+        ));
+    }
+    let conflicts = [
+        (expose.update_with.is_some(), "`update_with`"),
+        (expose.write_once, "`write_once`"),
+        (column.encrypt, "`#[column(encrypt)]`")
+    ];
+    if let Some((_, name)) = conflicts.into_iter().find(|(set, _)| *set) {
+        return Err(error(format!(
+            "`update_type = \"Option<Option<T>>\"` cannot be combined with {}",
+            name
+        )));
+    }
+
+    expose.update_type = None;
+    expose.double_option = true;
+    Ok(())
+}
+
+/// Move `rename_column = "..."` into the column configuration.
+///
+/// `#[column(name = "...")]` sets the same name, so the two are exclusive.
+fn resolve_rename_column(
+    attrs: &[Attribute],
+    expose: &mut ExposeConfig,
+    column: &mut ColumnConfig
+) -> darling::Result<()> {
+    let Some(name) = expose.rename_column.take() else {
+        return Ok(());
+    };
+    if column.name.is_some() {
+        let span = expose::find_flag(attrs, "rename_column")
+            .map_or_else(TokenStream::new, |path| path.to_token_stream());
+        return Err(darling::Error::custom(
+            "`rename_column` cannot be combined with `#[column(name = \"...\")]`"
+        )
+        .with_span(&span));
+    }
+    column.name = Some(name);
+    Ok(())
+}
+
+/// Reject attributes that bind a `#[column(transparent)]` field directly.
+///
+/// Ids, foreign keys, encrypted and flattened fields, and update type
+/// overrides have their own binding; they cannot also go through the
+/// newtype conversion.
+fn validate_transparent(
+    field: &Field,
+    expose: &ExposeConfig,
+    storage: &StorageConfig,
+    column: &ColumnConfig
+) -> darling::Result<()> {
+    if column.transparent.is_none() {
+        return Ok(());
+    }
+    let conflicts = [
+        (storage.is_id, "`#[id]`"),
+        (storage.belongs_to.is_some(), "`#[belongs_to]`"),
+        (column.encrypt, "`encrypt`"),
+        (expose.flatten, "`flatten`"),
+        (expose.update_type.is_some(), "`update_type`"),
+        (expose.update_with.is_some(), "`update_with`")
+    ];
+    match conflicts.into_iter().find(|(set, _)| *set) {
+        Some((_, name)) => {
+            let span = field
+                .attrs
+                .iter()
+                .find(|a| a.path().is_ident("column"))
+                .map_or_else(|| field.ty.to_token_stream(), ToTokens::to_token_stream);
+            Err(darling::Error::custom(format!(
+                "`#[column(transparent)]` cannot be combined with {}",
+                name
+            ))
+            .with_span(&span))
+        }
+        None => Ok(())
+    }
+}
+
+/// Check if a type is `Option<T>`.
+fn is_option_type(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+    {
+        return segment.ident == "Option";
+    }
+    false
+}
+
+/// Extract `T` from `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Option"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+    {
+        return Some(inner);
+    }
+    None
+}
+
+/// Replace every occurrence of `from` in `ty`, including generic arguments
+/// and tuple elements, with `to`.
+fn replace_type(ty: &Type, from: &Type, to: &Type) -> Type {
+    if ty == from {
+        return to.clone();
+    }
+    let mut ty = ty.clone();
+    match &mut ty {
+        Type::Path(type_path) => {
+            for segment in &mut type_path.path.segments {
+                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    for arg in &mut args.args {
+                        if let syn::GenericArgument::Type(arg) = arg {
+                            *arg = replace_type(arg, from, to);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Tuple(tuple) => {
+            for elem in &mut tuple.elems {
+                *elem = replace_type(elem, from, to);
+            }
+        }
+        _ => {}
+    }
+    ty
+}
+
+/// Check if a field follows the `created_at`/`updated_at` timestamp
+/// convention and should be treated as `#[auto]`.
+fn is_conventional_timestamp(ident: &Ident, ty: &Type) -> bool {
+    if ident != "created_at" && ident != "updated_at" {
+        return false;
+    }
+    if let Type::Path(type_path) = option_inner(ty).unwrap_or(ty)
+        && let Some(segment) = type_path.path.segments.last()
+    {
+        return matches!(
+            segment.ident.to_string().as_str(),
+            "DateTime" | "NaiveDateTime" | "OffsetDateTime" | "PrimitiveDateTime"
+        );
+    }
+    false
+}
+
+/// Extract `T` from `PgRange<T>`.
+fn range_inner(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "PgRange"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+    {
+        return Some(inner);
+    }
+    None
+}
+
 /// Field definition with all parsed attributes.
 ///
 /// Represents a single field from the entity struct, combining
@@ -114,6 +368,9 @@ pub struct FieldDef {
     /// Parsed from `#[column(...)]` attributes for constraints and indexes.
     pub column: ColumnConfig,
 
+    /// Whether the field can be used to order lists (`#[sortable]`).
+    pub sortable: bool,
+
     /// Documentation comment from the field.
     ///
     /// Extracted from `///` comments for use in OpenAPI descriptions.
@@ -141,7 +398,8 @@ impl FieldDef {
     ///
     /// # Errors
     ///
-    /// Returns error if the field has no identifier (tuple struct field).
+    /// Returns error if the field has no identifier (tuple struct field) or
+    /// combines contradictory attributes.
     pub fn from_field(field: &Field) -> darling::Result<Self> {
         let ident = field.ident.clone().ok_or_else(|| {
             darling::Error::custom("Entity fields must be named").with_span(field)
@@ -155,6 +413,7 @@ impl FieldDef {
         let mut storage = StorageConfig::default();
         let mut filter = FilterConfig::default();
         let mut column = ColumnConfig::default();
+        let mut sortable = false;
 
         for attr in &field.attrs {
             if attr.path().is_ident("id") {
@@ -162,7 +421,7 @@ impl FieldDef {
             } else if attr.path().is_ident("auto") {
                 storage.is_auto = true;
             } else if attr.path().is_ident("field") {
-                expose = ExposeConfig::from_attr(attr);
+                expose.merge_attr(attr);
             } else if attr.path().is_ident("belongs_to") {
                 let (entity, on_del) = parse_belongs_to(attr);
                 storage.belongs_to = entity;
@@ -171,8 +430,21 @@ impl FieldDef {
                 filter = FilterConfig::from_attr(attr);
             } else if attr.path().is_ident("column") {
                 column = ColumnConfig::from_attr(attr);
+            } else if attr.path().is_ident("sortable") {
+                sortable = true;
             }
         }
+        if !storage.is_id
+            && !expose.create
+            && !expose.update
+            && is_conventional_timestamp(&ident, &ty)
+        {
+            storage.is_auto = true;
+        }
+        validate_flags(&field.attrs, &expose, &storage)?;
+        resolve_rename_column(&field.attrs, &mut expose, &mut column)?;
+        resolve_double_option(&field.attrs, &ty, &mut expose, &column)?;
+        validate_transparent(field, &expose, &storage, &column)?;
 
         Ok(Self {
             ident,
@@ -181,6 +453,7 @@ impl FieldDef {
             storage,
             filter,
             column,
+            sortable,
             doc,
             validation,
             example
@@ -212,12 +485,44 @@ impl FieldDef {
     /// Used to determine whether to wrap update fields in `Option`.
     #[must_use]
     pub fn is_option(&self) -> bool {
-        if let Type::Path(type_path) = &self.ty
-            && let Some(segment) = type_path.path.segments.last()
-        {
-            return segment.ident == "Option";
+        is_option_type(&self.ty)
+    }
+
+    /// Get the inner type of an `Option<T>` field, or the field type itself.
+    #[must_use]
+    pub fn inner_ty(&self) -> &Type {
+        option_inner(&self.ty).unwrap_or(&self.ty)
+    }
+
+    /// Get `T` of a `PgRange<T>` or `Option<PgRange<T>>` field.
+    #[must_use]
+    pub fn range_element(&self) -> Option<&Type> {
+        range_inner(self.inner_ty())
+    }
+
+    /// Get the field type used in `CreateRequest`.
+    ///
+    /// Returns the `create_type` override if set, otherwise the field type.
+    #[must_use]
+    pub fn create_ty(&self) -> &Type {
+        self.expose.create_type.as_ref().unwrap_or(&self.ty)
+    }
+
+    /// Get the field type used in `UpdateRequest`, before `Option` wrapping.
+    ///
+    /// Returns the `update_type` override if set, otherwise the field type.
+    #[must_use]
+    pub fn update_ty(&self) -> &Type {
+        self.expose.update_type.as_ref().unwrap_or(&self.ty)
+    }
+
+    /// Check if the `UpdateRequest` type is already `Option<T>`.
+    #[must_use]
+    pub fn update_ty_is_option(&self) -> bool {
+        match &self.expose.update_type {
+            Some(ty) => is_option_type(ty),
+            None => self.is_option()
         }
-        false
     }
 
     /// Check if this is the primary key field.
@@ -244,12 +549,26 @@ impl FieldDef {
         self.expose.in_update()
     }
 
+    /// Get the field type used in `Response`.
+    ///
+    /// `String` (kept in `Option` if the field is optional) for `as_str`
+    /// fields, otherwise the field type.
+    #[must_use]
+    pub fn response_ty(&self) -> Type {
+        match (self.expose.as_str, self.is_option()) {
+            (true, true) => syn::parse_quote!(Option<String>),
+            (true, false) => syn::parse_quote!(String),
+            (false, _) => self.ty.clone()
+        }
+    }
+
     /// Check if field should be in `Response`.
     ///
-    /// ID fields are always included regardless of expose config.
+    /// ID fields are included unless excluded with `skip` or
+    /// `skip_response`.
     #[must_use]
     pub fn in_response(&self) -> bool {
-        !self.expose.skip && (self.expose.response || self.storage.is_id)
+        self.expose.allows_response() && (self.expose.response || self.storage.is_id)
     }
 
     /// Get the related entity name if this is a foreign key.
@@ -272,6 +591,12 @@ impl FieldDef {
         self.filter.has_filter()
     }
 
+    /// Check if lists can be ordered by this field.
+    #[must_use]
+    pub fn is_sortable(&self) -> bool {
+        self.sortable
+    }
+
     /// Get the filter configuration.
     #[must_use]
     pub fn filter(&self) -> &FilterConfig {
@@ -282,7 +607,6 @@ impl FieldDef {
     ///
     /// Returns the extracted doc comment for use in OpenAPI descriptions.
     #[must_use]
-    #[allow(dead_code)] // Will be used for schema field descriptions (#78)
     pub fn doc(&self) -> Option<&str> {
         self.doc.as_deref()
     }
@@ -340,6 +664,189 @@ impl FieldDef {
         self.column.has_index()
     }
 
+    /// Check if this field is `Option<Option<T>>` in `UpdateRequest`.
+    ///
+    /// An absent value leaves the column unchanged; `Some(None)` sets it to
+    /// `NULL`.
+    #[must_use]
+    pub fn is_double_option(&self) -> bool {
+        self.expose.double_option
+    }
+
+    /// Check if `#[entity(double_option_updates)]` applies to this field.
+    ///
+    /// Nullable update fields qualify unless their update value is
+    /// converted or rewritten (`update_type`, `update_with`, `write_once`,
+    /// encryption).
+    #[must_use]
+    pub fn allows_double_option(&self) -> bool {
+        self.in_update()
+            && self.is_option()
+            && self.expose.update_type.is_none()
+            && self.expose.update_with.is_none()
+            && !self.expose.write_once
+            && !self.column.encrypt
+    }
+
+    /// Get the `#[column(decode_with = "...")]` function, if any.
+    #[must_use]
+    pub fn decode_with(&self) -> Option<&syn::Path> {
+        self.column.decode_with.as_ref()
+    }
+
+    /// Get the inner type of a `#[column(transparent = "...")]` newtype.
+    #[must_use]
+    pub fn transparent_inner(&self) -> Option<&Type> {
+        self.column.transparent.as_ref()
+    }
+
+    /// Get the type this field has in Row and Insertable.
+    ///
+    /// A transparent newtype is stored as its inner type, keeping the
+    /// `Option` wrapper; any other field keeps its own type.
+    #[must_use]
+    pub fn db_ty(&self) -> Type {
+        match (self.transparent_inner(), self.is_option()) {
+            (Some(inner), true) => syn::parse_quote!(Option<#inner>),
+            (Some(inner), false) => inner.clone(),
+            (None, _) => self.ty.clone()
+        }
+    }
+
+    /// Convert a stored value (of [`db_ty`](Self::db_ty)) to the field
+    /// type.
+    ///
+    /// `<Email as From<String>>::from(value)` for a transparent newtype,
+    /// mapped over `Option`; `value` unchanged otherwise.
+    #[must_use]
+    pub fn db_to_field(&self, value: TokenStream) -> TokenStream {
+        let Some(inner) = self.transparent_inner() else {
+            return value;
+        };
+        let newtype = self.inner_ty();
+        let convert = quote! { <#newtype as ::core::convert::From<#inner>>::from };
+        if self.is_option() {
+            quote! { #value.map(#convert) }
+        } else {
+            quote! { #convert(#value) }
+        }
+    }
+
+    /// Convert a field value to its stored type, the reverse of
+    /// [`db_to_field`](Self::db_to_field).
+    ///
+    /// `value` is the newtype itself; wrap in `Option` handling at the call
+    /// site when the field is nullable.
+    #[must_use]
+    pub fn to_db(&self, value: TokenStream) -> TokenStream {
+        let Some(inner) = self.transparent_inner() else {
+            return value;
+        };
+        let newtype = self.inner_ty();
+        quote! { <#inner as ::core::convert::From<#newtype>>::from(#value) }
+    }
+
+    /// Convert a field value, `Option`-wrapped when the field is nullable,
+    /// to its stored type.
+    #[must_use]
+    pub fn field_to_db(&self, value: TokenStream) -> TokenStream {
+        if self.transparent_inner().is_some() && self.is_option() {
+            let convert = self.to_db(quote! { v });
+            quote! { #value.map(|v| #convert) }
+        } else {
+            self.to_db(value)
+        }
+    }
+
+    /// Get the OpenAPI type of a DTO field of this field declared as `ty`.
+    ///
+    /// A transparent newtype is documented as its inner type, so
+    /// `Option<Email>` becomes `Option<String>` and `Email` needs no
+    /// `ToSchema` impl. `None` for other fields and for a `ty` that does
+    /// not contain the newtype, such as a `create_type` override.
+    #[must_use]
+    pub fn api_value_type(&self, ty: &Type) -> Option<Type> {
+        let inner = self.transparent_inner()?;
+        let value_type = replace_type(ty, self.inner_ty(), inner);
+        (value_type != *ty).then_some(value_type)
+    }
+
+    /// `#[schema(value_type = ...)]` for a DTO field declared as `ty`, from
+    /// [`api_value_type`](Self::api_value_type); empty if there is none.
+    #[must_use]
+    pub fn api_value_type_attr(&self, ty: &Type) -> TokenStream {
+        match self.api_value_type(ty) {
+            Some(value_type) => {
+                quote! { #[cfg_attr(feature = "api", schema(value_type = #value_type))] }
+            }
+            None => TokenStream::new()
+        }
+    }
+
+    /// Check if `update` may only fill this column while it is `NULL`.
+    #[must_use]
+    pub fn is_write_once(&self) -> bool {
+        self.expose.write_once
+    }
+
+    /// Check if the Row decodes this field with `#[sqlx(flatten)]`.
+    #[must_use]
+    pub fn is_flattened(&self) -> bool {
+        self.expose.flatten
+    }
+
+    /// SQL expression computed by the database in place of a stored column.
+    ///
+    /// Set by `#[field(returning = "...")]`.
+    #[must_use]
+    pub fn returning_expr(&self) -> Option<&str> {
+        self.expose.returning.as_deref()
+    }
+
+    /// Check if this field is computed rather than stored.
+    #[must_use]
+    pub fn is_computed(&self) -> bool {
+        self.expose.returning.is_some()
+    }
+
+    /// Text search configuration if this is a `#[column(tsvector)]` field.
+    #[must_use]
+    pub fn ts_config(&self) -> Option<&str> {
+        self.column.tsvector.as_deref()
+    }
+
+    /// Check if this field is a generated full-text search document.
+    #[must_use]
+    pub fn is_tsvector(&self) -> bool {
+        self.column.tsvector.is_some()
+    }
+
+    /// Check if the database produces this value, so it is never written.
+    ///
+    /// True for computed expressions and generated `tsvector` columns.
+    #[must_use]
+    pub fn is_db_generated(&self) -> bool {
+        self.is_computed() || self.is_tsvector()
+    }
+
+    /// String length bounds from `min_len`/`max_len`.
+    #[must_use]
+    pub fn length_bounds(&self) -> (Option<usize>, Option<usize>) {
+        (self.expose.min_len, self.expose.max_len)
+    }
+
+    /// Numeric bounds from `min`/`max`.
+    #[must_use]
+    pub fn range_bounds(&self) -> (Option<i64>, Option<i64>) {
+        (self.expose.min, self.expose.max)
+    }
+
+    /// Check if this column is stored encrypted.
+    #[must_use]
+    pub fn is_encrypted(&self) -> bool {
+        self.column.encrypt
+    }
+
     /// Get the database column name.
     ///
     /// Returns custom name if set, otherwise the field name.
@@ -347,6 +854,34 @@ impl FieldDef {
     pub fn column_name(&self) -> String {
         self.column.column_name(&self.name_str()).to_string()
     }
+
+    /// Get the JSON property name used by DTOs.
+    ///
+    /// Returns `rename_api` if set, otherwise the field name.
+    #[must_use]
+    pub fn api_name(&self) -> String {
+        self.expose
+            .rename_api
+            .clone()
+            .unwrap_or_else(|| self.name_str())
+    }
+
+    /// `#[serde(rename)]` for a `rename_api` field, plus `#[graphql(name)]`
+    /// with `graphql`; empty otherwise.
+    #[must_use]
+    pub fn api_rename_attrs(&self, graphql: bool) -> TokenStream {
+        let Some(name) = &self.expose.rename_api else {
+            return TokenStream::new();
+        };
+        if graphql {
+            quote! {
+                #[serde(rename = #name)]
+                #[graphql(name = #name)]
+            }
+        } else {
+            quote! { #[serde(rename = #name)] }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -387,6 +922,108 @@ mod tests {
         assert!(field.is_auto());
     }
 
+    #[test]
+    fn field_conventional_timestamps_are_auto() {
+        let created = parse_field(quote::quote! {
+            #[field(response)]
+            pub created_at: chrono::DateTime<chrono::Utc>
+        });
+        assert!(created.is_auto());
+        assert!(created.in_response());
+
+        let updated = parse_field(quote::quote! {
+            pub updated_at: Option<time::OffsetDateTime>
+        });
+        assert!(updated.is_auto());
+    }
+
+    #[test]
+    fn field_conventional_timestamp_opt_out() {
+        let field = parse_field(quote::quote! {
+            #[field(create, response)]
+            pub created_at: chrono::NaiveDateTime
+        });
+        assert!(!field.is_auto());
+        assert!(field.in_create());
+    }
+
+    #[test]
+    fn field_timestamp_convention_requires_exact_name_and_type() {
+        let other_name = parse_field(quote::quote! {
+            pub published_at: chrono::DateTime<chrono::Utc>
+        });
+        assert!(!other_name.is_auto());
+
+        let other_type = parse_field(quote::quote! {
+            pub created_at: i64
+        });
+        assert!(!other_type.is_auto());
+    }
+
+    fn field_error(tokens: proc_macro2::TokenStream) -> String {
+        let field: Field = parse_quote!(#tokens);
+        FieldDef::from_field(&field).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn skip_conflicts_with_dto_flags() {
+        let error = field_error(quote::quote! {
+            #[field(skip, create)]
+            pub secret: String
+        });
+        assert_eq!(error, "`skip` cannot be combined with `create`");
+
+        let error = field_error(quote::quote! {
+            #[field(skip)]
+            #[field(response)]
+            pub secret: String
+        });
+        assert_eq!(error, "`skip` cannot be combined with `response`");
+    }
+
+    #[test]
+    fn id_and_auto_conflict_with_request_flags() {
+        let error = field_error(quote::quote! {
+            #[id]
+            #[field(create, response)]
+            pub id: uuid::Uuid
+        });
+        assert_eq!(error, "`#[id]` cannot be combined with `create`");
+
+        let error = field_error(quote::quote! {
+            #[auto]
+            #[field(update, response, max_len = 10)]
+            pub created_at: chrono::DateTime<chrono::Utc>
+        });
+        assert_eq!(error, "`#[auto]` cannot be combined with `update`");
+    }
+
+    #[test]
+    fn as_str_conflicts_with_serialize_as_string() {
+        let error = field_error(quote::quote! {
+            #[field(response, as_str, serialize_as_string)]
+            pub count: i64
+        });
+        assert_eq!(
+            error,
+            "`as_str` cannot be combined with `serialize_as_string`"
+        );
+    }
+
+    #[test]
+    fn compatible_flags_are_accepted() {
+        parse_field(quote::quote! {
+            #[id]
+            #[field(response, skip_response)]
+            pub id: uuid::Uuid
+        });
+        parse_field(quote::quote! {
+            #[auto]
+            #[field(response)]
+            pub created_at: chrono::DateTime<chrono::Utc>
+        });
+    }
+
     #[test]
     fn field_expose_config() {
         let field = parse_field(quote::quote! {
@@ -409,6 +1046,28 @@ mod tests {
         assert!(!field.in_response());
     }
 
+    #[test]
+    fn field_expose_skip_response() {
+        let field = parse_field(quote::quote! {
+            #[field(create, skip_response)]
+            pub password_hash: String
+        });
+        assert!(field.in_create());
+        assert!(!field.in_update());
+        assert!(!field.in_response());
+    }
+
+    #[test]
+    fn field_id_skip_response_is_internal() {
+        let field = parse_field(quote::quote! {
+            #[id]
+            #[field(skip_response)]
+            pub id: uuid::Uuid
+        });
+        assert!(field.is_id());
+        assert!(!field.in_response());
+    }
+
     #[test]
     fn field_belongs_to() {
         let field = parse_field(quote::quote! {
@@ -452,6 +1111,29 @@ mod tests {
         assert!(field.has_filter());
     }
 
+    #[test]
+    fn field_sortable_attribute() {
+        let field = parse_field(quote::quote! {
+            #[sortable]
+            pub name: String
+        });
+        assert!(field.is_sortable());
+        assert!(!parse_field(quote::quote! { pub name: String }).is_sortable());
+    }
+
+    #[test]
+    fn field_response_ty_as_str() {
+        let field = parse_field(quote::quote! {
+            #[field(response, as_str)]
+            pub role: Option<Role>
+        });
+        let ty = field.response_ty();
+        assert_eq!(quote::quote!(#ty).to_string(), "Option < String >");
+        let plain = parse_field(quote::quote! { pub role: Role });
+        let ty = plain.response_ty();
+        assert_eq!(quote::quote!(#ty).to_string(), "Role");
+    }
+
     #[test]
     fn field_is_option() {
         let field = parse_field(quote::quote! { pub avatar: Option<String> });
@@ -461,6 +1143,102 @@ mod tests {
         assert!(!field2.is_option());
     }
 
+    #[test]
+    fn field_inner_ty() {
+        let field = parse_field(quote::quote! { pub avatar: Option<String> });
+        let inner = field.inner_ty();
+        assert_eq!(quote::quote!(#inner).to_string(), "String");
+
+        let field2 = parse_field(quote::quote! { pub count: i64 });
+        let inner2 = field2.inner_ty();
+        assert_eq!(quote::quote!(#inner2).to_string(), "i64");
+    }
+
+    #[test]
+    fn field_dto_type_overrides() {
+        let field = parse_field(quote::quote! {
+            #[field(create, update, create_type = "u32", update_type = "Option<u16>")]
+            pub count: i64
+        });
+        let create_ty = field.create_ty();
+        assert_eq!(quote::quote!(#create_ty).to_string(), "u32");
+        assert!(field.update_ty_is_option());
+
+        let plain = parse_field(quote::quote! {
+            #[field(create, update)]
+            pub count: i64
+        });
+        let create_ty = plain.create_ty();
+        assert_eq!(quote::quote!(#create_ty).to_string(), "i64");
+        assert!(!plain.update_ty_is_option());
+    }
+
+    #[test]
+    fn field_double_option_update_type() {
+        let field = parse_field(quote::quote! {
+            #[field(update, update_type = "Option<Option<String>>")]
+            pub bio: Option<String>
+        });
+        assert!(field.is_double_option());
+        assert!(field.expose.update_type.is_none());
+        let update_ty = field.update_ty();
+        assert_eq!(quote::quote!(#update_ty).to_string(), "Option < String >");
+    }
+
+    #[test]
+    fn field_double_option_requires_option_field() {
+        let error = field_error(quote::quote! {
+            #[field(update, update_type = "Option<Option<String>>")]
+            pub bio: String
+        });
+        assert_eq!(
+            error,
+            "`update_type = \"Option<Option<T>>\"` requires an `Option<T>` field"
+        );
+    }
+
+    #[test]
+    fn field_double_option_conflicts_with_write_once() {
+        let error = field_error(quote::quote! {
+            #[field(update, write_once, update_type = "Option<Option<String>>")]
+            pub bio: Option<String>
+        });
+        assert_eq!(
+            error,
+            "`update_type = \"Option<Option<T>>\"` cannot be combined with `write_once`"
+        );
+    }
+
+    #[test]
+    fn field_transparent_db_ty() {
+        let field = parse_field(quote::quote! {
+            #[column(transparent = "i64")]
+            pub price: Option<Cents>
+        });
+        let db_ty = field.db_ty();
+        assert_eq!(quote::quote!(#db_ty).to_string(), "Option < i64 >");
+        assert_eq!(
+            field.db_to_field(quote::quote!(row.price)).to_string(),
+            "row . price . map (< Cents as :: core :: convert :: From < i64 >> :: from)"
+        );
+        assert_eq!(
+            field.to_db(quote::quote!(v)).to_string(),
+            "< i64 as :: core :: convert :: From < Cents >> :: from (v)"
+        );
+    }
+
+    #[test]
+    fn field_transparent_conflicts_with_encrypt() {
+        let error = field_error(quote::quote! {
+            #[column(transparent = "String", encrypt)]
+            pub email: Email
+        });
+        assert_eq!(
+            error,
+            "`#[column(transparent)]` cannot be combined with `encrypt`"
+        );
+    }
+
     #[test]
     fn field_ty_accessor() {
         let field = parse_field(quote::quote! { pub count: i32 });
@@ -586,6 +1364,46 @@ mod tests {
         assert_eq!(field.column_name(), "email");
     }
 
+    #[test]
+    fn field_three_way_naming() {
+        let field = parse_field(quote::quote! {
+            #[field(create, response, rename_column = "usr_mail", rename_api = "emailAddress")]
+            pub email: String
+        });
+        assert_eq!(field.name_str(), "email");
+        assert_eq!(field.column_name(), "usr_mail");
+        assert_eq!(field.api_name(), "emailAddress");
+        assert!(
+            field
+                .api_rename_attrs(false)
+                .to_string()
+                .contains("serde (rename = \"emailAddress\")")
+        );
+        assert!(
+            field
+                .api_rename_attrs(true)
+                .to_string()
+                .contains("graphql (name = \"emailAddress\")")
+        );
+    }
+
+    #[test]
+    fn field_default_api_name() {
+        let field = parse_field(quote::quote! { pub email: String });
+        assert_eq!(field.api_name(), "email");
+        assert!(field.api_rename_attrs(true).is_empty());
+    }
+
+    #[test]
+    fn rename_column_conflicts_with_column_name() {
+        let error = field_error(quote::quote! {
+            #[field(create, rename_column = "usr_mail")]
+            #[column(name = "user_email")]
+            pub email: String
+        });
+        assert!(error.contains("`rename_column` cannot be combined"));
+    }
+
     #[test]
     fn field_column_multiple_attrs() {
         let field = parse_field(quote::quote! {
@@ -12,14 +12,52 @@
 //! | `unique` | `#[column(unique)]` | `UNIQUE` |
 //! | `index` | `#[column(index)]` | `CREATE INDEX` (btree) |
 //! | `index` | `#[column(index = "gin")]` | `CREATE INDEX USING gin` |
+//! | `unique, index` | `#[column(unique, index)]` | `CREATE UNIQUE INDEX` instead of `UNIQUE` |
 //! | `default` | `#[column(default = "true")]` | `DEFAULT true` |
 //! | `check` | `#[column(check = "age >= 0")]` | `CHECK (age >= 0)` |
 //! | `varchar` | `#[column(varchar = 255)]` | `VARCHAR(255)` |
+//! | `citext` | `#[column(citext)]` | `CITEXT` (case-insensitive text) |
+//! | `collation` | `#[column(collation = "en-US-x-icu")]` | `COLLATE "en-US-x-icu"` |
+//! | `storage` | `#[column(storage = "external")]` | `ALTER COLUMN ... SET STORAGE EXTERNAL` |
 //! | `sql_type` | `#[column(sql_type = "JSONB")]` | Explicit type |
 //! | `nullable` | `#[column(nullable)]` | Allow NULL |
 //! | `name` | `#[column(name = "user_name")]` | Custom column name |
+//! | `encrypt` | `#[column(encrypt)]` | Stored as ciphertext via `Encryptor` |
+//! | `transparent` | `#[column(transparent = "String")]` | Newtype stored as its inner type |
+//! | `tsvector` | `#[column(tsvector, sources = [title, body])]` | `TSVECTOR GENERATED ALWAYS AS (...) STORED` + GIN index |
+//! | `decode_with` | `#[column(decode_with = "decode_money")]` | — (Row decoding only) |
+//!
+//! # Custom Decoding
+//!
+//! `decode_with` names a function the Row calls instead of `try_get`,
+//! switching the Row to a hand-written `sqlx::FromRow` impl:
+//!
+//! ```rust,ignore
+//! #[column(decode_with = "decode_money")]
+//! pub balance: Money,
+//!
+//! fn decode_money(row: &PgRow, column: &str) -> Result<Money, sqlx::Error> {
+//!     let (amount, currency): (i64, String) = row.try_get(column)?;
+//!     Ok(Money { amount, currency })
+//! }
+//! ```
+//!
+//! The function receives the dialect's row and the column the Row decodes
+//! (including any `row_prefix`) and returns the field's Row type.
+//!
+//! # Full-Text Search
+//!
+//! `tsvector` turns a `String` field into a search document the database
+//! maintains from the `sources` columns. Sources are weighted `A`, `B`, `C`,
+//! `D` in declaration order (later ones share `D`), so earlier columns rank
+//! higher. The text search configuration defaults to `simple`; name another
+//! with `tsvector = "english"`. The field is never written, is read back as
+//! its text form, and adds a ranked `search` method to the repository.
+
+use syn::{Attribute, Meta, Type};
 
-use syn::{Attribute, Meta};
+/// Text search configuration of `#[column(tsvector)]` when none is named.
+pub const DEFAULT_TS_CONFIG: &str = "simple";
 
 /// Index type for database indexes.
 ///
@@ -74,6 +112,51 @@ impl IndexType {
     }
 }
 
+/// TOAST storage mode of a column.
+///
+/// Applied after `CREATE TABLE` with `ALTER COLUMN ... SET STORAGE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnStorage {
+    /// Inline and uncompressed. For fixed-length types.
+    Plain,
+
+    /// Inline if possible, compressed but never moved out of line first.
+    Main,
+
+    /// Out of line and uncompressed. Fast substring access on large values.
+    External,
+
+    /// Compressed, then moved out of line if still too large.
+    Extended
+}
+
+impl ColumnStorage {
+    /// Parse storage mode from string.
+    ///
+    /// Returns `None` for unrecognized values.
+    #[must_use]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "plain" => Some(Self::Plain),
+            "main" => Some(Self::Main),
+            "external" => Some(Self::External),
+            "extended" => Some(Self::Extended),
+            _ => None
+        }
+    }
+
+    /// Get SQL keyword for this storage mode.
+    #[must_use]
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Self::Plain => "PLAIN",
+            Self::Main => "MAIN",
+            Self::External => "EXTERNAL",
+            Self::Extended => "EXTENDED"
+        }
+    }
+}
+
 /// Referential action for foreign key ON DELETE/ON UPDATE.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReferentialAction {
@@ -159,6 +242,17 @@ pub struct ColumnConfig {
     /// VARCHAR length. Converts `String` to `VARCHAR(n)`.
     pub varchar: Option<usize>,
 
+    /// Map `String` to the case-insensitive `CITEXT` type.
+    ///
+    /// Takes precedence over `varchar`, as `CITEXT` has no length.
+    pub citext: bool,
+
+    /// Collation applied to the column with `COLLATE "..."`.
+    pub collation: Option<String>,
+
+    /// TOAST storage mode set with `ALTER COLUMN ... SET STORAGE`.
+    pub storage: Option<ColumnStorage>,
+
     /// Explicit SQL type override.
     ///
     /// Bypasses automatic type mapping.
@@ -168,7 +262,31 @@ pub struct ColumnConfig {
     pub nullable: bool,
 
     /// Custom column name. Defaults to field name.
-    pub name: Option<String>
+    pub name: Option<String>,
+
+    /// Store this column encrypted through an `entity_core::crypto::Encryptor`.
+    pub encrypt: bool,
+
+    /// Inner type of a single-field newtype stored as that type.
+    ///
+    /// Row and Insertable hold the inner type; values are converted with
+    /// `From` in both directions.
+    pub transparent: Option<Type>,
+
+    /// Text search configuration of a generated `tsvector` column.
+    ///
+    /// `Some` marks the field as a full-text search document built from
+    /// [`sources`](Self::sources).
+    pub tsvector: Option<String>,
+
+    /// Fields the `tsvector` document is built from, in weight order.
+    pub sources: Vec<String>,
+
+    /// Function decoding this column from a row.
+    ///
+    /// Called as `decode(row, column)` by a hand-written `sqlx::FromRow`
+    /// impl in place of `try_get`.
+    pub decode_with: Option<syn::Path>
 }
 
 impl ColumnConfig {
@@ -182,9 +300,19 @@ impl ColumnConfig {
     /// - `default = "expr"` — Set DEFAULT value
     /// - `check = "expr"` — Add CHECK constraint
     /// - `varchar = N` — Use VARCHAR(N) instead of TEXT
+    /// - `citext` — Use CITEXT instead of TEXT
+    /// - `collation = "name"` — Add a COLLATE clause
+    /// - `storage = "mode"` — Set TOAST storage (`plain`, `main`, `external`,
+    ///   `extended`)
     /// - `sql_type = "TYPE"` — Override SQL type
     /// - `nullable` — Allow NULL
     /// - `name = "col"` — Custom column name
+    /// - `encrypt` — Encrypt values at the database boundary
+    /// - `transparent = "Type"` — Bind and decode a newtype as its inner type
+    /// - `tsvector` / `tsvector = "config"` — Generated full-text search column
+    ///   with a GIN index
+    /// - `sources = [a, b]` — Fields indexed by `tsvector`
+    /// - `decode_with = "path"` — Decode the Row column with a custom function
     pub fn from_attr(attr: &Attribute) -> Self {
         let mut config = Self::default();
 
@@ -213,6 +341,16 @@ impl ColumnConfig {
                     let _: syn::Token![=] = meta.input.parse()?;
                     let value: syn::LitInt = meta.input.parse()?;
                     config.varchar = value.base10_parse().ok();
+                } else if meta.path.is_ident("citext") {
+                    config.citext = true;
+                } else if meta.path.is_ident("collation") {
+                    let _: syn::Token![=] = meta.input.parse()?;
+                    let value: syn::LitStr = meta.input.parse()?;
+                    config.collation = Some(value.value());
+                } else if meta.path.is_ident("storage") {
+                    let _: syn::Token![=] = meta.input.parse()?;
+                    let value: syn::LitStr = meta.input.parse()?;
+                    config.storage = ColumnStorage::from_str(&value.value());
                 } else if meta.path.is_ident("sql_type") {
                     let _: syn::Token![=] = meta.input.parse()?;
                     let value: syn::LitStr = meta.input.parse()?;
@@ -223,11 +361,42 @@ impl ColumnConfig {
                     let _: syn::Token![=] = meta.input.parse()?;
                     let value: syn::LitStr = meta.input.parse()?;
                     config.name = Some(value.value());
+                } else if meta.path.is_ident("encrypt") {
+                    config.encrypt = true;
+                } else if meta.path.is_ident("transparent") {
+                    let _: syn::Token![=] = meta.input.parse()?;
+                    let value: syn::LitStr = meta.input.parse()?;
+                    config.transparent = Some(value.parse()?);
+                } else if meta.path.is_ident("tsvector") {
+                    if meta.input.peek(syn::Token![=]) {
+                        let _: syn::Token![=] = meta.input.parse()?;
+                        let value: syn::LitStr = meta.input.parse()?;
+                        config.tsvector = Some(value.value());
+                    } else {
+                        config.tsvector = Some(DEFAULT_TS_CONFIG.to_string());
+                    }
+                } else if meta.path.is_ident("sources") {
+                    let _: syn::Token![=] = meta.input.parse()?;
+                    let content;
+                    syn::bracketed!(content in meta.input);
+                    let sources =
+                        syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated(
+                            &content
+                        )?;
+                    config.sources = sources.iter().map(ToString::to_string).collect();
+                } else if meta.path.is_ident("decode_with") {
+                    let _: syn::Token![=] = meta.input.parse()?;
+                    let value: syn::LitStr = meta.input.parse()?;
+                    config.decode_with = Some(value.parse()?);
                 }
                 Ok(())
             });
         }
 
+        if config.tsvector.is_some() && config.index.is_none() {
+            config.index = Some(IndexType::Gin);
+        }
+
         config
     }
 
@@ -271,9 +440,15 @@ mod tests {
         assert!(config.default.is_none());
         assert!(config.check.is_none());
         assert!(config.varchar.is_none());
+        assert!(!config.citext);
+        assert!(config.collation.is_none());
+        assert!(config.storage.is_none());
         assert!(config.sql_type.is_none());
         assert!(!config.nullable);
         assert!(config.name.is_none());
+        assert!(!config.encrypt);
+        assert!(config.tsvector.is_none());
+        assert!(config.sources.is_empty());
     }
 
     #[test]
@@ -324,6 +499,33 @@ mod tests {
         assert_eq!(config.varchar, Some(255));
     }
 
+    #[test]
+    fn parse_citext_and_collation() {
+        let config = parse_column_attr(quote! { citext, collation = "en-US-x-icu" });
+        assert!(config.citext);
+        assert_eq!(config.collation, Some("en-US-x-icu".to_string()));
+    }
+
+    #[test]
+    fn parse_storage() {
+        let config = parse_column_attr(quote! { storage = "external" });
+        assert_eq!(config.storage, Some(ColumnStorage::External));
+
+        let config = parse_column_attr(quote! { storage = "EXTENDED" });
+        assert_eq!(config.storage, Some(ColumnStorage::Extended));
+
+        let config = parse_column_attr(quote! { storage = "compressed" });
+        assert!(config.storage.is_none());
+    }
+
+    #[test]
+    fn storage_as_sql() {
+        assert_eq!(ColumnStorage::Plain.as_sql(), "PLAIN");
+        assert_eq!(ColumnStorage::Main.as_sql(), "MAIN");
+        assert_eq!(ColumnStorage::External.as_sql(), "EXTERNAL");
+        assert_eq!(ColumnStorage::Extended.as_sql(), "EXTENDED");
+    }
+
     #[test]
     fn parse_sql_type() {
         let config = parse_column_attr(quote! { sql_type = "JSONB" });
@@ -342,6 +544,42 @@ mod tests {
         assert_eq!(config.name, Some("user_name".to_string()));
     }
 
+    #[test]
+    fn parse_encrypt() {
+        let config = parse_column_attr(quote! { encrypt, varchar = 512 });
+        assert!(config.encrypt);
+        assert_eq!(config.varchar, Some(512));
+    }
+
+    #[test]
+    fn parse_transparent() {
+        let config = parse_column_attr(quote! { transparent = "i64", unique });
+        let inner = config.transparent.unwrap();
+        assert_eq!(quote!(#inner).to_string(), "i64");
+        assert!(config.unique);
+    }
+
+    #[test]
+    fn parse_decode_with() {
+        let config = parse_column_attr(quote! { decode_with = "crate::money::decode" });
+        let path = config.decode_with.unwrap();
+        assert_eq!(quote!(#path).to_string(), "crate :: money :: decode");
+    }
+
+    #[test]
+    fn parse_tsvector() {
+        let config = parse_column_attr(quote! { tsvector, sources = [title, body] });
+        assert_eq!(config.tsvector.as_deref(), Some("simple"));
+        assert_eq!(config.sources, vec!["title", "body"]);
+        assert_eq!(config.index, Some(IndexType::Gin));
+    }
+
+    #[test]
+    fn parse_tsvector_config() {
+        let config = parse_column_attr(quote! { tsvector = "english", sources = [title] });
+        assert_eq!(config.tsvector.as_deref(), Some("english"));
+    }
+
     #[test]
     fn parse_multiple_attrs() {
         let config = parse_column_attr(quote! { unique, index = "btree", default = "true" });
@@ -5,8 +5,152 @@
 //!
 //! Controls which DTOs a field appears in: CreateRequest, UpdateRequest,
 //! Response.
+//!
+//! # Precedence
+//!
+//! | Flag | Effect |
+//! |------|--------|
+//! | `skip` | Excluded from every DTO. Overrides all other flags. |
+//! | `skip_response` | Never in `Response`, even for `#[id]` or `response`. |
+//! | `create` / `update` / `response` | Include in that DTO. |
+//!
+//! Sensitive input that must never be echoed back, such as a password that
+//! is hashed server-side, uses `#[field(create, skip_response)]`. Flags from
+//! several `#[field(...)]` attributes on one field are combined.
+//!
+//! # Type Overrides
+//!
+//! A field may use a different type in the Create or Update DTO than in
+//! storage. The value is converted on the way in, via `Into` by default or
+//! via a user function:
+//!
+//! ```rust,ignore
+//! #[field(create, create_type = "rust_decimal::Decimal", create_with = "money::to_cents")]
+//! pub price_cents: i64,
+//!
+//! #[field(update, update_type = "u32")]
+//! pub quantity: i64,
+//! ```
+//!
+//! # Masking
+//!
+//! `mask` keeps a `String` (or `Option<String>`) field in the Response but
+//! transforms its value on the way out. `last4`, `email` and `redact` map to
+//! `entity_core::mask`; any other value is a path to a `fn(&str) -> String`:
+//!
+//! ```rust,ignore
+//! #[field(create, response, mask = "last4")]
+//! pub card_number: String,
+//!
+//! #[field(response, mask = "crate::masks::phone")]
+//! pub phone: Option<String>,
+//! ```
+//!
+//! # String Coercion
+//!
+//! `as_str` exposes a field as `String` (or `Option<String>`) in the
+//! Response, converted with `to_string()`. The field type must implement
+//! `Display`. This keeps an enum a plain string at the API boundary without
+//! custom serde on the shared type:
+//!
+//! ```rust,ignore
+//! #[field(create, response, as_str)]
+//! pub role: Role,
+//! ```
+//!
+//! # String Serialization
+//!
+//! `serialize_as_string` keeps the field type in the Response but writes it
+//! as a JSON string through `entity_core::as_string`, and documents it as a
+//! string in OpenAPI. An `i64` id then survives JavaScript clients, whose
+//! numbers lose precision above 2^53:
+//!
+//! ```rust,ignore
+//! #[id]
+//! #[field(response, serialize_as_string)]
+//! pub id: i64,
+//! ```
+//!
+//! # Write-Once Fields
+//!
+//! `write_once` lets `update` fill a column only while it is `NULL`. The
+//! generated statement sets it to `COALESCE(column, $n)`, so once a value is
+//! stored, later updates leave it unchanged while still applying to the
+//! other fields:
+//!
+//! ```rust,ignore
+//! #[field(update, response, write_once)]
+//! pub onboarded_at: Option<DateTime<Utc>>,
+//! ```
+//!
+//! # Explicit-Null Updates
+//!
+//! `update_type = "Option<Option<T>>"` on an `Option<T>` field tells an
+//! absent key from an explicit `null` in the Update DTO. An absent key
+//! leaves the column unchanged and `null` sets it to `NULL`.
+//! `#[entity(double_option_updates)]` applies this to every nullable
+//! update field:
+//!
+//! ```rust,ignore
+//! #[field(update, response, update_type = "Option<Option<String>>")]
+//! pub bio: Option<String>,
+//! ```
+//!
+//! # Flattened Value Objects
+//!
+//! `flatten` embeds a value object that derives `sqlx::FromRow` itself. The
+//! Row marks the field `#[sqlx(flatten)]`, so one query hydrates the nested
+//! struct from its own columns. Those columns are not known to the entity,
+//! so flattened fields require `sql = "trait"` and hand-written queries:
+//!
+//! ```rust,ignore
+//! #[field(create, response, flatten)]
+//! pub address: Address,
+//! ```
+//!
+//! # Computed Columns
+//!
+//! `returning = "..."` makes the field a SQL expression evaluated by
+//! PostgreSQL instead of a stored column. It is never inserted or updated;
+//! every SELECT and `RETURNING` list carries it as `expr AS column`, so the
+//! Row and entity get the computed value back from `create` and `update`:
+//!
+//! ```rust,ignore
+//! #[field(response, returning = "lower(email)")]
+//! pub normalized_email: String,
+//! ```
+//!
+//! # Renaming
+//!
+//! A field has three names: the Rust identifier, the database column and
+//! the JSON property. The identifier is always the field's own and names it
+//! in every generated struct. `rename_column` sets the column used by all
+//! generated SQL, like `#[column(name = "...")]`, and `rename_api` sets the
+//! property name of the Create, Update and Response DTOs and projections,
+//! which OpenAPI schemas follow:
+//!
+//! ```rust,ignore
+//! #[field(create, update, response, rename_column = "usr_mail", rename_api = "emailAddress")]
+//! pub email: String,
+//! ```
+//!
+//! # Constraints
+//!
+//! `min_len`/`max_len` bound a string's length and `min`/`max` bound a
+//! number. Each bound is emitted twice on the Create and Update DTO field:
+//! as `#[validate(length(...))]` or `#[validate(range(...))]` for runtime
+//! validation, and as the matching `#[schema(...)]` constraint for OpenAPI,
+//! so the two cannot drift apart:
+//!
+//! ```rust,ignore
+//! #[field(create, update, response, max_len = 255)]
+//! pub name: String,
+//!
+//! #[field(create, update, response, min = 0, max = 150)]
+//! pub age: i32,
+//! ```
 
-use syn::{Attribute, Meta};
+use syn::{Attribute, Meta, Path, Type};
 
 /// DTO exposure configuration.
 ///
@@ -18,6 +162,8 @@ use syn::{Attribute, Meta};
 /// #[field(create, update, response)]  // All DTOs
 /// #[field(create, response)]          // Not in UpdateRequest
 /// #[field(skip)]                      // Excluded from all
+/// #[field(create, skip_response)]     // Settable, never returned
+/// #[field(create, create_type = "u32")] // CreateRequest uses u32
 /// ```
 #[derive(Debug, Default, Clone)]
 pub struct ExposeConfig {
@@ -35,11 +181,93 @@ pub struct ExposeConfig {
     /// Exclude from all DTOs.
     ///
     /// Overrides all other flags. Use for sensitive data like passwords.
-    pub skip: bool
+    pub skip: bool,
+
+    /// Exclude from `Response` DTO only.
+    ///
+    /// Takes precedence over `response` and the implicit `#[id]` inclusion.
+    pub skip_response: bool,
+
+    /// Type of this field in `CreateRequest` (`create_type = "..."`).
+    ///
+    /// Converted into the storage type when the entity is built.
+    pub create_type: Option<Type>,
+
+    /// Conversion function for `create_type` (`create_with = "..."`).
+    ///
+    /// Called as `f(dto.field)`. Defaults to `Into::into`.
+    pub create_with: Option<Path>,
+
+    /// Type of this field in `UpdateRequest` (`update_type = "..."`).
+    ///
+    /// Wrapped in `Option<T>` unless already optional, and converted into
+    /// the storage type before binding.
+    pub update_type: Option<Type>,
+
+    /// Conversion function for `update_type` (`update_with = "..."`).
+    ///
+    /// Called as `f(value)` on the provided value. Defaults to `From::from`.
+    pub update_with: Option<Path>,
+
+    /// Mask function applied when mapping to `Response` (`mask = "..."`).
+    ///
+    /// Built-in names are resolved to `entity_derive::mask::*`.
+    pub mask: Option<Path>,
+
+    /// Only set by `update` while the stored value is `NULL`
+    /// (`write_once`).
+    pub write_once: bool,
+
+    /// Exposed as `String` in `Response`, converted with `to_string()`
+    /// (`as_str`).
+    pub as_str: bool,
+
+    /// Serialized as a string in `Response`, keeping the field type
+    /// (`serialize_as_string`).
+    pub serialize_as_string: bool,
+
+    /// `Option<Option<T>>` in `UpdateRequest`, where `Some(None)` sets the
+    /// column to `NULL`.
+    ///
+    /// Set from `update_type = "Option<Option<T>>"` or
+    /// `#[entity(double_option_updates)]`, not parsed as a flag.
+    pub double_option: bool,
+
+    /// Decoded by the Row with `#[sqlx(flatten)]` (`flatten`).
+    pub flatten: bool,
+
+    /// SQL expression selected in place of a stored column
+    /// (`returning = "..."`).
+    ///
+    /// Passed through verbatim as `expr AS column`.
+    pub returning: Option<String>,
+
+    /// Minimum string length in request DTOs (`min_len = N`).
+    pub min_len: Option<usize>,
+
+    /// Maximum string length in request DTOs (`max_len = N`).
+    pub max_len: Option<usize>,
+
+    /// Minimum numeric value in request DTOs (`min = N`).
+    pub min: Option<i64>,
+
+    /// Maximum numeric value in request DTOs (`max = N`).
+    pub max: Option<i64>,
+
+    /// Database column name (`rename_column = "..."`).
+    ///
+    /// Moved into the field's `ColumnConfig` when the field is parsed.
+    pub rename_column: Option<String>,
+
+    /// JSON property name in DTOs (`rename_api = "..."`).
+    pub rename_api: Option<String>
 }
 
 impl ExposeConfig {
-    /// Parse exposure config from `#[field(...)]` attribute.
+    /// Merge options from a `#[field(...)]` attribute into this config.
+    ///
+    /// Boolean flags are combined across attributes; type overrides are
+    /// replaced when present.
     ///
     /// # Recognized options
     ///
@@ -47,25 +275,78 @@ impl ExposeConfig {
     /// - `update` → include in UpdateRequest
     /// - `response` → include in Response
     /// - `skip` → exclude from all DTOs
-    pub fn from_attr(attr: &Attribute) -> Self {
-        let mut config = Self::default();
-
+    /// - `skip_response` → exclude from Response only
+    /// - `create_type = "T"` / `create_with = "path"` → Create DTO type
+    /// - `update_type = "T"` / `update_with = "path"` → Update DTO type
+    /// - `mask = "last4" | "email" | "redact" | "path"` → mask in Response
+    /// - `write_once` → update only fills the column while it is `NULL`
+    /// - `as_str` → `String` in Response via `Display`
+    /// - `serialize_as_string` → JSON string in Response, same Rust type
+    /// - `flatten` → Row decodes the value object with `#[sqlx(flatten)]`
+    /// - `returning = "expr"` → computed by the database, never written
+    /// - `min_len = N` / `max_len = N` → string length bounds
+    /// - `min = N` / `max = N` → numeric bounds
+    /// - `rename_column = "name"` → database column name
+    /// - `rename_api = "name"` → JSON property name in DTOs
+    pub fn merge_attr(&mut self, attr: &Attribute) {
         if let Meta::List(meta_list) = &attr.meta {
             let _ = meta_list.parse_nested_meta(|meta| {
                 if meta.path.is_ident("create") {
-                    config.create = true;
+                    self.create = true;
                 } else if meta.path.is_ident("update") {
-                    config.update = true;
+                    self.update = true;
                 } else if meta.path.is_ident("response") {
-                    config.response = true;
+                    self.response = true;
                 } else if meta.path.is_ident("skip") {
-                    config.skip = true;
+                    self.skip = true;
+                } else if meta.path.is_ident("skip_response") {
+                    self.skip_response = true;
+                } else if meta.path.is_ident("write_once") {
+                    self.write_once = true;
+                } else if meta.path.is_ident("as_str") {
+                    self.as_str = true;
+                } else if meta.path.is_ident("serialize_as_string") {
+                    self.serialize_as_string = true;
+                } else if meta.path.is_ident("flatten") {
+                    self.flatten = true;
+                } else if meta.path.is_ident("create_type") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    self.create_type = Some(value.parse()?);
+                } else if meta.path.is_ident("create_with") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    self.create_with = Some(value.parse()?);
+                } else if meta.path.is_ident("update_type") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    self.update_type = Some(value.parse()?);
+                } else if meta.path.is_ident("update_with") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    self.update_with = Some(value.parse()?);
+                } else if meta.path.is_ident("mask") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    self.mask = Some(mask_path(&value)?);
+                } else if meta.path.is_ident("returning") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    self.returning = Some(value.value());
+                } else if meta.path.is_ident("rename_column") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    self.rename_column = Some(value.value());
+                } else if meta.path.is_ident("rename_api") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    self.rename_api = Some(value.value());
+                } else if meta.path.is_ident("min_len") {
+                    let value: syn::LitInt = meta.value()?.parse()?;
+                    self.min_len = Some(value.base10_parse()?);
+                } else if meta.path.is_ident("max_len") {
+                    let value: syn::LitInt = meta.value()?.parse()?;
+                    self.max_len = Some(value.base10_parse()?);
+                } else if meta.path.is_ident("min") {
+                    self.min = Some(parse_signed(meta.value()?)?);
+                } else if meta.path.is_ident("max") {
+                    self.max = Some(parse_signed(meta.value()?)?);
                 }
                 Ok(())
             });
         }
-
-        config
     }
 
     /// Check if field should appear in CreateRequest.
@@ -79,12 +360,67 @@ impl ExposeConfig {
     pub fn in_update(&self) -> bool {
         !self.skip && self.update
     }
+
+    /// Check if field is allowed in Response.
+    ///
+    /// The caller decides inclusion (`response` or `#[id]`); this only
+    /// applies the `skip` and `skip_response` exclusions.
+    #[must_use]
+    pub fn allows_response(&self) -> bool {
+        !self.skip && !self.skip_response
+    }
+}
+
+/// Find a `#[field(...)]` flag by name, for error spans.
+///
+/// Returns the path of the first occurrence across all `#[field]`
+/// attributes of the field.
+pub fn find_flag(attrs: &[Attribute], name: &str) -> Option<Path> {
+    let mut found = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("field")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if found.is_none() && meta.path.is_ident(name) {
+                found = Some(meta.path.clone());
+            }
+            if meta.input.peek(syn::Token![=]) {
+                let _: syn::Expr = meta.value()?.parse()?;
+            }
+            Ok(())
+        });
+        if found.is_some() {
+            break;
+        }
+    }
+    found
+}
+
+/// Parse an integer literal with an optional leading minus sign.
+fn parse_signed(input: syn::parse::ParseStream<'_>) -> syn::Result<i64> {
+    let negative = input.parse::<Option<syn::Token![-]>>()?.is_some();
+    let value: i64 = input.parse::<syn::LitInt>()?.base10_parse()?;
+    Ok(if negative { -value } else { value })
+}
+
+/// Resolve a `mask = "..."` value to the function to call.
+fn mask_path(value: &syn::LitStr) -> syn::Result<Path> {
+    match value.value().as_str() {
+        "last4" => Ok(syn::parse_quote!(entity_derive::mask::last4)),
+        "email" => Ok(syn::parse_quote!(entity_derive::mask::email)),
+        "redact" => Ok(syn::parse_quote!(entity_derive::mask::redact)),
+        _ => value.parse()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn parse(attr: &Attribute) -> ExposeConfig {
+        let mut config = ExposeConfig::default();
+        config.merge_attr(attr);
+        config
+    }
+
     #[test]
     fn default_is_all_false() {
         let config = ExposeConfig::default();
@@ -97,14 +433,137 @@ mod tests {
     #[test]
     fn skip_overrides_all() {
         let config = ExposeConfig {
-            create:   true,
-            update:   true,
+            create: true,
+            update: true,
             response: true,
-            skip:     true
+            skip: true,
+            ..Default::default()
         };
         assert!(!config.in_create());
         assert!(!config.in_update());
         // response check is done via FieldDef::in_response() which includes ID logic
         assert!(config.skip);
     }
+
+    #[test]
+    fn skip_response_keeps_create() {
+        let attr: Attribute = syn::parse_quote!(#[field(create, response, skip_response)]);
+        let config = parse(&attr);
+        assert!(config.in_create());
+        assert!(!config.allows_response());
+    }
+
+    #[test]
+    fn merges_multiple_attrs() {
+        let first: Attribute = syn::parse_quote!(#[field(create)]);
+        let second: Attribute = syn::parse_quote!(#[field(skip_response)]);
+        let mut config = parse(&first);
+        config.merge_attr(&second);
+        assert!(config.in_create());
+        assert!(config.skip_response);
+    }
+
+    #[test]
+    fn default_has_no_type_overrides() {
+        let config = ExposeConfig::default();
+        assert!(config.create_type.is_none());
+        assert!(config.create_with.is_none());
+        assert!(config.update_type.is_none());
+        assert!(config.update_with.is_none());
+    }
+
+    #[test]
+    fn parses_type_overrides() {
+        let attr: Attribute = syn::parse_quote!(
+            #[field(
+                create,
+                update,
+                create_type = "rust_decimal::Decimal",
+                create_with = "money::to_cents",
+                update_type = "u32"
+            )]
+        );
+        let config = parse(&attr);
+        assert!(config.create && config.update);
+        let create_ty = config.create_type.unwrap();
+        assert_eq!(
+            quote::quote!(#create_ty).to_string(),
+            "rust_decimal :: Decimal"
+        );
+        let create_with = config.create_with.unwrap();
+        assert_eq!(quote::quote!(#create_with).to_string(), "money :: to_cents");
+        let update_ty = config.update_type.unwrap();
+        assert_eq!(quote::quote!(#update_ty).to_string(), "u32");
+        assert!(config.update_with.is_none());
+    }
+
+    #[test]
+    fn mask_builtin_resolves_to_core() {
+        let attr: Attribute = syn::parse_quote!(#[field(response, mask = "last4")]);
+        let mask = parse(&attr).mask.unwrap();
+        assert_eq!(
+            quote::quote!(#mask).to_string(),
+            "entity_derive :: mask :: last4"
+        );
+    }
+
+    #[test]
+    fn as_str_flag() {
+        let attr: Attribute = syn::parse_quote!(#[field(response, as_str)]);
+        assert!(parse(&attr).as_str);
+        assert!(!ExposeConfig::default().as_str);
+    }
+
+    #[test]
+    fn serialize_as_string_flag() {
+        let attr: Attribute = syn::parse_quote!(#[field(response, serialize_as_string)]);
+        assert!(parse(&attr).serialize_as_string);
+        assert!(!ExposeConfig::default().serialize_as_string);
+    }
+
+    #[test]
+    fn flatten_flag() {
+        let attr: Attribute = syn::parse_quote!(#[field(create, response, flatten)]);
+        assert!(parse(&attr).flatten);
+        assert!(!ExposeConfig::default().flatten);
+    }
+
+    #[test]
+    fn returning_expression() {
+        let attr: Attribute = syn::parse_quote!(#[field(response, returning = "lower(email)")]);
+        assert_eq!(parse(&attr).returning.as_deref(), Some("lower(email)"));
+        assert!(ExposeConfig::default().returning.is_none());
+    }
+
+    #[test]
+    fn length_and_range_bounds() {
+        let attr: Attribute = syn::parse_quote!(
+            #[field(create, update, min_len = 1, max_len = 255, min = -10, max = 150)]
+        );
+        let config = parse(&attr);
+        assert_eq!(config.min_len, Some(1));
+        assert_eq!(config.max_len, Some(255));
+        assert_eq!(config.min, Some(-10));
+        assert_eq!(config.max, Some(150));
+        assert!(ExposeConfig::default().max_len.is_none());
+    }
+
+    #[test]
+    fn column_and_api_renames() {
+        let attr: Attribute = syn::parse_quote!(
+            #[field(create, rename_column = "usr_mail", rename_api = "emailAddress")]
+        );
+        let config = parse(&attr);
+        assert!(config.create);
+        assert_eq!(config.rename_column.as_deref(), Some("usr_mail"));
+        assert_eq!(config.rename_api.as_deref(), Some("emailAddress"));
+        assert!(ExposeConfig::default().rename_api.is_none());
+    }
+
+    #[test]
+    fn mask_custom_path() {
+        let attr: Attribute = syn::parse_quote!(#[field(response, mask = "crate::masks::phone")]);
+        let mask = parse(&attr).mask.unwrap();
+        assert_eq!(quote::quote!(#mask).to_string(), "crate :: masks :: phone");
+    }
 }
@@ -34,12 +34,19 @@ pub enum FilterType {
     /// Generates two optional fields:
     /// - `field_from`: `WHERE field >= $n`
     /// - `field_to`: `WHERE field <= $n`
-    Range
+    Range,
+
+    /// Overlap filter for `PgRange<T>` columns.
+    ///
+    /// Generates `field_overlaps: Option<(T, T)>`, bound as the half-open
+    /// range `[start, end)`: `WHERE field && $n`
+    Overlaps
 }
 
 /// Filter configuration for a field.
 ///
-/// Parsed from `#[filter]`, `#[filter(like)]`, or `#[filter(range)]`.
+/// Parsed from `#[filter]`, `#[filter(like)]`, `#[filter(range)]` or
+/// `#[filter(overlaps)]`.
 #[derive(Debug, Clone, Default)]
 pub struct FilterConfig {
     /// The type of filter to apply.
@@ -55,6 +62,7 @@ impl FilterConfig {
     /// - `#[filter(eq)]` — exact match (explicit)
     /// - `#[filter(like)]` — LIKE pattern match
     /// - `#[filter(range)]` — range filter (from/to)
+    /// - `#[filter(overlaps)]` — overlap with a `(start, end)` range
     pub fn from_attr(attr: &Attribute) -> Self {
         let filter_type = attr
             .parse_args_with(|input: syn::parse::ParseStream<'_>| {
@@ -66,6 +74,7 @@ impl FilterConfig {
                 "eq" => FilterType::Eq,
                 "like" => FilterType::Like,
                 "range" => FilterType::Range,
+                "overlaps" => FilterType::Overlaps,
                 _ => FilterType::Eq
             })
             .unwrap_or(FilterType::Eq);
@@ -109,6 +118,11 @@ mod tests {
         };
         assert!(range.has_filter());
 
+        let overlaps = FilterConfig {
+            filter_type: FilterType::Overlaps
+        };
+        assert!(overlaps.has_filter());
+
         let none = FilterConfig {
             filter_type: FilterType::None
         };
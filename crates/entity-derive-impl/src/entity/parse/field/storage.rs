@@ -44,12 +44,15 @@ pub struct StorageConfig {
     ///
     /// Effects:
     /// - Auto-generates UUID (v7 or v4 based on entity config)
-    /// - Always included in Response DTO
+    /// - Included in Response DTO unless `#[field(skip_response)]`
     /// - Excluded from CreateRequest and UpdateRequest
     pub is_id: bool,
 
     /// Auto-generated field (`#[auto]`).
     ///
+    /// Also set for `created_at`/`updated_at` datetime fields that are not
+    /// listed in `create` or `update`.
+    ///
     /// Effects:
     /// - Gets `Default::default()` in From implementations
     /// - Excluded from CreateRequest and UpdateRequest
@@ -7,6 +7,17 @@
 //! - Passing through to generated DTOs
 //! - Converting to OpenAPI schema constraints
 //!
+//! `#[validate(...)]` rules apply to both request DTOs.
+//! `#[validate_create(...)]` and `#[validate_update(...)]` rules apply to
+//! the Create or Update DTO only:
+//!
+//! ```rust,ignore
+//! #[field(create, update)]
+//! #[validate_create(length(min = 8))]
+//! #[validate_update(length(min = 12))]
+//! pub password: String,
+//! ```
+//!
 //! # Supported Validators
 //!
 //! | Validator | OpenAPI Constraint |
@@ -63,10 +74,31 @@ pub struct ValidationConfig {
     pub pattern: Option<String>,
 
     /// Raw validate attributes to pass through.
-    pub raw_attrs: Vec<TokenStream>
+    pub raw_attrs: Vec<TokenStream>,
+
+    /// Rules of `#[validate(...)]`, e.g. `length(min = 1)`.
+    pub rules: Vec<TokenStream>,
+
+    /// Rules of `#[validate_create(...)]`.
+    pub create_rules: Vec<TokenStream>,
+
+    /// Rules of `#[validate_update(...)]`.
+    pub update_rules: Vec<TokenStream>
 }
 
 impl ValidationConfig {
+    /// Validator rules for the Create DTO field.
+    #[must_use]
+    pub fn create_validators(&self) -> Vec<&TokenStream> {
+        self.rules.iter().chain(&self.create_rules).collect()
+    }
+
+    /// Validator rules for the Update DTO field.
+    #[must_use]
+    pub fn update_validators(&self) -> Vec<&TokenStream> {
+        self.rules.iter().chain(&self.update_rules).collect()
+    }
+
     /// Check if any validation is configured.
     #[must_use]
     #[allow(dead_code)] // Will be used when generating schema constraints
@@ -121,16 +153,31 @@ impl ValidationConfig {
 /// Parse validation attributes from a field.
 ///
 /// Extracts all `#[validate(...)]` attributes and parses their content.
+/// `#[validate_create(...)]` and `#[validate_update(...)]` rules are kept
+/// as written, without schema constraints.
 pub fn parse_validation_attrs(attrs: &[Attribute]) -> ValidationConfig {
     let mut config = ValidationConfig::default();
 
     for attr in attrs {
+        let rules = match attr.meta.require_list() {
+            Ok(list) => list.tokens.clone(),
+            Err(_) => continue
+        };
+        if attr.path().is_ident("validate_create") {
+            config.create_rules.push(rules);
+            continue;
+        }
+        if attr.path().is_ident("validate_update") {
+            config.update_rules.push(rules);
+            continue;
+        }
         if !attr.path().is_ident("validate") {
             continue;
         }
 
         // Store raw attribute for passthrough
         config.raw_attrs.push(quote! { #attr });
+        config.rules.push(rules);
 
         // Parse the attribute content
         let _ = attr.parse_nested_meta(|meta| {
@@ -306,6 +353,40 @@ mod tests {
         assert!(config.has_validation());
     }
 
+    #[test]
+    fn per_operation_rules() {
+        let attrs = parse_attrs(
+            r#"
+            struct Foo {
+                #[validate(does_not_contain(pattern = " "))]
+                #[validate_create(length(min = 8))]
+                #[validate_update(length(min = 12))]
+                password: String,
+            }
+        "#
+        );
+        let config = parse_validation_attrs(&attrs);
+        let create: Vec<String> = config
+            .create_validators()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let update: Vec<String> = config
+            .update_validators()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            create,
+            ["does_not_contain (pattern = \" \")", "length (min = 8)"]
+        );
+        assert_eq!(
+            update,
+            ["does_not_contain (pattern = \" \")", "length (min = 12)"]
+        );
+        assert_eq!(config.min_length, None);
+    }
+
     #[test]
     fn schema_attrs_generation() {
         let config = ValidationConfig {
@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Transaction isolation level configuration.
+//!
+//! This module defines [`IsolationLevel`], parsed from
+//! `#[entity(transactions, isolation = "...")]`. The generated `with_*()`
+//! builder method raises the transaction to this level.
+
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Isolation level required by an entity's transactions.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[entity(table = "accounts", transactions, isolation = "serializable")]
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    /// `READ UNCOMMITTED`.
+    ReadUncommitted,
+
+    /// `READ COMMITTED`.
+    ReadCommitted,
+
+    /// `REPEATABLE READ`.
+    RepeatableRead,
+
+    /// `SERIALIZABLE`.
+    Serializable
+}
+
+impl IsolationLevel {
+    /// Path to the matching `entity_derive::transaction::IsolationLevel`
+    /// variant.
+    pub fn to_tokens(self) -> TokenStream {
+        match self {
+            Self::ReadUncommitted => {
+                quote! { entity_derive::transaction::IsolationLevel::ReadUncommitted }
+            }
+            Self::ReadCommitted => {
+                quote! { entity_derive::transaction::IsolationLevel::ReadCommitted }
+            }
+            Self::RepeatableRead => {
+                quote! { entity_derive::transaction::IsolationLevel::RepeatableRead }
+            }
+            Self::Serializable => {
+                quote! { entity_derive::transaction::IsolationLevel::Serializable }
+            }
+        }
+    }
+}
+
+impl FromMeta for IsolationLevel {
+    /// Parse isolation level from string attribute value.
+    ///
+    /// # Accepted Values
+    ///
+    /// - `"read_uncommitted"` → [`IsolationLevel::ReadUncommitted`]
+    /// - `"read_committed"` → [`IsolationLevel::ReadCommitted`]
+    /// - `"repeatable_read"` → [`IsolationLevel::RepeatableRead`]
+    /// - `"serializable"` → [`IsolationLevel::Serializable`]
+    ///
+    /// Values are case-insensitive; spaces may be used instead of
+    /// underscores (`"repeatable read"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `darling::Error::unknown_value` for unrecognized values.
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value.to_lowercase().replace(' ', "_").as_str() {
+            "read_uncommitted" => Ok(Self::ReadUncommitted),
+            "read_committed" => Ok(Self::ReadCommitted),
+            "repeatable_read" => Ok(Self::RepeatableRead),
+            "serializable" => Ok(Self::Serializable),
+            _ => Err(darling::Error::unknown_value(value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_meta_values() {
+        assert_eq!(
+            IsolationLevel::from_string("read_uncommitted").unwrap(),
+            IsolationLevel::ReadUncommitted
+        );
+        assert_eq!(
+            IsolationLevel::from_string("READ COMMITTED").unwrap(),
+            IsolationLevel::ReadCommitted
+        );
+        assert_eq!(
+            IsolationLevel::from_string("repeatable_read").unwrap(),
+            IsolationLevel::RepeatableRead
+        );
+        assert_eq!(
+            IsolationLevel::from_string("Serializable").unwrap(),
+            IsolationLevel::Serializable
+        );
+    }
+
+    #[test]
+    fn from_meta_invalid() {
+        assert!(IsolationLevel::from_string("snapshot").is_err());
+    }
+
+    #[test]
+    fn tokens_name_core_variant() {
+        assert_eq!(
+            IsolationLevel::Serializable.to_tokens().to_string(),
+            "entity_derive :: transaction :: IsolationLevel :: Serializable"
+        );
+    }
+}
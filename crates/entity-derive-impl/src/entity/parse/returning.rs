@@ -18,9 +18,10 @@ use darling::FromMeta;
 ///
 /// | Mode | RETURNING Clause | Use Case |
 /// |------|-----------------|----------|
-/// | `Full` | `RETURNING *` | Need all fields including DB-generated |
+/// | `Full` | `RETURNING col1, col2, ...` | Need all fields including DB-generated |
 /// | `Id` | `RETURNING id` | Only need to confirm the ID |
 /// | `None` | (no RETURNING) | Fire-and-forget, return pre-built entity |
+/// | `Minimal` | (no RETURNING) | Hot insert paths, `create` returns the id |
 ///
 /// # Examples
 ///
@@ -33,13 +34,16 @@ use darling::FromMeta;
 ///
 /// // None - don't fetch anything back (fastest)
 /// #[entity(table = "users", returning = "none")]
+///
+/// // Minimal - no RETURNING, `create` returns only the id
+/// #[entity(table = "events", returning = "minimal")]
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum ReturningMode {
     /// Return all fields from the database.
     ///
-    /// Uses `RETURNING *` to fetch the complete row including any
-    /// database-generated values (sequences, triggers, defaults).
+    /// Lists every Row column in `RETURNING` to fetch the complete row,
+    /// including any database-generated values (sequences, triggers, defaults).
     /// This is the safest option when the DB might modify data.
     #[default]
     Full,
@@ -57,6 +61,13 @@ pub enum ReturningMode {
     /// (triggers, default values, etc.).
     None,
 
+    /// Don't use RETURNING clause and return only the id from `create`.
+    ///
+    /// Skips building and marshaling the entity on the way out. The id must
+    /// be known client-side, e.g. a UUID generated in `From<CreateRequest>`.
+    /// Updates behave as in [`None`](Self::None).
+    Minimal,
+
     /// Return specific columns.
     ///
     /// Uses `RETURNING col1, col2, ...` to fetch only specified fields.
@@ -73,6 +84,7 @@ impl FromMeta for ReturningMode {
     /// - `"full"` → [`ReturningMode::Full`]
     /// - `"id"` → [`ReturningMode::Id`]
     /// - `"none"` → [`ReturningMode::None`]
+    /// - `"minimal"` → [`ReturningMode::Minimal`]
     /// - `"col1, col2, col3"` → [`ReturningMode::Custom`] with specified
     ///   columns
     ///
@@ -90,6 +102,7 @@ impl FromMeta for ReturningMode {
             "full" => Ok(ReturningMode::Full),
             "id" => Ok(ReturningMode::Id),
             "none" => Ok(ReturningMode::None),
+            "minimal" => Ok(ReturningMode::Minimal),
             _ => {
                 // Parse as comma-separated column list
                 let columns: Vec<String> = value
@@ -137,6 +150,10 @@ mod tests {
             ReturningMode::from_string("NONE").unwrap(),
             ReturningMode::None
         );
+        assert_eq!(
+            ReturningMode::from_string("minimal").unwrap(),
+            ReturningMode::Minimal
+        );
     }
 
     #[test]
@@ -23,6 +23,11 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
     let create_dto = entity.ident_with("Create", "Request");
     let update_dto = entity.ident_with("Update", "Request");
     let id_type = entity.id_field().ty();
+    let created = if entity.create_returns_id() {
+        quote! { #id_type }
+    } else {
+        quote! { #entity_name }
+    };
 
     let doc = format!(
         "Repository wrapper for [`{entity_name}`] with authorization checks.\n\n\
@@ -66,7 +71,7 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
                 &self,
                 dto: #create_dto,
                 ctx: &P::Context,
-            ) -> Result<#entity_name, ::entity_core::policy::PolicyError<R::Error, P::Error>> {
+            ) -> Result<#created, ::entity_core::policy::PolicyError<R::Error, P::Error>> {
                 self.policy
                     .can_create(&dto, ctx)
                     .await
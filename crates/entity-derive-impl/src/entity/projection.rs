@@ -28,12 +28,21 @@
 //!
 //! For each projection, generates:
 //! - `{Entity}{Projection}` struct with specified fields
-//! - `From<{Entity}> for {Entity}{Projection}` implementation
+//! - `From<{Entity}>` and `From<&{Entity}>` implementations
+//! - `From<{Entity}Row>` and `From<&{Entity}Row>` implementations (unless `sql
+//!   = "none"`), so a full fetch can be narrowed without re-querying
+//!
+//! A `#[column(transparent = "...")]` field is decoded as its inner type
+//! through `#[sqlx(try_from = "...")]`. sqlx cannot convert inside `Option`,
+//! so nullable transparent fields cannot be projected.
 
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{ToTokens, format_ident, quote};
 
-use super::parse::EntityDef;
+use super::{
+    parse::{EntityDef, FieldDef, SqlLevel},
+    row::sqlx_cfg
+};
 use crate::utils::marker;
 
 /// Generates all projection structs for the entity.
@@ -50,11 +59,12 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
     quote! { #(#projections)* }
 }
 
-/// Generate a single projection struct and its From impl.
+/// Generate a single projection struct and its From impls.
 fn generate_projection(entity: &EntityDef, proj: &super::parse::ProjectionDef) -> TokenStream {
     let vis = &entity.vis;
     let entity_name = entity.name();
     let proj_name = format_ident!("{}{}", entity_name, proj.name);
+    let sqlx_cfg = sqlx_cfg(entity);
 
     let field_defs: Vec<TokenStream> = proj
         .fields
@@ -67,7 +77,20 @@ fn generate_projection(entity: &EntityDef, proj: &super::parse::ProjectionDef) -
                 .map(|f| {
                     let n = f.name();
                     let t = f.ty();
-                    quote! { pub #n: #t }
+                    let rename = f.api_rename_attrs(false);
+                    match f.transparent_inner() {
+                        Some(inner) => {
+                            let value_type = f.api_value_type_attr(t);
+                            let inner = inner.to_token_stream().to_string();
+                            quote! {
+                                #rename
+                                #value_type
+                                #[cfg_attr(#sqlx_cfg, sqlx(try_from = #inner))]
+                                pub #n: #t
+                            }
+                        }
+                        None => quote! { #rename pub #n: #t }
+                    }
                 })
         })
         .collect();
@@ -76,28 +99,74 @@ fn generate_projection(entity: &EntityDef, proj: &super::parse::ProjectionDef) -
         return TokenStream::new();
     }
 
-    let field_mappings: Vec<TokenStream> = proj
+    let projected: Vec<&FieldDef> = proj
         .fields
         .iter()
-        .filter_map(|field_name| {
-            entity
-                .fields
-                .iter()
-                .find(|f| f.name() == field_name)
-                .map(|f| {
-                    let n = f.name();
-                    quote! { #n: value.#n.clone() }
-                })
+        .filter_map(|field_name| entity.fields.iter().find(|f| f.name() == field_name))
+        .collect();
+    let field_moves: Vec<TokenStream> = projected
+        .iter()
+        .map(|f| {
+            let n = f.name();
+            quote! { #n: value.#n }
+        })
+        .collect();
+    let field_clones: Vec<TokenStream> = projected
+        .iter()
+        .map(|f| {
+            let n = f.name();
+            quote! { #n: value.#n.clone() }
+        })
+        .collect();
+    let row_moves: Vec<TokenStream> = projected
+        .iter()
+        .map(|f| {
+            let n = f.name();
+            let value = f.db_to_field(quote! { value.#n });
+            quote! { #n: #value }
+        })
+        .collect();
+    let row_clones: Vec<TokenStream> = projected
+        .iter()
+        .map(|f| {
+            let n = f.name();
+            let value = f.db_to_field(quote! { value.#n.clone() });
+            quote! { #n: #value }
         })
         .collect();
 
     let marker = marker::generated();
 
+    let from_row = if entity.sql == SqlLevel::None {
+        TokenStream::new()
+    } else {
+        let row_name = entity.ident_with("", "Row");
+        quote! {
+            #marker
+            impl From<#row_name> for #proj_name {
+                fn from(value: #row_name) -> Self {
+                    Self {
+                        #(#row_moves),*
+                    }
+                }
+            }
+
+            #marker
+            impl From<&#row_name> for #proj_name {
+                fn from(value: &#row_name) -> Self {
+                    Self {
+                        #(#row_clones),*
+                    }
+                }
+            }
+        }
+    };
+
     quote! {
         #marker
         #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
         #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
-        #[cfg_attr(feature = "postgres", derive(sqlx::FromRow))]
+        #[cfg_attr(#sqlx_cfg, derive(sqlx::FromRow))]
         #vis struct #proj_name {
             #(#field_defs),*
         }
@@ -106,7 +175,7 @@ fn generate_projection(entity: &EntityDef, proj: &super::parse::ProjectionDef) -
         impl From<#entity_name> for #proj_name {
             fn from(value: #entity_name) -> Self {
                 Self {
-                    #(#field_mappings),*
+                    #(#field_moves),*
                 }
             }
         }
@@ -115,9 +184,11 @@ fn generate_projection(entity: &EntityDef, proj: &super::parse::ProjectionDef) -
         impl From<&#entity_name> for #proj_name {
             fn from(value: &#entity_name) -> Self {
                 Self {
-                    #(#field_mappings),*
+                    #(#field_clones),*
                 }
             }
         }
+
+        #from_row
     }
 }
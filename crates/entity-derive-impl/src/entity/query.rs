@@ -25,6 +25,10 @@
 //!     #[auto]
 //!     #[filter(range)]
 //!     pub created_at: DateTime<Utc>,
+//!
+//!     #[field(create, response)]
+//!     #[filter(overlaps)]
+//!     pub during: PgRange<DateTime<Utc>>,
 //! }
 //! ```
 //!
@@ -36,16 +40,36 @@
 //!     pub name: Option<String>,
 //!     pub created_at_from: Option<DateTime<Utc>>,
 //!     pub created_at_to: Option<DateTime<Utc>>,
+//!     pub during_overlaps: Option<(DateTime<Utc>, DateTime<Utc>)>,
 //!     pub limit: Option<i64>,
 //!     pub offset: Option<i64>,
 //! }
 //! ```
+//!
+//! Each field carries a doc comment describing its filter, which becomes
+//! the parameter description in OpenAPI.
+//!
+//! Every field is optional, so unset filters can be left to `Default`:
+//!
+//! ```rust,ignore
+//! let query = UserQuery {
+//!     name: Some("alice".into()),
+//!     ..Default::default()
+//! };
+//! ```
+//!
+//! # List Query Parameters
+//!
+//! With `api(list_query)`, the struct also derives `utoipa::IntoParams`
+//! as query parameters, and the generated list handler extracts it from
+//! the query string. `limit` is documented with the `max_limit` bound the
+//! handler clamps it to.
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use super::parse::{EntityDef, FilterType};
-use crate::utils::marker;
+use super::parse::{DeriveTarget, EntityDef, FieldDef, FilterType};
+use crate::utils::{derives, marker};
 
 /// Generates the query struct for the entity.
 ///
@@ -58,6 +82,8 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
     let vis = &entity.vis;
     let query_name = entity.ident_with("", "Query");
 
+    let api_config = entity.api_config();
+
     let filter_fields = entity.filter_fields();
     let field_defs: Vec<TokenStream> = filter_fields
         .iter()
@@ -65,19 +91,34 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
             let name = f.name();
             let ty = f.ty();
             let filter = f.filter();
+            let value_type = value_type_attrs(f, api_config.list_query);
 
             match filter.filter_type {
-                FilterType::Eq | FilterType::Like => {
-                    vec![quote! { pub #name: Option<#ty> }]
+                FilterType::Eq => {
+                    let doc = format!(" Only `{}` equal to this value.", name);
+                    vec![quote! { #[doc = #doc] #value_type pub #name: Option<#ty> }]
+                }
+                FilterType::Like => {
+                    let doc = format!(" Only `{}` containing this text, case-insensitive.", name);
+                    vec![quote! { #[doc = #doc] #value_type pub #name: Option<#ty> }]
                 }
                 FilterType::Range => {
                     let from_name = format_ident!("{}_from", name);
                     let to_name = format_ident!("{}_to", name);
+                    let from_doc =
+                        format!(" Only `{}` greater than or equal to this value.", name);
+                    let to_doc = format!(" Only `{}` less than or equal to this value.", name);
                     vec![
-                        quote! { pub #from_name: Option<#ty> },
-                        quote! { pub #to_name: Option<#ty> },
+                        quote! { #[doc = #from_doc] #value_type pub #from_name: Option<#ty> },
+                        quote! { #[doc = #to_doc] #value_type pub #to_name: Option<#ty> },
                     ]
                 }
+                FilterType::Overlaps => {
+                    let overlaps_name = format_ident!("{}_overlaps", name);
+                    let element = f.range_element();
+                    let doc = format!(" Only `{}` overlapping `[start, end)`.", name);
+                    vec![quote! { #[doc = #doc] pub #overlaps_name: Option<(#element, #element)> }]
+                }
                 // Skip: filter_fields() should only return fields with filters
                 FilterType::None => vec![]
             }
@@ -85,16 +126,33 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
         .collect();
 
     let marker = marker::generated();
+    let extra_derives = derives::extra(entity, DeriveTarget::Query);
 
     let filter_name = entity.ident_with("", "Filter");
 
+    let (into_params, limit_param) = if api_config.list_query {
+        let max_limit = proc_macro2::Literal::i64_unsuffixed(api_config.max_limit_or_default());
+        (
+            quote! {
+                #[derive(utoipa::IntoParams)]
+                #[into_params(parameter_in = Query)]
+            },
+            quote! { #[param(minimum = 1, maximum = #max_limit)] }
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
     quote! {
         #marker
         #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+        #extra_derives
         #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+        #into_params
         #vis struct #query_name {
             #(#field_defs,)*
             /// Maximum number of results to return.
+            #limit_param
             pub limit: Option<i64>,
             /// Number of results to skip.
             pub offset: Option<i64>,
@@ -104,3 +162,22 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
         #vis type #filter_name = #query_name;
     }
 }
+
+/// `#[schema(value_type = ...)]`, and `#[param(value_type = ...)]` with
+/// `api(list_query)`, documenting a transparent newtype filter by its inner
+/// type.
+fn value_type_attrs(field: &FieldDef, into_params: bool) -> TokenStream {
+    let ty = field.ty();
+    let Some(value_type) = field.api_value_type(&syn::parse_quote!(Option<#ty>)) else {
+        return TokenStream::new();
+    };
+    let param = if into_params {
+        quote! { #[param(value_type = #value_type)] }
+    } else {
+        TokenStream::new()
+    };
+    quote! {
+        #[cfg_attr(feature = "api", schema(value_type = #value_type))]
+        #param
+    }
+}
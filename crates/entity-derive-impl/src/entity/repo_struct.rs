@@ -0,0 +1,204 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Pool-owning repository struct for `#[entity(repo_struct)]`.
+//!
+//! The repository trait is implemented directly on the pool type. For
+//! application state it is often handier to hold a named, cloneable value,
+//! so this generates:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Clone)]
+//! pub struct UserRepo {
+//!     pool: sqlx::PgPool,
+//! }
+//!
+//! impl UserRepo {
+//!     pub fn new(pool: sqlx::PgPool) -> Self { ... }
+//! }
+//!
+//! impl UserRepository for UserRepo {
+//!     type Error = <sqlx::PgPool as UserRepository>::Error;
+//!     type Pool = sqlx::PgPool;
+//!
+//!     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, Self::Error> {
+//!         <sqlx::PgPool as UserRepository>::find_by_id(&self.pool, id).await
+//!     }
+//!     // ... every other trait method
+//! }
+//! ```
+//!
+//! The impl is derived from the generated trait itself, so each method,
+//! including those with default bodies, forwards to the pool's impl and
+//! the wrapper never drifts from the trait. `PgPool` is reference-counted,
+//! so cloning the wrapper is cheap and needs no `Arc`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FnArg, ItemTrait, Pat, TraitItem};
+
+use super::{
+    parse::{DatabaseDialect, EntityDef},
+    repository
+};
+use crate::utils::marker;
+
+/// Generate `{Entity}Repo` and its repository impl.
+///
+/// Returns empty `TokenStream` unless `repo_struct` is set.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    if !entity.has_repo_struct() {
+        return TokenStream::new();
+    }
+
+    let vis = &entity.vis;
+    let entity_name = entity.name();
+    let struct_name = format_ident!("{}Repo", entity_name);
+    let trait_name = format_ident!("{}Repository", entity_name);
+    let feature = entity.dialect.feature_flag();
+    let pool = match entity.dialect {
+        DatabaseDialect::Sqlite => quote! { sqlx::SqlitePool },
+        _ => quote! { sqlx::PgPool }
+    };
+    let methods = delegated_methods(entity, &pool, &trait_name);
+    let marker = marker::generated();
+    let doc = format!(
+        "[`{}`] backed by an owned pool.\n\n\
+         Cheap to clone; store it in application state directly.",
+        trait_name
+    );
+
+    quote! {
+        #marker
+        #[doc = #doc]
+        #[cfg(feature = #feature)]
+        #[derive(Debug, Clone)]
+        #vis struct #struct_name {
+            pool: #pool,
+        }
+
+        #[cfg(feature = #feature)]
+        impl #struct_name {
+            /// Wrap a connection pool.
+            pub fn new(pool: #pool) -> Self {
+                Self { pool }
+            }
+        }
+
+        #[cfg(feature = #feature)]
+        #[async_trait::async_trait]
+        impl #trait_name for #struct_name {
+            type Error = <#pool as #trait_name>::Error;
+            type Pool = #pool;
+
+            fn pool(&self) -> &Self::Pool {
+                &self.pool
+            }
+
+            #(#methods)*
+        }
+    }
+}
+
+/// Forwarding impls for every method of the generated repository trait.
+fn delegated_methods(
+    entity: &EntityDef,
+    pool: &TokenStream,
+    trait_name: &syn::Ident
+) -> Vec<TokenStream> {
+    let item: ItemTrait = syn::parse2(repository::generate(entity))
+        .expect("generated repository trait is valid Rust");
+
+    item.items
+        .into_iter()
+        .filter_map(|item| match item {
+            TraitItem::Fn(method) if method.sig.ident != "pool" => Some(method),
+            _ => None
+        })
+        .map(|method| {
+            let mut sig = method.sig;
+            let name = &sig.ident;
+            let mut args = Vec::new();
+            for input in &mut sig.inputs {
+                if let FnArg::Typed(arg) = input
+                    && let Pat::Ident(ident) = arg.pat.as_mut()
+                {
+                    ident.mutability = None;
+                    args.push(ident.ident.clone());
+                }
+            }
+            let call = quote! { <#pool as #trait_name>::#name(&self.pool, #(#args),*) };
+            let call = if sig.asyncness.is_some() {
+                quote! { #call.await }
+            } else {
+                call
+            };
+            quote! {
+                #sig {
+                    #call
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate_for(input: syn::DeriveInput) -> String {
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        generate(&entity).to_string()
+    }
+
+    #[test]
+    fn not_generated_without_flag() {
+        let output = generate_for(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn delegates_every_method_to_pool() {
+        let output = generate_for(syn::parse_quote! {
+            #[entity(table = "users", repo_struct)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub name: String,
+            }
+        });
+        assert!(output.contains("pub struct UserRepo { pool : sqlx :: PgPool , }"));
+        assert!(output.contains("impl UserRepository for UserRepo"));
+        assert!(output.contains("type Error = < sqlx :: PgPool as UserRepository > :: Error"));
+        assert!(output.contains(
+            "< sqlx :: PgPool as UserRepository > :: find_by_id (& self . pool , id) . await"
+        ));
+        assert!(output.contains(
+            "< sqlx :: PgPool as UserRepository > :: update (& self . pool , id , dto) . await"
+        ));
+        assert!(output.contains(
+            "< sqlx :: PgPool as UserRepository > :: for_each_chunk (& self . pool , size , f) . await"
+        ));
+        assert!(!output.contains("mut f"));
+    }
+
+    #[test]
+    fn sqlite_wraps_sqlite_pool() {
+        let output = generate_for(syn::parse_quote! {
+            #[entity(table = "users", dialect = "sqlite", repo_struct)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(output.contains("pool : sqlx :: SqlitePool"));
+        assert!(output.contains("# [cfg (feature = \"sqlite\")]"));
+    }
+}
@@ -20,12 +20,50 @@
 //!     fn pool(&self) -> &Self::Pool;
 //!     async fn create(&self, dto: CreateUserRequest) -> Result<User, Self::Error>;
 //!     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, Self::Error>;
+//!     async fn find_by_id_or_error(&self, id: Uuid) -> Result<User, Self::Error>;
+//!     async fn find_by_ids_map(&self, ids: Vec<Uuid>) -> Result<HashMap<Uuid, User>, Self::Error>;
 //!     async fn update(&self, id: Uuid, dto: UpdateUserRequest) -> Result<User, Self::Error>;
+//!     async fn update_many(&self, ids: Vec<Uuid>, dto: UpdateUserRequest) -> Result<u64, Self::Error>;
 //!     async fn delete(&self, id: Uuid) -> Result<bool, Self::Error>;
 //!     async fn list(&self, limit: i64, offset: i64) -> Result<Vec<User>, Self::Error>;
+//!     async fn list_paged(&self, page: Pagination) -> Result<Vec<User>, Self::Error>;
+//!     async fn fetch_chunk(&self, offset: i64, size: i64) -> Result<Vec<User>, Self::Error>;
+//!     async fn for_each_chunk<F>(&self, size: i64, f: F) -> Result<u64, Self::Error>;
+//!     async fn list_page(&self, cursor: Option<Uuid>, limit: i64) -> Result<Page<User, Uuid>, Self::Error>;
+//!     async fn ping(&self) -> Result<(), Self::Error>;
 //! }
 //! ```
 //!
+//! `list_paged` has a default implementation that forwards to `list`, so
+//! `Pagination::page(2, 25)` works with any implementation.
+//!
+//! `fetch_chunk` and `for_each_chunk` walk the whole table in fixed-size
+//! chunks for batch jobs such as CSV exports, also on top of `list`.
+//! Compared with `stream_query`, each chunk is a separate query, so no
+//! connection or cursor is held between chunks and the caller can stop,
+//! resume from an offset or do blocking work in between. The cost is
+//! offset pagination: rows inserted or deleted while the export runs shift
+//! later chunks, so a row can be skipped or seen twice. Export from a
+//! snapshot (e.g. inside a `REPEATABLE READ` transaction) or use
+//! `stream_query` when that matters.
+//!
+//! With `#[entity(ids_map)]`, `find_by_ids_map` loads many entities and
+//! keys them by id, for dataloader-style lookups. Ids without a row are
+//! absent from the map, and the id type must implement `Hash`, `Eq` and
+//! `Clone`. Its default implementation
+//! calls `find_by_id` per id; the generated sqlx implementation overrides it
+//! with a single query.
+//!
+//! `find_by_id_or_error` has a default implementation on top of
+//! `find_by_id` that turns `None` into
+//! `entity_core::error::NotFoundError::not_found()`. It is available when
+//! the error type implements `NotFoundError`, as `sqlx::Error` and types
+//! using `impl_from_sqlx_error!` do.
+//!
+//! With `#[entity(cache)]`, default `find_by_id_cached`, `update_cached` and
+//! `delete_cached` methods are added that read through and invalidate an
+//! `entity_core::cache::EntityCache`.
+//!
 //! # Associated Types
 //!
 //! - `Error` — custom error type (default: `sqlx::Error`)
@@ -38,8 +76,15 @@
 //! | Method | Condition |
 //! |--------|-----------|
 //! | `create` | Entity has `#[field(create)]` fields |
-//! | `update` | Entity has `#[field(update)]` fields |
-//! | `find_by_id`, `delete`, `list` | Always generated |
+//! | `update`, `update_many` | Entity has `#[field(update)]` fields |
+//! | `bulk_upsert` | `#[entity(upsert = "...")]` |
+//! | `find_by_id`, `find_by_id_or_error`, `delete`, `list`, `list_paged`, `fetch_chunk`, `for_each_chunk`, `list_page`, `ping` | Always generated |
+//! | `list_ordered` | Entity has `#[sortable]` fields |
+//! | `list_after` | `#[entity(cursor = "...")]` |
+//! | `search` | Entity has a `#[column(tsvector)]` field |
+//! | `find_by_ids_map` | `#[entity(ids_map)]` |
+//! | `find_by_id_cached`, `delete_cached` | `#[entity(cache)]` |
+//! | `update_cached` | `#[entity(cache)]` and `#[field(update)]` fields |
 //!
 //! # SQL Level Control
 //!
@@ -73,20 +118,78 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
     let create_method = if entity.create_fields().is_empty() {
         TokenStream::new()
     } else {
-        quote! { async fn create(&self, dto: #create_dto) -> Result<#entity_name, Self::Error>; }
+        let created = if entity.create_returns_id() {
+            quote! { #id_type }
+        } else {
+            quote! { #entity_name }
+        };
+        quote! { async fn create(&self, dto: #create_dto) -> Result<#created, Self::Error>; }
     };
 
     let update_method = if entity.update_fields().is_empty() {
         TokenStream::new()
     } else {
-        quote! { async fn update(&self, id: #id_type, dto: #update_dto) -> Result<#entity_name, Self::Error>; }
+        quote! {
+            async fn update(&self, id: #id_type, dto: #update_dto) -> Result<#entity_name, Self::Error>;
+
+            /// Apply the same patch to every entity in `ids` in one statement.
+            ///
+            /// Only fields set to `Some` are written. Returns the number of
+            /// rows updated; ids without a row are skipped and an empty `ids`
+            /// is a no-op.
+            async fn update_many(&self, ids: Vec<#id_type>, dto: #update_dto) -> Result<u64, Self::Error>;
+        }
+    };
+
+    let ids_map_method = if entity.has_ids_map() {
+        quote! {
+            /// Find all entities with the given IDs, keyed by ID.
+            ///
+            /// IDs without a matching row are absent from the map. The
+            /// default calls `find_by_id` once per ID; the generated sqlx
+            /// implementation uses a single query.
+            async fn find_by_ids_map(&self, ids: Vec<#id_type>) -> Result<std::collections::HashMap<#id_type, #entity_name>, Self::Error> {
+                let mut found = std::collections::HashMap::with_capacity(ids.len());
+                for id in ids {
+                    if let Some(entity) = self.find_by_id(id.clone()).await? {
+                        found.insert(id, entity);
+                    }
+                }
+                Ok(found)
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let upsert_method = if entity.upsert_key().is_empty() {
+        TokenStream::new()
+    } else {
+        let key = entity.upsert_key().join(", ");
+        let doc = format!(
+            " Insert every DTO in one statement, updating rows whose `({})` already exists.",
+            key
+        );
+        quote! {
+            #[doc = #doc]
+            ///
+            /// Returns the inserted or updated entities. An empty `dtos` is a
+            /// no-op. When a key repeats, the last DTO wins. Loads above
+            /// PostgreSQL's 65535 bound parameters are split into several
+            /// statements within one transaction.
+            async fn bulk_upsert(&self, dtos: Vec<#create_dto>) -> Result<Vec<#entity_name>, Self::Error>;
+        }
     };
 
     let relation_methods = generate_relation_methods(entity, id_type);
     let projection_methods = generate_projection_methods(entity, id_type);
     let soft_delete_methods = generate_soft_delete_methods(entity, id_type);
     let query_method = generate_query_method(entity);
+    let list_ordered_method = generate_list_ordered_method(entity);
+    let list_after_method = generate_list_after_method(entity);
+    let search_method = generate_search_method(entity);
     let stream_method = generate_stream_method(entity);
+    let cache_methods = generate_cache_methods(entity, id_type);
     let marker = marker::generated();
 
     quote! {
@@ -114,12 +217,86 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
 
             async fn find_by_id(&self, id: #id_type) -> Result<Option<#entity_name>, Self::Error>;
 
+            /// Find entity by ID, failing with a not-found error if it
+            /// does not exist.
+            async fn find_by_id_or_error(&self, id: #id_type) -> Result<#entity_name, Self::Error>
+            where
+                Self::Error: entity_derive::error::NotFoundError
+            {
+                self.find_by_id(id)
+                    .await?
+                    .ok_or_else(<Self::Error as entity_derive::error::NotFoundError>::not_found)
+            }
+
+            #ids_map_method
+
             #update_method
 
+            #upsert_method
+
             async fn delete(&self, id: #id_type) -> Result<bool, Self::Error>;
 
             async fn list(&self, limit: i64, offset: i64) -> Result<Vec<#entity_name>, Self::Error>;
 
+            /// List entities with [`Pagination`](entity_derive::Pagination) parameters.
+            ///
+            /// Forwards to `list` with the page's limit and offset.
+            async fn list_paged(&self, page: entity_derive::Pagination) -> Result<Vec<#entity_name>, Self::Error> {
+                self.list(page.limit, page.offset).await
+            }
+
+            /// Fetch one chunk of at most `size` entities starting at `offset`.
+            ///
+            /// Forwards to `list`, so chunks follow its order.
+            async fn fetch_chunk(&self, offset: i64, size: i64) -> Result<Vec<#entity_name>, Self::Error> {
+                self.list(size, offset).await
+            }
+
+            /// Fetch every entity in chunks of `size`, passing each chunk to `f`.
+            ///
+            /// Stops after the first short chunk or when `f` returns an error,
+            /// and returns the number of entities fetched. A `size` below 1
+            /// fetches nothing.
+            async fn for_each_chunk<F>(&self, size: i64, mut f: F) -> Result<u64, Self::Error>
+            where
+                F: FnMut(Vec<#entity_name>) -> Result<(), Self::Error> + Send
+            {
+                if size < 1 {
+                    return Ok(0);
+                }
+                let mut offset = 0;
+                loop {
+                    let chunk = self.fetch_chunk(offset, size).await?;
+                    let fetched = chunk.len() as i64;
+                    offset += fetched;
+                    f(chunk)?;
+                    if fetched < size {
+                        return Ok(offset as u64);
+                    }
+                }
+            }
+
+            /// List entities after `cursor` (keyset pagination, newest first).
+            ///
+            /// Pass `None` for the first page, then `next_cursor` of the
+            /// previous page. Pages are ordered by id descending, ignoring
+            /// `order_by`, and hold the entities with an id below `cursor`,
+            /// so a cursor means the same to every implementation.
+            async fn list_page(&self, cursor: Option<#id_type>, limit: i64) -> Result<entity_derive::Page<#entity_name, #id_type>, Self::Error>;
+
+            /// Check that the backing store is reachable, e.g. for readiness
+            /// probes.
+            ///
+            /// Implementations must contact the store rather than report
+            /// success unconditionally.
+            async fn ping(&self) -> Result<(), Self::Error>;
+
+            #list_ordered_method
+
+            #list_after_method
+
+            #search_method
+
             #query_method
 
             #stream_method
@@ -129,6 +306,78 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
             #projection_methods
 
             #soft_delete_methods
+
+            #cache_methods
+        }
+    }
+}
+
+/// Generate cache-aside methods when `#[entity(cache)]` is enabled.
+///
+/// All methods have default implementations on top of `find_by_id`,
+/// `update` and `delete`, keyed by `{schema}.{table}:{id}`:
+/// - `find_by_id_cached` — read from cache, fall back to DB and populate
+/// - `update_cached` — update, then invalidate the cached entry
+/// - `delete_cached` — delete, then invalidate the cached entry
+fn generate_cache_methods(entity: &EntityDef, id_type: &syn::Type) -> TokenStream {
+    if !entity.has_cache() {
+        return TokenStream::new();
+    }
+
+    let entity_name = entity.name();
+    let update_dto = entity.ident_with("Update", "Request");
+    let table = entity.full_table_name();
+
+    let update_method = if entity.update_fields().is_empty() {
+        TokenStream::new()
+    } else {
+        quote! {
+            /// Update an entity and invalidate its cache entry.
+            async fn update_cached<C>(&self, cache: &C, id: #id_type, dto: #update_dto) -> Result<#entity_name, Self::Error>
+            where
+                C: entity_derive::cache::EntityCache<#entity_name> + ?Sized
+            {
+                let key = entity_derive::cache::cache_key(#table, &id);
+                let entity = self.update(id, dto).await?;
+                cache.del(&key).await;
+                Ok(entity)
+            }
+        }
+    };
+
+    quote! {
+        /// Find entity by ID, reading through `cache`.
+        ///
+        /// On a miss the entity is loaded from the database and stored in
+        /// the cache.
+        async fn find_by_id_cached<C>(&self, cache: &C, id: #id_type) -> Result<Option<#entity_name>, Self::Error>
+        where
+            C: entity_derive::cache::EntityCache<#entity_name> + ?Sized
+        {
+            let key = entity_derive::cache::cache_key(#table, &id);
+            if let Some(entity) = cache.get(&key).await {
+                return Ok(Some(entity));
+            }
+            let entity = self.find_by_id(id).await?;
+            if let Some(entity) = &entity {
+                cache.set(&key, entity).await;
+            }
+            Ok(entity)
+        }
+
+        #update_method
+
+        /// Delete an entity and invalidate its cache entry.
+        async fn delete_cached<C>(&self, cache: &C, id: #id_type) -> Result<bool, Self::Error>
+        where
+            C: entity_derive::cache::EntityCache<#entity_name> + ?Sized
+        {
+            let key = entity_derive::cache::cache_key(#table, &id);
+            let deleted = self.delete(id).await?;
+            if deleted {
+                cache.del(&key).await;
+            }
+            Ok(deleted)
         }
     }
 }
@@ -257,11 +506,12 @@ fn generate_soft_delete_methods(entity: &EntityDef, id_type: &syn::Type) -> Toke
     }
 }
 
-/// Generate query method when entity has filter fields.
+/// Generate query methods when entity has filter fields.
 ///
 /// Generates:
 /// ```rust,ignore
 /// async fn query(&self, query: UserQuery) -> Result<Vec<User>, Self::Error>;
+/// async fn list_with_total(&self, query: UserQuery) -> Result<(Vec<User>, i64), Self::Error>;
 /// ```
 fn generate_query_method(entity: &EntityDef) -> TokenStream {
     if !entity.has_filters() {
@@ -276,6 +526,90 @@ fn generate_query_method(entity: &EntityDef) -> TokenStream {
         ///
         /// Supports filtering by fields marked with `#[filter]`.
         async fn query(&self, query: #query_type) -> Result<Vec<#entity_name>, Self::Error>;
+
+        /// Query a page of entities together with the total match count.
+        ///
+        /// Runs one statement with `COUNT(*) OVER()`, so the total ignores
+        /// `limit`/`offset` and is consistent with the page. A page past the
+        /// last match has no row to carry the count and reports `0`.
+        async fn list_with_total(&self, query: #query_type) -> Result<(Vec<#entity_name>, i64), Self::Error>;
+    }
+}
+
+/// Generate ordered list method when entity has sortable fields.
+///
+/// Generates:
+/// ```rust,ignore
+/// async fn list_ordered(&self, order: UserOrderBy, limit: i64, offset: i64) -> Result<Vec<User>, Self::Error>;
+/// ```
+fn generate_list_ordered_method(entity: &EntityDef) -> TokenStream {
+    if !entity.has_sortable() {
+        return TokenStream::new();
+    }
+
+    let entity_name = entity.name();
+    let order_type = entity.ident_with("", "OrderBy");
+
+    quote! {
+        /// List entities in the given order with limit/offset pagination.
+        async fn list_ordered(&self, order: #order_type, limit: i64, offset: i64) -> Result<Vec<#entity_name>, Self::Error>;
+    }
+}
+
+/// Generate the keyset `list_after` method when `cursor` is set.
+///
+/// Generates:
+/// ```rust,ignore
+/// async fn list_after(&self, cursor: Option<PostCursor>, limit: i64) -> Result<Page<Post, PostCursor>, Self::Error>;
+/// ```
+fn generate_list_after_method(entity: &EntityDef) -> TokenStream {
+    let fields = entity.cursor_fields();
+    if fields.is_empty() {
+        return TokenStream::new();
+    }
+
+    let entity_name = entity.name();
+    let cursor_name = entity.ident_with("", "Cursor");
+    let columns: Vec<String> = fields.iter().map(|f| f.column_name()).collect();
+    let doc = format!(
+        " List entities after `cursor` (keyset pagination ordered by `{}`).",
+        columns.join(", ")
+    );
+
+    quote! {
+        #[doc = #doc]
+        ///
+        /// Pass `None` for the first page, then `next_cursor` of the
+        /// previous page. Unlike `list`, deep pages cost the same as the
+        /// first one.
+        async fn list_after(&self, cursor: Option<#cursor_name>, limit: i64) -> Result<entity_derive::Page<#entity_name, #cursor_name>, Self::Error>;
+    }
+}
+
+/// Generate the full-text `search` method when entity has a `tsvector` field.
+///
+/// Generates:
+/// ```rust,ignore
+/// async fn search(&self, query: &str, limit: i64, offset: i64) -> Result<Vec<Article>, Self::Error>;
+/// ```
+fn generate_search_method(entity: &EntityDef) -> TokenStream {
+    let Some(field) = entity.search_field() else {
+        return TokenStream::new();
+    };
+
+    let entity_name = entity.name();
+    let doc = format!(
+        " Find entities whose `{}` matches `query`, best matches first.",
+        field.name_str()
+    );
+
+    quote! {
+        #[doc = #doc]
+        ///
+        /// `query` is parsed with `websearch_to_tsquery`, so raw user input
+        /// is accepted: `"quoted phrases"`, `or` and `-excluded` words.
+        /// Results are ranked with `ts_rank`.
+        async fn search(&self, query: &str, limit: i64, offset: i64) -> Result<Vec<#entity_name>, Self::Error>;
     }
 }
 
@@ -287,6 +621,11 @@ fn generate_query_method(entity: &EntityDef) -> TokenStream {
 ///     &self,
 ///     filter: UserFilter,
 /// ) -> Result<impl futures::Stream<Item = Result<User, sqlx::Error>>, Self::Error>;
+///
+/// async fn stream_query(
+///     &self,
+///     query: UserQuery,
+/// ) -> Result<impl futures::Stream<Item = Result<User, sqlx::Error>>, Self::Error>;
 /// ```
 pub fn generate_stream_method(entity: &EntityDef) -> TokenStream {
     if !entity.has_streams() || !entity.has_filters() {
@@ -295,6 +634,7 @@ pub fn generate_stream_method(entity: &EntityDef) -> TokenStream {
 
     let entity_name = entity.name();
     let filter_type = entity.ident_with("", "Filter");
+    let query_type = entity.ident_with("", "Query");
 
     quote! {
         /// Stream entities with type-safe filters.
@@ -304,5 +644,16 @@ pub fn generate_stream_method(entity: &EntityDef) -> TokenStream {
             &self,
             filter: #filter_type,
         ) -> Result<std::pin::Pin<Box<dyn futures::Stream<Item = Result<#entity_name, Self::Error>> + Send + '_>>, Self::Error>;
+
+        /// Stream the result of a rich `query` lazily.
+        ///
+        /// Applies the same predicates as `query` but fetches matching rows
+        /// in batches as the stream is polled, so large exports never hold
+        /// the full result in memory. `query.limit` caps the total streamed;
+        /// without it the stream runs to the last match.
+        async fn stream_query(
+            &self,
+            query: #query_type,
+        ) -> Result<std::pin::Pin<Box<dyn futures::Stream<Item = Result<#entity_name, Self::Error>> + Send + '_>>, Self::Error>;
     }
 }
@@ -41,16 +41,80 @@
 //! | `#[field(skip)]` | Yes | Still stored in DB |
 //! | Regular fields | Yes | All data columns |
 //!
+//! # Column Prefix
+//!
+//! With `#[entity(row_prefix = "u_")]`, every field is renamed to its
+//! prefixed column (`#[sqlx(rename = "u_name")]`). This lets the Row decode
+//! joined queries such as `SELECT u.id AS u_id, o.id AS o_id ...`. The
+//! generated repository aliases its own SELECT and RETURNING columns to
+//! match.
+//!
+//! # Flattened Fields
+//!
+//! Fields marked `#[field(flatten)]` hold a value object that derives
+//! `sqlx::FromRow` itself and are emitted as `#[sqlx(flatten)]`, so a single
+//! query hydrates the nested struct from its own columns. `row_prefix` does
+//! not apply to them; the value object names its columns.
+//!
+//! # Transparent Newtypes
+//!
+//! A `#[column(transparent = "String")]` field is declared with its inner
+//! type (`Option<String>` for an `Option<Email>`), so sqlx decodes the
+//! primitive and the newtype needs no `Decode` impl. `From<String>` builds
+//! the newtype when the Row is mapped to the entity.
+//!
+//! # Soft Delete Marker
+//!
+//! `{Name}Row::SOFT_DELETE` records whether the entity uses
+//! `#[entity(soft_delete)]`. A parent with `cascade_soft_delete` reads it to
+//! decide which `#[has_many]` children to mark deleted.
+//!
+//! # Selected Columns
+//!
+//! `{Name}Row::SELECT_COLUMNS` is the explicit column list the Row decodes,
+//! as used by the generated SELECTs. Relation loaders of other entities
+//! select it instead of `*`, so columns the entity does not know about are
+//! never fetched. With flattened fields the value object's columns are not
+//! known and it is `*`.
+//!
+//! # Manual `FromRow`
+//!
+//! With `#[entity(manual_from_row)]`, or when any field has
+//! `#[column(decode_with = "...")]`, the Row does not derive `FromRow`.
+//! Instead an explicit impl decodes each field, which `cargo expand` shows
+//! as a starting point for hand-written variants:
+//!
+//! ```rust,ignore
+//! #[cfg(feature = "postgres")]
+//! impl<'r> sqlx::FromRow<'r, sqlx::postgres::PgRow> for AccountRow {
+//!     fn from_row(row: &'r sqlx::postgres::PgRow) -> Result<Self, sqlx::Error> {
+//!         Ok(Self {
+//!             id: sqlx::Row::try_get(row, "id")?,
+//!             balance: decode_money(row, "balance")?,
+//!         })
+//!     }
+//! }
+//! ```
+//!
+//! Flattened fields delegate to their own `FromRow` impl. With SQLite as
+//! `dialect` or `alt_dialect`, a second impl for `SqliteRow` is generated,
+//! so `decode_with` functions must then accept either row type.
+//!
 //! # Conditional Compilation
 //!
-//! The `sqlx::FromRow` derive is gated behind `#[cfg(feature = "postgres")]`.
-//! This allows using the crate without sqlx for DTO-only scenarios.
+//! The `sqlx::FromRow` derive is gated behind `#[cfg(feature = "postgres")]`,
+//! or `any(feature = "postgres", feature = "sqlite")` with SQLite as
+//! `dialect` or `alt_dialect`. This allows using the crate without sqlx for
+//! DTO-only scenarios. Manual impls are gated per dialect feature.
 
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use super::parse::{EntityDef, SqlLevel};
-use crate::utils::marker;
+use super::{
+    parse::{DatabaseDialect, DeriveTarget, EntityDef, SqlLevel},
+    sql::postgres::helpers::select_columns
+};
+use crate::utils::{derives, marker};
 
 /// Generates the `{Name}Row` struct for database query results.
 ///
@@ -63,18 +127,113 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
 
     let vis = &entity.vis;
     let row_name = entity.ident_with("", "Row");
+    let sqlx_cfg = sqlx_cfg(entity);
+    let manual = entity.has_manual_from_row();
     let field_defs = entity.all_fields().iter().map(|f| {
         let name = f.name();
-        let ty = f.ty();
-        quote! { pub #name: #ty }
+        let ty = f.db_ty();
+        if manual {
+            quote! { pub #name: #ty }
+        } else if f.is_flattened() {
+            quote! {
+                #[cfg_attr(#sqlx_cfg, sqlx(flatten))]
+                pub #name: #ty
+            }
+        } else if entity.row_prefix.is_some() {
+            let column = entity.row_column(f);
+            quote! {
+                #[cfg_attr(#sqlx_cfg, sqlx(rename = #column))]
+                pub #name: #ty
+            }
+        } else {
+            quote! { pub #name: #ty }
+        }
     });
 
+    let soft_delete = entity.is_soft_delete();
+    let select_columns = if entity.all_fields().iter().any(|f| f.is_flattened()) {
+        "*".to_string()
+    } else {
+        select_columns(entity.all_fields(), entity.row_prefix.as_deref())
+    };
     let marker = marker::generated();
+    let extra_derives = derives::extra(entity, DeriveTarget::Row);
+    let (derive_from_row, from_row_impls) = if manual {
+        (TokenStream::new(), manual_from_row(entity))
+    } else {
+        (
+            quote! { #[cfg_attr(#sqlx_cfg, derive(sqlx::FromRow))] },
+            TokenStream::new()
+        )
+    };
 
     quote! {
         #marker
         #[derive(Debug, Clone)]
-        #[cfg_attr(feature = "postgres", derive(sqlx::FromRow))]
+        #extra_derives
+        #derive_from_row
         #vis struct #row_name { #(#field_defs),* }
+
+        #from_row_impls
+
+        impl #row_name {
+            /// Whether rows are soft-deleted through `deleted_at`.
+            pub const SOFT_DELETE: bool = #soft_delete;
+
+            /// Columns selected to decode this Row.
+            pub const SELECT_COLUMNS: &'static str = #select_columns;
+        }
+    }
+}
+
+/// Explicit `sqlx::FromRow` impls for the Row, one per sqlx dialect.
+///
+/// Each field is read with `sqlx::Row::try_get` from its
+/// [`row_column`](EntityDef::row_column), decoded by its `decode_with`
+/// function, or, if flattened, built by its own `FromRow` impl.
+fn manual_from_row(entity: &EntityDef) -> TokenStream {
+    let row_name = entity.ident_with("", "Row");
+    let mut dialects = vec![(quote! { "postgres" }, quote! { sqlx::postgres::PgRow })];
+    if entity.has_dialect(DatabaseDialect::Sqlite) {
+        dialects.push((quote! { "sqlite" }, quote! { sqlx::sqlite::SqliteRow }));
+    }
+
+    let impls = dialects.into_iter().map(|(feature, db_row)| {
+        let fields = entity.all_fields().iter().map(|f| {
+            let name = f.name();
+            let column = entity.row_column(f);
+            let value = if let Some(decode) = f.decode_with() {
+                quote! { #decode(row, #column)? }
+            } else if f.is_flattened() {
+                let ty = f.db_ty();
+                quote! { <#ty as sqlx::FromRow<'r, #db_row>>::from_row(row)? }
+            } else {
+                quote! { sqlx::Row::try_get(row, #column)? }
+            };
+            quote! { #name: #value }
+        });
+
+        quote! {
+            #[cfg(feature = #feature)]
+            impl<'r> sqlx::FromRow<'r, #db_row> for #row_name {
+                fn from_row(row: &'r #db_row) -> Result<Self, sqlx::Error> {
+                    Ok(Self { #(#fields),* })
+                }
+            }
+        }
+    });
+
+    quote! { #(#impls)* }
+}
+
+/// `cfg` predicate for sqlx derives and attributes.
+///
+/// `feature = "postgres"`, widened to `sqlite` when SQLite code is
+/// generated as well.
+pub fn sqlx_cfg(entity: &EntityDef) -> TokenStream {
+    if entity.has_dialect(DatabaseDialect::Sqlite) {
+        quote! { any(feature = "postgres", feature = "sqlite") }
+    } else {
+        quote! { feature = "postgres" }
     }
 }
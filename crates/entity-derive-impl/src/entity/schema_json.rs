@@ -0,0 +1,247 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Machine-readable entity schema.
+//!
+//! Every entity gets a `{ENTITY}_SCHEMA_JSON` constant describing its
+//! table, fields and relations, for code generators (TypeScript types,
+//! admin UIs) that should not parse Rust:
+//!
+//! ```rust,ignore
+//! pub const USER_SCHEMA_JSON: &str = r#"{"name":"User","table":"users",...}"#;
+//! ```
+//!
+//! The JSON is built at macro time from the parsed definition. Keys are
+//! always emitted in the same order and absent values are `null`, so the
+//! output is stable across builds:
+//!
+//! ```json
+//! {
+//!   "name": "User",
+//!   "table": "users",
+//!   "schema": "public",
+//!   "doc": null,
+//!   "soft_delete": false,
+//!   "fields": [
+//!     {
+//!       "name": "org_id",
+//!       "column": "org_id",
+//!       "api_name": "org_id",
+//!       "type": "Uuid",
+//!       "nullable": false,
+//!       "primary_key": false,
+//!       "auto": false,
+//!       "computed": false,
+//!       "create": true,
+//!       "update": false,
+//!       "response": true,
+//!       "unique": false,
+//!       "filter": "eq",
+//!       "sortable": false,
+//!       "belongs_to": "Organization",
+//!       "doc": null
+//!     }
+//!   ],
+//!   "has_many": ["Post"]
+//! }
+//! ```
+//!
+//! `type` is the Rust type without `Option`; nullability is reported in
+//! `nullable`. `filter` is `"eq"`, `"like"`, `"range"`, `"overlaps"` or
+//! `null`.
+
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::Type;
+
+use super::parse::{EntityDef, FieldDef, FilterType};
+
+/// Generate the `{ENTITY}_SCHEMA_JSON` constant.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    let vis = &entity.vis;
+    let const_name = format_ident!("{}_SCHEMA_JSON", entity.name_str().to_case(Case::Constant));
+    let json = render(entity);
+    let doc = format!("JSON description of [`{}`].", entity.name_str());
+
+    quote! {
+        #[doc = #doc]
+        #[allow(dead_code)]
+        #vis const #const_name: &str = #json;
+    }
+}
+
+/// Render the entity schema as a JSON string.
+fn render(entity: &EntityDef) -> String {
+    let fields = entity
+        .all_fields()
+        .iter()
+        .map(render_field)
+        .collect::<Vec<_>>()
+        .join(",");
+    let has_many = entity
+        .has_many_relations()
+        .iter()
+        .map(|related| string(&related.to_string()))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        "{{\"name\":{},\"table\":{},\"schema\":{},\"doc\":{},\"soft_delete\":{},\"fields\":[{}],\"has_many\":[{}]}}",
+        string(&entity.name_str()),
+        string(&entity.table),
+        string(&entity.schema),
+        optional(entity.doc()),
+        entity.is_soft_delete(),
+        fields,
+        has_many
+    )
+}
+
+/// Render one field object.
+fn render_field(field: &FieldDef) -> String {
+    let filter = match field.filter().filter_type {
+        FilterType::None => None,
+        FilterType::Eq => Some("eq"),
+        FilterType::Like => Some("like"),
+        FilterType::Range => Some("range"),
+        FilterType::Overlaps => Some("overlaps")
+    };
+    let belongs_to = field.belongs_to().map(ToString::to_string);
+
+    format!(
+        "{{\"name\":{},\"column\":{},\"api_name\":{},\"type\":{},\"nullable\":{},\"primary_key\":{},\"auto\":{},\"computed\":{},\"create\":{},\"update\":{},\"response\":{},\"unique\":{},\"filter\":{},\"sortable\":{},\"belongs_to\":{},\"doc\":{}}}",
+        string(&field.name_str()),
+        string(&field.column_name()),
+        string(&field.api_name()),
+        string(&type_name(field.inner_ty())),
+        field.is_option(),
+        field.is_id(),
+        field.is_auto(),
+        field.is_computed(),
+        field.in_create(),
+        field.in_update(),
+        field.in_response(),
+        field.is_unique(),
+        optional(filter),
+        field.is_sortable(),
+        optional(belongs_to.as_deref()),
+        optional(field.doc())
+    )
+}
+
+/// Format a type as written in source, e.g. `Vec<String>`.
+fn type_name(ty: &Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
+
+/// JSON string literal, or `null`.
+fn optional(value: Option<&str>) -> String {
+    value.map_or_else(|| "null".to_string(), string)
+}
+
+/// JSON string literal with escaping.
+fn string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c)
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_entity(input: syn::DeriveInput) -> EntityDef {
+        EntityDef::from_derive_input(&input).unwrap()
+    }
+
+    #[test]
+    fn renders_fields_and_relations() {
+        let entity = parse_entity(syn::parse_quote! {
+            #[entity(table = "users", schema = "core")]
+            #[has_many(Post)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                /// Display name.
+                #[field(create, update, response)]
+                #[filter(like)]
+                pub name: String,
+                #[field(create, response)]
+                #[belongs_to(Organization)]
+                pub org_id: uuid::Uuid,
+                #[field(response)]
+                pub tags: Option<Vec<String>>,
+            }
+        });
+        let json = render(&entity);
+        assert!(json.starts_with(
+            "{\"name\":\"User\",\"table\":\"users\",\"schema\":\"core\",\"doc\":null,\"soft_delete\":false,"
+        ));
+        assert!(json.contains(
+            "{\"name\":\"id\",\"column\":\"id\",\"api_name\":\"id\",\"type\":\"uuid::Uuid\",\"nullable\":false,\"primary_key\":true,"
+        ));
+        assert!(json.contains(
+            "\"filter\":\"like\",\"sortable\":false,\"belongs_to\":null,\"doc\":\"Display name.\"}"
+        ));
+        assert!(json.contains("\"belongs_to\":\"Organization\""));
+        assert!(json.contains("\"type\":\"Vec<String>\",\"nullable\":true"));
+        assert!(json.ends_with("\"has_many\":[\"Post\"]}"));
+    }
+
+    #[test]
+    fn reports_column_and_api_names() {
+        let entity = parse_entity(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response, rename_column = "usr_mail", rename_api = "emailAddress")]
+                pub email: String,
+            }
+        });
+        assert!(render(&entity).contains(
+            "{\"name\":\"email\",\"column\":\"usr_mail\",\"api_name\":\"emailAddress\","
+        ));
+    }
+
+    #[test]
+    fn const_is_named_after_entity() {
+        let entity = parse_entity(syn::parse_quote! {
+            #[entity(table = "order_items")]
+            pub struct OrderItem {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        let output = generate(&entity).to_string();
+        assert!(output.contains("pub const ORDER_ITEM_SCHEMA_JSON : & str ="));
+    }
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(string("a \"b\"\\\n"), "\"a \\\"b\\\"\\\\\\n\"");
+        assert_eq!(string("\u{1}"), "\"\\u0001\"");
+        assert_eq!(optional(None), "null");
+    }
+}
@@ -11,6 +11,7 @@
 //! ```text
 //! sql.rs (coordinator)
 //! ├── postgres.rs   - PostgreSQL via sqlx::PgPool
+//! ├── sqlite.rs     - SQLite via sqlx::SqlitePool, reusing postgres.rs
 //! ├── clickhouse.rs - ClickHouse (planned)
 //! └── mongodb.rs    - MongoDB (planned)
 //! ```
@@ -20,22 +21,25 @@
 //! | Dialect | Feature | Client | Status |
 //! |---------|---------|--------|--------|
 //! | PostgreSQL | `postgres` | `sqlx::PgPool` | Stable |
+//! | SQLite | `sqlite` | `sqlx::SqlitePool` | Repository only |
 //! | ClickHouse | `clickhouse` | `clickhouse::Client` | Planned |
 //! | MongoDB | `mongodb` | `mongodb::Client` | Planned |
 
 mod clickhouse;
 mod mongodb;
+mod sqlite;
 
 pub mod postgres;
 
 use proc_macro2::TokenStream;
+use quote::quote;
 
 use super::parse::{DatabaseDialect, EntityDef, SqlLevel};
 
 /// Generate SQL implementation based on entity configuration.
 ///
 /// Delegates to dialect-specific generators based on `#[entity(dialect =
-/// "...")]`.
+/// "...")]`, then adds the `alt_dialect` implementation if one is set.
 ///
 /// # Returns
 ///
@@ -46,9 +50,19 @@ pub fn generate(entity: &EntityDef) -> TokenStream {
         return TokenStream::new();
     }
 
-    match entity.dialect {
+    let primary = match entity.dialect {
         DatabaseDialect::Postgres => postgres::generate(entity),
         DatabaseDialect::ClickHouse => clickhouse::generate(entity),
-        DatabaseDialect::MongoDB => mongodb::generate(entity)
+        DatabaseDialect::MongoDB => mongodb::generate(entity),
+        DatabaseDialect::Sqlite => sqlite::generate(entity)
+    };
+    let alt = match entity.alt_dialect {
+        Some(DatabaseDialect::Sqlite) => sqlite::generate(entity),
+        _ => TokenStream::new()
+    };
+
+    quote! {
+        #primary
+        #alt
     }
 }
@@ -14,8 +14,12 @@
 //! ├── mod.rs         — Main generator and public API
 //! ├── context.rs     — Generation context with precomputed values
 //! ├── crud.rs        — CREATE, READ, UPDATE, DELETE, LIST methods
+//! ├── debug_sql.rs   — SQL text constants and debug_sql()
+//! ├── insert_query.rs — Standalone INSERT on the Insertable struct
 //! ├── query.rs       — Type-safe query filtering method
+//! ├── search.rs      — Full-text search over a tsvector column
 //! ├── relations.rs   — belongs_to and has_many relation methods
+//! ├── slow_queries.rs — Timing of methods for log_slow_queries
 //! ├── projections.rs — Optimized projection SELECT methods
 //! ├── soft_delete.rs — Soft delete support methods
 //! └── helpers.rs     — SQL building helper functions
@@ -38,9 +42,14 @@
 //!     async fn update(&self, id: Uuid, dto: UpdateUserRequest) -> Result<User, Self::Error>;
 //!     async fn delete(&self, id: Uuid) -> Result<bool, Self::Error>;
 //!     async fn list(&self, limit: i64, offset: i64) -> Result<Vec<User>, Self::Error>;
+//!     async fn ping(&self) -> Result<(), Self::Error>;
 //!
-//!     // Query method (if #[filter] used)
+//!     // Query methods (if #[filter] used)
 //!     async fn query(&self, query: UserQuery) -> Result<Vec<User>, Self::Error>;
+//!     async fn list_with_total(&self, query: UserQuery) -> Result<(Vec<User>, i64), Self::Error>;
+//!
+//!     // Full-text search (if #[column(tsvector)] used)
+//!     async fn search(&self, query: &str, limit: i64, offset: i64) -> Result<Vec<User>, Self::Error>;
 //!
 //!     // Relation methods
 //!     async fn find_organization(&self, id: Uuid) -> Result<Option<Organization>, Self::Error>;
@@ -55,16 +64,24 @@
 //! }
 //! ```
 //!
+//! The base `entity_core::Repository` trait is implemented once for
+//! `sqlx::PgPool` in entity-core rather than per entity, so any number of
+//! entities can share the pool without conflicting impls.
+//!
 //! # Feature Flag
 //!
 //! Generated code is gated behind `#[cfg(feature = "postgres")]`.
 
 mod context;
 mod crud;
+mod debug_sql;
+mod insert_query;
 mod notify;
 mod projections;
 mod query;
 mod relations;
+mod search;
+mod slow_queries;
 mod soft_delete;
 
 pub mod helpers;
@@ -84,51 +101,110 @@ use crate::{entity::parse::EntityDef, utils::marker};
 ///
 /// | Category | Methods |
 /// |----------|---------|
-/// | CRUD | `create`, `find_by_id`, `update`, `delete`, `list` |
-/// | Query | `query` (if entity has `#[filter]` fields) |
+/// | CRUD | `create`, `find_by_id`, `update`, `delete`, `list`, `list_page` |
+/// | Batch lookup | `find_by_ids_map` |
+/// | Health | `ping` |
+/// | Bulk | `update_many` (if entity has update fields), `bulk_upsert` (with `upsert`) |
+/// | Ordered list | `list_ordered` (if entity has `#[sortable]` fields) |
+/// | Keyset list | `list_after` (if `#[entity(cursor = "...")]` is set) |
+/// | Query | `query`, `list_with_total` (if entity has `#[filter]` fields) |
+/// | Search | `search` (if entity has a `#[column(tsvector)]` field) |
+/// | Streams | `stream_filtered`, `stream_query` (with `streams` and `#[filter]` fields) |
 /// | Relations | `find_{parent}`, `find_{children}` |
+/// | Relation loaders | `{Entity}::load_{parent}`, `{Entity}::load_{children}` (inherent) |
+/// | Debug SQL | `{Entity}::*_SQL` constants, `debug_sql` (inherent) |
+/// | Insert query | `Insertable{Entity}::INSERT_SQL`, `into_insert_query` (inherent); `COLUMNS` comes with the struct |
 /// | Projections | `find_by_id_{projection}` |
 /// | Soft Delete | `hard_delete`, `restore`, `*_with_deleted` |
+///
+/// With `log_slow_queries = N`, every method except the `stream_*` ones
+/// warns through `tracing` when a call takes `N` milliseconds or longer.
 pub fn generate(entity: &EntityDef) -> TokenStream {
     let ctx = Context::new(entity);
+    let repository = repository_impl(&ctx, &quote! { sqlx::PgPool });
+    let relation_loaders = ctx.relation_loaders();
+    let insert_query = ctx.insert_query();
+    let debug_sql = ctx.debug_sql();
+
+    quote! {
+        #repository
+        #relation_loaders
+        #insert_query
+        #debug_sql
+    }
+}
+
+/// Generate `impl {Name}Repository for #pool`, gated by the context
+/// dialect's feature.
+///
+/// Shared with the SQLite generator, which passes a context built for
+/// [`DatabaseDialect::Sqlite`](crate::entity::parse::DatabaseDialect).
+pub fn repository_impl(ctx: &Context<'_>, pool: &TokenStream) -> TokenStream {
     let trait_name = &ctx.trait_name;
-    let feature = entity.dialect.feature_flag();
-    let error_type = entity.error_type();
+    let feature = ctx.dialect.feature_flag();
+    let error_type = ctx.entity.error_type();
 
     let create_impl = ctx.create_method();
     let find_impl = ctx.find_by_id_method();
+    let find_map_impl = ctx.find_by_ids_map_method();
     let update_impl = ctx.update_method();
+    let update_many_impl = ctx.update_many_method();
+    let bulk_upsert_impl = ctx.bulk_upsert_method();
     let delete_impl = ctx.delete_method();
     let list_impl = ctx.list_method();
+    let list_page_impl = ctx.list_page_method();
+    let list_ordered_impl = ctx.list_ordered_method();
+    let list_after_impl = ctx.list_after_method();
+    let ping_impl = ctx.ping_method();
     let query_impl = ctx.query_method();
+    let list_with_total_impl = ctx.list_with_total_method();
+    let search_impl = ctx.search_method();
     let stream_impl = ctx.stream_filtered_method();
+    let stream_query_impl = ctx.stream_query_method();
     let relation_impls = ctx.relation_methods();
     let projection_impls = ctx.projection_methods();
     let soft_delete_impls = ctx.soft_delete_methods();
+    let methods = slow_queries::instrument(
+        &ctx.entity.name_str(),
+        ctx.entity.slow_query_threshold(),
+        quote! {
+            #create_impl
+            #find_impl
+            #find_map_impl
+            #update_impl
+            #update_many_impl
+            #bulk_upsert_impl
+            #delete_impl
+            #list_impl
+            #list_page_impl
+            #list_ordered_impl
+            #list_after_impl
+            #ping_impl
+            #query_impl
+            #list_with_total_impl
+            #search_impl
+            #stream_impl
+            #stream_query_impl
+            #relation_impls
+            #projection_impls
+            #soft_delete_impls
+        }
+    );
     let marker = marker::generated();
 
     quote! {
         #marker
         #[cfg(feature = #feature)]
         #[async_trait::async_trait]
-        impl #trait_name for sqlx::PgPool {
+        impl #trait_name for #pool {
             type Error = #error_type;
-            type Pool = sqlx::PgPool;
+            type Pool = #pool;
 
             fn pool(&self) -> &Self::Pool {
                 self
             }
 
-            #create_impl
-            #find_impl
-            #update_impl
-            #delete_impl
-            #list_impl
-            #query_impl
-            #stream_impl
-            #relation_impls
-            #projection_impls
-            #soft_delete_impls
+            #methods
         }
     }
 }
@@ -8,7 +8,7 @@
 
 use quote::format_ident;
 
-use super::helpers::join_columns;
+use super::helpers::{join_columns, select_columns};
 use crate::entity::parse::{DatabaseDialect, EntityDef, ReturningMode};
 
 /// Context for PostgreSQL code generation.
@@ -22,13 +22,16 @@ use crate::entity::parse::{DatabaseDialect, EntityDef, ReturningMode};
 /// | Field | Description |
 /// |-------|-------------|
 /// | `entity` | Reference to the parsed entity definition |
-/// | `dialect` | Database dialect (always Postgres here) |
+/// | `dialect` | Database dialect (Postgres, or SQLite for `alt_dialect`) |
 /// | `trait_name` | Repository trait name (e.g., `UserRepository`) |
 /// | `entity_name` | Entity struct name (e.g., `User`) |
 /// | `row_name` | Row struct name (e.g., `UserRow`) |
 /// | `table` | Full table name with schema (e.g., `public.users`) |
-/// | `columns_str` | Comma-separated column names |
+/// | `columns_str` | Comma-separated stored column names |
+/// | `select_str` | Column list for SELECT, aliased to the row prefix |
+/// | `returning_str` | Column list for `RETURNING`, the same explicit list as `select_str` |
 /// | `placeholders_str` | Comma-separated placeholders (`$1, $2, ...`) |
+/// | `list_order` | `ORDER BY` terms of `list` and `query` |
 pub struct Context<'a> {
     /// Reference to the parsed entity definition.
     pub entity: &'a EntityDef,
@@ -60,15 +63,31 @@ pub struct Context<'a> {
     /// Primary key field name.
     pub id_name: &'a syn::Ident,
 
+    /// Primary key column name.
+    pub id_column: String,
+
     /// Primary key field type.
     pub id_type: &'a syn::Type,
 
-    /// Comma-separated column names for SELECT/INSERT.
+    /// Comma-separated stored column names for INSERT.
     pub columns_str: String,
 
+    /// Column list for SELECT that decodes into the Row struct.
+    ///
+    /// Equal to `columns_str` unless `row_prefix` is set.
+    pub select_str: String,
+
+    /// Column list for `RETURNING` that decodes into the Row struct.
+    ///
+    /// `*` unless `row_prefix` is set or a field is computed.
+    pub returning_str: String,
+
     /// Comma-separated placeholders for INSERT ($1, $2, ...).
     pub placeholders_str: String,
 
+    /// `ORDER BY` terms of `list` and `query` (e.g. `id DESC`).
+    pub list_order: String,
+
     /// Whether soft delete is enabled.
     pub soft_delete: bool,
 
@@ -82,9 +101,16 @@ pub struct Context<'a> {
 impl<'a> Context<'a> {
     /// Create a new generation context from an entity definition.
     pub fn new(entity: &'a EntityDef) -> Self {
+        Self::with_dialect(entity, entity.dialect)
+    }
+
+    /// Create a generation context for `dialect`, e.g. the entity's
+    /// `alt_dialect`.
+    ///
+    /// SQLite has no schemas, so its table name is unqualified.
+    pub fn with_dialect(entity: &'a EntityDef, dialect: DatabaseDialect) -> Self {
         let id_field = entity.id_field();
         let fields = entity.all_fields();
-        let dialect = entity.dialect;
 
         Self {
             entity,
@@ -95,11 +121,19 @@ impl<'a> Context<'a> {
             insertable_name: entity.ident_with("Insertable", ""),
             create_dto: entity.ident_with("Create", "Request"),
             update_dto: entity.ident_with("Update", "Request"),
-            table: entity.full_table_name(),
+            table: match dialect {
+                DatabaseDialect::Sqlite => entity.table.clone(),
+                _ => entity.full_table_name()
+            },
             id_name: id_field.name(),
+            id_column: id_field.column_name(),
             id_type: id_field.ty(),
             columns_str: join_columns(fields),
-            placeholders_str: dialect.placeholders(fields.len()),
+            select_str: select_columns(fields, entity.row_prefix.as_deref()),
+            returning_str: select_columns(fields, entity.row_prefix.as_deref()),
+            placeholders_str: dialect
+                .placeholders(fields.iter().filter(|f| !f.is_db_generated()).count()),
+            list_order: entity.list_order(),
             soft_delete: entity.is_soft_delete(),
             returning: entity.returning.clone(),
             streams: entity.has_streams()
@@ -9,9 +9,15 @@
 //! |--------|---------------|
 //! | [`create`](Context::create_method) | `INSERT INTO ... VALUES ... RETURNING ...` |
 //! | [`find_by_id`](Context::find_by_id_method) | `SELECT ... WHERE id = $1` |
+//! | [`find_by_ids_map`](Context::find_by_ids_map_method) | `SELECT ... WHERE id = ANY($1)` |
 //! | [`update`](Context::update_method) | `UPDATE ... SET ... WHERE id = $n` |
+//! | [`update_many`](Context::update_many_method) | `UPDATE ... SET ... WHERE id = ANY($n)` |
+//! | [`bulk_upsert`](Context::bulk_upsert_method) | `INSERT ... VALUES (...), (...) ON CONFLICT (key) DO UPDATE SET ...` |
 //! | [`delete`](Context::delete_method) | `DELETE FROM ... WHERE id = $1` |
 //! | [`list`](Context::list_method) | `SELECT ... ORDER BY ... LIMIT ... OFFSET ...` |
+//! | [`list_ordered`](Context::list_ordered_method) | `SELECT ... ORDER BY {order}, id DESC LIMIT ... OFFSET ...` |
+//! | [`list_page`](Context::list_page_method) | `SELECT ... WHERE id < $1 ORDER BY ... LIMIT limit + 1` |
+//! | [`ping`](Context::ping_method) | `SELECT 1` |
 //!
 //! # RETURNING Modes
 //!
@@ -20,9 +26,10 @@
 //!
 //! | Mode | Behavior |
 //! |------|----------|
-//! | `Full` | Uses `RETURNING *` to fetch all columns |
+//! | `Full` | Uses `RETURNING` with the explicit column list to fetch all columns |
 //! | `Id` | Uses `RETURNING id` for minimal overhead |
 //! | `None` | No RETURNING clause (fire-and-forget) |
+//! | `Minimal` | No RETURNING clause, `create` returns the id |
 //! | `Custom` | Returns specified columns |
 
 use proc_macro2::TokenStream;
@@ -30,9 +37,15 @@ use quote::quote;
 
 use super::{
     context::Context,
-    helpers::{insert_bindings, update_bindings}
+    helpers::{
+        generate_patch_assignments, generate_patch_bindings, insert_bindings, update_bindings,
+        update_param_count, update_set_clause
+    }
 };
-use crate::entity::parse::ReturningMode;
+use crate::entity::parse::{DatabaseDialect, ReturningMode};
+
+/// Bound parameters PostgreSQL accepts in one statement.
+const POSTGRES_MAX_BINDS: usize = 65535;
 
 impl Context<'_> {
     /// Generate the `create` method implementation.
@@ -42,7 +55,7 @@ impl Context<'_> {
     /// ```sql
     /// INSERT INTO schema.table (col1, col2, ...)
     /// VALUES ($1, $2, ...)
-    /// RETURNING *  -- depends on returning mode
+    /// RETURNING col1, col2, ...  -- depends on returning mode
     /// ```
     ///
     /// # Returns
@@ -61,6 +74,7 @@ impl Context<'_> {
             table,
             columns_str,
             placeholders_str,
+            returning_str,
             entity,
             returning,
             ..
@@ -75,7 +89,7 @@ impl Context<'_> {
                         let entity = #entity_name::from(dto);
                         let insertable = #insertable_name::from(&entity);
                         let row: #row_name = sqlx::query_as(
-                            concat!("INSERT INTO ", #table, " (", #columns_str, ") VALUES (", #placeholders_str, ") RETURNING *")
+                            concat!("INSERT INTO ", #table, " (", #columns_str, ") VALUES (", #placeholders_str, ") RETURNING ", #returning_str)
                         )
                             #(#bindings)*
                             .fetch_one(self).await?;
@@ -86,13 +100,13 @@ impl Context<'_> {
                 }
             }
             ReturningMode::Id => {
-                let id_name = self.id_name;
+                let id_column = &self.id_column;
                 let notify = self.notify_created();
                 quote! {
                     async fn create(&self, dto: #create_dto) -> Result<#entity_name, Self::Error> {
                         let entity = #entity_name::from(dto);
                         let insertable = #insertable_name::from(&entity);
-                        sqlx::query(concat!("INSERT INTO ", #table, " (", #columns_str, ") VALUES (", #placeholders_str, ") RETURNING ", stringify!(#id_name)))
+                        sqlx::query(concat!("INSERT INTO ", #table, " (", #columns_str, ") VALUES (", #placeholders_str, ") RETURNING ", #id_column))
                             #(#bindings)*
                             .execute(self).await?;
                         #notify
@@ -105,15 +119,32 @@ impl Context<'_> {
                 quote! {
                     async fn create(&self, dto: #create_dto) -> Result<#entity_name, Self::Error> {
                         let entity = #entity_name::from(dto);
-                        let insertable = #insertable_name::from(&entity);
-                        sqlx::query(concat!("INSERT INTO ", #table, " (", #columns_str, ") VALUES (", #placeholders_str, ")"))
-                            #(#bindings)*
+                        #insertable_name::from(&entity)
+                            .into_insert_query()
                             .execute(self).await?;
                         #notify
                         Ok(entity)
                     }
                 }
             }
+            ReturningMode::Minimal => {
+                let Self {
+                    id_name,
+                    id_type,
+                    ..
+                } = self;
+                let notify = self.notify_created();
+                quote! {
+                    async fn create(&self, dto: #create_dto) -> Result<#id_type, Self::Error> {
+                        let entity = #entity_name::from(dto);
+                        #insertable_name::from(&entity)
+                            .into_insert_query()
+                            .execute(self).await?;
+                        #notify
+                        Ok(entity.#id_name)
+                    }
+                }
+            }
             ReturningMode::Custom(columns) => {
                 let returning_cols = columns.join(", ");
                 let notify = self.notify_created();
@@ -146,8 +177,8 @@ impl Context<'_> {
             entity_name,
             row_name,
             table,
-            columns_str,
-            id_name,
+            select_str,
+            id_column,
             id_type,
             dialect,
             soft_delete,
@@ -163,13 +194,94 @@ impl Context<'_> {
         quote! {
             async fn find_by_id(&self, id: #id_type) -> Result<Option<#entity_name>, Self::Error> {
                 let row: Option<#row_name> = sqlx::query_as(
-                    &format!("SELECT {} FROM {} WHERE {} = {}{}", #columns_str, #table, stringify!(#id_name), #placeholder, #deleted_filter)
+                    &format!("SELECT {} FROM {} WHERE {} = {}{}", #select_str, #table, #id_column, #placeholder, #deleted_filter)
                 ).bind(&id).fetch_optional(self).await?;
                 Ok(row.map(#entity_name::from))
             }
         }
     }
 
+    /// Generate the `find_by_ids_map` method implementation.
+    ///
+    /// # SQL Pattern
+    ///
+    /// ```sql
+    /// SELECT col1, col2, ... FROM schema.table
+    /// WHERE id = ANY($1)      -- id IN ($1, $2, ...) on SQLite
+    /// AND deleted_at IS NULL  -- if soft_delete enabled
+    /// ```
+    ///
+    /// An empty id list returns an empty map without a query.
+    ///
+    /// # Returns
+    ///
+    /// Empty `TokenStream` without `#[entity(ids_map)]`.
+    pub fn find_by_ids_map_method(&self) -> TokenStream {
+        if !self.entity.has_ids_map() {
+            return TokenStream::new();
+        }
+
+        let Self {
+            entity_name,
+            row_name,
+            table,
+            select_str,
+            id_name,
+            id_column,
+            id_type,
+            soft_delete,
+            ..
+        } = self;
+        let deleted_filter = if *soft_delete {
+            " AND deleted_at IS NULL"
+        } else {
+            ""
+        };
+
+        // SQLite has no arrays, so the ids are bound one by one.
+        let (id_filter, id_bindings) = match self.dialect {
+            DatabaseDialect::Sqlite => (
+                quote! {
+                    let id_placeholders: Vec<String> =
+                        (1..=ids.len()).map(|i| format!("${}", i)).collect();
+                    let id_filter = format!("IN ({})", id_placeholders.join(", "));
+                },
+                quote! {
+                    for id in ids {
+                        q = q.bind(id);
+                    }
+                }
+            ),
+            _ => (
+                quote! { let id_filter = "= ANY($1)"; },
+                quote! { q = q.bind(ids); }
+            )
+        };
+
+        quote! {
+            async fn find_by_ids_map(&self, ids: Vec<#id_type>) -> Result<std::collections::HashMap<#id_type, #entity_name>, Self::Error> {
+                if ids.is_empty() {
+                    return Ok(std::collections::HashMap::new());
+                }
+
+                #id_filter
+                let sql = format!(
+                    "SELECT {} FROM {} WHERE {} {}{}",
+                    #select_str, #table, #id_column, id_filter, #deleted_filter
+                );
+
+                let mut q = sqlx::query_as::<_, #row_name>(&sql);
+                #id_bindings
+                let rows = q.fetch_all(self).await?;
+                Ok(rows
+                    .into_iter()
+                    .map(#entity_name::from)
+                    .map(|entity| (entity.#id_name.clone(), entity))
+                    .collect())
+            }
+        }
+    }
+
     /// Generate the `update` method implementation.
     ///
     /// # SQL Pattern
@@ -178,7 +290,7 @@ impl Context<'_> {
     /// UPDATE schema.table
     /// SET col1 = $1, col2 = $2, ...
     /// WHERE id = $n
-    /// RETURNING *  -- depends on returning mode
+    /// RETURNING col1, col2, ...  -- depends on returning mode
     /// ```
     ///
     /// # Returns
@@ -195,18 +307,17 @@ impl Context<'_> {
             row_name,
             update_dto,
             table,
-            id_name,
+            id_column,
             id_type,
             dialect,
             trait_name,
             returning,
+            returning_str,
             ..
         } = self;
 
-        let field_names: Vec<String> = update_fields.iter().map(|f| f.name_str()).collect();
-        let field_refs: Vec<&str> = field_names.iter().map(String::as_str).collect();
-        let set_clause = dialect.set_clause(&field_refs);
-        let where_placeholder = dialect.placeholder(update_fields.len() + 1);
+        let set_clause = update_set_clause(dialect, &update_fields);
+        let where_placeholder = dialect.placeholder(update_param_count(&update_fields) + 1);
         let bindings = update_bindings(&update_fields);
 
         let fetch_old = self.fetch_old_for_update();
@@ -218,7 +329,7 @@ impl Context<'_> {
                     async fn update(&self, id: #id_type, dto: #update_dto) -> Result<#entity_name, Self::Error> {
                         #fetch_old
                         let row: #row_name = sqlx::query_as(
-                            &format!("UPDATE {} SET {} WHERE {} = {} RETURNING *", #table, #set_clause, stringify!(#id_name), #where_placeholder)
+                            &format!("UPDATE {} SET {} WHERE {} = {} RETURNING {}", #table, #set_clause, #id_column, #where_placeholder, #returning_str)
                         )
                             #(#bindings)*
                             .bind(&id)
@@ -229,11 +340,11 @@ impl Context<'_> {
                     }
                 }
             }
-            ReturningMode::Id | ReturningMode::None => {
+            ReturningMode::Id | ReturningMode::None | ReturningMode::Minimal => {
                 quote! {
                     async fn update(&self, id: #id_type, dto: #update_dto) -> Result<#entity_name, Self::Error> {
                         #fetch_old
-                        sqlx::query(&format!("UPDATE {} SET {} WHERE {} = {}", #table, #set_clause, stringify!(#id_name), #where_placeholder))
+                        sqlx::query(&format!("UPDATE {} SET {} WHERE {} = {}", #table, #set_clause, #id_column, #where_placeholder))
                             #(#bindings)*
                             .bind(&id)
                             .execute(self).await?;
@@ -248,7 +359,7 @@ impl Context<'_> {
                 quote! {
                     async fn update(&self, id: #id_type, dto: #update_dto) -> Result<#entity_name, Self::Error> {
                         #fetch_old
-                        sqlx::query(&format!("UPDATE {} SET {} WHERE {} = {} RETURNING {}", #table, #set_clause, stringify!(#id_name), #where_placeholder, #returning_cols))
+                        sqlx::query(&format!("UPDATE {} SET {} WHERE {} = {} RETURNING {}", #table, #set_clause, #id_column, #where_placeholder, #returning_cols))
                             #(#bindings)*
                             .bind(&id)
                             .execute(self).await?;
@@ -261,6 +372,206 @@ impl Context<'_> {
         }
     }
 
+    /// Generate the `update_many` method implementation.
+    ///
+    /// # SQL Pattern
+    ///
+    /// ```sql
+    /// UPDATE schema.table
+    /// SET col1 = $1, ...   -- only the fields set in the patch
+    /// WHERE id = ANY($n)    -- id IN ($n, $n+1, ...) on SQLite
+    /// AND deleted_at IS NULL  -- if soft_delete enabled
+    /// ```
+    ///
+    /// Returns the number of affected rows. An empty id list or a patch
+    /// with no fields set is a no-op returning `0`. No events are emitted.
+    ///
+    /// # Returns
+    ///
+    /// Empty `TokenStream` if entity has no update fields.
+    pub fn update_many_method(&self) -> TokenStream {
+        let update_fields = self.entity.update_fields();
+        if update_fields.is_empty() {
+            return TokenStream::new();
+        }
+
+        let Self {
+            update_dto,
+            table,
+            id_column,
+            id_type,
+            soft_delete,
+            ..
+        } = self;
+        let deleted_filter = if *soft_delete {
+            " AND deleted_at IS NULL"
+        } else {
+            ""
+        };
+        let assignments = generate_patch_assignments(&update_fields);
+        let bindings = generate_patch_bindings(&update_fields);
+
+        // SQLite has no arrays, so the ids are bound one by one.
+        let (id_filter, id_bindings) = match self.dialect {
+            DatabaseDialect::Sqlite => (
+                quote! {
+                    let id_placeholders: Vec<String> =
+                        (0..ids.len()).map(|i| format!("${}", param_idx + i)).collect();
+                    let id_filter = format!("IN ({})", id_placeholders.join(", "));
+                },
+                quote! {
+                    for id in ids {
+                        q = q.bind(id);
+                    }
+                }
+            ),
+            _ => (
+                quote! { let id_filter = format!("= ANY(${})", param_idx); },
+                quote! { q = q.bind(ids); }
+            )
+        };
+
+        quote! {
+            async fn update_many(&self, ids: Vec<#id_type>, dto: #update_dto) -> Result<u64, Self::Error> {
+                let mut sets: Vec<String> = Vec::new();
+                let mut param_idx: usize = 1;
+
+                #assignments
+
+                if ids.is_empty() || sets.is_empty() {
+                    return Ok(0);
+                }
+
+                #id_filter
+                let sql = format!(
+                    "UPDATE {} SET {} WHERE {} {}{}",
+                    #table, sets.join(", "), #id_column, id_filter, #deleted_filter
+                );
+
+                let mut q = sqlx::query(&sql);
+                #bindings
+                #id_bindings
+                let result = q.execute(self).await?;
+                Ok(result.rows_affected())
+            }
+        }
+    }
+
+    /// Generate the `bulk_upsert` method implementation.
+    ///
+    /// # SQL Pattern
+    ///
+    /// ```sql
+    /// INSERT INTO schema.table (col1, col2, ...)
+    /// VALUES ($1, $2, ...), ($n, $n+1, ...), ...
+    /// ON CONFLICT (key) DO UPDATE SET col2 = EXCLUDED.col2, ...
+    /// RETURNING col1, col2, ...
+    /// ```
+    ///
+    /// Every create field outside the key is overwritten on conflict. When
+    /// the key covers all create fields, the key is assigned to itself so
+    /// `RETURNING` still yields the existing row. Rows are always returned
+    /// in full, whatever the `returning` mode, because a conflicting row
+    /// keeps its stored id. No events, hooks or notifications are emitted.
+    ///
+    /// PostgreSQL rejects a key affected twice by one statement, so only the
+    /// last DTO per key is kept, which requires `Hash + Eq` key fields. The
+    /// rows are split into statements of at most 65535 bound parameters, run
+    /// in one transaction.
+    ///
+    /// # Returns
+    ///
+    /// Empty `TokenStream` without `#[entity(upsert = "...")]`.
+    pub fn bulk_upsert_method(&self) -> TokenStream {
+        let key = self.entity.upsert_key();
+        if key.is_empty() {
+            return TokenStream::new();
+        }
+
+        let Self {
+            entity_name,
+            row_name,
+            insertable_name,
+            create_dto,
+            table,
+            columns_str,
+            returning_str,
+            id_column,
+            ..
+        } = self;
+        let fields = self.entity.all_fields();
+        let key_fields: Vec<_> = key
+            .iter()
+            .filter_map(|name| fields.iter().find(|f| f.name_str() == *name))
+            .collect();
+        let key_idents: Vec<_> = key_fields.iter().map(|f| f.name()).collect();
+        let key: Vec<String> = key_fields.iter().map(|f| f.column_name()).collect();
+        let binds: Vec<_> = fields
+            .iter()
+            .filter(|f| !f.is_db_generated())
+            .map(|f| {
+                let name = f.name();
+                quote! { b.push_bind(insertable.#name); }
+            })
+            .collect();
+        let chunk_size = POSTGRES_MAX_BINDS / binds.len().max(1);
+        let mut assignments: Vec<String> = self
+            .entity
+            .create_fields()
+            .iter()
+            .map(|f| f.column_name())
+            .filter(|name| name != id_column && !key.contains(name))
+            .map(|name| format!("{name} = EXCLUDED.{name}"))
+            .collect();
+        if assignments.is_empty() {
+            assignments.push(format!("{0} = EXCLUDED.{0}", key[0]));
+        }
+        let conflict = format!(
+            " ON CONFLICT ({}) DO UPDATE SET {} RETURNING ",
+            key.join(", "),
+            assignments.join(", ")
+        );
+
+        quote! {
+            async fn bulk_upsert(&self, dtos: Vec<#create_dto>) -> Result<Vec<#entity_name>, Self::Error> {
+                if dtos.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                let insertables: Vec<#insertable_name> = dtos
+                    .into_iter()
+                    .map(|dto| #insertable_name::from(#entity_name::from(dto)))
+                    .collect();
+                let mut seen = std::collections::HashSet::new();
+                let mut keep = vec![false; insertables.len()];
+                for (i, insertable) in insertables.iter().enumerate().rev() {
+                    keep[i] = seen.insert((#(&insertable.#key_idents,)*));
+                }
+                let mut insertables = insertables
+                    .into_iter()
+                    .zip(keep)
+                    .filter_map(|(insertable, keep)| keep.then_some(insertable))
+                    .peekable();
+
+                let mut tx = sqlx::PgPool::begin(self).await?;
+                let mut rows: Vec<#row_name> = Vec::new();
+                while insertables.peek().is_some() {
+                    let mut qb = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+                        concat!("INSERT INTO ", #table, " (", #columns_str, ") ")
+                    );
+                    qb.push_values(insertables.by_ref().take(#chunk_size), |mut b, insertable| {
+                        #(#binds)*
+                    });
+                    qb.push(#conflict);
+                    qb.push(#returning_str);
+                    rows.extend(qb.build_query_as::<#row_name>().fetch_all(&mut *tx).await?);
+                }
+                tx.commit().await?;
+                Ok(#entity_name::from_rows(rows))
+            }
+        }
+    }
+
     /// Generate the `delete` method implementation.
     ///
     /// # SQL Pattern
@@ -275,24 +586,52 @@ impl Context<'_> {
     /// UPDATE schema.table SET deleted_at = NOW()
     /// WHERE id = $1 AND deleted_at IS NULL
     /// ```
+    ///
+    /// With `cascade_soft_delete`, the update and the child cascades run in
+    /// one transaction.
     pub fn delete_method(&self) -> TokenStream {
         let Self {
             table,
-            id_name,
+            id_column,
             id_type,
             dialect,
             soft_delete,
             ..
         } = self;
         let placeholder = dialect.placeholder(1);
+        let soft_delete_sql = format!(
+            "UPDATE {{}} SET deleted_at = {} WHERE {{}} = {{}} AND deleted_at IS NULL",
+            dialect.current_timestamp()
+        );
 
-        if *soft_delete {
+        if *soft_delete && self.entity.has_cascade_soft_delete() {
             let notify = self.notify_soft_deleted();
+            let cascade = self.cascade_soft_delete();
             quote! {
                 async fn delete(&self, id: #id_type) -> Result<bool, Self::Error> {
+                    let mut tx = sqlx::PgPool::begin(self).await?;
                     let result = sqlx::query(&format!(
-                        "UPDATE {} SET deleted_at = NOW() WHERE {} = {} AND deleted_at IS NULL",
-                        #table, stringify!(#id_name), #placeholder
+                        #soft_delete_sql,
+                        #table, #id_column, #placeholder
+                    )).bind(&id).execute(&mut *tx).await?;
+                    let deleted = result.rows_affected() > 0;
+                    if deleted {
+                        #cascade
+                    }
+                    tx.commit().await?;
+                    if deleted {
+                        #notify
+                    }
+                    Ok(deleted)
+                }
+            }
+        } else if *soft_delete {
+            let notify = self.notify_soft_deleted();
+            quote! {
+                async fn delete(&self, id: #id_type) -> Result<bool, Self::Error> {
+                    let result = sqlx::query(&format!(
+                        #soft_delete_sql,
+                        #table, #id_column, #placeholder
                     )).bind(&id).execute(self).await?;
                     let deleted = result.rows_affected() > 0;
                     if deleted {
@@ -305,7 +644,7 @@ impl Context<'_> {
             let notify = self.notify_hard_deleted();
             quote! {
                 async fn delete(&self, id: #id_type) -> Result<bool, Self::Error> {
-                    let result = sqlx::query(&format!("DELETE FROM {} WHERE {} = {}", #table, stringify!(#id_name), #placeholder))
+                    let result = sqlx::query(&format!("DELETE FROM {} WHERE {} = {}", #table, #id_column, #placeholder))
                         .bind(&id).execute(self).await?;
                     let deleted = result.rows_affected() > 0;
                     if deleted {
@@ -324,7 +663,7 @@ impl Context<'_> {
     /// ```sql
     /// SELECT col1, col2, ... FROM schema.table
     /// WHERE deleted_at IS NULL  -- if soft_delete enabled
-    /// ORDER BY id DESC
+    /// ORDER BY id DESC          -- or #[entity(order_by = "...")]
     /// LIMIT $1 OFFSET $2
     /// ```
     pub fn list_method(&self) -> TokenStream {
@@ -332,8 +671,8 @@ impl Context<'_> {
             entity_name,
             row_name,
             table,
-            columns_str,
-            id_name,
+            select_str,
+            list_order,
             dialect,
             soft_delete,
             ..
@@ -349,11 +688,385 @@ impl Context<'_> {
         quote! {
             async fn list(&self, limit: i64, offset: i64) -> Result<Vec<#entity_name>, Self::Error> {
                 let rows: Vec<#row_name> = sqlx::query_as(
-                    &format!("SELECT {} FROM {} {}ORDER BY {} DESC LIMIT {} OFFSET {}",
-                        #columns_str, #table, #where_clause, stringify!(#id_name), #limit_placeholder, #offset_placeholder)
+                    &format!("SELECT {} FROM {} {}ORDER BY {} LIMIT {} OFFSET {}",
+                        #select_str, #table, #where_clause, #list_order, #limit_placeholder, #offset_placeholder)
                 ).bind(limit).bind(offset).fetch_all(self).await?;
-                Ok(rows.into_iter().map(#entity_name::from).collect())
+                Ok(#entity_name::from_rows(rows))
+            }
+        }
+    }
+
+    /// Generate the `list_ordered` method implementation.
+    ///
+    /// # SQL Pattern
+    ///
+    /// ```sql
+    /// SELECT col1, col2, ... FROM schema.table
+    /// WHERE deleted_at IS NULL  -- if soft_delete enabled
+    /// ORDER BY name ASC, id DESC
+    /// LIMIT $1 OFFSET $2
+    /// ```
+    ///
+    /// The order term comes from the closed `{Entity}OrderBy` enum; the id
+    /// breaks ties so offset pagination stays stable.
+    ///
+    /// # Returns
+    ///
+    /// Empty `TokenStream` if entity has no sortable fields.
+    pub fn list_ordered_method(&self) -> TokenStream {
+        if !self.entity.has_sortable() {
+            return TokenStream::new();
+        }
+
+        let Self {
+            entity_name,
+            row_name,
+            table,
+            select_str,
+            id_column,
+            dialect,
+            soft_delete,
+            ..
+        } = self;
+        let order_type = self.entity.ident_with("", "OrderBy");
+        let limit_placeholder = dialect.placeholder(1);
+        let offset_placeholder = dialect.placeholder(2);
+        let where_clause = if *soft_delete {
+            "WHERE deleted_at IS NULL "
+        } else {
+            ""
+        };
+
+        quote! {
+            async fn list_ordered(&self, order: #order_type, limit: i64, offset: i64) -> Result<Vec<#entity_name>, Self::Error> {
+                let rows: Vec<#row_name> = sqlx::query_as(
+                    &format!("SELECT {} FROM {} {}ORDER BY {}, {} DESC LIMIT {} OFFSET {}",
+                        #select_str, #table, #where_clause, order.as_sql(), #id_column, #limit_placeholder, #offset_placeholder)
+                ).bind(limit).bind(offset).fetch_all(self).await?;
+                Ok(#entity_name::from_rows(rows))
+            }
+        }
+    }
+
+    /// Generate the `list_page` method implementation.
+    ///
+    /// Fetches `limit + 1` rows and trims the extra one to compute
+    /// `has_next`.
+    ///
+    /// # SQL Pattern
+    ///
+    /// ```sql
+    /// SELECT col1, col2, ... FROM schema.table
+    /// WHERE id < $1             -- when a cursor is given
+    /// AND deleted_at IS NULL    -- if soft_delete enabled
+    /// ORDER BY id DESC
+    /// LIMIT $2
+    /// ```
+    pub fn list_page_method(&self) -> TokenStream {
+        let Self {
+            entity_name,
+            row_name,
+            table,
+            select_str,
+            id_name,
+            id_column,
+            id_type,
+            dialect,
+            soft_delete,
+            ..
+        } = self;
+        let cursor_placeholder = dialect.placeholder(1);
+        let cursor_limit_placeholder = dialect.placeholder(2);
+        let first_limit_placeholder = dialect.placeholder(1);
+        let (cursor_filter, first_where) = if *soft_delete {
+            (" AND deleted_at IS NULL", "WHERE deleted_at IS NULL ")
+        } else {
+            ("", "")
+        };
+
+        quote! {
+            async fn list_page(&self, cursor: Option<#id_type>, limit: i64) -> Result<entity_derive::Page<#entity_name, #id_type>, Self::Error> {
+                let fetch = limit.saturating_add(1);
+                let rows: Vec<#row_name> = match cursor {
+                    Some(cursor) => sqlx::query_as(
+                        &format!("SELECT {} FROM {} WHERE {} < {}{} ORDER BY {} DESC LIMIT {}",
+                            #select_str, #table, #id_column, #cursor_placeholder, #cursor_filter, #id_column, #cursor_limit_placeholder)
+                    ).bind(cursor).bind(fetch).fetch_all(self).await?,
+                    None => sqlx::query_as(
+                        &format!("SELECT {} FROM {} {}ORDER BY {} DESC LIMIT {}",
+                            #select_str, #table, #first_where, #id_column, #first_limit_placeholder)
+                    ).bind(fetch).fetch_all(self).await?
+                };
+                let items: Vec<#entity_name> = #entity_name::from_rows(rows);
+                Ok(entity_derive::Page::from_overfetch(items, limit, |e| e.#id_name.clone()))
             }
         }
     }
+
+    /// Generate the `list_after` method implementation.
+    ///
+    /// Fetches `limit + 1` rows and trims the extra one to compute
+    /// `has_next`, like `list_page`, but orders by the `cursor` columns and
+    /// compares them as a row value.
+    ///
+    /// # SQL Pattern
+    ///
+    /// ```sql
+    /// SELECT col1, col2, ... FROM schema.table
+    /// WHERE (created_at, id) > ($1, $2)  -- when a cursor is given
+    /// AND deleted_at IS NULL             -- if soft_delete enabled
+    /// ORDER BY created_at, id
+    /// LIMIT $3
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Empty `TokenStream` without `#[entity(cursor = "...")]`.
+    pub fn list_after_method(&self) -> TokenStream {
+        let fields = self.entity.cursor_fields();
+        if fields.is_empty() {
+            return TokenStream::new();
+        }
+
+        let Self {
+            entity_name,
+            row_name,
+            table,
+            select_str,
+            dialect,
+            soft_delete,
+            ..
+        } = self;
+        let cursor_name = self.entity.ident_with("", "Cursor");
+        let columns = fields
+            .iter()
+            .map(|f| f.column_name())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let placeholders = dialect.placeholders(fields.len());
+        let cursor_limit_placeholder = dialect.placeholder(fields.len() + 1);
+        let first_limit_placeholder = dialect.placeholder(1);
+        let (cursor_filter, first_where) = if *soft_delete {
+            (" AND deleted_at IS NULL", "WHERE deleted_at IS NULL ")
+        } else {
+            ("", "")
+        };
+        let cursor_where = format!("WHERE ({}) > ({}){}", columns, placeholders, cursor_filter);
+        let binds = fields.iter().map(|f| {
+            let name = f.name();
+            quote! { .bind(cursor.#name) }
+        });
+
+        quote! {
+            async fn list_after(&self, cursor: Option<#cursor_name>, limit: i64) -> Result<entity_derive::Page<#entity_name, #cursor_name>, Self::Error> {
+                let fetch = limit.saturating_add(1);
+                let rows: Vec<#row_name> = match cursor {
+                    Some(cursor) => sqlx::query_as(
+                        &format!("SELECT {} FROM {} {} ORDER BY {} LIMIT {}",
+                            #select_str, #table, #cursor_where, #columns, #cursor_limit_placeholder)
+                    )#(#binds)*.bind(fetch).fetch_all(self).await?,
+                    None => sqlx::query_as(
+                        &format!("SELECT {} FROM {} {}ORDER BY {} LIMIT {}",
+                            #select_str, #table, #first_where, #columns, #first_limit_placeholder)
+                    ).bind(fetch).fetch_all(self).await?
+                };
+                let items: Vec<#entity_name> = #entity_name::from_rows(rows);
+                Ok(entity_derive::Page::from_overfetch(items, limit, |e| #cursor_name::from(e)))
+            }
+        }
+    }
+
+    /// Generate the `ping` method implementation.
+    ///
+    /// # SQL Pattern
+    ///
+    /// ```sql
+    /// SELECT 1
+    /// ```
+    pub fn ping_method(&self) -> TokenStream {
+        quote! {
+            async fn ping(&self) -> Result<(), Self::Error> {
+                sqlx::query("SELECT 1").execute(self).await?;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::parse::EntityDef;
+
+    fn context_method(input: syn::DeriveInput) -> String {
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        Context::new(&entity).bulk_upsert_method().to_string()
+    }
+
+    #[test]
+    fn list_after_without_cursor_returns_empty() {
+        let entity = EntityDef::from_derive_input(&syn::parse_quote! {
+            #[entity(table = "posts")]
+            pub struct Post {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        })
+        .unwrap();
+        assert!(Context::new(&entity).list_after_method().is_empty());
+    }
+
+    #[test]
+    fn list_after_compares_cursor_row() {
+        let entity = EntityDef::from_derive_input(&syn::parse_quote! {
+            #[entity(table = "posts", cursor = "created_at, id", soft_delete)]
+            pub struct Post {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(response)]
+                pub created_at: chrono::DateTime<chrono::Utc>,
+                pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+            }
+        })
+        .unwrap();
+        let method = Context::new(&entity).list_after_method().to_string();
+        assert!(method.contains(
+            "async fn list_after (& self , cursor : Option < PostCursor > , limit : i64)"
+        ));
+        assert!(method.contains("\"WHERE (created_at, id) > ($1, $2) AND deleted_at IS NULL\""));
+        assert!(method.contains("\"created_at, id\" , \"$3\""));
+        assert!(method.contains("\"WHERE deleted_at IS NULL \""));
+        assert!(
+            method.contains(". bind (cursor . created_at) . bind (cursor . id) . bind (fetch)")
+        );
+    }
+
+    #[test]
+    fn bulk_upsert_without_key_returns_empty() {
+        let method = context_method(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub email: String,
+            }
+        });
+        assert!(method.is_empty());
+    }
+
+    #[test]
+    fn bulk_upsert_updates_non_key_create_fields() {
+        let method = context_method(syn::parse_quote! {
+            #[entity(table = "users", upsert = "email")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub email: String,
+                #[field(create, response)]
+                pub name: String,
+            }
+        });
+        assert!(method.contains("async fn bulk_upsert"));
+        assert!(method.contains("push_values (insertables . by_ref () . take (21845usize)"));
+        assert!(
+            method.contains(
+                "\" ON CONFLICT (email) DO UPDATE SET name = EXCLUDED.name RETURNING \""
+            )
+        );
+        assert!(method.contains("b . push_bind (insertable . id)"));
+    }
+
+    #[test]
+    fn bulk_upsert_key_only_assigns_key() {
+        let method = context_method(syn::parse_quote! {
+            #[entity(table = "tags", upsert = "name")]
+            pub struct Tag {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub name: String,
+            }
+        });
+        assert!(method.contains("ON CONFLICT (name) DO UPDATE SET name = EXCLUDED.name"));
+    }
+
+    #[test]
+    fn bulk_upsert_keeps_last_dto_per_key() {
+        let method = context_method(syn::parse_quote! {
+            #[entity(table = "memberships", upsert = "org_id, user_id")]
+            pub struct Membership {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub org_id: uuid::Uuid,
+                #[field(create, response)]
+                pub user_id: uuid::Uuid,
+            }
+        });
+        assert!(method.contains("enumerate () . rev ()"));
+        assert!(
+            method.contains("seen . insert ((& insertable . org_id , & insertable . user_id ,))")
+        );
+        assert!(method.contains("sqlx :: PgPool :: begin (self)"));
+        assert!(method.contains("fetch_all (& mut * tx)"));
+    }
+
+    fn find_by_ids_map(input: syn::DeriveInput) -> String {
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        Context::new(&entity).find_by_ids_map_method().to_string()
+    }
+
+    #[test]
+    fn find_by_ids_map_requires_opt_in() {
+        let method = find_by_ids_map(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(method.is_empty());
+    }
+
+    #[test]
+    fn find_by_ids_map_uses_any_on_postgres() {
+        let method = find_by_ids_map(syn::parse_quote! {
+            #[entity(table = "users", soft_delete, ids_map)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub email: String,
+                #[field(skip)]
+                pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+            }
+        });
+        assert!(method.contains(
+            "async fn find_by_ids_map (& self , ids : Vec < uuid :: Uuid >) -> Result < std :: collections :: HashMap < uuid :: Uuid , User > , Self :: Error >"
+        ));
+        assert!(method.contains("\"= ANY($1)\""));
+        assert!(method.contains("\" AND deleted_at IS NULL\""));
+        assert!(method.contains("q = q . bind (ids)"));
+        assert!(method.contains("(entity . id . clone () , entity)"));
+    }
+
+    #[test]
+    fn find_by_ids_map_binds_each_id_on_sqlite() {
+        let method = find_by_ids_map(syn::parse_quote! {
+            #[entity(table = "users", dialect = "sqlite", ids_map)]
+            pub struct User {
+                #[id]
+                #[column(name = "user_id")]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub email: String,
+            }
+        });
+        assert!(method.contains("IN ({})"));
+        assert!(method.contains("for id in ids"));
+        assert!(method.contains("\"user_id\""));
+        assert!(!method.contains("ANY"));
+    }
 }
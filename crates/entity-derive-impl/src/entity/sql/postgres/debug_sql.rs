@@ -0,0 +1,324 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Inspectable SQL text of the generated repository statements.
+//!
+//! Exposes each statement as an associated constant on the entity, plus
+//! `debug_sql()` listing them all, so the exact text can be pasted into
+//! `EXPLAIN ANALYZE`:
+//!
+//! ```rust,ignore
+//! for (name, sql) in User::debug_sql() {
+//!     println!("-- {name}\n{sql};");
+//! }
+//! ```
+//!
+//! | Constant | Statement | Present when |
+//! |----------|-----------|--------------|
+//! | `FIND_BY_ID_SQL` | `find_by_id` | always |
+//! | `LIST_SQL` | `list` | always |
+//! | `CREATE_SQL` | `create` | entity has create fields |
+//! | `UPDATE_SQL` | `update` | entity has update fields |
+//! | `DELETE_SQL` | `delete` | always |
+//! | `QUERY_SQL` | `query` | entity has `#[filter]` fields |
+//! | `SEARCH_SQL` | `search` | entity has a `#[column(tsvector)]` field |
+//!
+//! `QUERY_SQL` is a skeleton: the `query` method builds its `WHERE` clause
+//! at runtime from the filters that are set, so the constant shows a
+//! `<filters>` marker in its place, and the `LIMIT`/`OFFSET` placeholders
+//! that follow the filter bindings as `<limit>` and `<offset>`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::{
+    context::Context,
+    helpers::{update_param_count, update_set_clause}
+};
+use crate::entity::parse::ReturningMode;
+
+impl Context<'_> {
+    /// Generate the SQL constants and `debug_sql()` on the entity.
+    pub fn debug_sql(&self) -> TokenStream {
+        let entity_name = self.entity_name;
+        let feature = self.entity.dialect.feature_flag();
+
+        let statements: Vec<(&str, String)> = [
+            ("find_by_id", Some(self.find_by_id_sql())),
+            ("list", Some(self.list_sql())),
+            ("create", self.create_sql()),
+            ("update", self.update_sql()),
+            ("delete", Some(self.delete_sql())),
+            ("query", self.query_sql()),
+            ("search", self.search_sql())
+        ]
+        .into_iter()
+        .filter_map(|(name, sql)| sql.map(|sql| (name, sql)))
+        .collect();
+
+        let idents: Vec<_> = statements
+            .iter()
+            .map(|(name, _)| format_ident!("{}_SQL", name.to_uppercase()))
+            .collect();
+        let consts = statements.iter().zip(&idents).map(|((name, sql), ident)| {
+            let doc = format!("SQL text of `{}`.", name);
+            quote! {
+                #[doc = #doc]
+                pub const #ident: &'static str = #sql;
+            }
+        });
+        let names = statements.iter().map(|(name, _)| name);
+
+        quote! {
+            #[cfg(feature = #feature)]
+            impl #entity_name {
+                #(#consts)*
+
+                /// Name and SQL text of every generated repository statement.
+                pub fn debug_sql() -> Vec<(&'static str, &'static str)> {
+                    vec![#((#names, Self::#idents)),*]
+                }
+            }
+        }
+    }
+
+    fn deleted_filter(&self) -> &'static str {
+        if self.soft_delete {
+            " AND deleted_at IS NULL"
+        } else {
+            ""
+        }
+    }
+
+    fn list_where(&self) -> &'static str {
+        if self.soft_delete {
+            "WHERE deleted_at IS NULL "
+        } else {
+            ""
+        }
+    }
+
+    fn find_by_id_sql(&self) -> String {
+        format!(
+            "SELECT {} FROM {} WHERE {} = {}{}",
+            self.select_str,
+            self.table,
+            self.id_column,
+            self.dialect.placeholder(1),
+            self.deleted_filter()
+        )
+    }
+
+    fn list_sql(&self) -> String {
+        format!(
+            "SELECT {} FROM {} {}ORDER BY {} LIMIT {} OFFSET {}",
+            self.select_str,
+            self.table,
+            self.list_where(),
+            self.list_order,
+            self.dialect.placeholder(1),
+            self.dialect.placeholder(2)
+        )
+    }
+
+    fn create_sql(&self) -> Option<String> {
+        if self.entity.create_fields().is_empty() {
+            return None;
+        }
+        let insert = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            self.table, self.columns_str, self.placeholders_str
+        );
+        Some(match &self.returning {
+            ReturningMode::Full => format!("{} RETURNING {}", insert, self.returning_str),
+            ReturningMode::Id => format!("{} RETURNING {}", insert, self.id_column),
+            ReturningMode::None | ReturningMode::Minimal => insert,
+            ReturningMode::Custom(columns) => {
+                format!("{} RETURNING {}", insert, columns.join(", "))
+            }
+        })
+    }
+
+    fn update_sql(&self) -> Option<String> {
+        let update_fields = self.entity.update_fields();
+        if update_fields.is_empty() {
+            return None;
+        }
+        let update = format!(
+            "UPDATE {} SET {} WHERE {} = {}",
+            self.table,
+            update_set_clause(&self.dialect, &update_fields),
+            self.id_column,
+            self.dialect
+                .placeholder(update_param_count(&update_fields) + 1)
+        );
+        Some(match &self.returning {
+            ReturningMode::Full => format!("{} RETURNING {}", update, self.returning_str),
+            ReturningMode::Id | ReturningMode::None | ReturningMode::Minimal => update,
+            ReturningMode::Custom(columns) => {
+                format!("{} RETURNING {}", update, columns.join(", "))
+            }
+        })
+    }
+
+    fn delete_sql(&self) -> String {
+        let placeholder = self.dialect.placeholder(1);
+        if self.soft_delete {
+            format!(
+                "UPDATE {} SET deleted_at = NOW() WHERE {} = {} AND deleted_at IS NULL",
+                self.table, self.id_column, placeholder
+            )
+        } else {
+            format!(
+                "DELETE FROM {} WHERE {} = {}",
+                self.table, self.id_column, placeholder
+            )
+        }
+    }
+
+    fn query_sql(&self) -> Option<String> {
+        if !self.entity.has_filters() {
+            return None;
+        }
+        Some(format!(
+            "SELECT {} FROM {} WHERE <filters> ORDER BY {} LIMIT <limit> OFFSET <offset>",
+            self.select_str, self.table, self.list_order
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::parse::EntityDef;
+
+    fn generate(input: syn::DeriveInput) -> String {
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        Context::new(&entity).debug_sql().to_string()
+    }
+
+    #[test]
+    fn emits_crud_statements() {
+        let output = generate(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub name: String,
+            }
+        });
+        assert!(output.contains("FIND_BY_ID_SQL"));
+        assert!(output.contains("\"SELECT id, name FROM public.users WHERE id = $1\""));
+        assert!(
+            output.contains(
+                "\"UPDATE public.users SET name = $1 WHERE id = $2 RETURNING id, name\""
+            )
+        );
+        assert!(output.contains("\"DELETE FROM public.users WHERE id = $1\""));
+        assert!(output.contains("pub fn debug_sql"));
+        assert!(!output.contains("QUERY_SQL"));
+    }
+
+    #[test]
+    fn skips_create_without_create_fields() {
+        let output = generate(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(response)]
+                pub name: String,
+            }
+        });
+        assert!(!output.contains("CREATE_SQL"));
+        assert!(!output.contains("UPDATE_SQL"));
+    }
+
+    #[test]
+    fn soft_delete_filters_deleted_rows() {
+        let output = generate(syn::parse_quote! {
+            #[entity(table = "users", soft_delete)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+            }
+        });
+        assert!(output.contains("SET deleted_at = NOW()"));
+        assert!(output.contains("WHERE deleted_at IS NULL ORDER BY"));
+    }
+
+    #[test]
+    fn list_uses_entity_order_by() {
+        let output = generate(syn::parse_quote! {
+            #[entity(table = "users", order_by = "name, created_at desc")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(response)]
+                #[column(name = "user_name")]
+                pub name: String,
+                #[field(response)]
+                pub created_at: chrono::DateTime<chrono::Utc>,
+            }
+        });
+        assert!(
+            output.contains("ORDER BY user_name ASC, created_at DESC, id DESC LIMIT $1 OFFSET $2")
+        );
+    }
+
+    #[test]
+    fn list_defaults_to_id_desc() {
+        let output = generate(syn::parse_quote! {
+            #[entity(table = "tags")]
+            pub struct Tag {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(output.contains("ORDER BY id DESC LIMIT $1 OFFSET $2"));
+    }
+
+    #[test]
+    fn filters_emit_query_skeleton() {
+        let output = generate(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(response)]
+                #[filter]
+                pub name: String,
+            }
+        });
+        assert!(output.contains("QUERY_SQL"));
+        assert!(output.contains("WHERE <filters>"));
+    }
+
+    #[test]
+    fn computed_field_returned_from_create_and_update() {
+        let output = generate(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub email: String,
+                #[field(response, returning = "lower(email)")]
+                pub normalized_email: String,
+            }
+        });
+        assert!(output.contains(
+            "\"INSERT INTO public.users (id, email) VALUES ($1, $2) \
+             RETURNING id, email, lower(email) AS normalized_email\""
+        ));
+        assert!(output.contains(
+            "\"UPDATE public.users SET email = $1 WHERE id = $2 \
+             RETURNING id, email, lower(email) AS normalized_email\""
+        ));
+        assert!(output.contains(
+            "\"SELECT id, email, lower(email) AS normalized_email FROM public.users WHERE id = $1\""
+        ));
+    }
+}
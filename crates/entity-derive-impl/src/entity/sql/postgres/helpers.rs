@@ -7,17 +7,28 @@
 //! generators:
 //!
 //! - [`join_columns`] — builds column list for SELECT/INSERT
+//! - [`select_columns`] — builds column list aliased to the Row prefix
+//! - [`select_column`] — builds one SELECT item, expanding computed fields
 //! - [`insert_bindings`] — builds `.bind()` chain for INSERT
 //! - [`update_bindings`] — builds `.bind()` chain for UPDATE
+//! - [`update_set_clause`] — builds SET clause for UPDATE
+//! - [`update_param_count`] — counts placeholders used by the SET clause
+//! - [`generate_patch_assignments`] — builds SET clause of set patch fields
+//! - [`generate_patch_bindings`] — binds set patch fields
 //! - [`generate_where_conditions`] — builds WHERE clause for query method
 //! - [`generate_query_bindings`] — builds parameter bindings for query method
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use crate::entity::parse::{FieldDef, FilterType};
+use crate::entity::parse::{DatabaseDialect, FieldDef, FilterType};
 
-/// Join field names into comma-separated column list.
+/// Join stored column names into comma-separated column list for INSERT.
+///
+/// Each field contributes its [`column_name`](FieldDef::column_name).
+/// Computed `#[field(returning = "...")]` fields have no column to write
+/// and generated `#[column(tsvector)]` columns are maintained by the
+/// database; both are skipped.
 ///
 /// # Example
 ///
@@ -27,11 +38,50 @@ use crate::entity::parse::{FieldDef, FilterType};
 pub fn join_columns(fields: &[FieldDef]) -> String {
     fields
         .iter()
-        .map(|f| f.name_str())
+        .filter(|f| !f.is_db_generated())
+        .map(|f| f.column_name())
         .collect::<Vec<_>>()
         .join(", ")
 }
 
+/// Join field names into a SELECT/RETURNING list aliased with a row prefix.
+///
+/// Computed fields select their expression under the field's name and
+/// `tsvector` columns are cast to text, which the Row decodes as `String`.
+/// A renamed column is aliased back to the field name, so the Row always
+/// decodes by field.
+///
+/// # Example
+///
+/// ```text
+/// ["id", "name"], Some("u_") -> "id AS u_id, name AS u_name"
+/// ["id", "email" (column "user_email")], None -> "id, user_email AS email"
+/// ["id", "email_lower" = "lower(email)"], None -> "id, lower(email) AS email_lower"
+/// ["id", "search" (tsvector)], None -> "id, search::text AS search"
+/// ```
+pub fn select_columns(fields: &[FieldDef], prefix: Option<&str>) -> String {
+    fields
+        .iter()
+        .map(|f| select_column(f, prefix))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Single SELECT/RETURNING item for a field.
+pub fn select_column(field: &FieldDef, prefix: Option<&str>) -> String {
+    let name = field.name_str();
+    let column = field.column_name();
+    match (field.returning_expr(), prefix) {
+        (Some(expr), prefix) => format!("{expr} AS {}{name}", prefix.unwrap_or_default()),
+        (None, prefix) if field.is_tsvector() => {
+            format!("{column}::text AS {}{name}", prefix.unwrap_or_default())
+        }
+        (None, Some(prefix)) => format!("{column} AS {prefix}{name}"),
+        (None, None) if column != name => format!("{column} AS {name}"),
+        (None, None) => name
+    }
+}
+
 /// Build `.bind(insertable.field)` chain for INSERT.
 ///
 /// # Generated Code
@@ -44,6 +94,7 @@ pub fn join_columns(fields: &[FieldDef]) -> String {
 pub fn insert_bindings(fields: &[FieldDef]) -> Vec<TokenStream> {
     fields
         .iter()
+        .filter(|f| !f.is_db_generated())
         .map(|f| {
             let name = f.name();
             quote! { .bind(insertable.#name) }
@@ -59,16 +110,145 @@ pub fn insert_bindings(fields: &[FieldDef]) -> Vec<TokenStream> {
 /// .bind(dto.name)
 /// .bind(dto.email)
 /// ```
+///
+/// A double-option field binds whether it is set, then its value:
+/// `.bind(dto.bio.is_some()).bind(dto.bio.flatten())`. Transparent newtypes
+/// are bound as their inner type.
 pub fn update_bindings(fields: &[&FieldDef]) -> Vec<TokenStream> {
     fields
         .iter()
         .map(|f| {
             let name = f.name();
-            quote! { .bind(dto.#name) }
+            let to_db = transparent_map(f);
+            if f.is_double_option() {
+                quote! { .bind(dto.#name.is_some()).bind(dto.#name.flatten()#to_db) }
+            } else if f.transparent_inner().is_some() {
+                quote! { .bind(dto.#name #to_db) }
+            } else if let Some(conv) = &f.expose.update_with {
+                quote! { .bind(dto.#name.map(#conv)) }
+            } else if f.expose.update_type.is_some() {
+                let inner = f.inner_ty();
+                quote! { .bind(dto.#name.map(<#inner as ::core::convert::From<_>>::from)) }
+            } else {
+                quote! { .bind(dto.#name) }
+            }
         })
         .collect()
 }
 
+/// Build the SET clause for UPDATE, numbering placeholders from `$1`.
+///
+/// `write_once` fields become `COALESCE(column, $n)`, so they are filled
+/// only while `NULL` and otherwise keep their stored value. Double-option
+/// fields take two placeholders, a set flag and the value, and keep the
+/// column when the flag is false.
+///
+/// # Example
+///
+/// ```text
+/// [name, onboarded_at (write_once), bio (double option)]
+///     -> "name = $1, onboarded_at = COALESCE(onboarded_at, $2),
+///         bio = CASE WHEN $3 THEN $4 ELSE bio END"
+/// ```
+pub fn update_set_clause(dialect: &DatabaseDialect, fields: &[&FieldDef]) -> String {
+    let mut index = 0;
+    let mut next = || {
+        index += 1;
+        dialect.placeholder(index)
+    };
+    fields
+        .iter()
+        .map(|f| {
+            let name = f.column_name();
+            if f.is_double_option() {
+                let (set, value) = (next(), next());
+                format!("{name} = CASE WHEN {set} THEN {value} ELSE {name} END")
+            } else if f.is_write_once() {
+                format!("{name} = COALESCE({name}, {})", next())
+            } else {
+                format!("{name} = {}", next())
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Number of placeholders bound by [`update_set_clause`].
+///
+/// The WHERE clause of an UPDATE continues from the next one.
+pub fn update_param_count(fields: &[&FieldDef]) -> usize {
+    fields
+        .iter()
+        .map(|f| if f.is_double_option() { 2 } else { 1 })
+        .sum()
+}
+
+/// `.map(|v| Inner::from(v))` converting an optional transparent newtype
+/// to its inner type; empty for other fields.
+fn transparent_map(field: &FieldDef) -> TokenStream {
+    if field.transparent_inner().is_none() {
+        return TokenStream::new();
+    }
+    let convert = field.to_db(quote! { v });
+    quote! { .map(|v| #convert) }
+}
+
+/// Generate SET clause building code for a partial update.
+///
+/// Creates runtime code that pushes `column = $n` onto `sets` for each
+/// field of `dto` that is `Some`, advancing `param_idx`. `write_once`
+/// fields use `COALESCE` as in [`update_set_clause`].
+pub fn generate_patch_assignments(fields: &[&FieldDef]) -> TokenStream {
+    let assignments = fields.iter().map(|f| {
+        let name = f.name();
+        let column = f.column_name();
+        let assignment = if f.is_write_once() {
+            quote! { format!("{0} = COALESCE({0}, ${1})", #column, param_idx) }
+        } else {
+            quote! { format!("{} = ${}", #column, param_idx) }
+        };
+        quote! {
+            if dto.#name.is_some() {
+                sets.push(#assignment);
+                param_idx += 1;
+            }
+        }
+    });
+
+    quote! { #(#assignments)* }
+}
+
+/// Generate binding code for a partial update.
+///
+/// Binds the value of each field of `dto` that is `Some`, in the same
+/// order as [`generate_patch_assignments`], applying `update_with` or
+/// `update_type` conversions like [`update_bindings`].
+pub fn generate_patch_bindings(fields: &[&FieldDef]) -> TokenStream {
+    let bindings = fields.iter().map(|f| {
+        let name = f.name();
+        let value = if f.is_double_option() {
+            let to_db = transparent_map(f);
+            quote! { v #to_db }
+        } else if f.transparent_inner().is_some() {
+            f.to_db(quote! { v })
+        } else if let Some(conv) = &f.expose.update_with {
+            quote! { #conv(v) }
+        } else if f.expose.update_type.is_some() {
+            let inner = f.inner_ty();
+            quote! { <#inner as ::core::convert::From<_>>::from(v) }
+        } else {
+            quote! { v }
+        };
+        quote! {
+            if let Some(v) = dto.#name {
+                q = q.bind(#value);
+            }
+        }
+    });
+
+    quote! { #(#bindings)* }
+}
+
 /// Generate WHERE condition building code for query method.
 ///
 /// Creates runtime code that builds a dynamic WHERE clause based on
@@ -79,25 +259,31 @@ pub fn update_bindings(fields: &[&FieldDef]) -> Vec<TokenStream> {
 /// | Type | SQL Generated |
 /// |------|---------------|
 /// | `Eq` | `field = $n` |
-/// | `Like` | `field ILIKE $n` |
+/// | `Like` | `field ILIKE $n` (`LIKE` on SQLite) |
 /// | `Range` | `field >= $n` and `field <= $n` |
+/// | `Overlaps` | `field && $n` |
 ///
 /// # Soft Delete
 ///
 /// When `soft_delete` is true, adds `deleted_at IS NULL` condition.
-pub fn generate_where_conditions(fields: &[&FieldDef], soft_delete: bool) -> TokenStream {
+pub fn generate_where_conditions(
+    dialect: &DatabaseDialect,
+    fields: &[&FieldDef],
+    soft_delete: bool
+) -> TokenStream {
+    let like = format!("{{}} {} ${{}}", dialect.ilike());
     let conditions: Vec<TokenStream> = fields
         .iter()
         .flat_map(|f| {
             let name = f.name();
-            let name_str = f.name_str();
+            let column = f.column_name();
             let filter = f.filter();
 
             match filter.filter_type {
                 FilterType::Eq => {
                     vec![quote! {
                         if query.#name.is_some() {
-                            conditions.push(format!("{} = ${}", #name_str, param_idx));
+                            conditions.push(format!("{} = ${}", #column, param_idx));
                             param_idx += 1;
                         }
                     }]
@@ -105,7 +291,7 @@ pub fn generate_where_conditions(fields: &[&FieldDef], soft_delete: bool) -> Tok
                 FilterType::Like => {
                     vec![quote! {
                         if query.#name.is_some() {
-                            conditions.push(format!("{} ILIKE ${}", #name_str, param_idx));
+                            conditions.push(format!(#like, #column, param_idx));
                             param_idx += 1;
                         }
                     }]
@@ -116,18 +302,27 @@ pub fn generate_where_conditions(fields: &[&FieldDef], soft_delete: bool) -> Tok
                     vec![
                         quote! {
                             if query.#from_name.is_some() {
-                                conditions.push(format!("{} >= ${}", #name_str, param_idx));
+                                conditions.push(format!("{} >= ${}", #column, param_idx));
                                 param_idx += 1;
                             }
                         },
                         quote! {
                             if query.#to_name.is_some() {
-                                conditions.push(format!("{} <= ${}", #name_str, param_idx));
+                                conditions.push(format!("{} <= ${}", #column, param_idx));
                                 param_idx += 1;
                             }
                         },
                     ]
                 }
+                FilterType::Overlaps => {
+                    let overlaps_name = format_ident!("{}_overlaps", name);
+                    vec![quote! {
+                        if query.#overlaps_name.is_some() {
+                            conditions.push(format!("{} && ${}", #column, param_idx));
+                            param_idx += 1;
+                        }
+                    }]
+                }
                 // Skip: filter_fields() should only return fields with filters,
                 // but handle gracefully if None slips through
                 FilterType::None => vec![]
@@ -157,18 +352,28 @@ pub fn generate_where_conditions(fields: &[&FieldDef], soft_delete: bool) -> Tok
 /// # LIKE Pattern
 ///
 /// For `Like` filters, wraps the value in `%...%` for substring matching.
+///
+/// # Overlap Range
+///
+/// For `Overlaps` filters, binds the `(start, end)` pair as the half-open
+/// `PgRange` `[start, end)`.
 pub fn generate_query_bindings(fields: &[&FieldDef]) -> TokenStream {
     let bindings: Vec<TokenStream> = fields
         .iter()
         .flat_map(|f| {
             let name = f.name();
             let filter = f.filter();
+            let value = if f.transparent_inner().is_some() {
+                f.field_to_db(quote! { v.clone() })
+            } else {
+                quote! { v }
+            };
 
             match filter.filter_type {
                 FilterType::Eq => {
                     vec![quote! {
                         if let Some(ref v) = query.#name {
-                            q = q.bind(v);
+                            q = q.bind(#value);
                         }
                     }]
                 }
@@ -176,7 +381,7 @@ pub fn generate_query_bindings(fields: &[&FieldDef]) -> TokenStream {
                     vec![quote! {
                         if let Some(ref v) = query.#name {
                             // Escape SQL LIKE wildcards to prevent injection
-                            let escaped = v
+                            let escaped = #value
                                 .replace('\\', "\\\\")
                                 .replace('%', "\\%")
                                 .replace('_', "\\_");
@@ -190,16 +395,24 @@ pub fn generate_query_bindings(fields: &[&FieldDef]) -> TokenStream {
                     vec![
                         quote! {
                             if let Some(ref v) = query.#from_name {
-                                q = q.bind(v);
+                                q = q.bind(#value);
                             }
                         },
                         quote! {
                             if let Some(ref v) = query.#to_name {
-                                q = q.bind(v);
+                                q = q.bind(#value);
                             }
                         },
                     ]
                 }
+                FilterType::Overlaps => {
+                    let overlaps_name = format_ident!("{}_overlaps", name);
+                    vec![quote! {
+                        if let Some((ref start, ref end)) = query.#overlaps_name {
+                            q = q.bind(sqlx::postgres::types::PgRange::from(start.clone()..end.clone()));
+                        }
+                    }]
+                }
                 // Skip: filter_fields() should only return fields with filters,
                 // but handle gracefully if None slips through
                 FilterType::None => vec![]
@@ -246,6 +459,78 @@ mod tests {
         assert_eq!(result, "");
     }
 
+    #[test]
+    fn select_columns_without_prefix() {
+        let fields = vec![
+            parse_field(quote! { pub id: Uuid }),
+            parse_field(quote! { pub name: String }),
+        ];
+        assert_eq!(select_columns(&fields, None), "id, name");
+    }
+
+    #[test]
+    fn select_columns_with_prefix() {
+        let fields = vec![
+            parse_field(quote! { pub id: Uuid }),
+            parse_field(quote! { pub name: String }),
+        ];
+        assert_eq!(
+            select_columns(&fields, Some("u_")),
+            "id AS u_id, name AS u_name"
+        );
+    }
+
+    #[test]
+    fn renamed_column_aliased_to_field() {
+        let fields = vec![
+            parse_field(quote! { pub id: Uuid }),
+            parse_field(quote! {
+                #[field(create, update, rename_column = "usr_mail")]
+                #[filter]
+                pub email: String
+            }),
+        ];
+        assert_eq!(join_columns(&fields), "id, usr_mail");
+        assert_eq!(select_columns(&fields, None), "id, usr_mail AS email");
+        assert_eq!(
+            select_columns(&fields, Some("u_")),
+            "id AS u_id, usr_mail AS u_email"
+        );
+        let refs = [&fields[1]];
+        assert_eq!(
+            update_set_clause(&DatabaseDialect::Postgres, &refs),
+            "usr_mail = $1"
+        );
+        let patch = generate_patch_assignments(&refs).to_string();
+        assert!(patch.contains("\"usr_mail\""));
+        assert!(patch.contains("dto . email"));
+        let conditions =
+            generate_where_conditions(&DatabaseDialect::Postgres, &refs, false).to_string();
+        assert!(conditions.contains("\"usr_mail\""));
+        assert!(conditions.contains("query . email"));
+    }
+
+    #[test]
+    fn computed_field_selected_as_expression() {
+        let fields = vec![
+            parse_field(quote! { pub id: Uuid }),
+            parse_field(quote! {
+                #[field(response, returning = "lower(email)")]
+                pub email_lower: String
+            }),
+        ];
+        assert_eq!(join_columns(&fields), "id");
+        assert_eq!(insert_bindings(&fields).len(), 1);
+        assert_eq!(
+            select_columns(&fields, None),
+            "id, lower(email) AS email_lower"
+        );
+        assert_eq!(
+            select_columns(&fields, Some("u_")),
+            "id AS u_id, lower(email) AS u_email_lower"
+        );
+    }
+
     #[test]
     fn insert_bindings_generates_bind_calls() {
         let fields = vec![
@@ -296,12 +581,103 @@ mod tests {
         assert!(first.contains("name"), "Expected 'name' in: {}", first);
     }
 
+    #[test]
+    fn update_bindings_converts_type_override() {
+        let fields = [
+            parse_field(quote! {
+                #[field(update, update_type = "u32")]
+                pub count: i64
+            }),
+            parse_field(quote! {
+                #[field(update, update_with = "parse_amount")]
+                pub amount: i64
+            })
+        ];
+        let refs: Vec<&FieldDef> = fields.iter().collect();
+        let bindings = update_bindings(&refs);
+
+        let first = bindings[0].to_string();
+        assert!(first.contains("map (< i64 as :: core :: convert :: From < _ >> :: from)"));
+        let second = bindings[1].to_string();
+        assert!(second.contains("map (parse_amount)"));
+    }
+
     #[test]
     fn update_bindings_empty() {
         let bindings = update_bindings(&[]);
         assert!(bindings.is_empty());
     }
 
+    #[test]
+    fn update_set_clause_numbers_placeholders() {
+        let fields = [
+            parse_field(quote! { pub name: String }),
+            parse_field(quote! { pub email: String })
+        ];
+        let refs: Vec<&FieldDef> = fields.iter().collect();
+        assert_eq!(
+            update_set_clause(&DatabaseDialect::Postgres, &refs),
+            "name = $1, email = $2"
+        );
+    }
+
+    #[test]
+    fn update_set_clause_write_once_coalesces() {
+        let fields = [
+            parse_field(quote! { pub name: String }),
+            parse_field(quote! {
+                #[field(update, write_once)]
+                pub onboarded_at: Option<chrono::DateTime<chrono::Utc>>
+            })
+        ];
+        let refs: Vec<&FieldDef> = fields.iter().collect();
+        assert_eq!(
+            update_set_clause(&DatabaseDialect::Postgres, &refs),
+            "name = $1, onboarded_at = COALESCE(onboarded_at, $2)"
+        );
+    }
+
+    #[test]
+    fn update_double_option_takes_two_placeholders() {
+        let fields = [
+            parse_field(quote! {
+                #[field(update, update_type = "Option<Option<String>>")]
+                pub bio: Option<String>
+            }),
+            parse_field(quote! { pub name: String })
+        ];
+        let refs: Vec<&FieldDef> = fields.iter().collect();
+        assert_eq!(
+            update_set_clause(&DatabaseDialect::Postgres, &refs),
+            "bio = CASE WHEN $1 THEN $2 ELSE bio END, name = $3"
+        );
+        assert_eq!(update_param_count(&refs), 3);
+
+        let bindings = update_bindings(&refs);
+        assert_eq!(
+            bindings[0].to_string(),
+            ". bind (dto . bio . is_some ()) . bind (dto . bio . flatten ())"
+        );
+    }
+
+    #[test]
+    fn transparent_fields_bind_inner_type() {
+        let field = parse_field(quote! {
+            #[field(update)]
+            #[filter(eq)]
+            #[column(transparent = "String")]
+            pub email: Email
+        });
+        let refs: Vec<&FieldDef> = vec![&field];
+        let convert = "< String as :: core :: convert :: From < Email >> :: from";
+        assert_eq!(
+            update_bindings(&refs)[0].to_string(),
+            format!(". bind (dto . email . map (| v | {convert} (v)))")
+        );
+        let code = generate_query_bindings(&refs).to_string();
+        assert!(code.contains(&format!("q = q . bind ({convert} (v . clone ()))")));
+    }
+
     #[test]
     fn where_conditions_eq_filter() {
         let field = parse_field(quote! {
@@ -309,7 +685,7 @@ mod tests {
             pub status: String
         });
         let refs: Vec<&FieldDef> = vec![&field];
-        let result = generate_where_conditions(&refs, false);
+        let result = generate_where_conditions(&DatabaseDialect::Postgres, &refs, false);
         let code = result.to_string();
         assert!(code.contains("query . status . is_some"));
         assert!(code.contains("= $"));
@@ -322,7 +698,7 @@ mod tests {
             pub name: String
         });
         let refs: Vec<&FieldDef> = vec![&field];
-        let result = generate_where_conditions(&refs, false);
+        let result = generate_where_conditions(&DatabaseDialect::Postgres, &refs, false);
         let code = result.to_string();
         assert!(code.contains("query . name . is_some"));
         assert!(code.contains("ILIKE"));
@@ -335,7 +711,7 @@ mod tests {
             pub age: i32
         });
         let refs: Vec<&FieldDef> = vec![&field];
-        let result = generate_where_conditions(&refs, false);
+        let result = generate_where_conditions(&DatabaseDialect::Postgres, &refs, false);
         let code = result.to_string();
         assert!(code.contains("age_from"));
         assert!(code.contains("age_to"));
@@ -343,11 +719,24 @@ mod tests {
         assert!(code.contains("<="));
     }
 
+    #[test]
+    fn where_conditions_overlaps_filter() {
+        let field = parse_field(quote! {
+            #[filter(overlaps)]
+            pub during: PgRange<DateTime<Utc>>
+        });
+        let refs: Vec<&FieldDef> = vec![&field];
+        let result = generate_where_conditions(&DatabaseDialect::Postgres, &refs, false);
+        let code = result.to_string();
+        assert!(code.contains("query . during_overlaps . is_some ()"));
+        assert!(code.contains("\"{} && ${}\""));
+    }
+
     #[test]
     fn where_conditions_none_filter() {
         let field = parse_field(quote! { pub name: String });
         let refs: Vec<&FieldDef> = vec![&field];
-        let result = generate_where_conditions(&refs, false);
+        let result = generate_where_conditions(&DatabaseDialect::Postgres, &refs, false);
         let code = result.to_string();
         // No conditions for None filter
         assert!(!code.contains("query"));
@@ -355,14 +744,14 @@ mod tests {
 
     #[test]
     fn where_conditions_with_soft_delete() {
-        let result = generate_where_conditions(&[], true);
+        let result = generate_where_conditions(&DatabaseDialect::Postgres, &[], true);
         let code = result.to_string();
         assert!(code.contains("deleted_at IS NULL"));
     }
 
     #[test]
     fn where_conditions_without_soft_delete() {
-        let result = generate_where_conditions(&[], false);
+        let result = generate_where_conditions(&DatabaseDialect::Postgres, &[], false);
         let code = result.to_string();
         assert!(!code.contains("deleted_at"));
     }
@@ -406,6 +795,19 @@ mod tests {
         assert!(code.contains("age_to"));
     }
 
+    #[test]
+    fn query_bindings_overlaps_filter() {
+        let field = parse_field(quote! {
+            #[filter(overlaps)]
+            pub during: PgRange<DateTime<Utc>>
+        });
+        let refs: Vec<&FieldDef> = vec![&field];
+        let result = generate_query_bindings(&refs);
+        let code = result.to_string();
+        assert!(code.contains("if let Some ((ref start , ref end)) = query . during_overlaps"));
+        assert!(code.contains("PgRange :: from (start . clone () .. end . clone ())"));
+    }
+
     #[test]
     fn query_bindings_none_filter() {
         let field = parse_field(quote! { pub name: String });
@@ -435,11 +837,37 @@ mod tests {
             })
         ];
         let refs: Vec<&FieldDef> = fields.iter().collect();
-        let result = generate_where_conditions(&refs, false);
+        let result = generate_where_conditions(&DatabaseDialect::Postgres, &refs, false);
         let code = result.to_string();
         assert!(code.contains("status"));
         assert!(code.contains("name"));
         assert!(code.contains("= $"));
         assert!(code.contains("ILIKE"));
     }
+
+    #[test]
+    fn patch_assignments_only_set_fields() {
+        let name = parse_field(quote! { #[field(update)] pub name: String });
+        let onboarded = parse_field(quote! {
+            #[field(update, write_once)]
+            pub onboarded_at: Option<String>
+        });
+        let code = generate_patch_assignments(&[&name, &onboarded]).to_string();
+        assert!(code.contains("if dto . name . is_some ()"));
+        assert!(code.contains("\"{} = ${}\""));
+        assert!(code.contains("COALESCE({0}, ${1})"));
+    }
+
+    #[test]
+    fn patch_bindings_bind_some_values() {
+        let name = parse_field(quote! { #[field(update)] pub name: String });
+        let quantity = parse_field(quote! {
+            #[field(update, update_type = "u32")]
+            pub quantity: i64
+        });
+        let code = generate_patch_bindings(&[&name, &quantity]).to_string();
+        assert!(code.contains("if let Some (v) = dto . name"));
+        assert!(code.contains("q = q . bind (v)"));
+        assert!(code.contains("< i64 as :: core :: convert :: From < _ >> :: from (v)"));
+    }
 }
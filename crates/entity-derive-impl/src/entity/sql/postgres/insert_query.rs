@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Standalone INSERT query for the Insertable struct.
+//!
+//! Exposes the statement the repository's `create` runs, so callers can
+//! execute it themselves, e.g. inside a larger transaction with their own
+//! error handling:
+//!
+//! ```rust,ignore
+//! let mut tx = pool.begin().await?;
+//! InsertableUser::from(&user).into_insert_query().execute(&mut *tx).await?;
+//! audit_insert(&mut tx, &user).await?;
+//! tx.commit().await?;
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{context::Context, helpers::insert_bindings};
+
+impl Context<'_> {
+    /// Generate `INSERT_SQL` and `into_insert_query` on `Insertable{Entity}`.
+    ///
+    /// The statement has no `RETURNING` clause; bindings follow
+    /// `Insertable{Entity}::COLUMNS`.
+    pub fn insert_query(&self) -> TokenStream {
+        let Self {
+            insertable_name,
+            table,
+            columns_str,
+            placeholders_str,
+            entity,
+            ..
+        } = self;
+        let feature = entity.dialect.feature_flag();
+        let sql = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            table, columns_str, placeholders_str
+        );
+        let bindings = insert_bindings(entity.all_fields());
+
+        quote! {
+            #[cfg(feature = #feature)]
+            impl #insertable_name {
+                /// `INSERT` statement for this entity, one placeholder per
+                /// column and no `RETURNING` clause.
+                pub const INSERT_SQL: &'static str = #sql;
+
+                /// Build the bound `INSERT` query without executing it.
+                pub fn into_insert_query(
+                    self
+                ) -> sqlx::query::Query<'static, sqlx::Postgres, sqlx::postgres::PgArguments> {
+                    let insertable = self;
+                    sqlx::query(Self::INSERT_SQL)
+                        #(#bindings)*
+                }
+            }
+        }
+    }
+}
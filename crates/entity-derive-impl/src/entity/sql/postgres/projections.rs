@@ -23,7 +23,7 @@ use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use super::context::Context;
+use super::{context::Context, helpers::select_column};
 use crate::entity::parse::ProjectionDef;
 
 impl Context<'_> {
@@ -55,7 +55,7 @@ impl Context<'_> {
         let proj_snake = proj.name.to_string().to_case(Case::Snake);
         let method_name = format_ident!("find_by_id_{}", proj_snake);
         let proj_type = format_ident!("{}{}", entity_name, proj.name);
-        let id_name = self.id_name;
+        let id_column = &self.id_column;
         let id_type = self.id_type;
         let table = &self.table;
         let placeholder = self.dialect.placeholder(1);
@@ -63,14 +63,19 @@ impl Context<'_> {
         let columns_str: String = proj
             .fields
             .iter()
-            .map(|f| f.to_string())
+            .map(
+                |name| match self.entity.all_fields().iter().find(|f| f.name() == name) {
+                    Some(field) => select_column(field, None),
+                    None => name.to_string()
+                }
+            )
             .collect::<Vec<_>>()
             .join(", ");
 
         quote! {
             async fn #method_name(&self, id: #id_type) -> Result<Option<#proj_type>, Self::Error> {
                 let row = sqlx::query_as::<_, #proj_type>(
-                    &format!("SELECT {} FROM {} WHERE {} = {}", #columns_str, #table, stringify!(#id_name), #placeholder)
+                    &format!("SELECT {} FROM {} WHERE {} = {}", #columns_str, #table, #id_column, #placeholder)
                 ).bind(&id).fetch_optional(self).await?;
                 Ok(row)
             }
@@ -11,7 +11,7 @@
 //! ```sql
 //! SELECT col1, col2, ... FROM schema.table
 //! WHERE condition1 AND condition2 AND ...
-//! ORDER BY id DESC
+//! ORDER BY id DESC  -- or #[entity(order_by = "...")]
 //! LIMIT $n OFFSET $m
 //! ```
 //!
@@ -19,6 +19,19 @@
 //!
 //! The WHERE clause is built at runtime based on which filter fields
 //! are set in the query struct. Only `Some` values generate conditions.
+//!
+//! # Total Count
+//!
+//! `list_with_total` selects `COUNT(*) OVER() AS __total_count` next to the
+//! columns, so the page and the number of all matching rows come from one
+//! statement instead of a racing `query` + count pair. A page past the last
+//! row has no row to carry the count, so then a separate `COUNT(*)` runs.
+//!
+//! # Streaming
+//!
+//! With `#[entity(streams)]`, `stream_query` runs the same predicates as
+//! `query` but yields entities lazily, fetching them in batches instead of
+//! collecting the whole result.
 
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -28,6 +41,9 @@ use super::{
     helpers::{generate_query_bindings, generate_where_conditions}
 };
 
+/// Rows fetched per round trip by `stream_query`.
+const STREAM_BATCH_SIZE: i64 = 1000;
+
 impl Context<'_> {
     /// Generate the `query` method implementation.
     ///
@@ -74,50 +90,63 @@ impl Context<'_> {
             return TokenStream::new();
         }
 
-        let Self {
-            entity_name,
-            row_name,
-            table,
-            columns_str,
-            id_name,
-            soft_delete,
-            ..
-        } = self;
-
+        let entity_name = self.entity_name;
         let query_type = self.entity.ident_with("", "Query");
-        let filter_fields = self.entity.filter_fields();
-
-        let where_conditions = generate_where_conditions(&filter_fields, *soft_delete);
-        let bindings = generate_query_bindings(&filter_fields);
+        let filtered_query = self.filtered_query();
 
         quote! {
             async fn query(&self, query: #query_type) -> Result<Vec<#entity_name>, Self::Error> {
-                let mut conditions: Vec<String> = Vec::new();
-                let mut param_idx: usize = 1;
+                #filtered_query
+                q = q.bind(query.limit.unwrap_or(100)).bind(query.offset.unwrap_or(0));
 
-                #where_conditions
+                let rows = q.fetch_all(self).await?;
+                Ok(#entity_name::from_rows(rows))
+            }
+        }
+    }
 
-                let where_clause = if conditions.is_empty() {
-                    String::new()
-                } else {
-                    format!("WHERE {}", conditions.join(" AND "))
-                };
+    /// Generate the `list_with_total` method implementation.
+    ///
+    /// # Returns
+    ///
+    /// Empty `TokenStream` if entity has no filter fields.
+    pub fn list_with_total_method(&self) -> TokenStream {
+        if !self.entity.has_filters() {
+            return TokenStream::new();
+        }
 
-                let limit_idx = param_idx;
-                param_idx += 1;
-                let offset_idx = param_idx;
+        let entity_name = self.entity_name;
+        let row_name = &self.row_name;
+        let query_type = self.entity.ident_with("", "Query");
+        let counted_query = self.build_filtered_query(true);
+        let count_query = self.count_query();
 
-                let sql = format!(
-                    "SELECT {} FROM {} {} ORDER BY {} DESC LIMIT ${} OFFSET ${}",
-                    #columns_str, #table, where_clause, stringify!(#id_name), limit_idx, offset_idx
-                );
+        quote! {
+            async fn list_with_total(
+                &self,
+                query: #query_type,
+            ) -> Result<(Vec<#entity_name>, i64), Self::Error> {
+                use sqlx::Row as _;
 
-                let mut q = sqlx::query_as::<_, #row_name>(&sql);
-                #bindings
+                #counted_query
                 q = q.bind(query.limit.unwrap_or(100)).bind(query.offset.unwrap_or(0));
 
                 let rows = q.fetch_all(self).await?;
-                Ok(rows.into_iter().map(#entity_name::from).collect())
+                let total = match rows.first() {
+                    Some(row) => row.try_get::<i64, _>("__total_count")?,
+                    // Past the last row the window count has no row to ride
+                    // on, so count separately.
+                    None if query.offset.unwrap_or(0) > 0 => {
+                        #count_query
+                        q.fetch_one(self).await?
+                    }
+                    None => 0
+                };
+                let entities = rows
+                    .iter()
+                    .map(|row| <#row_name as sqlx::FromRow<'_, _>>::from_row(row).map(#entity_name::from))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((entities, total))
             }
         }
     }
@@ -132,21 +161,9 @@ impl Context<'_> {
             return TokenStream::new();
         }
 
-        let Self {
-            entity_name,
-            row_name,
-            table,
-            columns_str,
-            id_name,
-            soft_delete,
-            ..
-        } = self;
-
+        let entity_name = self.entity_name;
         let filter_type = self.entity.ident_with("", "Filter");
-        let filter_fields = self.entity.filter_fields();
-
-        let where_conditions = generate_where_conditions(&filter_fields, *soft_delete);
-        let bindings = generate_query_bindings(&filter_fields);
+        let filtered_query = self.filtered_query();
 
         // For now, generate a simple implementation that fetches all and converts to
         // stream True streaming would require more complex lifetime handling
@@ -157,41 +174,174 @@ impl Context<'_> {
             ) -> Result<std::pin::Pin<Box<dyn futures::Stream<Item = Result<#entity_name, Self::Error>> + Send + '_>>, Self::Error> {
                 use futures::StreamExt;
 
-                let mut conditions: Vec<String> = Vec::new();
-                let mut param_idx: usize = 1;
                 // Rename filter to query for binding code compatibility
                 let query = filter;
 
-                #where_conditions
-
-                let where_clause = if conditions.is_empty() {
-                    String::new()
-                } else {
-                    format!("WHERE {}", conditions.join(" AND "))
-                };
-
-                let limit_idx = param_idx;
-                param_idx += 1;
-                let offset_idx = param_idx;
-
-                let sql = format!(
-                    "SELECT {} FROM {} {} ORDER BY {} DESC LIMIT ${} OFFSET ${}",
-                    #columns_str, #table, where_clause, stringify!(#id_name), limit_idx, offset_idx
-                );
-
-                let mut q = sqlx::query_as::<_, #row_name>(&sql);
-                #bindings
+                #filtered_query
                 q = q.bind(query.limit.unwrap_or(10000)).bind(query.offset.unwrap_or(0));
 
                 // Fetch all results and convert to stream for simpler lifetime handling
                 let rows = q.fetch_all(self).await?;
-                let entities: Vec<#entity_name> = rows.into_iter().map(#entity_name::from).collect();
+                let entities: Vec<#entity_name> = #entity_name::from_rows(rows);
                 let stream = futures::stream::iter(entities.into_iter().map(Ok));
 
                 Ok(Box::pin(stream))
             }
         }
     }
+
+    /// Generate the `stream_query` method implementation.
+    ///
+    /// Runs the `query` predicates in batches of [`STREAM_BATCH_SIZE`] rows,
+    /// fetching the next batch only once the previous one is consumed.
+    /// `query.limit` caps the total streamed; without it the stream runs to
+    /// the last matching row.
+    ///
+    /// # Returns
+    ///
+    /// Empty `TokenStream` if entity has no streams or filter fields.
+    pub fn stream_query_method(&self) -> TokenStream {
+        if !self.streams || !self.entity.has_filters() {
+            return TokenStream::new();
+        }
+
+        let entity_name = self.entity_name;
+        let query_type = self.entity.ident_with("", "Query");
+        let filtered_query = self.filtered_query();
+
+        quote! {
+            async fn stream_query(
+                &self,
+                query: #query_type,
+            ) -> Result<std::pin::Pin<Box<dyn futures::Stream<Item = Result<#entity_name, Self::Error>> + Send + '_>>, Self::Error> {
+                use futures::{StreamExt, TryStreamExt};
+
+                let start = query.offset.unwrap_or(0);
+                let end = query.limit.map(|limit| start.saturating_add(limit));
+
+                // State is the offset of the next batch, `None` once exhausted.
+                let batches = futures::stream::try_unfold(Some(start), move |next| {
+                    let query = query.clone();
+                    async move {
+                        let Some(offset) = next else {
+                            return Ok::<_, Self::Error>(None);
+                        };
+                        let batch = end.map_or(#STREAM_BATCH_SIZE, |end| (end - offset).min(#STREAM_BATCH_SIZE));
+                        if batch <= 0 {
+                            return Ok(None);
+                        }
+
+                        #filtered_query
+                        q = q.bind(batch).bind(offset);
+
+                        let rows = q.fetch_all(self).await?;
+                        let fetched = rows.len() as i64;
+                        let next = (fetched == batch).then_some(offset + fetched);
+                        let entities: Vec<#entity_name> = #entity_name::from_rows(rows);
+                        Ok(Some((entities, next)))
+                    }
+                });
+
+                let stream = batches
+                    .map_ok(|entities| futures::stream::iter(entities.into_iter().map(Ok)))
+                    .try_flatten();
+
+                Ok(stream.boxed())
+            }
+        }
+    }
+
+    /// Build the filtered `SELECT` and bind its filter values.
+    ///
+    /// Shared by `query`, `stream_filtered` and `stream_query`. Expects a
+    /// `query` with the filter fields in scope and leaves `q` with the
+    /// `LIMIT` and `OFFSET` placeholders still to bind.
+    fn filtered_query(&self) -> TokenStream {
+        self.build_filtered_query(false)
+    }
+
+    /// Build the filtered `SELECT COUNT(*)` and bind its filter values.
+    ///
+    /// Leaves `q` as a `sqlx::query_scalar` ready to fetch the count.
+    fn count_query(&self) -> TokenStream {
+        let table = &self.table;
+        let where_clause = self.where_clause();
+        let bindings = generate_query_bindings(&self.entity.filter_fields());
+
+        quote! {
+            #where_clause
+            let _ = param_idx;
+
+            let sql = format!("SELECT COUNT(*) FROM {} {}", #table, where_clause);
+
+            let mut q = sqlx::query_scalar::<_, i64>(&sql);
+            #bindings
+        }
+    }
+
+    /// Build the `where_clause` of the set filter fields.
+    ///
+    /// Leaves `param_idx` at the next free placeholder.
+    fn where_clause(&self) -> TokenStream {
+        let filter_fields = self.entity.filter_fields();
+        let where_conditions =
+            generate_where_conditions(&self.dialect, &filter_fields, self.soft_delete);
+
+        quote! {
+            let mut conditions: Vec<String> = Vec::new();
+            let mut param_idx: usize = 1;
+
+            #where_conditions
+
+            let where_clause = if conditions.is_empty() {
+                String::new()
+            } else {
+                format!("WHERE {}", conditions.join(" AND "))
+            };
+        }
+    }
+
+    /// Build the filtered `SELECT`, optionally with the window count.
+    ///
+    /// With `total`, the statement also selects `__total_count` and `q` is
+    /// an untyped `sqlx::query` whose rows are decoded by the caller.
+    fn build_filtered_query(&self, total: bool) -> TokenStream {
+        let Self {
+            row_name,
+            table,
+            list_order,
+            ..
+        } = self;
+        let select_str = if total {
+            format!("{}, COUNT(*) OVER() AS __total_count", self.select_str)
+        } else {
+            self.select_str.clone()
+        };
+        let new_query = if total {
+            quote! { sqlx::query(&sql) }
+        } else {
+            quote! { sqlx::query_as::<_, #row_name>(&sql) }
+        };
+
+        let where_clause = self.where_clause();
+        let bindings = generate_query_bindings(&self.entity.filter_fields());
+
+        quote! {
+            #where_clause
+
+            let limit_idx = param_idx;
+            param_idx += 1;
+            let offset_idx = param_idx;
+
+            let sql = format!(
+                "SELECT {} FROM {} {} ORDER BY {} LIMIT ${} OFFSET ${}",
+                #select_str, #table, where_clause, #list_order, limit_idx, offset_idx
+            );
+
+            let mut q = #new_query;
+            #bindings
+        }
+    }
 }
 
 #[cfg(test)]
@@ -216,6 +366,62 @@ mod tests {
         assert!(method.is_empty());
     }
 
+    #[test]
+    fn list_with_total_uses_window_count() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                #[filter]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let ctx = Context::new(&entity);
+        let method = ctx.list_with_total_method().to_string();
+        assert!(method.contains("async fn list_with_total"));
+        assert!(method.contains("COUNT(*) OVER() AS __total_count"));
+        assert!(method.contains("sqlx :: query (& sql)"));
+        assert!(method.contains("try_get :: < i64 , _ > (\"__total_count\")"));
+        assert!(!ctx.query_method().to_string().contains("__total_count"));
+    }
+
+    #[test]
+    fn list_with_total_counts_past_last_page() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                #[filter]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let ctx = Context::new(&entity);
+        let method = ctx.list_with_total_method().to_string();
+        assert!(method.contains("None if query . offset . unwrap_or (0) > 0"));
+        assert!(method.contains("\"SELECT COUNT(*) FROM {} {}\""));
+        assert!(method.contains("sqlx :: query_scalar :: < _ , i64 > (& sql)"));
+        assert!(method.contains("q . fetch_one (self) . await ?"));
+    }
+
+    #[test]
+    fn list_with_total_no_filters_returns_empty() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        assert!(Context::new(&entity).list_with_total_method().is_empty());
+    }
+
     #[test]
     fn query_method_with_filter() {
         let input: syn::DeriveInput = syn::parse_quote! {
@@ -315,4 +521,41 @@ mod tests {
         assert!(method_str.contains("UserFilter"));
         assert!(method_str.contains("futures"));
     }
+
+    #[test]
+    fn stream_query_with_streams_and_filters() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users", streams)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                #[filter]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let ctx = Context::new(&entity);
+        let method_str = ctx.stream_query_method().to_string();
+        assert!(method_str.contains("async fn stream_query"));
+        assert!(method_str.contains("UserQuery"));
+        assert!(method_str.contains("try_unfold"));
+    }
+
+    #[test]
+    fn stream_query_no_streams_returns_empty() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                #[filter]
+                pub name: String,
+            }
+        };
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let ctx = Context::new(&entity);
+        assert!(ctx.stream_query_method().is_empty());
+    }
 }
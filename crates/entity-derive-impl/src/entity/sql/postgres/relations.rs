@@ -19,10 +19,42 @@
 //! // For a User with #[has_many(Post)]
 //! async fn find_posts(&self, user_id: Uuid) -> Result<Vec<Post>, Self::Error>;
 //! ```
+//!
+//! # Connection Reuse
+//!
+//! Each relation query is also generated as an associated function on the
+//! entity that runs on any `sqlx::Acquire` — a pool, a connection or a
+//! transaction. Loading parents and children in a loop on one connection
+//! avoids taking a pool connection per call:
+//!
+//! ```rust,ignore
+//! let mut conn = pool.acquire().await?;
+//! for user in &users {
+//!     let posts = User::load_posts(&mut *conn, user.id).await?;
+//! }
+//! let author = Post::load_user(&mut *conn, post.user_id).await?;
+//! ```
+//!
+//! The trait methods delegate to these functions using the pool.
+//!
+//! # Nullable Foreign Keys
+//!
+//! `load_{entity}` takes the foreign key with the field's own type. For an
+//! optional key (`org_id: Option<Uuid>`) it takes `Option<Uuid>` and
+//! returns `Ok(None)` for `None` without acquiring a connection; a required
+//! key is taken as `Uuid`.
+//!
+//! # Foreign Key Type Check
+//!
+//! A derive macro cannot resolve the parent's id type, so `find_{entity}`
+//! carries a best-effort assertion that the foreign key (or its `Option`
+//! inner type) matches `{Parent}Row::id`. A mismatch is reported as a type
+//! error on the foreign key field's type instead of a runtime SQL error.
 
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
 
 use super::context::Context;
 use crate::entity::parse::FieldDef;
@@ -53,14 +85,44 @@ impl Context<'_> {
         }
     }
 
-    /// Generate a `find_{entity}` method for a `#[belongs_to]` relation.
+    /// Generate `load_*` associated functions running relation queries on
+    /// a caller-provided `sqlx::Acquire`.
     ///
-    /// # SQL Pattern
+    /// Returns an empty `TokenStream` if the entity has no relations.
+    pub fn relation_loaders(&self) -> TokenStream {
+        let belongs_to_loaders: Vec<TokenStream> = self
+            .entity
+            .relation_fields()
+            .iter()
+            .filter_map(|field| self.belongs_to_loader(field))
+            .collect();
+
+        let has_many_loaders: Vec<TokenStream> = self
+            .entity
+            .has_many_relations()
+            .iter()
+            .map(|related| self.has_many_loader(related))
+            .collect();
+
+        if belongs_to_loaders.is_empty() && has_many_loaders.is_empty() {
+            return TokenStream::new();
+        }
+
+        let entity_name = self.entity_name;
+        let feature = self.entity.dialect.feature_flag();
+        quote! {
+            #[cfg(feature = #feature)]
+            impl #entity_name {
+                #(#belongs_to_loaders)*
+                #(#has_many_loaders)*
+            }
+        }
+    }
+
+    /// Generate a `find_{entity}` method for a `#[belongs_to]` relation.
     ///
-    /// First fetches the current entity, then queries the parent:
-    /// ```sql
-    /// SELECT * FROM {schema}.{parent}s WHERE id = $1
-    /// ```
+    /// Fetches the current entity, then delegates to `load_{entity}` with
+    /// its foreign key.
     ///
     /// # Returns
     ///
@@ -69,24 +131,23 @@ impl Context<'_> {
         let related_entity = field.belongs_to()?;
         let related_snake = related_entity.to_string().to_case(Case::Snake);
         let method_name = format_ident!("find_{}", related_snake);
+        let loader_name = format_ident!("load_{}", related_snake);
         let related_row = format_ident!("{}Row", related_entity);
-        let schema = &self.entity.schema;
-        let related_table = format!("{}.{}s", schema, related_snake);
+        let entity_name = self.entity_name;
         let fk_name = field.name();
         let id_type = self.id_type;
-        let placeholder = self.dialect.placeholder(1);
         let trait_name = &self.trait_name;
+        let fk_ty = field.inner_ty();
+        let fk_check = quote_spanned! { fk_ty.span() =>
+            let _: fn(&#related_row) -> &#fk_ty = |parent| &parent.id;
+        };
 
         Some(quote! {
             async fn #method_name(&self, id: #id_type) -> Result<Option<#related_entity>, Self::Error> {
+                #fk_check
                 let entity = <Self as #trait_name>::find_by_id(self, id).await?;
                 match entity {
-                    Some(e) => {
-                        let row: Option<#related_row> = sqlx::query_as(
-                            &format!("SELECT * FROM {} WHERE id = {}", #related_table, #placeholder)
-                        ).bind(&e.#fk_name).fetch_optional(self).await?;
-                        Ok(row.map(#related_entity::from))
-                    }
+                    Some(e) => Ok(#entity_name::#loader_name(self, e.#fk_name).await?),
                     None => Ok(None)
                 }
             }
@@ -95,29 +156,142 @@ impl Context<'_> {
 
     /// Generate a `find_{entities}` method for a `#[has_many]` relation.
     ///
+    /// Delegates to `load_{entities}` on the pool.
+    fn has_many_method(&self, related: &syn::Ident) -> TokenStream {
+        let related_snake = related.to_string().to_case(Case::Snake);
+        let method_name = format_ident!("find_{}s", related_snake);
+        let loader_name = format_ident!("load_{}s", related_snake);
+        let entity_name = self.entity_name;
+        let entity_snake = self.entity.name_str().to_case(Case::Snake);
+        let fk_field = format_ident!("{}_id", entity_snake);
+        let id_type = self.id_type;
+
+        quote! {
+            async fn #method_name(&self, #fk_field: #id_type) -> Result<Vec<#related>, Self::Error> {
+                Ok(#entity_name::#loader_name(self, #fk_field).await?)
+            }
+        }
+    }
+
+    /// Generate `load_{entity}` for a `#[belongs_to]` relation.
+    ///
+    /// An `Option` foreign key short-circuits to `Ok(None)` when absent.
+    ///
     /// # SQL Pattern
     ///
     /// ```sql
-    /// SELECT * FROM {schema}.{child}s WHERE {parent}_id = $1
+    /// SELECT {ParentRow::SELECT_COLUMNS} FROM {schema}.{parent}s WHERE id = $1
     /// ```
-    fn has_many_method(&self, related: &syn::Ident) -> TokenStream {
+    fn belongs_to_loader(&self, field: &FieldDef) -> Option<TokenStream> {
+        let related_entity = field.belongs_to()?;
+        let related_snake = related_entity.to_string().to_case(Case::Snake);
+        let loader_name = format_ident!("load_{}", related_snake);
+        let related_row = format_ident!("{}Row", related_entity);
+        let related_table = format!("{}.{}s", self.entity.schema, related_snake);
+        let fk_name = field.name();
+        let fk_ty = field.ty();
+        let placeholder = self.dialect.placeholder(1);
+        let doc = format!(
+            "Load the [`{related_entity}`] referenced by `{fk_name}` on a caller-provided connection."
+        );
+        let unwrap_fk = if field.is_option() {
+            quote! {
+                let Some(#fk_name) = #fk_name else {
+                    return Ok(None);
+                };
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        Some(quote! {
+            #[doc = #doc]
+            pub async fn #loader_name<'c, A>(conn: A, #fk_name: #fk_ty) -> Result<Option<#related_entity>, sqlx::Error>
+            where
+                A: sqlx::Acquire<'c, Database = sqlx::Postgres>
+            {
+                #unwrap_fk
+                let mut conn = conn.acquire().await?;
+                let row: Option<#related_row> = sqlx::query_as(
+                    &format!("SELECT {} FROM {} WHERE id = {}", #related_row::SELECT_COLUMNS, #related_table, #placeholder)
+                ).bind(&#fk_name).fetch_optional(&mut *conn).await?;
+                Ok(row.map(#related_entity::from))
+            }
+        })
+    }
+
+    /// Generate `load_{entities}` for a `#[has_many]` relation.
+    ///
+    /// # SQL Pattern
+    ///
+    /// ```sql
+    /// SELECT {ChildRow::SELECT_COLUMNS} FROM {schema}.{child}s WHERE {parent}_id = $1
+    /// ```
+    fn has_many_loader(&self, related: &syn::Ident) -> TokenStream {
         let related_snake = related.to_string().to_case(Case::Snake);
-        let method_name = format_ident!("find_{}s", related_snake);
+        let loader_name = format_ident!("load_{}s", related_snake);
         let related_row = format_ident!("{}Row", related);
-        let schema = &self.entity.schema;
-        let related_table = format!("{}.{}s", schema, related_snake);
+        let related_table = format!("{}.{}s", self.entity.schema, related_snake);
         let entity_snake = self.entity.name_str().to_case(Case::Snake);
         let fk_field = format_ident!("{}_id", entity_snake);
         let id_type = self.id_type;
         let placeholder = self.dialect.placeholder(1);
+        let doc =
+            format!("Load all [`{related}`]s for this parent on a caller-provided connection.");
 
         quote! {
-            async fn #method_name(&self, #fk_field: #id_type) -> Result<Vec<#related>, Self::Error> {
+            #[doc = #doc]
+            pub async fn #loader_name<'c, A>(conn: A, #fk_field: #id_type) -> Result<Vec<#related>, sqlx::Error>
+            where
+                A: sqlx::Acquire<'c, Database = sqlx::Postgres>
+            {
+                let mut conn = conn.acquire().await?;
                 let rows: Vec<#related_row> = sqlx::query_as(
-                    &format!("SELECT * FROM {} WHERE {}_id = {}", #related_table, #entity_snake, #placeholder)
-                ).bind(&#fk_field).fetch_all(self).await?;
+                    &format!("SELECT {} FROM {} WHERE {}_id = {}", #related_row::SELECT_COLUMNS, #related_table, #entity_snake, #placeholder)
+                ).bind(&#fk_field).fetch_all(&mut *conn).await?;
                 Ok(rows.into_iter().map(#related::from).collect())
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::parse::EntityDef;
+
+    fn loaders(input: syn::DeriveInput) -> String {
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        Context::new(&entity).relation_loaders().to_string()
+    }
+
+    #[test]
+    fn required_foreign_key_is_taken_as_is() {
+        let code = loaders(syn::parse_quote! {
+            #[entity(table = "posts")]
+            pub struct Post {
+                #[id]
+                pub id: uuid::Uuid,
+                #[belongs_to(User)]
+                pub user_id: uuid::Uuid,
+            }
+        });
+        assert!(code.contains("user_id : uuid :: Uuid"));
+        assert!(!code.contains("return Ok (None)"));
+    }
+
+    #[test]
+    fn optional_foreign_key_short_circuits() {
+        let code = loaders(syn::parse_quote! {
+            #[entity(table = "users")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[belongs_to(Organization, on_delete = "set null")]
+                pub org_id: Option<uuid::Uuid>,
+            }
+        });
+        assert!(code.contains("org_id : Option < uuid :: Uuid >"));
+        assert!(code.contains("let Some (org_id) = org_id else { return Ok (None) ; } ;"));
+    }
+}
@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Full-text search method generator for PostgreSQL.
+//!
+//! Generates `search` for entities with a `#[column(tsvector)]` field. The
+//! query is parsed with the column's text search configuration and matched
+//! against the GIN-indexed column:
+//!
+//! ```sql
+//! SELECT ... FROM public.articles
+//! WHERE search @@ websearch_to_tsquery('english', $1)
+//!   AND deleted_at IS NULL  -- if soft_delete enabled
+//! ORDER BY ts_rank(search, websearch_to_tsquery('english', $1)) DESC, id DESC
+//! LIMIT $2 OFFSET $3
+//! ```
+//!
+//! `websearch_to_tsquery` accepts raw user input, unlike `to_tsquery`, which
+//! rejects anything that is not tsquery syntax. The id breaks rank ties so
+//! offset pagination stays stable.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::context::Context;
+
+impl Context<'_> {
+    /// Generate the `search` method implementation.
+    ///
+    /// # Returns
+    ///
+    /// Empty `TokenStream` if entity has no `tsvector` field.
+    pub fn search_method(&self) -> TokenStream {
+        let Some(sql) = self.search_sql() else {
+            return TokenStream::new();
+        };
+        let entity_name = self.entity_name;
+        let row_name = &self.row_name;
+
+        quote! {
+            async fn search(&self, query: &str, limit: i64, offset: i64) -> Result<Vec<#entity_name>, Self::Error> {
+                let rows: Vec<#row_name> = sqlx::query_as(#sql)
+                    .bind(query)
+                    .bind(limit)
+                    .bind(offset)
+                    .fetch_all(self)
+                    .await?;
+                Ok(#entity_name::from_rows(rows))
+            }
+        }
+    }
+
+    /// SQL text of `search`, if the entity has a `tsvector` field.
+    pub(super) fn search_sql(&self) -> Option<String> {
+        let field = self.entity.search_field()?;
+        let column = field.column_name();
+        let tsquery = format!(
+            "websearch_to_tsquery('{}', {})",
+            field.ts_config().unwrap_or_default(),
+            self.dialect.placeholder(1)
+        );
+        let deleted_filter = if self.soft_delete {
+            " AND deleted_at IS NULL"
+        } else {
+            ""
+        };
+
+        Some(format!(
+            "SELECT {} FROM {} WHERE {} @@ {}{} ORDER BY ts_rank({}, {}) DESC, {} DESC LIMIT {} OFFSET {}",
+            self.select_str,
+            self.table,
+            column,
+            tsquery,
+            deleted_filter,
+            column,
+            tsquery,
+            self.id_column,
+            self.dialect.placeholder(2),
+            self.dialect.placeholder(3)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::parse::EntityDef;
+
+    fn context_sql(input: syn::DeriveInput) -> (Option<String>, String) {
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        let ctx = Context::new(&entity);
+        (ctx.search_sql(), ctx.search_method().to_string())
+    }
+
+    #[test]
+    fn search_without_tsvector_returns_empty() {
+        let (sql, method) = context_sql(syn::parse_quote! {
+            #[entity(table = "articles")]
+            pub struct Article {
+                #[id]
+                pub id: uuid::Uuid,
+            }
+        });
+        assert!(sql.is_none());
+        assert!(method.is_empty());
+    }
+
+    #[test]
+    fn search_ranks_matches() {
+        let (sql, method) = context_sql(syn::parse_quote! {
+            #[entity(table = "articles", soft_delete)]
+            pub struct Article {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, response)]
+                pub title: String,
+                #[field(response)]
+                #[column(tsvector = "english", sources = [title])]
+                pub search: String,
+                pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+            }
+        });
+        assert_eq!(
+            sql.unwrap(),
+            "SELECT id, title, search::text AS search, deleted_at FROM public.articles \
+             WHERE search @@ websearch_to_tsquery('english', $1) AND deleted_at IS NULL \
+             ORDER BY ts_rank(search, websearch_to_tsquery('english', $1)) DESC, id DESC \
+             LIMIT $2 OFFSET $3"
+        );
+        assert!(method.contains("async fn search"));
+        assert!(method.contains(". bind (query) . bind (limit) . bind (offset)"));
+    }
+}
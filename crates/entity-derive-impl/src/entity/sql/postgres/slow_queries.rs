@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Slow query logging for `#[entity(log_slow_queries = N)]`.
+//!
+//! Every async repository method is timed from the first statement to the
+//! returned result. When that exceeds `N` milliseconds, a
+//! `tracing::warn!` event is emitted with the entity, the method name and
+//! the elapsed time; faster calls log nothing.
+//!
+//! ```rust,ignore
+//! async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, Self::Error> {
+//!     let __entity_started = std::time::Instant::now();
+//!     let __entity_result: Result<Option<User>, Self::Error> = async move {
+//!         // generated body
+//!     }
+//!     .await;
+//!     let __entity_elapsed = __entity_started.elapsed();
+//!     if __entity_elapsed >= std::time::Duration::from_millis(100u64) {
+//!         tracing::warn!(entity = "User", operation = "find_by_id", ...);
+//!     }
+//!     __entity_result
+//! }
+//! ```
+//!
+//! `stream_*` methods are not timed: they return before any row is read.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ImplItem, ImplItemFn};
+
+/// Wrap the async methods of a repository impl body with slow query timing.
+///
+/// `methods` are the generated `async fn` items; the result has the same
+/// items with timed bodies. Returns `methods` unchanged without a
+/// threshold.
+pub fn instrument(
+    entity_name: &str,
+    threshold_ms: Option<u64>,
+    methods: TokenStream
+) -> TokenStream {
+    let Some(threshold_ms) = threshold_ms else {
+        return methods;
+    };
+    let Ok(item) =
+        syn::parse2::<syn::ItemImpl>(quote! { impl __Repository for __Pool { #methods } })
+    else {
+        return methods;
+    };
+
+    let items = item.items.into_iter().map(|item| match item {
+        ImplItem::Fn(method) => {
+            let method = timed(entity_name, threshold_ms, method);
+            quote! { #method }
+        }
+        other => quote! { #other }
+    });
+    quote! { #(#items)* }
+}
+
+/// Time one method, unless it is synchronous or returns a stream.
+fn timed(entity_name: &str, threshold_ms: u64, mut method: ImplItemFn) -> ImplItemFn {
+    let operation = method.sig.ident.to_string();
+    if method.sig.asyncness.is_none() || operation.starts_with("stream_") {
+        return method;
+    }
+    let syn::ReturnType::Type(_, output) = &method.sig.output else {
+        return method;
+    };
+
+    let body = &method.block;
+    method.block = syn::parse_quote! {{
+        let __entity_started = std::time::Instant::now();
+        let __entity_result: #output = async move #body.await;
+        let __entity_elapsed = __entity_started.elapsed();
+        if __entity_elapsed >= std::time::Duration::from_millis(#threshold_ms) {
+            tracing::warn!(
+                entity = #entity_name,
+                operation = #operation,
+                elapsed_ms = __entity_elapsed.as_millis() as u64,
+                threshold_ms = #threshold_ms,
+                "slow query"
+            );
+        }
+        __entity_result
+    }};
+    method
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_threshold_keeps_methods() {
+        let methods = quote! {
+            async fn ping(&self) -> Result<(), Self::Error> { Ok(()) }
+        };
+        let output = instrument("User", None, methods.clone());
+        assert_eq!(output.to_string(), methods.to_string());
+    }
+
+    #[test]
+    fn async_methods_are_timed() {
+        let methods = quote! {
+            async fn ping(&self) -> Result<(), Self::Error> { Ok(()) }
+        };
+        let output = instrument("User", Some(100), methods).to_string();
+        assert!(output.contains("std :: time :: Instant :: now ()"));
+        assert!(
+            output.contains("let __entity_result : Result < () , Self :: Error > = async move")
+        );
+        assert!(output.contains("from_millis (100u64)"));
+        assert!(output.contains("entity = \"User\""));
+        assert!(output.contains("operation = \"ping\""));
+    }
+
+    #[test]
+    fn streams_are_not_timed() {
+        let methods = quote! {
+            async fn stream_filtered(&self) -> Result<Stream, Self::Error> { todo!() }
+        };
+        let output = instrument("User", Some(100), methods).to_string();
+        assert!(!output.contains("Instant"));
+    }
+}
@@ -21,9 +21,21 @@
 //! ```
 //!
 //! Regular `find_by_id` and `list` automatically filter out deleted records.
+//!
+//! # Cascade
+//!
+//! With `#[entity(cascade_soft_delete)]`, `delete` runs in a transaction and
+//! also marks the children of every `#[has_many]` relation whose
+//! `{Child}Row::SOFT_DELETE` is `true`:
+//!
+//! ```sql
+//! UPDATE schema.posts SET deleted_at = NOW()
+//! WHERE user_id = $1 AND deleted_at IS NULL
+//! ```
 
+use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 use super::context::Context;
 
@@ -51,6 +63,31 @@ impl Context<'_> {
         }
     }
 
+    /// Generate statements soft-deleting `has_many` children inside the
+    /// `delete` transaction `tx`.
+    ///
+    /// Children that are not soft-deletable are skipped by a constant
+    /// condition on `{Child}Row::SOFT_DELETE`.
+    pub fn cascade_soft_delete(&self) -> TokenStream {
+        let parent_snake = self.entity.name_str().to_case(Case::Snake);
+        let placeholder = self.dialect.placeholder(1);
+        let statements = self.entity.has_many_relations().iter().map(|related| {
+            let related_snake = related.to_string().to_case(Case::Snake);
+            let related_row = format_ident!("{}Row", related);
+            let sql = format!(
+                "UPDATE {}.{}s SET deleted_at = NOW() WHERE {}_id = {} AND deleted_at IS NULL",
+                self.entity.schema, related_snake, parent_snake, placeholder
+            );
+            quote! {
+                if #related_row::SOFT_DELETE {
+                    sqlx::query(#sql).bind(&id).execute(&mut *tx).await?;
+                }
+            }
+        });
+
+        quote! { #(#statements)* }
+    }
+
     /// Generate the `hard_delete` method.
     ///
     /// Permanently removes the record from the database.
@@ -63,7 +100,7 @@ impl Context<'_> {
     fn hard_delete_method(&self) -> TokenStream {
         let Self {
             table,
-            id_name,
+            id_column,
             id_type,
             dialect,
             ..
@@ -74,7 +111,7 @@ impl Context<'_> {
             async fn hard_delete(&self, id: #id_type) -> Result<bool, Self::Error> {
                 let result = sqlx::query(&format!(
                     "DELETE FROM {} WHERE {} = {}",
-                    #table, stringify!(#id_name), #placeholder
+                    #table, #id_column, #placeholder
                 )).bind(&id).execute(self).await?;
                 Ok(result.rows_affected() > 0)
             }
@@ -94,7 +131,7 @@ impl Context<'_> {
     fn restore_method(&self) -> TokenStream {
         let Self {
             table,
-            id_name,
+            id_column,
             id_type,
             dialect,
             ..
@@ -105,7 +142,7 @@ impl Context<'_> {
             async fn restore(&self, id: #id_type) -> Result<bool, Self::Error> {
                 let result = sqlx::query(&format!(
                     "UPDATE {} SET deleted_at = NULL WHERE {} = {} AND deleted_at IS NOT NULL",
-                    #table, stringify!(#id_name), #placeholder
+                    #table, #id_column, #placeholder
                 )).bind(&id).execute(self).await?;
                 Ok(result.rows_affected() > 0)
             }
@@ -128,8 +165,8 @@ impl Context<'_> {
             entity_name,
             row_name,
             table,
-            columns_str,
-            id_name,
+            select_str,
+            id_column,
             id_type,
             dialect,
             ..
@@ -139,7 +176,7 @@ impl Context<'_> {
         quote! {
             async fn find_by_id_with_deleted(&self, id: #id_type) -> Result<Option<#entity_name>, Self::Error> {
                 let row: Option<#row_name> = sqlx::query_as(
-                    &format!("SELECT {} FROM {} WHERE {} = {}", #columns_str, #table, stringify!(#id_name), #placeholder)
+                    &format!("SELECT {} FROM {} WHERE {} = {}", #select_str, #table, #id_column, #placeholder)
                 ).bind(&id).fetch_optional(self).await?;
                 Ok(row.map(#entity_name::from))
             }
@@ -154,7 +191,7 @@ impl Context<'_> {
     ///
     /// ```sql
     /// SELECT ... FROM schema.table
-    /// ORDER BY id DESC
+    /// ORDER BY id DESC  -- or #[entity(order_by = "...")]
     /// LIMIT $1 OFFSET $2
     /// ```
     ///
@@ -164,8 +201,8 @@ impl Context<'_> {
             entity_name,
             row_name,
             table,
-            columns_str,
-            id_name,
+            select_str,
+            list_order,
             dialect,
             ..
         } = self;
@@ -175,10 +212,10 @@ impl Context<'_> {
         quote! {
             async fn list_with_deleted(&self, limit: i64, offset: i64) -> Result<Vec<#entity_name>, Self::Error> {
                 let rows: Vec<#row_name> = sqlx::query_as(
-                    &format!("SELECT {} FROM {} ORDER BY {} DESC LIMIT {} OFFSET {}",
-                        #columns_str, #table, stringify!(#id_name), #limit_placeholder, #offset_placeholder)
+                    &format!("SELECT {} FROM {} ORDER BY {} LIMIT {} OFFSET {}",
+                        #select_str, #table, #list_order, #limit_placeholder, #offset_placeholder)
                 ).bind(limit).bind(offset).fetch_all(self).await?;
-                Ok(rows.into_iter().map(#entity_name::from).collect())
+                Ok(#entity_name::from_rows(rows))
             }
         }
     }
@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! SQLite repository implementation generator.
+//!
+//! Generates `impl {Name}Repository for sqlx::SqlitePool` behind
+//! `#[cfg(feature = "sqlite")]`, usually next to the PostgreSQL one via
+//! `alt_dialect`:
+//!
+//! ```rust,ignore
+//! #[derive(Entity)]
+//! #[entity(table = "users", dialect = "postgres", alt_dialect = "sqlite")]
+//! pub struct User { ... }
+//!
+//! // Cargo.toml of the calling crate
+//! // [features]
+//! // postgres = []
+//! // sqlite = []
+//! ```
+//!
+//! The methods come from the PostgreSQL generators run with a SQLite
+//! [`Context`], which differs in:
+//!
+//! | Aspect | PostgreSQL | SQLite |
+//! |--------|------------|--------|
+//! | Placeholders | `$1, $2` | `?1, ?2` |
+//! | Table | `schema.table` | `table` |
+//! | `update_many`, `find_by_ids_map` ids | `= ANY($n)` | `IN ($n, ...)` |
+//! | Soft delete timestamp | `NOW()` | `CURRENT_TIMESTAMP` |
+//! | `#[filter(like)]` | `ILIKE` | `LIKE` |
+//!
+//! Streams, relations, `cascade_soft_delete` and computed fields are
+//! PostgreSQL-only and rejected at parse time. Inherent items such as
+//! `debug_sql` and the `load_*` relation loaders stay PostgreSQL-only.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::postgres::{Context, repository_impl};
+use crate::entity::parse::{DatabaseDialect, EntityDef};
+
+/// Generate the SQLite repository implementation.
+pub fn generate(entity: &EntityDef) -> TokenStream {
+    let ctx = Context::with_dialect(entity, DatabaseDialect::Sqlite);
+    repository_impl(&ctx, &quote! { sqlx::SqlitePool })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate_for(input: syn::DeriveInput) -> String {
+        let entity = EntityDef::from_derive_input(&input).unwrap();
+        generate(&entity).to_string()
+    }
+
+    #[test]
+    fn implements_repository_for_sqlite_pool() {
+        let output = generate_for(syn::parse_quote! {
+            #[entity(table = "users", alt_dialect = "sqlite")]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(create, update, response)]
+                pub name: String,
+            }
+        });
+        assert!(output.contains("feature = \"sqlite\""));
+        assert!(output.contains("impl UserRepository for sqlx :: SqlitePool"));
+        assert!(output.contains("\"users\""));
+        assert!(!output.contains("public.users"));
+        assert!(output.contains("\"?1, ?2\""));
+        assert!(output.contains("IN ({})"));
+        assert!(!output.contains("ANY"));
+    }
+
+    #[test]
+    fn soft_delete_uses_current_timestamp() {
+        let output = generate_for(syn::parse_quote! {
+            #[entity(table = "users", alt_dialect = "sqlite", soft_delete)]
+            pub struct User {
+                #[id]
+                pub id: uuid::Uuid,
+                #[field(response)]
+                #[filter(like)]
+                pub name: String,
+                pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+            }
+        });
+        assert!(output.contains("deleted_at = CURRENT_TIMESTAMP"));
+        assert!(!output.contains("NOW()"));
+        assert!(output.contains("{} LIKE ${}"));
+    }
+}
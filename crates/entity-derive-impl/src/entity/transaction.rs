@@ -11,7 +11,8 @@
 //! For an entity `User` with `#[entity(transactions)]`:
 //!
 //! - `UserTransactionRepo<'t>` — Repository adapter for transaction context
-//! - `with_users()` — Builder method on `Transaction` (fluent, chainable)
+//! - `with_users()` — Builder method on `Transaction` (fluent, chainable); with
+//!   `isolation = "..."` it raises the transaction to that level
 //! - `users()` — Accessor method on `TransactionContext`
 //!
 //! # Example
@@ -68,8 +69,11 @@ fn generate_repo_adapter(entity: &EntityDef) -> TokenStream {
     let update_dto = &ctx.update_dto;
     let table = &ctx.table;
     let columns_str = &ctx.columns_str;
+    let select_str = &ctx.select_str;
+    let returning_str = &ctx.returning_str;
     let placeholders_str = &ctx.placeholders_str;
-    let id_name = ctx.id_name;
+    let id_column = &ctx.id_column;
+    let list_order = &ctx.list_order;
     let id_type = ctx.id_type;
     let soft_delete = ctx.soft_delete;
     let repo_name = format_ident!("{}TransactionRepo", entity_name);
@@ -94,7 +98,7 @@ fn generate_repo_adapter(entity: &EntityDef) -> TokenStream {
                 let entity = #entity_name::from(dto);
                 let insertable = #insertable_name::from(&entity);
                 let row: #row_name = sqlx::query_as(
-                    concat!("INSERT INTO ", #table, " (", #columns_str, ") VALUES (", #placeholders_str, ") RETURNING *")
+                    concat!("INSERT INTO ", #table, " (", #columns_str, ") VALUES (", #placeholders_str, ") RETURNING ", #returning_str)
                 )
                     #(#bindings)*
                     .fetch_one(&mut **self.tx).await?;
@@ -107,10 +111,11 @@ fn generate_repo_adapter(entity: &EntityDef) -> TokenStream {
         TokenStream::new()
     } else {
         let update_fields = entity.update_fields();
-        let field_names: Vec<String> = update_fields.iter().map(|f| f.name_str()).collect();
-        let field_refs: Vec<&str> = field_names.iter().map(String::as_str).collect();
-        let set_clause = ctx.dialect.set_clause(&field_refs);
-        let where_placeholder = ctx.dialect.placeholder(update_fields.len() + 1);
+        let set_clause =
+            super::sql::postgres::helpers::update_set_clause(&ctx.dialect, &update_fields);
+        let where_placeholder = ctx
+            .dialect
+            .placeholder(super::sql::postgres::helpers::update_param_count(&update_fields) + 1);
         let update_bindings = super::sql::postgres::helpers::update_bindings(&update_fields);
 
         quote! {
@@ -121,8 +126,8 @@ fn generate_repo_adapter(entity: &EntityDef) -> TokenStream {
                 dto: #update_dto
             ) -> Result<#entity_name, sqlx::Error> {
                 let row: #row_name = sqlx::query_as(
-                    &format!("UPDATE {} SET {} WHERE {} = {} RETURNING *",
-                        #table, #set_clause, stringify!(#id_name), #where_placeholder)
+                    &format!("UPDATE {} SET {} WHERE {} = {} RETURNING {}",
+                        #table, #set_clause, #id_column, #where_placeholder, #returning_str)
                 )
                     #(#update_bindings)*
                     .bind(&id)
@@ -136,7 +141,7 @@ fn generate_repo_adapter(entity: &EntityDef) -> TokenStream {
         quote! {
             let result = sqlx::query(&format!(
                 "UPDATE {} SET deleted_at = NOW() WHERE {} = $1 AND deleted_at IS NULL",
-                #table, stringify!(#id_name)
+                #table, #id_column
             )).bind(&id).execute(&mut **self.tx).await?;
             Ok(result.rows_affected() > 0)
         }
@@ -144,7 +149,7 @@ fn generate_repo_adapter(entity: &EntityDef) -> TokenStream {
         quote! {
             let result = sqlx::query(&format!(
                 "DELETE FROM {} WHERE {} = $1",
-                #table, stringify!(#id_name)
+                #table, #id_column
             )).bind(&id).execute(&mut **self.tx).await?;
             Ok(result.rows_affected() > 0)
         }
@@ -176,7 +181,7 @@ fn generate_repo_adapter(entity: &EntityDef) -> TokenStream {
             ) -> Result<Option<#entity_name>, sqlx::Error> {
                 let row: Option<#row_name> = sqlx::query_as(
                     &format!("SELECT {} FROM {} WHERE {} = $1{}",
-                        #columns_str, #table, stringify!(#id_name), #deleted_filter)
+                        #select_str, #table, #id_column, #deleted_filter)
                 ).bind(&id).fetch_optional(&mut **self.tx).await?;
                 Ok(row.map(#entity_name::from))
             }
@@ -199,10 +204,10 @@ fn generate_repo_adapter(entity: &EntityDef) -> TokenStream {
             ) -> Result<Vec<#entity_name>, sqlx::Error> {
                 let where_clause = if #soft_delete { "WHERE deleted_at IS NULL " } else { "" };
                 let rows: Vec<#row_name> = sqlx::query_as(
-                    &format!("SELECT {} FROM {} {}ORDER BY {} DESC LIMIT $1 OFFSET $2",
-                        #columns_str, #table, where_clause, stringify!(#id_name))
+                    &format!("SELECT {} FROM {} {}ORDER BY {} LIMIT $1 OFFSET $2",
+                        #select_str, #table, where_clause, #list_order)
                 ).bind(limit).bind(offset).fetch_all(&mut **self.tx).await?;
-                Ok(rows.into_iter().map(#entity_name::from).collect())
+                Ok(#entity_name::from_rows(rows))
             }
         }
     }
@@ -221,6 +226,13 @@ fn generate_builder_extension(entity: &EntityDef) -> TokenStream {
     let method_name = format_ident!("with_{}", plural);
     let trait_name = format_ident!("TransactionWith{}", entity_name);
     let marker = marker::generated();
+    let body = match entity.isolation_level() {
+        Some(level) => {
+            let level = level.to_tokens();
+            quote! { self.require_isolation(#level) }
+        }
+        None => quote! { self }
+    };
 
     quote! {
         #marker
@@ -237,7 +249,7 @@ fn generate_builder_extension(entity: &EntityDef) -> TokenStream {
 
         impl<'p> #trait_name<'p> for entity_core::transaction::Transaction<'p, sqlx::PgPool> {
             fn #method_name(self) -> Self {
-                self
+                #body
             }
         }
     }
@@ -42,7 +42,7 @@
 //!
 //! ```rust,ignore
 //! pub struct User {
-//!     #[id]                           // Primary key, UUID v7, always in response
+//!     #[id]                           // Primary key, UUID v7, in response
 //!     pub id: Uuid,
 //!
 //!     #[field(create, update, response)]  // In all DTOs
@@ -92,6 +92,8 @@
 //! | `impl UserRepository for PgPool` | PostgreSQL implementation |
 //! | `User{Projection}` | Projection structs (e.g., `UserPublic`, `UserAdmin`) |
 //! | `From<...>` impls | Type conversions between all structs |
+//! | `impl Entity for User` | Table, schema, columns and id type for generic code |
+//! | `USER_SCHEMA_JSON` | JSON description of fields, types, filters and relations for tooling |
 //!
 //! # SQL Generation Modes
 //!
@@ -116,6 +118,10 @@
 //!     /// Find entity by primary key
 //!     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, Self::Error>;
 //!
+//!     /// Find entity by primary key, or a not-found error
+//!     /// (requires `Self::Error: NotFoundError`)
+//!     async fn find_by_id_or_error(&self, id: Uuid) -> Result<User, Self::Error>;
+//!
 //!     /// Update entity with partial data
 //!     async fn update(&self, id: Uuid, dto: UpdateUserRequest) -> Result<User, Self::Error>;
 //!
@@ -128,6 +134,9 @@
 //!     /// Query entities with type-safe filters (if #[filter] used)
 //!     async fn query(&self, query: UserQuery) -> Result<Vec<User>, Self::Error>;
 //!
+//!     /// Page plus total match count in one statement (if #[filter] used)
+//!     async fn list_with_total(&self, query: UserQuery) -> Result<(Vec<User>, i64), Self::Error>;
+//!
 //!     // For each projection, generates optimized SELECT method
 //!     async fn find_by_id_public(&self, id: Uuid) -> Result<Option<UserPublic>, Self::Error>;
 //!     async fn find_by_id_admin(&self, id: Uuid) -> Result<Option<UserAdmin>, Self::Error>;
@@ -161,6 +170,9 @@
 //!
 //! // SQL: SELECT id, name, avatar FROM public.users WHERE id = $1
 //! let public = repo.find_by_id_public(user_id).await?;
+//!
+//! // Narrow an already fetched entity or row, no query
+//! let public = UserPublic::from(&user_row);
 //! ```
 //!
 //! # Error Handling
@@ -244,6 +256,10 @@ use proc_macro::TokenStream;
 /// - **`UserRepository`** — Async trait with CRUD methods
 /// - **`impl UserRepository for PgPool`** — PostgreSQL implementation (when
 ///   `sql = "full"`)
+/// - **`impl Entity for User`** — `TABLE`, `SCHEMA`, `COLUMNS` and `Id` of the
+///   `entity_core::Entity` trait
+/// - **`USER_SCHEMA_JSON`** — JSON description of the fields, types,
+///   nullability, filters and relations for code generators
 ///
 /// # Entity Attributes
 ///
@@ -254,33 +270,84 @@ use proc_macro::TokenStream;
 /// | `table` | **Yes** | — | Database table name |
 /// | `schema` | No | `"public"` | Database schema name |
 /// | `sql` | No | `"full"` | SQL generation: `"full"`, `"trait"`, or `"none"` |
-/// | `dialect` | No | `"postgres"` | Database dialect: `"postgres"`, `"clickhouse"`, `"mongodb"` |
+/// | `dialect` | No | `"postgres"` | Database dialect: `"postgres"`, `"clickhouse"`, `"mongodb"`, `"sqlite"` |
+/// | `alt_dialect` | No | — | `"sqlite"` next to `dialect = "postgres"`: adds `impl {Name}Repository for sqlx::SqlitePool` behind `#[cfg(feature = "sqlite")]` |
 /// | `uuid` | No | `"v7"` | UUID version for ID: `"v7"` (time-ordered) or `"v4"` (random) |
-/// | `migrations` | No | `false` | Generate `MIGRATION_UP` and `MIGRATION_DOWN` constants |
+/// | `id_prefix` | No | — | Prefixed string IDs (e.g. `"usr_"` gives `usr_0190b5c8...`). The `#[id]` must be `String`; the UUID is appended in simple hex form and the column is `TEXT` |
+/// | `migrations` | No | `false` | Generate `MIGRATION_UP`, `MIGRATION_DOWN` and `SCHEMA_VERSION` constants |
+/// | `schema_create` | No | `false` | With `migrations` and a non-`public` `schema`, start `MIGRATION_UP` with `CREATE SCHEMA IF NOT EXISTS {schema};` |
+/// | `audit` | No | — | Audit table name. Generates `{Entity}AuditRepository` recording every mutation in the same transaction |
+/// | `cache` | No | `false` | Add `find_by_id_cached`, `update_cached` and `delete_cached` reading through an `EntityCache` |
+/// | `ids_map` | No | `false` | Add `find_by_ids_map(Vec<Id>)` returning a `HashMap` keyed by id in one query; the id type must implement `Hash`, `Eq` and `Clone` |
+/// | `immutable` | No | `false` | Skip `Update{Entity}Request`, `update` and the PATCH handler; create, read and delete remain |
+/// | `command_dedup` | No | `false` | With `commands`, add `{Entity}Command::dedup_key()` returning `({Entity}CommandKind, Option<Id>)` and `impl Hash` over it, for idempotent command buses; payloads are not compared |
+/// | `repo_struct` | No | `false` | Generate `{Entity}Repo { pool }` with `new(pool)`, `Clone` and a `{Entity}Repository` impl forwarding to the pool; PostgreSQL or SQLite with `sql = "full"` |
+/// | `ord_by_id` | No | `false` | Implement `PartialEq`, `Eq`, `PartialOrd` and `Ord` by the `#[id]` field; UUID v7 ids sort by creation time |
+/// | `diffable` | No | `false` | Generate `diff(&self, other)` returning `(field, old, new)` for fields whose `Debug` output differs; masked fields are reported masked |
+/// | `cascade_soft_delete` | No | `false` | With `soft_delete`, `delete` also soft-deletes rows of `#[has_many]` children that are themselves `soft_delete`, in one transaction |
+/// | `unique_among_active` | No | `false` | With `soft_delete` and `migrations`, `#[column(unique)]` columns and unique composite indexes become partial unique indexes `WHERE deleted_at IS NULL`, so soft-deleted rows free their values |
+/// | `temporary` | No | `false` | Test-only: with `migrations`, `MIGRATION_UP` emits `CREATE TEMPORARY TABLE` and the entity lives in `pg_temp` |
+/// | `upsert` | No | — | Comma-separated conflict key (e.g. `"email"`). Adds `bulk_upsert(Vec<Create{Entity}Request>)`: multi-row `INSERT ... ON CONFLICT (key) DO UPDATE` in one transaction returning the rows, last DTO per key wins. PostgreSQL only |
+/// | `order_by` | No | `id DESC` | Comma-separated `field [ASC\|DESC]` terms (e.g. `"name ASC, created_at DESC"`) used as the `ORDER BY` of `list`, `list_with_deleted` and `query`, followed by `id DESC` unless the id column is listed. Unknown fields are a compile error |
+/// | `cursor` | No | — | Comma-separated ordering columns (e.g. `"created_at, id"`). Adds `{Entity}Cursor` and `list_after(Option<{Entity}Cursor>, limit)` returning a `Page` with the next cursor |
+/// | `strict_requests` | No | `false` | Add `#[serde(deny_unknown_fields)]` to Create/Update DTOs so unexpected keys are rejected; Response is unaffected |
+/// | `arbitrary` | No | `false` | Derive `arbitrary::Arbitrary` on Create/Update/Response DTOs for fuzzing; field types need `Arbitrary` impls |
+/// | `graphql` | No | `false` | Derive `async_graphql::InputObject` on Create/Update and `SimpleObject` on Response; `rename_api` also sets `#[graphql(name)]` |
+/// | `double_option_updates` | No | `false` | Nullable update fields become `Option<Option<T>>` in `Update{Entity}Request`: an absent key leaves the column unchanged, `null` sets it to `NULL` |
+/// | `log_slow_queries` | No | — | Threshold in milliseconds (e.g. `100`). Repository methods log a `tracing::warn!` with entity, operation and elapsed time when a call takes longer; needs the `tracing` crate |
+/// | `isolation` | No | — | With `transactions`, the isolation level (`"read_committed"`, `"repeatable_read"`, `"serializable"`) that `with_{entities}()` raises the transaction to; the strictest requested level wins. `Transaction::isolation` sets a level directly |
+/// | `row_prefix` | No | — | Column prefix the Row decodes from (e.g. `"u_"` maps `name` to `u_name`) for joined queries |
+/// | `manual_from_row` | No | `false` | Implement `sqlx::FromRow` for `{Entity}Row` by hand, one `sqlx::Row::try_get(row, column)` per field, instead of deriving it. Implied by any `decode_with` field |
+/// | `derive(Target: ...)` | No | — | Extra derives for a generated type (`Create`, `Update`, `Response`, `Row`, `Insertable`, `Query`) |
 ///
 /// # Field Attributes
 ///
 /// | Attribute | Description |
 /// |-----------|-------------|
-/// | `#[id]` | Primary key. Auto-generates UUID (v7 by default, configurable with `uuid` attribute). Always included in `Response`. |
-/// | `#[auto]` | Auto-generated field (e.g., `created_at`). Excluded from `Create`/`Update`. |
+/// | `#[id]` | Primary key. Auto-generates UUID (v7 by default, configurable with `uuid` attribute). Included in `Response` unless `#[field(skip_response)]` keeps it internal. Cannot be `create` or `update`. |
+/// | `#[auto]` | Auto-generated field. Cannot be `create` or `update`. Implied for `created_at`/`updated_at` datetime fields unless they are `create` or `update`. |
 /// | `#[field(create)]` | Include in `CreateRequest`. |
 /// | `#[field(update)]` | Include in `UpdateRequest`. Wrapped in `Option<T>` if not already. |
 /// | `#[field(response)]` | Include in `Response`. |
-/// | `#[field(skip)]` | Exclude from ALL DTOs. Use for sensitive data. |
-/// | `#[belongs_to(Entity)]` | Foreign key relation. Generates `find_{entity}` method in repository. |
+/// | `#[field(skip)]` | Exclude from ALL DTOs. Use for sensitive data. Cannot be combined with `create`, `update` or `response`. |
+/// | `#[field(skip_response)]` | Exclude from `Response` only. Combine with `create` for settable secrets. |
+/// | `#[field(response, mask = "last4")]` | Keep in `Response` but masked. Built-ins `last4`, `email`, `redact`, or a path to `fn(&str) -> String`. |
+/// | `#[field(response, as_str)]` | `String` in `Response`, converted with `to_string()`; the field type must implement `Display`. |
+/// | `#[field(response, serialize_as_string)]` | Keeps the type in `Response` but serializes it as a JSON string, e.g. `i64` ids for JavaScript clients; needs `Display` and `FromStr`. |
+/// | `#[field(rename_column = "name")]` | Database column used by all generated SQL; selected back as `name AS field`. Same as `#[column(name = "...")]`, which it cannot be combined with. |
+/// | `#[field(rename_api = "name")]` | JSON property name in the Create/Update/Response DTOs and projections (`#[serde(rename)]`), followed by OpenAPI and GraphQL. The Rust field name is unchanged. |
+/// | `#[field(flatten)]` | Row decodes the field, a value object deriving `sqlx::FromRow`, with `#[sqlx(flatten)]`. Requires `sql = "trait"`. |
+/// | `#[field(returning = "expr")]` | Computed by PostgreSQL: never written, selected and returned as `expr AS column`. Cannot be `create`, `update` or `#[filter]`. |
+/// | `#[field(min_len = N, max_len = N)]` | String length bounds on the Create/Update DTO field: `#[validate(length(...))]` and OpenAPI `minLength`/`maxLength`. |
+/// | `#[field(min = N, max = N)]` | Numeric bounds on the Create/Update DTO field: `#[validate(range(...))]` and OpenAPI `minimum`/`maximum`. |
+/// | `#[validate(...)]` | `validator` rules copied to the Create and Update DTO fields (behind the `validate` feature). |
+/// | `#[validate_create(...)]`, `#[validate_update(...)]` | `validator` rules for the Create or Update DTO field only, e.g. a length check on create alone. |
+/// | `#[field(update, write_once)]` | Update fills the column only while it is `NULL` (`SET col = COALESCE(col, $n)`). |
+/// | `#[field(create, create_type = "T")]` | Use `T` in `CreateRequest`, converted with `Into`. Add `create_with = "path"` for a custom conversion fn. |
+/// | `#[field(update, update_type = "T")]` | Use `T` in `UpdateRequest`, converted with `From`. Add `update_with = "path"` for a custom conversion fn. |
+/// | `#[field(update, update_type = "Option<Option<T>>")]` | On an `Option<T>` field: absent leaves the column unchanged, `null` sets it to `NULL` (`SET col = CASE WHEN $n THEN $n+1 ELSE col END`). |
+/// | `#[belongs_to(Entity)]` | Foreign key relation. Generates `find_{entity}` method in repository. The key type must match the parent's `id`. |
 /// | `#[belongs_to(Entity, on_delete = "...")]` | Foreign key with ON DELETE action (`cascade`, `set null`, `restrict`). |
 /// | `#[has_many(Entity)]` | One-to-many relation (entity-level). Generates `find_{entities}` method. |
-/// | `#[projection(Name: f1, f2)]` | Entity-level. Defines a projection struct with specified fields. |
+/// | `#[projection(Name: f1, f2)]` | Entity-level. Defines a projection struct with specified fields, convertible `From` the entity and the Row. |
 /// | `#[filter]` | Exact match filter. Generates field in Query struct with `=` comparison. |
 /// | `#[filter(like)]` | ILIKE pattern filter. Generates field for text pattern matching. |
 /// | `#[filter(range)]` | Range filter. Generates `field_from` and `field_to` fields. |
+/// | `#[filter(overlaps)]` | Overlap filter for `PgRange<T>` columns (`TSTZRANGE`, `INT8RANGE`, ...). Generates `field_overlaps: Option<(T, T)>`, matched as `[start, end)` with `&&`. PostgreSQL only. |
+/// | `#[sortable]` | Add `{Field}Asc`/`{Field}Desc` variants to `{Entity}OrderBy`, used by `list_ordered`. |
 /// | `#[column(unique)]` | Add UNIQUE constraint in migrations. |
 /// | `#[column(index)]` | Add btree index in migrations. |
 /// | `#[column(index = "gin")]` | Add index with specific type (btree, hash, gin, gist, brin). |
 /// | `#[column(default = "...")]` | Set DEFAULT value in migrations. |
 /// | `#[column(check = "...")]` | Add CHECK constraint in migrations. |
 /// | `#[column(varchar = N)]` | Use VARCHAR(N) instead of TEXT in migrations. |
+/// | `#[column(citext)]` | Use case-insensitive `CITEXT` instead of TEXT in migrations; the migration creates the `citext` extension. The Rust type stays `String`. |
+/// | `#[column(collation = "...")]` | Add `COLLATE "..."` to the column in migrations, e.g. `"en-US-x-icu"`. |
+/// | `#[column(storage = "...")]` | Set the TOAST storage mode in migrations: `plain`, `main`, `external` or `extended`. |
+/// | `#[column(encrypt)]` | Store `String` column as ciphertext. Generates `{Entity}EncryptedRepository` using an `Encryptor`. |
+/// | `#[column(transparent = "T")]` | Store a newtype as its inner type `T`: Row, Insertable, bindings and migrations use `T`, while the entity and DTOs keep the newtype, documented in OpenAPI as `T`. Requires `From<T>` for the newtype and `From<Newtype>` for `T`. |
+/// | `#[column(decode_with = "path")]` | Decode the Row field with `path(row, column) -> Result<T, sqlx::Error>` instead of `try_get`, where `T` is the Row field type; for composite or otherwise exotic column types. Implies `manual_from_row`. |
+/// | `#[column(tsvector, sources = [a, b])]` | PostgreSQL full-text search: a `String` field becomes a `TSVECTOR GENERATED ALWAYS AS (...) STORED` column over the sources, weighted `A`–`D` in order, with a GIN index. It is never written and reads back as text. Adds a ranked `search(query, limit, offset)` repository method. `tsvector = "english"` picks the text search configuration (default `simple`). |
 ///
 /// Multiple attributes can be combined: `#[field(create, update, response)]`
 ///
@@ -395,6 +462,7 @@ use proc_macro::TokenStream;
 /// // Generated constants:
 /// // Product::MIGRATION_UP - CREATE TABLE, indexes, constraints
 /// // Product::MIGRATION_DOWN - DROP TABLE CASCADE
+/// // Product::SCHEMA_VERSION - hash of the column definitions
 ///
 /// // Apply migration:
 /// sqlx::query(Product::MIGRATION_UP).execute(&pool).await?;
@@ -419,7 +487,9 @@ use proc_macro::TokenStream;
 ///
 /// # Generated SQL
 ///
-/// The macro generates parameterized SQL queries that are safe from injection:
+/// The macro generates parameterized SQL queries that are safe from injection.
+/// Every SELECT and RETURNING names its columns, so columns added to the
+/// table outside the entity do not break decoding:
 ///
 /// ```sql
 /// -- CREATE
@@ -427,7 +497,7 @@ use proc_macro::TokenStream;
 /// VALUES ($1, $2, $3, ...)
 ///
 /// -- READ
-/// SELECT * FROM schema.table WHERE id = $1
+/// SELECT id, field1, field2, ... FROM schema.table WHERE id = $1
 ///
 /// -- UPDATE (dynamic based on provided fields)
 /// UPDATE schema.table SET field1 = $1, field2 = $2 WHERE id = $3
@@ -436,13 +506,26 @@ use proc_macro::TokenStream;
 /// DELETE FROM schema.table WHERE id = $1 RETURNING id
 ///
 /// -- LIST
-/// SELECT * FROM schema.table ORDER BY created_at DESC LIMIT $1 OFFSET $2
+/// SELECT id, field1, field2, ... FROM schema.table ORDER BY id DESC LIMIT $1 OFFSET $2
 /// ```
 #[proc_macro_derive(
     Entity,
     attributes(
-        entity, field, id, auto, validate, belongs_to, has_many, projection, filter, command,
-        example, column
+        entity,
+        field,
+        id,
+        auto,
+        validate,
+        validate_create,
+        validate_update,
+        belongs_to,
+        has_many,
+        projection,
+        filter,
+        command,
+        example,
+        column,
+        sortable
     )
 )]
 pub fn derive_entity(input: TokenStream) -> TokenStream {
@@ -7,10 +7,12 @@
 //!
 //! # Submodules
 //!
+//! - [`derives`] — Extra derive attributes for generated types
 //! - [`docs`] — Documentation extraction from attributes
 //! - [`fields`] — Field assignment generation for `From` implementations
 //! - [`marker`] — Generated code marker comments
 
+pub mod derives;
 pub mod docs;
 pub mod fields;
 pub mod marker;
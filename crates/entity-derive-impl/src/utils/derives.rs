@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Extra derive emission for generated types.
+//!
+//! Turns `#[entity(derive(Target: ...))]` entries into a derive attribute
+//! placed after the derives the macro always emits.
+//!
+//! # Example Output
+//!
+//! For `derive(Response: PartialEq, Eq)`:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+//! #[derive(PartialEq, Eq)]
+//! pub struct UserResponse { ... }
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::entity::parse::{DeriveTarget, EntityDef};
+
+/// Generates the extra `#[derive(...)]` attribute for a generated type.
+///
+/// Returns an empty `TokenStream` when no extra derives target `target`.
+#[must_use]
+pub fn extra(entity: &EntityDef, target: DeriveTarget) -> TokenStream {
+    let paths = entity.derives_for(target);
+    if paths.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! { #[derive(#(#paths),*)] }
+}
@@ -14,14 +14,19 @@
 //! |----------|----------------|
 //! | [`assigns`] | `name: source.name` |
 //! | [`assigns_clone`] | `name: source.name.clone()` |
-//! | [`create_assigns`] | `name: dto.name` or `name: Uuid::now_v7()` |
+//! | [`response_assigns`] | `name: source.name`, `name: mask(&source.name)` or `name: source.name.to_string()` |
+//! | [`create_assigns`] | `name: dto.name`, `name: Uuid::now_v7()` or a prefixed string id |
+//!
+//! Assignments between the entity and its Row or Insertable pass a
+//! [`Conversion`], which converts `#[column(transparent)]` newtypes to or
+//! from their stored inner type.
 //!
 //! # Usage
 //!
 //! These functions are used by `mappers.rs` to generate `From` implementations:
 //!
 //! ```rust,ignore
-//! let assigns = fields::assigns(entity.all_fields(), "row");
+//! let assigns = fields::assigns(entity.all_fields(), "row", Conversion::FromDb);
 //! quote! {
 //!     impl From<UserRow> for User {
 //!         fn from(row: UserRow) -> Self {
@@ -37,16 +42,48 @@ use syn::Ident;
 
 use crate::entity::parse::{FieldDef, UuidVersion};
 
+/// Conversion applied to field values by an assignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conversion {
+    /// Values keep their type (entity to Response).
+    None,
+
+    /// Values come from a Row: transparent newtypes are built from their
+    /// inner type.
+    FromDb,
+
+    /// Values go to an Insertable: transparent newtypes are unwrapped to
+    /// their inner type.
+    IntoDb
+}
+
+impl Conversion {
+    /// Apply the conversion to `value`, a field of the source.
+    fn apply(self, field: &FieldDef, value: TokenStream) -> TokenStream {
+        match self {
+            Self::None => value,
+            Self::FromDb => field.db_to_field(value),
+            Self::IntoDb => field.field_to_db(value)
+        }
+    }
+
+    /// Whether the conversion changes the value of `field`.
+    fn changes(self, field: &FieldDef) -> bool {
+        self != Self::None && field.transparent_inner().is_some()
+    }
+}
+
 /// Generates move assignments: `name: source.name`.
 ///
 /// Used when the source is consumed (owned value).
-pub fn assigns(fields: &[FieldDef], source: &str) -> Vec<TokenStream> {
+pub fn assigns(fields: &[FieldDef], source: &str, conversion: Conversion) -> Vec<TokenStream> {
     let src = Ident::new(source, Span::call_site());
     fields
         .iter()
         .map(|f: &FieldDef| {
             let name = f.name();
-            quote! { #name: #src.#name }
+            let value = conversion.apply(f, quote! { #src.#name });
+            quote! { #name: #value }
         })
         .collect()
 }
@@ -54,58 +91,134 @@ pub fn assigns(fields: &[FieldDef], source: &str) -> Vec<TokenStream> {
 /// Generates clone assignments: `name: source.name.clone()`.
 ///
 /// Used when the source is borrowed and values need to be cloned.
-pub fn assigns_clone(fields: &[FieldDef], source: &str) -> Vec<TokenStream> {
+pub fn assigns_clone(
+    fields: &[FieldDef],
+    source: &str,
+    conversion: Conversion
+) -> Vec<TokenStream> {
     let src = Ident::new(source, Span::call_site());
     fields
         .iter()
         .map(|f: &FieldDef| {
             let name = f.name();
-            quote! { #name: #src.#name.clone() }
+            let value = conversion.apply(f, quote! { #src.#name.clone() });
+            quote! { #name: #value }
         })
         .collect()
 }
 
-/// Generates move assignments from field references.
+/// Generates move assignments for Response fields.
 ///
-/// Same as [`assigns`] but accepts `&[&FieldDef]` instead of `&[FieldDef]`.
-pub fn assigns_from_refs(fields: &[&FieldDef], source: &str) -> Vec<TokenStream> {
+/// Same as [`assigns`] but accepts `&[&FieldDef]` and applies
+/// `#[field(mask = "...")]` masks and `as_str` conversions.
+pub fn response_assigns(
+    fields: &[&FieldDef],
+    source: &str,
+    conversion: Conversion
+) -> Vec<TokenStream> {
     let src = Ident::new(source, Span::call_site());
     fields
         .iter()
         .map(|f: &&FieldDef| {
             let name = f.name();
-            quote! { #name: #src.#name }
+            let borrowed = borrowed_value(f, &src, conversion);
+            masked(f, &borrowed)
+                .or_else(|| stringified(f, &borrowed))
+                .unwrap_or_else(|| {
+                    let value = conversion.apply(f, quote! { #src.#name });
+                    quote! { #name: #value }
+                })
         })
         .collect()
 }
 
-/// Generates clone assignments from field references.
+/// Generates clone assignments for Response fields.
 ///
-/// Same as [`assigns_clone`] but accepts `&[&FieldDef]` instead of
-/// `&[FieldDef]`.
-pub fn assigns_clone_from_refs(fields: &[&FieldDef], source: &str) -> Vec<TokenStream> {
+/// Same as [`assigns_clone`] but accepts `&[&FieldDef]` and applies
+/// `#[field(mask = "...")]` masks and `as_str` conversions.
+pub fn response_assigns_clone(
+    fields: &[&FieldDef],
+    source: &str,
+    conversion: Conversion
+) -> Vec<TokenStream> {
     let src = Ident::new(source, Span::call_site());
     fields
         .iter()
         .map(|f: &&FieldDef| {
             let name = f.name();
-            quote! { #name: #src.#name.clone() }
+            let borrowed = borrowed_value(f, &src, conversion);
+            masked(f, &borrowed)
+                .or_else(|| stringified(f, &borrowed))
+                .unwrap_or_else(|| {
+                    let value = conversion.apply(f, quote! { #src.#name.clone() });
+                    quote! { #name: #value }
+                })
         })
         .collect()
 }
 
+/// Field value that masks and `as_str` borrow: `source.name`, or a
+/// converted clone when the conversion changes the field.
+fn borrowed_value(field: &FieldDef, src: &Ident, conversion: Conversion) -> TokenStream {
+    let name = field.name();
+    if conversion.changes(field) {
+        let value = conversion.apply(field, quote! { #src.#name.clone() });
+        quote! { (#value) }
+    } else {
+        quote! { #src.#name }
+    }
+}
+
+/// Masked assignment `name: mask(&value)`, if the field has a mask.
+///
+/// Masks only borrow the value, so the same expression serves owned and
+/// borrowed sources.
+fn masked(field: &FieldDef, value: &TokenStream) -> Option<TokenStream> {
+    let mask = field.expose.mask.as_ref()?;
+    let name = field.name();
+    Some(if field.is_option() {
+        quote! { #name: #value.as_deref().map(#mask) }
+    } else {
+        quote! { #name: #mask(&#value) }
+    })
+}
+
+/// `as_str` assignment `name: value.to_string()`, if the field has
+/// `#[field(as_str)]`.
+///
+/// `to_string` only borrows, so the same expression serves owned and
+/// borrowed sources.
+fn stringified(field: &FieldDef, value: &TokenStream) -> Option<TokenStream> {
+    if !field.expose.as_str {
+        return None;
+    }
+    let name = field.name();
+    Some(if field.is_option() {
+        quote! { #name: #value.as_ref().map(ToString::to_string) }
+    } else {
+        quote! { #name: #value.to_string() }
+    })
+}
+
 /// Generates field assignments for `From<CreateRequest> for Entity`.
 ///
 /// Handles three field categories:
 ///
-/// - **Create fields**: `name: dto.name` (from DTO)
-/// - **ID fields**: `id: Uuid::now_v7()` or `Uuid::new_v4()` (auto-generated)
+/// - **Create fields**: `name: dto.name` (from DTO), converted via
+///   `create_with` or `Into` when `create_type` overrides the DTO type
+/// - **ID fields**: `id: Uuid::now_v7()` or `Uuid::new_v4()` (auto-generated),
+///   or `format!("{prefix}{}", uuid.simple())` with an `id_prefix`
 /// - **Other fields**: `name: Default::default()` (auto/skip fields)
 pub fn create_assigns(
     all_fields: &[FieldDef],
     create_fields: &[&FieldDef],
-    uuid_version: UuidVersion
+    uuid_version: UuidVersion,
+    id_prefix: Option<&str>
 ) -> Vec<TokenStream> {
+    let uuid = match uuid_version {
+        UuidVersion::V7 => quote! { uuid::Uuid::now_v7() },
+        UuidVersion::V4 => quote! { uuid::Uuid::new_v4() }
+    };
     all_fields
         .iter()
         .map(|f: &FieldDef| {
@@ -113,11 +226,17 @@ pub fn create_assigns(
             let is_in_create = create_fields.iter().any(|cf: &&FieldDef| cf.name() == name);
 
             if is_in_create {
-                quote! { #name: dto.#name }
+                if let Some(conv) = &f.expose.create_with {
+                    quote! { #name: #conv(dto.#name) }
+                } else if f.expose.create_type.is_some() {
+                    quote! { #name: ::core::convert::Into::into(dto.#name) }
+                } else {
+                    quote! { #name: dto.#name }
+                }
             } else if f.is_id() {
-                match uuid_version {
-                    UuidVersion::V7 => quote! { #name: uuid::Uuid::now_v7() },
-                    UuidVersion::V4 => quote! { #name: uuid::Uuid::new_v4() }
+                match id_prefix {
+                    Some(prefix) => quote! { #name: format!("{}{}", #prefix, #uuid.simple()) },
+                    None => quote! { #name: #uuid }
                 }
             } else {
                 quote! { #name: Default::default() }
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "organizations")]
+pub struct Organization {
+    #[id]
+    #[auto]
+    pub id: i64,
+
+    #[field(response)]
+    pub name: String,
+}
+
+/// Foreign key type must match the parent's id type.
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "members")]
+pub struct Member {
+    #[id]
+    pub id: Uuid,
+
+    #[belongs_to(Organization)]
+    #[field(create, response)]
+    pub org_id: i32,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use chrono::{DateTime, Utc};
+use entity_derive::Entity;
+use uuid::Uuid;
+
+/// Cursor column listed twice should fail.
+#[derive(Entity)]
+#[entity(table = "posts", cursor = "created_at, created_at, id")]
+pub struct Post {
+    #[id]
+    pub id: Uuid,
+
+    #[field(response)]
+    pub created_at: DateTime<Utc>,
+}
+
+fn main() {}
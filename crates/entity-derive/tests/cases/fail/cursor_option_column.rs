@@ -0,0 +1,19 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use chrono::{DateTime, Utc};
+use entity_derive::Entity;
+use uuid::Uuid;
+
+/// Nullable cursor column should fail.
+#[derive(Entity)]
+#[entity(table = "posts", cursor = "published_at, id")]
+pub struct Post {
+    #[id]
+    pub id: Uuid,
+
+    #[field(response)]
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+/// Extra derive for an unknown generated type should fail.
+#[derive(Entity)]
+#[entity(table = "users", derive(Entity: PartialEq))]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub name: String,
+}
+
+fn main() {}
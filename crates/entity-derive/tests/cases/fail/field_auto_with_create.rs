@@ -0,0 +1,20 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use chrono::{DateTime, Utc};
+use entity_derive::Entity;
+use uuid::Uuid;
+
+/// `#[auto]` fields are never taken from requests.
+#[derive(Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[auto]
+    #[field(create, response)]
+    pub created_at: DateTime<Utc>,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+/// `skip` excludes the field from every DTO, so `create` contradicts it.
+#[derive(Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(skip, create)]
+    pub password_hash: String,
+}
+
+fn main() {}
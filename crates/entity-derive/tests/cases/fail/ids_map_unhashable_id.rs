@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use entity_derive::Entity;
+
+/// Id newtype without `Hash` or `Eq`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct TagId(pub i64);
+
+/// `ids_map` with an id type that cannot key a `HashMap` should fail.
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "tags", sql = "trait", ids_map)]
+pub struct Tag {
+    #[id]
+    pub id: TagId,
+
+    #[field(response)]
+    pub name: String,
+}
+
+fn main() {}
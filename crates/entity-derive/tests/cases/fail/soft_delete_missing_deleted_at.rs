@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+/// Soft delete entity without a deleted_at field should fail.
+#[derive(Entity)]
+#[entity(table = "items", soft_delete)]
+pub struct Item {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! `alt_dialect = "sqlite"` keeps the PostgreSQL implementation intact; the
+//! SQLite one is gated behind the calling crate's `sqlite` feature.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "users", dialect = "postgres", alt_dialect = "sqlite", soft_delete)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    #[filter(like)]
+    pub name: String,
+
+    #[field(skip)]
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+fn main() {
+    fn assert_repository<R: UserRepository>() {}
+    assert_repository::<sqlx::PgPool>();
+
+    assert_eq!(
+        User::FIND_BY_ID_SQL,
+        "SELECT id, name, deleted_at FROM public.users WHERE id = $1 AND deleted_at IS NULL"
+    );
+}
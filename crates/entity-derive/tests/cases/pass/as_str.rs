@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[field(response, as_str)]` string coercion in Response.
+
+use std::fmt;
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default, PartialEq, sqlx::Type, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[sqlx(type_name = "text")]
+pub enum Role {
+    #[default]
+    Member,
+    Admin
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Member => f.write_str("member"),
+            Self::Admin => f.write_str("admin")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response, as_str)]
+    pub role: Role,
+
+    #[field(create, response, as_str)]
+    pub previous_role: Option<Role>,
+}
+
+fn main() {
+    let user = User {
+        id: Uuid::now_v7(),
+        role: Role::Admin,
+        previous_role: Some(Role::Member)
+    };
+
+    let response = UserResponse::from(&user);
+    let role: String = response.role;
+    assert_eq!(role, "admin");
+    assert_eq!(response.previous_role.as_deref(), Some("member"));
+
+    let response = UserResponse::from(User {
+        previous_role: None,
+        ..user
+    });
+    assert_eq!(response.previous_role, None);
+}
@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(audit = "...")]` audited repository generation.
+
+use chrono::{DateTime, Utc};
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "accounts", audit = "audit_logs")]
+pub struct Account {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub owner: String,
+
+    #[field(create, skip_response)]
+    pub secret: String,
+
+    #[field(response)]
+    #[auto]
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "notes", audit = "audit_logs", soft_delete)]
+pub struct Note {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub body: String,
+
+    #[field(response)]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[allow(dead_code)]
+async fn use_audited(pool: sqlx::PgPool) -> Result<(), sqlx::Error> {
+    let accounts = AccountAuditRepository::new(pool.clone());
+    let account = accounts
+        .create(CreateAccountRequest {
+            owner: "alice".to_string(),
+            secret: "s3cret".to_string(),
+        })
+        .await?;
+    accounts
+        .update(account.id, UpdateAccountRequest { owner: Some("bob".to_string()) })
+        .await?;
+    accounts.delete(account.id).await?;
+
+    let notes = NoteAuditRepository::new(pool);
+    let _ = notes.pool();
+    let note = notes.create(CreateNoteRequest { body: "hi".to_string() }).await?;
+    notes.delete(note.id).await?;
+    Ok(())
+}
+
+fn main() {}
@@ -23,7 +23,17 @@ pub struct User {
     pub created_at: DateTime<Utc>,
 }
 
-// Child entity with belongs_to relation
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "teams")]
+pub struct Team {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub name: String,
+}
+
+// Child entity with belongs_to relations
 #[derive(Debug, Clone, Entity)]
 #[entity(table = "posts")]
 pub struct Post {
@@ -33,6 +43,10 @@ pub struct Post {
     #[belongs_to(User)]
     pub user_id: Uuid,
 
+    // Optional foreign key: load_team takes Option<Uuid>
+    #[belongs_to(Team)]
+    pub team_id: Option<Uuid>,
+
     #[field(create, update, response)]
     pub title: String,
 
@@ -41,6 +55,26 @@ pub struct Post {
     pub created_at: DateTime<Utc>,
 }
 
+// Relation loaders share one connection across parent and child queries
+#[allow(dead_code)]
+async fn load_on_one_connection(pool: sqlx::PgPool, post: Post) -> Result<(), sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    let author = Post::load_user(&mut *conn, post.user_id).await?;
+    if let Some(author) = author {
+        let _posts: Vec<Post> = User::load_posts(&mut *conn, author.id).await?;
+    }
+
+    let _team: Option<Team> = Post::load_team(&mut *conn, post.team_id).await?;
+    assert!(Post::load_team(&pool, None).await?.is_none());
+
+    let mut tx = pool.begin().await?;
+    let _ = User::load_posts(&mut *tx, post.user_id).await?;
+    tx.commit().await?;
+
+    let _ = User::load_posts(&pool, post.user_id).await?;
+    Ok(())
+}
+
 fn main() {
     // Verify generated types exist
     let _: fn(CreatePostRequest) = |_| {};
@@ -0,0 +1,36 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for the multi-row `bulk_upsert` repository method.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", upsert = "email")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub email: String,
+
+    #[field(create, update, response)]
+    pub name: String,
+}
+
+async fn _sync(pool: &sqlx::PgPool) -> Result<Vec<User>, sqlx::Error> {
+    let dtos = vec![
+        CreateUserRequest {
+            email: "a@example.com".to_string(),
+            name: "Alice".to_string()
+        },
+        CreateUserRequest {
+            email: "b@example.com".to_string(),
+            name: "Bob".to_string()
+        },
+    ];
+    pool.bulk_upsert(dtos).await
+}
+
+fn main() {}
@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(cache)]` cache-aside repository methods.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use entity_derive::{Entity, Page, cache::EntityCache};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "tags", sql = "trait", cache)]
+pub struct Tag {
+    #[id]
+    pub id: Uuid,
+
+    #[field(update, response)]
+    pub name: String,
+}
+
+#[derive(Default)]
+struct MapCache(Mutex<HashMap<String, Tag>>);
+
+#[async_trait::async_trait]
+impl EntityCache<Tag> for MapCache {
+    async fn get(&self, key: &str) -> Option<Tag> {
+        self.0.lock().unwrap().get(key).cloned()
+    }
+
+    async fn set(&self, key: &str, value: &Tag) {
+        self.0.lock().unwrap().insert(key.to_string(), value.clone());
+    }
+
+    async fn del(&self, key: &str) {
+        self.0.lock().unwrap().remove(key);
+    }
+}
+
+struct MemoryRepo {
+    tags: Mutex<Vec<Tag>>,
+    reads: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl TagRepository for MemoryRepo {
+    type Error = std::io::Error;
+    type Pool = ();
+
+    fn pool(&self) -> &Self::Pool {
+        &()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Tag>, Self::Error> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.tags.lock().unwrap().iter().find(|t| t.id == id).cloned())
+    }
+
+    async fn update(&self, id: Uuid, dto: UpdateTagRequest) -> Result<Tag, Self::Error> {
+        let mut tags = self.tags.lock().unwrap();
+        let tag = tags.iter_mut().find(|t| t.id == id).unwrap();
+        if let Some(name) = dto.name {
+            tag.name = name;
+        }
+        Ok(tag.clone())
+    }
+
+    async fn update_many(&self, ids: Vec<Uuid>, dto: UpdateTagRequest) -> Result<u64, Self::Error> {
+        let mut tags = self.tags.lock().unwrap();
+        let mut updated = 0;
+        for tag in tags.iter_mut().filter(|t| ids.contains(&t.id)) {
+            if let Some(name) = &dto.name {
+                tag.name = name.clone();
+            }
+            updated += 1;
+        }
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, Self::Error> {
+        let mut tags = self.tags.lock().unwrap();
+        let len = tags.len();
+        tags.retain(|t| t.id != id);
+        Ok(tags.len() != len)
+    }
+
+    async fn list(&self, _limit: i64, _offset: i64) -> Result<Vec<Tag>, Self::Error> {
+        Ok(self.tags.lock().unwrap().clone())
+    }
+
+    async fn list_page(&self, _cursor: Option<Uuid>, limit: i64) -> Result<Page<Tag, Uuid>, Self::Error> {
+        let items = self.tags.lock().unwrap().clone();
+        Ok(Page::from_overfetch(items, limit, |t| t.id))
+    }
+
+    async fn ping(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let id = Uuid::new_v4();
+    let repo = MemoryRepo {
+        tags: Mutex::new(vec![Tag {
+            id,
+            name: "rust".to_string(),
+        }]),
+        reads: AtomicUsize::new(0),
+    };
+    let cache = MapCache::default();
+
+    repo.find_by_id_cached(&cache, id).await.unwrap().unwrap();
+    repo.find_by_id_cached(&cache, id).await.unwrap().unwrap();
+    assert_eq!(repo.reads.load(Ordering::SeqCst), 1);
+
+    let dto = UpdateTagRequest {
+        name: Some("go".to_string()),
+    };
+    repo.update_cached(&cache, id, dto).await.unwrap();
+    let tag = repo.find_by_id_cached(&cache, id).await.unwrap().unwrap();
+    assert_eq!(tag.name, "go");
+    assert_eq!(repo.reads.load(Ordering::SeqCst), 2);
+
+    assert!(repo.delete_cached(&cache, id).await.unwrap());
+    assert!(repo.find_by_id_cached(&cache, id).await.unwrap().is_none());
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(soft_delete, cascade_soft_delete)]`.
+
+use chrono::{DateTime, Utc};
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", soft_delete, cascade_soft_delete)]
+#[has_many(Post)]
+#[has_many(Tag)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub name: String,
+
+    #[field(skip)]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "posts", soft_delete)]
+pub struct Post {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub user_id: Uuid,
+
+    #[field(skip)]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+// Not soft-deletable: left untouched by the cascade.
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "tags")]
+pub struct Tag {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub user_id: Uuid,
+}
+
+async fn _delete(pool: &sqlx::PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+    UserRepository::delete(pool, id).await
+}
+
+fn main() {
+    assert!(PostRow::SOFT_DELETE);
+    assert!(!TagRow::SOFT_DELETE);
+}
@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(command_dedup)]` keying commands by kind and id.
+
+use std::{
+    collections::HashSet,
+    hash::{BuildHasher, RandomState}
+};
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity, Debug, Clone)]
+#[entity(table = "users", commands, command_dedup)]
+#[command(Register)]
+#[command(UpdateEmail: email)]
+#[command(Deactivate, requires_id)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub email: String,
+
+    #[field(create, response)]
+    pub name: String,
+}
+
+fn update_email(id: Uuid, email: &str) -> UserCommand {
+    UserCommand::UpdateEmail(UpdateEmailUser {
+        id,
+        email: email.to_string()
+    })
+}
+
+fn main() {
+    let id = Uuid::now_v7();
+    let other = Uuid::now_v7();
+
+    assert_eq!(
+        update_email(id, "a@example.com").dedup_key(),
+        (UserCommandKind::UpdateEmail, Some(id))
+    );
+    let register = UserCommand::Register(RegisterUser {
+        email: "a@example.com".to_string(),
+        name:  "Alice".to_string()
+    });
+    assert_eq!(register.dedup_key(), (UserCommandKind::Register, None));
+
+    let hasher = RandomState::new();
+    assert_eq!(
+        hasher.hash_one(update_email(id, "a@example.com")),
+        hasher.hash_one(update_email(id, "b@example.com"))
+    );
+
+    let mut seen = HashSet::new();
+    assert!(seen.insert(update_email(id, "a@example.com").dedup_key()));
+    assert!(!seen.insert(update_email(id, "b@example.com").dedup_key()));
+    assert!(seen.insert(update_email(other, "a@example.com").dedup_key()));
+    assert!(seen.insert(UserCommand::Deactivate(DeactivateUser { id }).dedup_key()));
+}
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for the generated `{Entity}CommandKind` discriminant enum.
+
+use entity_derive::{Entity, EntityCommand, UnknownCommand};
+use uuid::Uuid;
+
+#[derive(Entity, Debug, Clone)]
+#[entity(table = "users", commands)]
+#[command(Register)]
+#[command(UpdateEmail: email)]
+#[command(Deactivate, requires_id)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub email: String,
+
+    #[field(create, response)]
+    pub name: String,
+}
+
+fn main() {
+    let kind: UserCommandKind = "UpdateEmail".parse().unwrap();
+    assert_eq!(kind, UserCommandKind::UpdateEmail);
+    assert_eq!(kind.to_string(), "UpdateEmail");
+    assert_eq!(
+        "Explode".parse::<UserCommandKind>(),
+        Err(UnknownCommand("Explode".to_string()))
+    );
+    assert_eq!(UserCommandKind::ALL.len(), 3);
+
+    let cmd = UserCommand::Deactivate(DeactivateUser { id: Uuid::nil() });
+    assert_eq!(cmd.command_kind(), UserCommandKind::Deactivate);
+    assert_eq!(cmd.command_kind().as_str(), cmd.name());
+
+    for kind in UserCommandKind::ALL {
+        assert_eq!(kind.as_str().parse::<UserCommandKind>().unwrap(), *kind);
+        assert_eq!(UserCommandKind::try_from(kind.path_segment()), Ok(*kind));
+    }
+
+    assert_eq!(UserCommandKind::UpdateEmail.path_segment(), "update-email");
+    assert_eq!(
+        UserCommandKind::try_from("update-email"),
+        Ok(UserCommandKind::UpdateEmail)
+    );
+    assert_eq!(
+        UserCommandKind::try_from("update_email"),
+        Ok(UserCommandKind::UpdateEmail)
+    );
+    assert_eq!(UserCommandKind::try_from("Register"), Ok(UserCommandKind::Register));
+    assert_eq!(
+        UserCommandKind::try_from("update email"),
+        Err(UnknownCommand("update email".to_string()))
+    );
+}
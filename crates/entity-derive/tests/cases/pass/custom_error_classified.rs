@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test a custom error type whose `From<sqlx::Error>` is generated by
+//! `impl_from_sqlx_error!`.
+
+use entity_derive::{
+    Entity,
+    error::{ErrorClass, FromSqlxError}
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum AppError {
+    NotFound,
+    Conflict,
+    Database(sqlx::Error)
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl FromSqlxError for AppError {
+    fn from_sqlx(class: ErrorClass, error: sqlx::Error) -> Self {
+        match class {
+            ErrorClass::NotFound => AppError::NotFound,
+            ErrorClass::Conflict => AppError::Conflict,
+            _ => AppError::Database(error)
+        }
+    }
+}
+
+entity_derive::impl_from_sqlx_error!(AppError);
+
+#[derive(Entity)]
+#[entity(table = "products", error = "AppError")]
+pub struct Product {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String
+}
+
+fn main() {
+    assert!(matches!(
+        AppError::from(sqlx::Error::RowNotFound),
+        AppError::NotFound
+    ));
+}
@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for the generated SQL constants and `debug_sql()`.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", schema = "core")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+}
+
+fn main() {
+    assert_eq!(
+        User::FIND_BY_ID_SQL,
+        "SELECT id, name FROM core.users WHERE id = $1"
+    );
+    assert_eq!(
+        User::LIST_SQL,
+        "SELECT id, name FROM core.users ORDER BY id DESC LIMIT $1 OFFSET $2"
+    );
+    assert_eq!(
+        User::CREATE_SQL,
+        "INSERT INTO core.users (id, name) VALUES ($1, $2) RETURNING id, name"
+    );
+    assert_eq!(
+        User::UPDATE_SQL,
+        "UPDATE core.users SET name = $1 WHERE id = $2 RETURNING id, name"
+    );
+    assert_eq!(User::DELETE_SQL, "DELETE FROM core.users WHERE id = $1");
+
+    let names: Vec<_> = User::debug_sql().into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, ["find_by_id", "list", "create", "update", "delete"]);
+}
@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(diffable)]` field change lists.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", sql = "trait", diffable)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+
+    #[field(create, update, response)]
+    pub bio: Option<String>,
+
+    #[field(create, response, mask = "last4")]
+    pub card: String,
+}
+
+fn main() {
+    let before = User {
+        id: Uuid::now_v7(),
+        name: "Alice".to_string(),
+        bio: None,
+        card: "4111111111111111".to_string(),
+    };
+    assert!(before.diff(&before.clone()).is_empty());
+
+    let after = User {
+        name: "Alicia".to_string(),
+        bio: Some("hi".to_string()),
+        card: "4111111111112222".to_string(),
+        ..before.clone()
+    };
+    assert_eq!(
+        before.diff(&after),
+        vec![
+            ("name", "\"Alice\"".to_string(), "\"Alicia\"".to_string()),
+            ("bio", "None".to_string(), "Some(\"hi\")".to_string()),
+            ("card", "\"****1111\"".to_string(), "\"****2222\"".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(double_option_updates)]` and
+//! `update_type = "Option<Option<T>>"`.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", double_option_updates)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+
+    #[field(create, update, response)]
+    pub bio: Option<String>,
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "posts")]
+pub struct Post {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response, update_type = "Option<Option<String>>")]
+    pub subtitle: Option<String>,
+}
+
+fn main() {
+    let absent: UpdateUserRequest = serde_json::from_str("{}").unwrap();
+    assert_eq!(absent.bio, None);
+
+    let cleared: UpdateUserRequest = serde_json::from_str(r#"{"bio":null}"#).unwrap();
+    assert_eq!(cleared.bio, Some(None));
+
+    let set: UpdateUserRequest = serde_json::from_str(r#"{"bio":"hi"}"#).unwrap();
+    assert_eq!(set.bio, Some(Some("hi".to_string())));
+    assert_eq!(set.name, None);
+
+    assert_eq!(serde_json::to_string(&absent).unwrap(), r#"{"name":null}"#);
+    assert_eq!(
+        serde_json::to_string(&cleared).unwrap(),
+        r#"{"name":null,"bio":null}"#
+    );
+
+    let post = UpdatePostRequest {
+        subtitle: Some(None)
+    };
+    let subtitle: Option<Option<String>> = post.subtitle;
+    assert_eq!(subtitle, Some(None));
+}
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for per-DTO type overrides with `create_type` / `update_type`.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+fn parse_cents(value: String) -> i64 {
+    value.replace('.', "").parse().unwrap_or_default()
+}
+
+#[derive(Entity)]
+#[entity(table = "products")]
+pub struct Product {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response, create_type = "u32", update_type = "u32")]
+    pub stock: i64,
+
+    #[field(create, response, create_type = "String", create_with = "parse_cents")]
+    pub price_cents: i64,
+}
+
+fn main() {
+    let create = CreateProductRequest {
+        stock: 10_u32,
+        price_cents: "12.50".to_string(),
+    };
+    let product = Product::from(create);
+    assert_eq!(product.stock, 10_i64);
+    assert_eq!(product.price_cents, 1250);
+
+    let update = UpdateProductRequest { stock: Some(5_u32) };
+    assert_eq!(update.stock, Some(5));
+}
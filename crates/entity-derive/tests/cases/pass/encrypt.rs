@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[column(encrypt)]` field-level encryption.
+
+use entity_derive::{
+    Entity,
+    crypto::{CryptoError, Encryptor},
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "patients")]
+pub struct Patient {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+
+    #[field(create, update, response)]
+    #[column(encrypt)]
+    pub ssn: String,
+
+    #[field(create, response)]
+    #[column(encrypt)]
+    pub notes: Option<String>,
+}
+
+struct Reverse;
+
+impl Encryptor for Reverse {
+    fn encrypt(&self, plaintext: &str) -> Result<String, CryptoError> {
+        Ok(plaintext.chars().rev().collect())
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> Result<String, CryptoError> {
+        Ok(ciphertext.chars().rev().collect())
+    }
+}
+
+#[allow(dead_code)]
+async fn use_encrypted(pool: sqlx::PgPool) -> Result<(), sqlx::Error> {
+    let patients = PatientEncryptedRepository::new(pool, Reverse);
+    let patient = patients
+        .create(CreatePatientRequest {
+            name: "alice".to_string(),
+            ssn: "123-45-6789".to_string(),
+            notes: None,
+        })
+        .await?;
+    patients
+        .update(patient.id, UpdatePatientRequest { name: None, ssn: Some("987".to_string()) })
+        .await?;
+    let _ = patients.find_by_id(patient.id).await?;
+    let _ = patients.list(10, 0).await?;
+    Ok(())
+}
+
+fn main() {
+    let insertable = InsertablePatient {
+        id: Uuid::nil(),
+        name: "alice".to_string(),
+        ssn: "123".to_string(),
+        notes: Some("abc".to_string()),
+    }
+    .encrypt(&Reverse)
+    .unwrap();
+    assert_eq!(insertable.name, "alice");
+    assert_eq!(insertable.ssn, "321");
+    assert_eq!(insertable.notes.as_deref(), Some("cba"));
+
+    let row = PatientRow {
+        id: Uuid::nil(),
+        name: "alice".to_string(),
+        ssn: "321".to_string(),
+        notes: None,
+    }
+    .decrypt(&Reverse)
+    .unwrap();
+    assert_eq!(row.ssn, "123");
+    assert!(row.notes.is_none());
+}
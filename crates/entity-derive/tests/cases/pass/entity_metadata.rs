@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for the generated `entity_core::Entity` implementation.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", schema = "core")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub name: String,
+
+    #[field(create, response)]
+    pub email: String,
+}
+
+fn describe<E: entity_derive::Entity>() -> String {
+    format!("{}.{} ({})", E::SCHEMA, E::TABLE, E::COLUMNS.join(", "))
+}
+
+fn main() {
+    assert_eq!(describe::<User>(), "core.users (id, name, email)");
+    let id: <User as entity_derive::Entity>::Id = Uuid::nil();
+    assert!(id.is_nil());
+}
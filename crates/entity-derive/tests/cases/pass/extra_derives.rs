@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(derive(...))]` extra derives.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(
+    table = "users",
+    derive(Response: PartialEq, Eq, Hash),
+    derive(Create: PartialEq),
+    derive(Update: PartialEq),
+    derive(Row: PartialEq),
+    derive(Insertable: PartialEq),
+    derive(Query: PartialEq)
+)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    #[filter]
+    pub name: String,
+}
+
+fn assert_hash<T: std::hash::Hash>() {}
+
+fn main() {
+    let a = UserResponse { id: Uuid::nil(), name: "a".to_string() };
+    let b = a.clone();
+    assert_eq!(a, b);
+    assert_hash::<UserResponse>();
+
+    let create = CreateUserRequest { name: "a".to_string() };
+    assert_eq!(create.clone(), create);
+
+    assert_eq!(UpdateUserRequest::default(), UpdateUserRequest::default());
+    assert_eq!(UserQuery::default(), UserQuery::default());
+
+    let row = UserRow { id: Uuid::nil(), name: "a".to_string() };
+    assert_eq!(row.clone(), row);
+
+    let insertable = InsertableUser { id: Uuid::nil(), name: "a".to_string() };
+    assert_eq!(insertable.clone(), insertable);
+}
@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test `min_len`/`max_len` and `min`/`max` field bounds.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response, min_len = 1, max_len = 255)]
+    pub name: String,
+
+    #[field(create, update, response, min = -10, max = 150)]
+    pub age: i32,
+
+    #[field(update, response, max_len = 500)]
+    pub bio: Option<String>
+}
+
+fn main() {
+    let _ = CreateUserRequest {
+        name: "Alice".into(),
+        age: 30
+    };
+    let _ = UpdateUserRequest {
+        bio: Some("hello".into()),
+        ..Default::default()
+    };
+}
@@ -0,0 +1,87 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `find_by_id_or_error` with a hand-written `NotFoundError`.
+
+use entity_derive::{Entity, Page, error::NotFoundError};
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq)]
+pub enum AppError {
+    NotFound
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl NotFoundError for AppError {
+    fn not_found() -> Self {
+        AppError::NotFound
+    }
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "tags", sql = "trait")]
+pub struct Tag {
+    #[id]
+    pub id: Uuid,
+
+    #[field(response)]
+    pub name: String,
+}
+
+struct MemoryRepo {
+    tags: Vec<Tag>,
+}
+
+#[async_trait::async_trait]
+impl TagRepository for MemoryRepo {
+    type Error = AppError;
+    type Pool = ();
+
+    fn pool(&self) -> &Self::Pool {
+        &()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Tag>, Self::Error> {
+        Ok(self.tags.iter().find(|t| t.id == id).cloned())
+    }
+
+    async fn delete(&self, _id: Uuid) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
+    async fn list(&self, _limit: i64, _offset: i64) -> Result<Vec<Tag>, Self::Error> {
+        Ok(self.tags.clone())
+    }
+
+    async fn list_page(&self, _cursor: Option<Uuid>, limit: i64) -> Result<Page<Tag, Uuid>, Self::Error> {
+        Ok(Page::from_overfetch(self.tags.clone(), limit, |t| t.id))
+    }
+
+    async fn ping(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let tag = Tag {
+        id: Uuid::new_v4(),
+        name: "rust".to_string(),
+    };
+    let repo = MemoryRepo {
+        tags: vec![tag.clone()],
+    };
+
+    assert_eq!(repo.find_by_id_or_error(tag.id).await.unwrap().name, "rust");
+    assert_eq!(
+        repo.find_by_id_or_error(Uuid::new_v4()).await.unwrap_err(),
+        AppError::NotFound
+    );
+}
@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use entity_derive::Entity;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+}
+
+#[derive(Entity)]
+#[entity(table = "users", sql = "trait")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+
+    #[field(create, response, flatten)]
+    pub address: Address,
+}
+
+fn main() {
+    fn assert_from_row<R: for<'r> sqlx::FromRow<'r, sqlx::postgres::PgRow>>() {}
+    assert_from_row::<UserRow>();
+
+    let row = UserRow {
+        id: Uuid::now_v7(),
+        name: "Alice".into(),
+        address: Address {
+            street: "Main St".into(),
+            city: "Springfield".into(),
+        },
+    };
+    let user = User::from(row);
+    assert_eq!(user.address.city, "Springfield");
+}
@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `{Entity}::from_rows`.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub name: String,
+}
+
+fn main() {
+    let rows = vec![
+        UserRow {
+            id: Uuid::nil(),
+            name: "a".to_string()
+        },
+        UserRow {
+            id: Uuid::nil(),
+            name: "b".to_string()
+        },
+    ];
+    let users: Vec<User> = User::from_rows(rows);
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[1].name, "b");
+}
@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[column(tsvector)]` and the generated `search` method.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "articles", migrations)]
+pub struct Article {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub title: String,
+
+    #[field(create, update, response)]
+    pub body: Option<String>,
+
+    #[field(response)]
+    #[column(tsvector = "english", sources = [title, body])]
+    pub search: String,
+}
+
+async fn _search(pool: &sqlx::PgPool) -> Result<Vec<Article>, sqlx::Error> {
+    pool.search("running shoes", 20, 0).await
+}
+
+fn main() {
+    let up = Article::MIGRATION_UP;
+    assert!(up.contains("search TSVECTOR GENERATED ALWAYS AS ("));
+    assert!(up.contains("USING gin (search)"));
+
+    assert!(Article::CREATE_SQL.contains("(id, title, body) VALUES ($1, $2, $3)"));
+    assert!(Article::SEARCH_SQL.contains("search @@ websearch_to_tsquery('english', $1)"));
+    assert!(Article::SEARCH_SQL.contains("ORDER BY ts_rank("));
+}
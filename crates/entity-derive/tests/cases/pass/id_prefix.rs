@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for prefixed string ids with `#[entity(id_prefix = "...")]`.
+
+use entity_derive::Entity;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", uuid = "v7", id_prefix = "usr_", migrations)]
+pub struct User {
+    #[id]
+    pub id: String,
+
+    #[field(create, response)]
+    pub name: String,
+}
+
+fn main() {
+    let first = User::from(CreateUserRequest {
+        name: "Ann".into()
+    });
+    let second = User::from(CreateUserRequest {
+        name: "Bob".into()
+    });
+
+    let suffix = first.id.strip_prefix("usr_").unwrap();
+    assert_eq!(suffix.len(), 32);
+    assert!(suffix.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(uuid::Uuid::parse_str(suffix).unwrap().get_version_num(), 7);
+    assert_ne!(first.id, second.id);
+
+    assert!(User::MIGRATION_UP.contains("id TEXT PRIMARY KEY"));
+}
@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test an internal-only `#[id]` hidden from the Response by
+//! `skip_response`, with a public slug in its place.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "articles")]
+pub struct Article {
+    #[id]
+    #[field(skip_response)]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub slug: String,
+
+    #[field(create, update, response)]
+    pub title: String
+}
+
+fn main() {
+    let article = Article::from(CreateArticleRequest {
+        slug: "hello-world".to_string(),
+        title: "Hello".to_string()
+    });
+
+    // The Response is keyed by the slug only; the id stays internal.
+    let ArticleResponse { slug, title } = ArticleResponse::from(&article);
+    assert_eq!(slug, "hello-world");
+    assert_eq!(title, "Hello");
+
+    // The repository still keys on the real id.
+    fn _keyed_by_id<R: ArticleRepository>(repo: &R, id: Uuid) -> impl std::future::Future + '_ {
+        repo.find_by_id(id)
+    }
+}
@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(immutable)]` skipping all update generation.
+
+use chrono::{DateTime, Utc};
+use entity_derive::{Entity, Page};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "events", immutable)]
+pub struct Event {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub payload: String,
+
+    #[field(response)]
+    #[auto]
+    pub created_at: DateTime<Utc>,
+}
+
+// Implementing the trait without `update` proves the method is not generated.
+struct NoopRepo;
+
+#[async_trait::async_trait]
+impl EventRepository for NoopRepo {
+    type Error = std::io::Error;
+    type Pool = ();
+
+    fn pool(&self) -> &Self::Pool {
+        &()
+    }
+
+    async fn create(&self, dto: CreateEventRequest) -> Result<Event, Self::Error> {
+        Ok(Event {
+            id: Uuid::nil(),
+            payload: dto.payload,
+            created_at: Utc::now(),
+        })
+    }
+
+    async fn find_by_id(&self, _id: Uuid) -> Result<Option<Event>, Self::Error> {
+        Ok(None)
+    }
+
+    async fn delete(&self, _id: Uuid) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
+    async fn list(&self, _limit: i64, _offset: i64) -> Result<Vec<Event>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    async fn list_page(&self, _cursor: Option<Uuid>, limit: i64) -> Result<Page<Event, Uuid>, Self::Error> {
+        Ok(Page::from_overfetch(Vec::new(), limit, |e: &Event| e.id))
+    }
+
+    async fn ping(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+fn main() {
+    let _ = NoopRepo.pool();
+    let _ = CreateEventRequest {
+        payload: "created".to_string(),
+    };
+}
@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `Insertable{Entity}::into_insert_query()` and `COLUMNS`.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", schema = "core")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+
+    #[field(create, response)]
+    #[column(name = "email_address")]
+    pub email: Option<String>,
+
+    #[field(response, returning = "lower(name)")]
+    pub name_lower: String,
+}
+
+// The query can run on any executor, such as a caller-owned transaction.
+async fn _insert(tx: &mut sqlx::PgConnection, user: &User) -> Result<(), sqlx::Error> {
+    InsertableUser::from(user)
+        .into_insert_query()
+        .execute(tx)
+        .await?;
+    Ok(())
+}
+
+fn main() {
+    assert_eq!(
+        InsertableUser::INSERT_SQL,
+        "INSERT INTO core.users (id, name, email_address) VALUES ($1, $2, $3)"
+    );
+    assert_eq!(InsertableUser::COLUMNS, &["id", "name", "email_address"]);
+}
@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test `serde_json::Value` and `Option<serde_json::Value>` columns.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "audit_logs", migrations)]
+pub struct AuditLog {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub new_data: serde_json::Value,
+
+    #[field(create, update, response)]
+    pub old_data: Option<serde_json::Value>
+}
+
+fn main() {
+    let row = AuditLogRow {
+        id: Uuid::nil(),
+        new_data: serde_json::json!({ "name": "Alice" }),
+        old_data: None
+    };
+    let log = AuditLog::from(row);
+    assert!(log.old_data.is_none());
+    assert!(AuditLog::MIGRATION_UP.contains("old_data JSONB"));
+}
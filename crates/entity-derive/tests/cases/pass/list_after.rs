@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(cursor = "...")]` keyset pagination.
+
+use chrono::{DateTime, Utc};
+use entity_derive::{Entity, Page, cursor::Cursor};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "posts", cursor = "created_at, id")]
+pub struct Post {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub title: String,
+
+    #[field(response)]
+    pub created_at: DateTime<Utc>,
+}
+
+async fn next_token<R: PostRepository>(repo: &R, token: Option<&str>) -> Option<String> {
+    let cursor = token.map(|t| PostCursor::decode(t).unwrap());
+    let page: Page<Post, PostCursor> = repo.list_after(cursor, 20).await.ok()?;
+    page.next_cursor.map(|c| c.encode())
+}
+
+fn main() {
+    let post = Post {
+        id:         Uuid::nil(),
+        title:      "Hello".to_string(),
+        created_at: DateTime::<Utc>::UNIX_EPOCH
+    };
+    let cursor = PostCursor::from(&post);
+    assert_eq!(cursor.id, post.id);
+    assert_eq!(PostCursor::decode(&cursor.encode()).unwrap(), cursor);
+
+    let _ = next_token::<sqlx::PgPool>;
+}
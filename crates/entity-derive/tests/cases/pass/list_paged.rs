@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `list_paged` forwarding `Pagination` to `list`, `list_page`, and
+//! chunked fetches with `fetch_chunk` and `for_each_chunk`.
+
+use entity_derive::{Entity, Page, Pagination};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "tags", sql = "trait")]
+pub struct Tag {
+    #[id]
+    pub id: Uuid,
+
+    #[field(response)]
+    pub name: String,
+}
+
+struct MemoryRepo {
+    tags: Vec<Tag>,
+}
+
+#[async_trait::async_trait]
+impl TagRepository for MemoryRepo {
+    type Error = std::io::Error;
+    type Pool = ();
+
+    fn pool(&self) -> &Self::Pool {
+        &()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Tag>, Self::Error> {
+        Ok(self.tags.iter().find(|t| t.id == id).cloned())
+    }
+
+    async fn delete(&self, _id: Uuid) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
+    async fn list(&self, limit: i64, offset: i64) -> Result<Vec<Tag>, Self::Error> {
+        Ok(self
+            .tags
+            .iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_page(&self, cursor: Option<Uuid>, limit: i64) -> Result<Page<Tag, Uuid>, Self::Error> {
+        let start = cursor
+            .and_then(|c| self.tags.iter().position(|t| t.id == c))
+            .map_or(0, |i| i + 1);
+        let items = self.tags.iter().skip(start).cloned().collect();
+        Ok(Page::from_overfetch(items, limit, |t| t.id))
+    }
+
+    async fn ping(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let repo = MemoryRepo {
+        tags: (0..5)
+            .map(|i| Tag {
+                id: Uuid::new_v4(),
+                name: format!("tag-{i}"),
+            })
+            .collect(),
+    };
+
+    let page = repo.list_paged(Pagination::page(1, 2)).await.unwrap();
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].name, "tag-2");
+
+    let first = repo.list_page(None, 3).await.unwrap();
+    assert!(first.has_next);
+    let second = repo.list_page(first.next_cursor, 3).await.unwrap();
+    assert_eq!(second.items.len(), 2);
+    assert!(!second.has_next);
+
+    let chunk = repo.fetch_chunk(4, 3).await.unwrap();
+    assert_eq!(chunk.len(), 1);
+    assert_eq!(chunk[0].name, "tag-4");
+
+    let mut sizes = Vec::new();
+    let total = repo
+        .for_each_chunk(2, |chunk| {
+            sizes.push(chunk.len());
+            Ok(())
+        })
+        .await
+        .unwrap();
+    assert_eq!(total, 5);
+    assert_eq!(sizes, [2, 2, 1]);
+    assert_eq!(repo.for_each_chunk(0, |_| Ok(())).await.unwrap(), 0);
+
+    repo.ping().await.unwrap();
+}
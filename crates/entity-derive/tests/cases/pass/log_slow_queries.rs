@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(log_slow_queries = N)]` repository timing.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity, Debug, Clone)]
+#[entity(table = "users", log_slow_queries = 100, soft_delete)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    #[filter(like)]
+    pub name: String,
+
+    #[field(skip)]
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+async fn find(pool: &sqlx::PgPool, id: Uuid) -> Result<Option<User>, sqlx::Error> {
+    pool.find_by_id(id).await
+}
+
+fn main() {
+    let _ = find;
+}
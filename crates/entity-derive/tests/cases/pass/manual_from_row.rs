@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(manual_from_row)]` and `#[column(decode_with)]`.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+/// Reads a `NUMERIC`-like column stored as text cents.
+fn decode_cents(row: &sqlx::postgres::PgRow, column: &str) -> Result<i64, sqlx::Error> {
+    let raw: String = sqlx::Row::try_get(row, column)?;
+    raw.parse().map_err(|e| sqlx::Error::ColumnDecode {
+        index:  column.to_string(),
+        source: Box::new(e)
+    })
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "accounts")]
+pub struct Account {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub owner: String,
+
+    #[field(create, update, response)]
+    #[column(decode_with = "decode_cents")]
+    pub balance: i64,
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "tags", manual_from_row, row_prefix = "t_")]
+pub struct Tag {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub label: String,
+}
+
+fn assert_from_row<T: for<'r> sqlx::FromRow<'r, sqlx::postgres::PgRow>>() {}
+
+fn main() {
+    assert_from_row::<AccountRow>();
+    assert_from_row::<TagRow>();
+
+    let row = AccountRow {
+        id:      Uuid::nil(),
+        owner:   "alice".to_string(),
+        balance: 42
+    };
+    let account = Account::from(row);
+    assert_eq!(account.balance, 42);
+}
@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[field(response, mask = "...")]` masking in Response mappers.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+mod masks {
+    pub fn initials(value: &str) -> String {
+        value
+            .split_whitespace()
+            .filter_map(|w| w.chars().next())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "customers")]
+pub struct Customer {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response, mask = "last4")]
+    pub card_number: String,
+
+    #[field(create, response, mask = "email")]
+    pub email: String,
+
+    #[field(create, response, mask = "redact")]
+    pub tax_id: Option<String>,
+
+    #[field(create, response, mask = "masks::initials")]
+    pub full_name: String,
+
+    #[field(create, response)]
+    pub city: String,
+}
+
+fn main() {
+    let customer = Customer {
+        id: Uuid::nil(),
+        card_number: "4111111111111234".to_string(),
+        email: "jane.doe@example.com".to_string(),
+        tax_id: None,
+        full_name: "Jane Doe".to_string(),
+        city: "Oslo".to_string(),
+    };
+
+    let response = CustomerResponse::from(&customer);
+    assert_eq!(response.card_number, "****1234");
+    assert_eq!(response.email, "j***@example.com");
+    assert_eq!(response.tax_id, None);
+    assert_eq!(response.full_name, "JD");
+    assert_eq!(response.city, "Oslo");
+
+    let row = CustomerRow {
+        id: Uuid::nil(),
+        card_number: "5500000000009876".to_string(),
+        email: "x@y.z".to_string(),
+        tax_id: Some("123-45".to_string()),
+        full_name: "Ola Nordmann".to_string(),
+        city: "Bergen".to_string(),
+    };
+    let response = CustomerResponse::from(row);
+    assert_eq!(response.card_number, "****9876");
+    assert_eq!(response.tax_id.as_deref(), Some("[REDACTED]"));
+
+    // The entity itself keeps the full values
+    let response = CustomerResponse::from(customer.clone());
+    assert_eq!(response.card_number, "****1234");
+    assert_eq!(customer.card_number, "4111111111111234");
+}
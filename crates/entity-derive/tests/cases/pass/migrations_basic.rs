@@ -34,4 +34,9 @@ fn main() {
     // Verify MIGRATION_DOWN is generated
     let down = User::MIGRATION_DOWN;
     assert!(down.contains("DROP TABLE IF EXISTS core.users CASCADE"));
+
+    // Verify SCHEMA_VERSION is a 64-bit hex hash
+    const VERSION: &str = User::SCHEMA_VERSION;
+    assert_eq!(VERSION.len(), 16);
+    assert!(VERSION.chars().all(|c| c.is_ascii_hexdigit()));
 }
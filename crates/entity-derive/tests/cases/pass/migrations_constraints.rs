@@ -18,6 +18,18 @@ pub struct Product {
     #[column(varchar = 200)]
     pub name: String,
 
+    #[field(create, update, response)]
+    #[column(citext)]
+    pub vendor_email: String,
+
+    #[field(create, update, response)]
+    #[column(collation = "C")]
+    pub code: String,
+
+    #[field(create, update, response)]
+    #[column(storage = "external")]
+    pub notes: String,
+
     #[field(create, update, response)]
     #[column(default = "0")]
     pub quantity: i32,
@@ -44,6 +56,14 @@ fn main() {
     // Check VARCHAR
     assert!(up.contains("name VARCHAR(200) NOT NULL"));
 
+    // Check CITEXT and COLLATE
+    assert!(up.starts_with("CREATE EXTENSION IF NOT EXISTS citext;"));
+    assert!(up.contains("vendor_email CITEXT NOT NULL"));
+    assert!(up.contains("code TEXT COLLATE \"C\" NOT NULL"));
+
+    // Check TOAST storage
+    assert!(up.contains("ALTER TABLE public.products ALTER COLUMN notes SET STORAGE EXTERNAL;"));
+
     // Check DEFAULT
     assert!(up.contains("quantity INTEGER NOT NULL DEFAULT 0"));
 
@@ -0,0 +1,24 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "users", migrations, temporary)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+}
+
+fn main() {
+    let up = User::MIGRATION_UP;
+    assert!(up.contains("CREATE TEMPORARY TABLE pg_temp.users"));
+    assert!(!up.contains("IF NOT EXISTS pg_temp.users"));
+
+    let down = User::MIGRATION_DOWN;
+    assert!(down.contains("DROP TABLE IF EXISTS pg_temp.users CASCADE"));
+}
@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(ord_by_id)]` identity-based ordering.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", sql = "trait", ord_by_id)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+}
+
+fn main() {
+    let first = User {
+        id: Uuid::now_v7(),
+        name: "zed".to_string(),
+    };
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    let second = User {
+        id: Uuid::now_v7(),
+        name: "amy".to_string(),
+    };
+
+    let mut users = vec![second.clone(), first.clone()];
+    users.sort();
+    assert_eq!(users[0].id, first.id);
+    assert_eq!(users[1].id, second.id);
+
+    let renamed = User {
+        name: "other".to_string(),
+        ..first.clone()
+    };
+    assert_eq!(first, renamed);
+    assert!(first < second);
+}
@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[sortable]` fields and `{Entity}OrderBy`.
+
+use chrono::{DateTime, Utc};
+use entity_derive::{Entity, SortDirection};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    #[sortable]
+    pub name: String,
+
+    #[field(response)]
+    #[auto]
+    #[sortable]
+    pub created_at: DateTime<Utc>,
+}
+
+async fn _newest(pool: &sqlx::PgPool) -> Result<Vec<User>, sqlx::Error> {
+    pool.list_ordered(UserOrderBy::CreatedAtDesc, 20, 0).await
+}
+
+fn main() {
+    assert_eq!(UserOrderBy::NameAsc.as_sql(), "name ASC");
+    assert_eq!(UserOrderBy::CreatedAtDesc.as_sql(), "created_at DESC");
+    assert_eq!(UserOrderBy::CreatedAtDesc.column(), "created_at");
+    assert_eq!(UserOrderBy::NameDesc.direction(), SortDirection::Desc);
+}
@@ -45,4 +45,17 @@ fn main() {
         email: String::new(),
         created_at: Utc::now(),
     });
+
+    // Narrow a full row without re-querying
+    let row = UserRow {
+        id: Uuid::nil(),
+        name: "Alice".to_string(),
+        email: "alice@example.com".to_string(),
+        password_hash: String::new(),
+        created_at: Utc::now(),
+    };
+    let public = UserPublic::from(&row);
+    assert_eq!(public.name, "Alice");
+    let admin = UserAdmin::from(row);
+    assert_eq!(admin.email, "alice@example.com");
 }
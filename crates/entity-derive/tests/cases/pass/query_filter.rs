@@ -65,4 +65,13 @@ fn main() {
     assert_eq!(query.price_to, Some(500));
     assert_eq!(query.limit, Some(10));
     assert_eq!(query.offset, Some(0));
+
+    // Unset filters fall back to Default, through the Filter alias too
+    let filter = ProductFilter {
+        category: Some("Books".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(filter.category.as_deref(), Some("Books"));
+    assert!(filter.name.is_none());
+    assert!(filter.limit.is_none());
 }
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `PgRange` columns and `#[filter(overlaps)]`.
+
+use chrono::{DateTime, Utc};
+use entity_derive::Entity;
+use sqlx::postgres::types::PgRange;
+use uuid::Uuid;
+
+fn span(value: (DateTime<Utc>, DateTime<Utc>)) -> PgRange<DateTime<Utc>> {
+    PgRange::from(value.0..value.1)
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "bookings", migrations)]
+pub struct Booking {
+    #[id]
+    pub id: Uuid,
+
+    #[field(
+        create,
+        response,
+        as_str,
+        create_type = "(DateTime<Utc>, DateTime<Utc>)",
+        create_with = "span"
+    )]
+    #[filter(overlaps)]
+    pub during: PgRange<DateTime<Utc>>,
+
+    #[filter(overlaps)]
+    pub seats: Option<PgRange<i64>>,
+}
+
+async fn _overlapping(
+    pool: &sqlx::PgPool,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>
+) -> Result<Vec<Booking>, sqlx::Error> {
+    pool.query(BookingQuery {
+        during_overlaps: Some((start, end)),
+        seats_overlaps: Some((1, 4)),
+        ..Default::default()
+    })
+    .await
+}
+
+fn main() {
+    let up = Booking::MIGRATION_UP;
+    assert!(up.contains("during TSTZRANGE NOT NULL"));
+    assert!(up.contains("seats INT8RANGE"));
+}
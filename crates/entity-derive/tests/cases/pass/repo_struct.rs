@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(repo_struct)]` generating a pool-owning `{Entity}Repo`.
+
+use chrono::{DateTime, Utc};
+use entity_derive::{Entity, Page, cache::EntityCache};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", soft_delete, cache, upsert = "email", repo_struct)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    #[filter(like)]
+    pub email: String,
+
+    #[field(response)]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+struct AppState {
+    users: UserRepo,
+}
+
+async fn _use(state: AppState, cache: &dyn EntityCache<User>, id: Uuid) -> Result<(), sqlx::Error> {
+    let repo = state.users.clone();
+    let _: &sqlx::PgPool = repo.pool();
+    let _: Option<User> = repo.find_by_id(id).await?;
+    let _: User = repo.find_by_id_or_error(id).await?;
+    let _: Option<User> = repo.find_by_id_cached(cache, id).await?;
+    let _: Page<User, Uuid> = repo.list_page(None, 10).await?;
+    let _: Vec<User> = repo.query(UserQuery::default()).await?;
+    let _: bool = repo.restore(id).await?;
+    let _: u64 = repo.for_each_chunk(100, |_| Ok(())).await?;
+    Ok(())
+}
+
+fn repo(pool: sqlx::PgPool) -> impl UserRepository<Error = sqlx::Error, Pool = sqlx::PgPool> + Clone {
+    UserRepo::new(pool)
+}
+
+fn main() {
+    let _ = repo;
+    let _ = _use;
+}
@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub email: String,
+
+    #[field(response, returning = "lower(email)")]
+    pub normalized_email: String,
+}
+
+fn main() {
+    assert_eq!(
+        User::CREATE_SQL,
+        "INSERT INTO public.users (id, email) VALUES ($1, $2) \
+         RETURNING id, email, lower(email) AS normalized_email"
+    );
+
+    let dto = CreateUserRequest {
+        email: "Alice@Example.com".into(),
+    };
+    let user = User::from(dto);
+    assert!(user.normalized_email.is_empty());
+
+    let row = UserRow {
+        id: user.id,
+        email: user.email,
+        normalized_email: "alice@example.com".into(),
+    };
+    let response = UserResponse::from(User::from(row));
+    assert_eq!(response.normalized_email, "alice@example.com");
+}
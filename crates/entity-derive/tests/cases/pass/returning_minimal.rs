@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[entity(returning = "minimal")]` attribute.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "page_views", returning = "minimal")]
+pub struct PageView {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub path: String,
+
+    #[field(create, update, response)]
+    pub referrer: Option<String>,
+}
+
+// `create` yields only the id; `update` still yields the entity.
+async fn _create<R: PageViewRepository>(
+    repo: &R,
+    dto: CreatePageViewRequest,
+) -> Result<Uuid, R::Error> {
+    repo.create(dto).await
+}
+
+async fn _update<R: PageViewRepository>(
+    repo: &R,
+    id: Uuid,
+    dto: UpdatePageViewRequest,
+) -> Result<PageView, R::Error> {
+    repo.update(id, dto).await
+}
+
+fn main() {
+    fn _check_trait<T: PageViewRepository>() {}
+    _check_trait::<sqlx::PgPool>();
+}
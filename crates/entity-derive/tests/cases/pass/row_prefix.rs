@@ -0,0 +1,32 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `row_prefix` on the generated Row struct.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "users", row_prefix = "u_")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+}
+
+fn assert_from_row<T: for<'r> sqlx::FromRow<'r, sqlx::postgres::PgRow>>() {}
+
+fn main() {
+    assert_from_row::<UserRow>();
+
+    let row = UserRow {
+        id: Uuid::nil(),
+        name: "John".to_string(),
+    };
+    let user = User::from(row);
+    assert_eq!(user.name, "John");
+
+    assert_eq!(UserRow::SELECT_COLUMNS, "id AS u_id, name AS u_name");
+}
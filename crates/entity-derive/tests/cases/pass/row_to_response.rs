@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `From<Row>` and `From<&Row>` Response conversions and
+//! `from_rows`.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+
+    #[field(skip)]
+    pub password_hash: String,
+}
+
+fn main() {
+    let rows = vec![UserRow {
+        id: Uuid::nil(),
+        name: "John".to_string(),
+        password_hash: "secret".to_string(),
+    }];
+
+    // Borrowing conversion leaves rows usable
+    let responses: Vec<UserResponse> = rows.iter().map(UserResponse::from).collect();
+    assert_eq!(responses[0].name, "John");
+    assert_eq!(rows[0].password_hash, "secret");
+
+    let owned: Vec<UserResponse> = rows.clone().into_iter().map(UserResponse::from).collect();
+    assert_eq!(owned[0].id, Uuid::nil());
+
+    let batch = UserResponse::from_rows(rows);
+    assert_eq!(batch.len(), 1);
+    assert_eq!(batch.capacity(), 1);
+    assert_eq!(batch[0].name, "John");
+}
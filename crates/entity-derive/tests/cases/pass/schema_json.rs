@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for the generated `{ENTITY}_SCHEMA_JSON` constant.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "organizations")]
+#[has_many(Member)]
+pub struct Organization {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub name: String,
+}
+
+/// Organization member.
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "members")]
+pub struct Member {
+    #[id]
+    pub id: Uuid,
+
+    /// Display name with "quotes".
+    #[field(create, update, response)]
+    #[filter(like)]
+    pub name: String,
+
+    #[field(create, response)]
+    #[belongs_to(Organization)]
+    pub organization_id: Uuid,
+
+    #[field(update, response)]
+    pub nickname: Option<String>,
+}
+
+fn main() {
+    let org: serde_json::Value = serde_json::from_str(ORGANIZATION_SCHEMA_JSON).unwrap();
+    assert_eq!(org["table"], "organizations");
+    assert_eq!(org["has_many"], serde_json::json!(["Member"]));
+
+    let member: serde_json::Value = serde_json::from_str(MEMBER_SCHEMA_JSON).unwrap();
+    assert_eq!(member["name"], "Member");
+    assert_eq!(member["schema"], "public");
+    assert_eq!(member["doc"], "Organization member.");
+
+    let fields = member["fields"].as_array().unwrap();
+    assert_eq!(fields.len(), 4);
+    assert_eq!(fields[0]["primary_key"], true);
+    assert_eq!(fields[1]["filter"], "like");
+    assert_eq!(fields[1]["doc"], "Display name with \"quotes\".");
+    assert_eq!(fields[2]["belongs_to"], "Organization");
+    assert_eq!(fields[3]["type"], "String");
+    assert_eq!(fields[3]["nullable"], true);
+    assert_eq!(fields[3]["create"], false);
+}
@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[field(response, serialize_as_string)]` in Response.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "orders")]
+pub struct Order {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response, serialize_as_string)]
+    pub number: i64,
+
+    #[field(create, response, serialize_as_string)]
+    pub parent_number: Option<i64>,
+
+    #[field(create, response)]
+    pub total: i64,
+}
+
+fn main() {
+    let order = Order {
+        id: Uuid::nil(),
+        number: 9_007_199_254_740_993,
+        parent_number: None,
+        total: 5
+    };
+
+    let response = OrderResponse::from(&order);
+    let number: i64 = response.number;
+    assert_eq!(number, 9_007_199_254_740_993);
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["number"], "9007199254740993");
+    assert_eq!(json["parent_number"], serde_json::Value::Null);
+    assert_eq!(json["total"], 5);
+
+    let json = serde_json::to_string(&OrderResponse::from(Order {
+        parent_number: Some(7),
+        ..order
+    }))
+    .unwrap();
+    assert!(json.contains("\"parent_number\":\"7\""));
+    let back: OrderResponse = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.parent_number, Some(7));
+}
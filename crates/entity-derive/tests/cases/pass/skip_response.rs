@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `skip_response`: settable on create, never returned.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub email: String,
+
+    #[field(create)]
+    #[field(skip_response)]
+    pub password_hash: String,
+}
+
+fn main() {
+    let create = CreateUserRequest {
+        email: "john@example.com".to_string(),
+        password_hash: "hashed".to_string(),
+    };
+    let user = User::from(create);
+    assert_eq!(user.password_hash, "hashed");
+
+    // Response has no password_hash field
+    let response = UserResponse::from(&user);
+    let UserResponse { id: _, email } = response;
+    assert_eq!(email, "john@example.com");
+}
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `strict_requests` rejecting unknown request fields.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users", strict_requests)]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+}
+
+fn main() {
+    let create: CreateUserRequest = serde_json::from_str(r#"{"name":"Alice"}"#).unwrap();
+    assert_eq!(create.name, "Alice");
+    assert!(serde_json::from_str::<CreateUserRequest>(r#"{"name":"Alice","nmae":"x"}"#).is_err());
+    assert!(serde_json::from_str::<UpdateUserRequest>(r#"{"is_admin":true}"#).is_err());
+
+    // Responses still tolerate extra keys
+    let response: UserResponse =
+        serde_json::from_str(r#"{"id":"00000000-0000-0000-0000-000000000000","name":"A","extra":1}"#)
+            .unwrap();
+    assert_eq!(response.name, "A");
+}
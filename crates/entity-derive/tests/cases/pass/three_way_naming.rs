@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for independent Rust, column and API names via `rename_column` and
+//! `rename_api`.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "legacy_users", migrations)]
+pub struct LegacyUser {
+    #[id]
+    #[field(rename_column = "usr_id", rename_api = "userId")]
+    pub id: Uuid,
+
+    #[field(create, update, response, rename_column = "usr_mail", rename_api = "emailAddress")]
+    #[filter]
+    pub email: String,
+
+    #[field(create, response, rename_column = "usr_nm")]
+    pub name: String,
+}
+
+fn main() {
+    assert!(LegacyUser::MIGRATION_UP.contains("usr_id UUID PRIMARY KEY"));
+    assert!(LegacyUser::MIGRATION_UP.contains("usr_mail TEXT NOT NULL"));
+    assert_eq!(
+        LegacyUserRow::SELECT_COLUMNS,
+        "usr_id AS id, usr_mail AS email, usr_nm AS name"
+    );
+
+    let request: CreateLegacyUserRequest =
+        serde_json::from_str(r#"{"emailAddress":"a@example.com","name":"Ann"}"#).unwrap();
+    assert_eq!(request.email, "a@example.com");
+
+    let user = LegacyUser {
+        id: Uuid::nil(),
+        email: request.email,
+        name: request.name
+    };
+    let json = serde_json::to_value(LegacyUserResponse::from(&user)).unwrap();
+    assert_eq!(json["userId"], Uuid::nil().to_string());
+    assert_eq!(json["emailAddress"], "a@example.com");
+    assert_eq!(json["name"], "Ann");
+}
@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for transaction isolation levels and `run_retrying`.
+
+use entity_derive::{
+    Entity,
+    transaction::{IsolationLevel, Transaction}
+};
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[entity(table = "accounts", transactions, isolation = "serializable")]
+pub struct Account {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub balance: i64,
+}
+
+#[derive(Entity)]
+#[entity(table = "transfers", transactions)]
+pub struct Transfer {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, response)]
+    pub amount: i64,
+}
+
+async fn deposit(pool: &sqlx::PgPool, id: Uuid, amount: i64) -> Result<(), sqlx::Error> {
+    Transaction::new(pool)
+        .with_accounts()
+        .run_retrying(5, |mut ctx| async move {
+            if let Some(account) = ctx.accounts().find_by_id(id).await? {
+                ctx.accounts()
+                    .update(
+                        id,
+                        UpdateAccountRequest {
+                            balance: Some(account.balance + amount)
+                        }
+                    )
+                    .await?;
+            }
+            ctx.commit().await
+        })
+        .await
+}
+
+#[tokio::main]
+async fn main() {
+    let pool = sqlx::PgPool::connect_lazy("postgres://localhost/test").unwrap();
+
+    let tx = Transaction::new(&pool).with_transfers();
+    assert_eq!(tx.isolation_level(), None);
+
+    let tx = Transaction::new(&pool)
+        .isolation(IsolationLevel::RepeatableRead)
+        .with_transfers()
+        .with_accounts();
+    assert_eq!(tx.isolation_level(), Some(IsolationLevel::Serializable));
+
+    let tx = tx.isolation(IsolationLevel::ReadCommitted);
+    assert_eq!(tx.isolation_level(), Some(IsolationLevel::ReadCommitted));
+
+    let _ = deposit;
+}
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[column(transparent = "T")]` newtype columns.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Email(String);
+
+impl From<String> for Email {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Email> for String {
+    fn from(value: Email) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Cents(i64);
+
+impl From<i64> for Cents {
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Cents> for i64 {
+    fn from(value: Cents) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "accounts", migrations)]
+#[projection(Contact: id, email)]
+pub struct Account {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    #[filter(eq)]
+    #[column(transparent = "String", unique)]
+    pub email: Email,
+
+    #[field(create, update, response)]
+    #[column(transparent = "i64")]
+    pub balance: Option<Cents>,
+}
+
+fn main() {
+    let row = AccountRow {
+        id: Uuid::nil(),
+        email: "a@example.com".to_string(),
+        balance: Some(42)
+    };
+    let account = Account::from(row.clone());
+    assert_eq!(account.email, Email("a@example.com".to_string()));
+    assert_eq!(account.balance, Some(Cents(42)));
+
+    let insertable = InsertableAccount::from(account.clone());
+    let email: String = insertable.email;
+    let balance: Option<i64> = insertable.balance;
+    assert_eq!((email.as_str(), balance), ("a@example.com", Some(42)));
+
+    let contact = AccountContact::from(row);
+    assert_eq!(contact.email, Email("a@example.com".to_string()));
+
+    let response = AccountResponse::from(account);
+    assert_eq!(response.balance, Some(Cents(42)));
+
+    let query = AccountQuery {
+        email: Some(Email("a@example.com".to_string())),
+        ..Default::default()
+    };
+    assert!(query.email.is_some());
+
+    assert!(Account::MIGRATION_UP.contains("email TEXT NOT NULL UNIQUE"));
+    assert!(Account::MIGRATION_UP.contains("balance BIGINT"));
+}
@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test that an id type without `Hash` or `Eq` compiles without `ids_map`.
+
+use entity_derive::Entity;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct TagId(pub i64);
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "tags", sql = "trait")]
+pub struct Tag {
+    #[id]
+    pub id: TagId,
+
+    #[field(response)]
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for the bulk `update_many` repository method.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "orders")]
+pub struct Order {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub status: String,
+
+    #[field(create, update, response)]
+    pub note: Option<String>,
+}
+
+async fn _mark_shipped(pool: &sqlx::PgPool, ids: Vec<Uuid>) -> Result<u64, sqlx::Error> {
+    let patch = UpdateOrderRequest {
+        status: Some("shipped".to_string()),
+        note: None
+    };
+    pool.update_many(ids, patch).await
+}
+
+fn main() {}
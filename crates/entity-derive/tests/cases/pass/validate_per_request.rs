@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[validate_create(...)]` and `#[validate_update(...)]`.
+
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "users")]
+pub struct User {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    #[validate(email)]
+    pub email: String,
+
+    #[field(create, update)]
+    #[validate_create(length(min = 8))]
+    #[validate_update(length(min = 12))]
+    pub password: String,
+}
+
+fn main() {
+    let _create = CreateUserRequest {
+        email: "a@example.com".to_string(),
+        password: "secret-password".to_string()
+    };
+    let _update = UpdateUserRequest {
+        email: None,
+        password: Some("longer-secret-password".to_string())
+    };
+}
@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test for `#[field(update, write_once)]`.
+
+use chrono::{DateTime, Utc};
+use entity_derive::Entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Entity)]
+#[entity(table = "accounts", transactions, audit = "audit_logs")]
+pub struct Account {
+    #[id]
+    pub id: Uuid,
+
+    #[field(create, update, response)]
+    pub name: String,
+
+    #[field(update, response, write_once)]
+    pub onboarded_at: Option<DateTime<Utc>>,
+}
+
+fn main() {
+    let _ = UpdateAccountRequest {
+        name: None,
+        onboarded_at: Some(Utc::now()),
+    };
+
+    fn _check_trait<T: AccountRepository>() {}
+    _check_trait::<sqlx::PgPool>();
+}
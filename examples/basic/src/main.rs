@@ -68,7 +68,7 @@ pub struct User {
     pub email: String,
 
     /// Hashed password (never exposed in API).
-    #[field(create, skip)]
+    #[field(create, skip_response)]
     pub password_hash: String,
 
     /// Account creation timestamp.
@@ -7,6 +7,11 @@
 //! - `#[filter]` for exact match
 //! - `#[filter(like)]` for pattern matching
 //! - `#[filter(range)]` for date/number ranges
+//!
+//! The handler maps its own query parameters onto `ProductQuery`, keeping
+//! the `price_min`/`price_max` names and a default `limit` of 20.
+//! `api(list_query)` can generate a list handler instead; it reads
+//! `ProductQuery` directly, so the parameters become `price_from`/`price_to`.
 
 use axum::{
     Json, Router,
@@ -113,12 +118,10 @@ impl From<ProductQueryParams> for ProductQuery {
             price_from: p.price_min,
             price_to: p.price_max,
             stock_from: p.stock_min,
-            stock_to: None,
             active: p.active,
-            created_at_from: None,
-            created_at_to: None,
             limit: Some(p.limit),
             offset: Some(p.offset),
+            ..Default::default()
         }
     }
 }
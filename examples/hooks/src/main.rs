@@ -41,7 +41,7 @@ pub struct User {
     #[field(create, update, response)]
     pub name: String,
 
-    #[field(create, skip)]
+    #[field(create, skip_response)]
     pub password_hash: String,
 
     #[field(response)]
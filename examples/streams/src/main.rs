@@ -61,6 +61,7 @@ pub struct AuditLog {
 // Generated streaming methods:
 // - stream_all() -> impl Stream<Item = Result<AuditLog>>
 // - stream_filtered(filter) -> impl Stream<Item = Result<AuditLog>>
+// - stream_query(query) -> impl Stream<Item = Result<AuditLog>> (batched, lazy)
 // - stream_by_action(action) -> impl Stream
 // - stream_by_resource_type(type) -> impl Stream
 